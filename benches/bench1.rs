@@ -426,6 +426,17 @@ fn add_2d_broadcast_1_to_2(bench: &mut test::Bencher)
     });
 }
 
+#[bench]
+fn add_2d_broadcast_column(bench: &mut test::Bencher)
+{
+    let mut a = Array2::<i32>::zeros((ADD2DSZ, ADD2DSZ));
+    let b = Array2::<i32>::zeros((ADD2DSZ, 1));
+    let bv = b.view();
+    bench.iter(|| {
+        a += &bv;
+    });
+}
+
 #[bench]
 fn add_2d_broadcast_0_to_2(bench: &mut test::Bencher)
 {
@@ -980,6 +991,31 @@ fn dot_extended(bench: &mut test::Bencher)
     })
 }
 
+#[bench]
+fn dot_f32_4096_rows(bench: &mut test::Bencher)
+{
+    let n = 4096;
+    let av = Array::<f32, _>::zeros((n, n));
+    bench.iter(|| av.row(1).dot(&av.row(2)));
+}
+
+#[bench]
+fn dot_f32_4096_columns(bench: &mut test::Bencher)
+{
+    let n = 4096;
+    let av = Array::<f32, _>::zeros((n, n));
+    bench.iter(|| av.column(1).dot(&av.column(2)));
+}
+
+#[bench]
+fn dot_f32_4096_strided(bench: &mut test::Bencher)
+{
+    let n = 4096;
+    let av = Array::<f32, _>::zeros(3 * n);
+    let (a, b) = (av.slice(s![..;3]), av.slice(s![1..;3]));
+    bench.iter(|| a.dot(&b));
+}
+
 const MEAN_SUM_N: usize = 127;
 
 fn range_mat(m: Ix, n: Ix) -> Array2<f32>
@@ -1004,6 +1040,35 @@ fn mean_axis1(bench: &mut test::Bencher)
     bench.iter(|| a.mean_axis(Axis(1)));
 }
 
+const TOPK_N: usize = 4096;
+const TOPK_K: usize = 8;
+
+fn topk_mat() -> Array2<f64>
+{
+    Array::from_shape_fn((16, TOPK_N), |(i, j)| ((i * 7919 + j * 104729) % TOPK_N) as f64)
+}
+
+#[bench]
+fn topk_axis1(bench: &mut test::Bencher)
+{
+    let a = topk_mat();
+    bench.iter(|| a.topk_axis(Axis(1), TOPK_K));
+}
+
+#[bench]
+fn topk_axis1_sort_then_slice(bench: &mut test::Bencher)
+{
+    let a = topk_mat();
+    bench.iter(|| {
+        a.map_axis(Axis(1), |lane| {
+            let mut v = lane.to_vec();
+            v.sort_by(|x, y| y.partial_cmp(x).unwrap());
+            v.truncate(TOPK_K);
+            v
+        })
+    });
+}
+
 #[bench]
 fn sum_axis0(bench: &mut test::Bencher)
 {
@@ -37,3 +37,42 @@ fn map_stride(bench: &mut test::Bencher)
     let av = a.slice(s![.., ..;2]);
     bench.iter(|| av.map(|&x| 2. * x));
 }
+
+const TRANSPOSE_N: usize = 8192;
+
+#[bench]
+fn transpose_as_standard_layout_f32(bench: &mut Bencher)
+{
+    let a = Array::<f32, _>::zeros((TRANSPOSE_N, TRANSPOSE_N));
+    bench.iter(|| a.t().as_standard_layout().into_owned())
+}
+
+#[bench]
+fn transpose_iter_collect_f32(bench: &mut Bencher)
+{
+    let a = Array::<f32, _>::zeros((TRANSPOSE_N, TRANSPOSE_N));
+    bench.iter(|| Array::from_iter(a.t().iter().cloned()))
+}
+
+const TRANSPOSE_3D_N: usize = 256;
+
+#[bench]
+fn transpose_3d_as_standard_layout_f32(bench: &mut Bencher)
+{
+    let a = Array::<f32, _>::zeros((TRANSPOSE_3D_N, TRANSPOSE_3D_N, TRANSPOSE_3D_N));
+    bench.iter(|| a.t().as_standard_layout().into_owned())
+}
+
+#[bench]
+fn transpose_3d_iter_collect_f32(bench: &mut Bencher)
+{
+    let a = Array::<f32, _>::zeros((TRANSPOSE_3D_N, TRANSPOSE_3D_N, TRANSPOSE_3D_N));
+    bench.iter(|| Array::from_iter(a.t().iter().cloned()))
+}
+
+#[bench]
+fn transpose_inplace_f32(bench: &mut Bencher)
+{
+    let mut a = Array::<f32, _>::zeros((TRANSPOSE_N, TRANSPOSE_N));
+    bench.iter(|| a.transpose_inplace())
+}
@@ -51,6 +51,30 @@ fn gemv_64_32(bench: &mut Bencher)
     });
 }
 
+#[bench]
+fn gemv_4096_c(bench: &mut Bencher)
+{
+    let a = Array::<f64, _>::zeros((4096, 4096));
+    let (m, n) = a.dim();
+    let x = Array::zeros(n);
+    let mut y = Array::zeros(m);
+    bench.iter(|| {
+        general_mat_vec_mul(1.0, &a, &x, 1.0, &mut y);
+    });
+}
+
+#[bench]
+fn gemv_4096_f(bench: &mut Bencher)
+{
+    let a = Array::<f64, _>::zeros((4096, 4096).f());
+    let (m, n) = a.dim();
+    let x = Array::zeros(n);
+    let mut y = Array::zeros(m);
+    bench.iter(|| {
+        general_mat_vec_mul(1.0, &a, &x, 1.0, &mut y);
+    });
+}
+
 #[bench]
 fn cgemm_100(bench: &mut Bencher)
 {
@@ -0,0 +1,62 @@
+#![feature(test)]
+#![allow(
+    clippy::many_single_char_names, clippy::deref_addrof, clippy::unreadable_literal, clippy::many_single_char_names
+)]
+extern crate test;
+use test::black_box;
+use test::Bencher;
+
+use ndarray::prelude::*;
+use ndarray::InlineArray;
+
+const CHAIN: usize = 100;
+
+fn rotation(i: usize) -> Array2<f64>
+{
+    let (s, c) = (i as f64 / 10.).sin_cos();
+    array![[c, -s, 0.], [s, c, 0.], [0., 0., 1.]]
+}
+
+#[bench]
+fn chained_dot_3x3_heap(bench: &mut Bencher)
+{
+    let ms: Vec<_> = (0..CHAIN).map(rotation).collect();
+    bench.iter(|| {
+        let mut acc = Array2::<f64>::eye(3);
+        for m in &ms {
+            acc = acc.dot(m);
+        }
+        black_box(acc)
+    });
+}
+
+#[bench]
+fn chained_dot_3x3_inline(bench: &mut Bencher)
+{
+    let ms: Vec<_> = (0..CHAIN)
+        .map(|i| InlineArray::<f64, Ix2, 9>::from_array(&rotation(i)).unwrap())
+        .collect();
+    bench.iter(|| {
+        let mut acc = InlineArray::<f64, Ix2, 9>::from_shape_fn((3, 3), |(i, j)| (i == j) as u8 as f64).unwrap();
+        for m in &ms {
+            acc = acc.dot(m);
+        }
+        black_box(acc)
+    });
+}
+
+#[bench]
+fn add_3x3_heap(bench: &mut Bencher)
+{
+    let a = rotation(1);
+    let b = rotation(2);
+    bench.iter(|| black_box(&a + &b));
+}
+
+#[bench]
+fn add_3x3_inline(bench: &mut Bencher)
+{
+    let a = InlineArray::<f64, Ix2, 9>::from_array(&rotation(1)).unwrap();
+    let b = InlineArray::<f64, Ix2, 9>::from_array(&rotation(2)).unwrap();
+    bench.iter(|| black_box(&a + &b));
+}
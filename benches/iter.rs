@@ -45,6 +45,38 @@ fn iter_sum_2d_transpose(bench: &mut Bencher)
     bench.iter(|| a.iter().sum::<i32>());
 }
 
+#[bench]
+fn iter_for_each_2d_f_order(bench: &mut Bencher)
+{
+    let a = Array::<i32, _>::zeros((1024, 64).f());
+    let a = a.slice(s![..-1, ..]);
+    bench.iter(|| {
+        let mut sum = 0;
+        a.iter().for_each(|&x| sum += x);
+        sum
+    });
+}
+
+#[bench]
+fn for_each_2d_f_order(bench: &mut Bencher)
+{
+    let a = Array::<i32, _>::zeros((1024, 64).f());
+    let a = a.slice(s![..-1, ..]);
+    bench.iter(|| {
+        let mut sum = 0;
+        a.for_each(|&x| sum += x);
+        sum
+    });
+}
+
+#[bench]
+fn iter_memory_order_sum_2d_f_order(bench: &mut Bencher)
+{
+    let a = Array::<i32, _>::zeros((1024, 64).f());
+    let a = a.slice(s![..-1, ..]);
+    bench.iter(|| a.iter_memory_order().sum::<i32>());
+}
+
 #[bench]
 fn iter_filter_sum_2d_u32(bench: &mut Bencher)
 {
@@ -29,3 +29,33 @@ fn clip(bench: &mut Bencher)
         })
     });
 }
+
+#[bench]
+fn exp_mapv(bench: &mut Bencher)
+{
+    let a = Array::linspace(-5., 5., N * 2)
+        .into_shape_with_order([X, Y * 2])
+        .unwrap();
+    bench.iter(|| a.mapv(f64::exp));
+}
+
+#[bench]
+fn exp_method(bench: &mut Bencher)
+{
+    let a = Array::linspace(-5., 5., N * 2)
+        .into_shape_with_order([X, Y * 2])
+        .unwrap();
+    bench.iter(|| a.exp());
+}
+
+#[bench]
+fn exp_inplace(bench: &mut Bencher)
+{
+    let mut a = Array::linspace(-5., 5., N * 2)
+        .into_shape_with_order([X, Y * 2])
+        .unwrap();
+    bench.iter(|| {
+        a.exp_inplace();
+        a.ln_inplace();
+    });
+}
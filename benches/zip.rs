@@ -76,6 +76,54 @@ fn zip_ff(b: &mut Bencher)
     b.iter(|| zip_copy(&data, &mut out));
 }
 
+pub fn zip_add<'a, A, P, Q, R>(x: P, y: Q, out: R)
+where
+    P: IntoNdProducer<Item = &'a A>,
+    Q: IntoNdProducer<Item = &'a A, Dim = P::Dim>,
+    R: IntoNdProducer<Item = &'a mut A, Dim = P::Dim>,
+    A: Copy + std::ops::Add<Output = A> + 'a,
+{
+    Zip::from(out).and(x).and(y).for_each(|o, &x, &y| {
+        *o = x + y;
+    });
+}
+
+#[bench]
+fn zip_add_c_out_ff_in(b: &mut Bencher)
+{
+    let x: Array3<f32> = Array3::zeros(SZ3.f());
+    let y: Array3<f32> = Array3::zeros(SZ3.f());
+    let mut out = Array3::zeros(SZ3);
+    b.iter(|| zip_add(&x, &y, &mut out));
+}
+
+#[bench]
+fn zip_add_f_out_cc_in(b: &mut Bencher)
+{
+    let x: Array3<f32> = Array3::zeros(SZ3);
+    let y: Array3<f32> = Array3::zeros(SZ3);
+    let mut out = Array3::zeros(SZ3.f());
+    b.iter(|| zip_add(&x, &y, &mut out));
+}
+
+#[bench]
+fn zip_add_c_out_cf_in(b: &mut Bencher)
+{
+    let x: Array3<f32> = Array3::zeros(SZ3);
+    let y: Array3<f32> = Array3::zeros(SZ3.f());
+    let mut out = Array3::zeros(SZ3);
+    b.iter(|| zip_add(&x, &y, &mut out));
+}
+
+#[bench]
+fn zip_add_f_out_cf_in(b: &mut Bencher)
+{
+    let x: Array3<f32> = Array3::zeros(SZ3);
+    let y: Array3<f32> = Array3::zeros(SZ3.f());
+    let mut out = Array3::zeros(SZ3.f());
+    b.iter(|| zip_add(&x, &y, &mut out));
+}
+
 #[bench]
 fn zip_indexed_cc(b: &mut Bencher)
 {
@@ -131,3 +179,26 @@ fn slice_split_zip_ff(b: &mut Bencher)
     let mut out = out.slice_mut(s![1.., 1.., 1..]);
     b.iter(|| zip_copy_split(&data, &mut out));
 }
+
+#[bench]
+fn sq_dist_temporary(b: &mut Bencher)
+{
+    let x: Array3<f32> = Array3::ones(SZ3);
+    let y: Array3<f32> = Array3::zeros(SZ3.f());
+    b.iter(|| {
+        let diff = &x - &y;
+        (&diff * &diff).sum()
+    });
+}
+
+#[bench]
+fn sq_dist_zip_fold(b: &mut Bencher)
+{
+    let x: Array3<f32> = Array3::ones(SZ3);
+    let y: Array3<f32> = Array3::zeros(SZ3.f());
+    b.iter(|| {
+        Zip::from(&x)
+            .and(&y)
+            .fold(0., |acc, &x, &y| acc + (x - y) * (x - y))
+    });
+}
@@ -0,0 +1,110 @@
+//! An array backed by a read-only memory-mapped file.
+#[cfg(feature = "std")]
+use std::fs::{self, File};
+#[cfg(feature = "std")]
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::mem;
+#[cfg(feature = "std")]
+use std::ptr::NonNull;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+#[cfg(feature = "std")]
+use memmap2::Mmap;
+#[cfg(feature = "std")]
+use ndarray::prelude::*;
+#[cfg(feature = "std")]
+use ndarray::{ExternalArray, ExternalStorage};
+
+/// The `f64` elements of a memory-mapped file, stored in native byte order.
+///
+/// The map is shared through an `Arc`, so cloning the storage (and the
+/// array) does not copy the file contents.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct MmapF64
+{
+    map: Arc<Mmap>,
+}
+
+#[cfg(feature = "std")]
+impl MmapF64
+{
+    fn open(file: &File) -> std::io::Result<Self>
+    {
+        // Safety: the file must not be modified while it is mapped, which
+        // holds in this example since we created it and don't write to it.
+        let map = unsafe { Mmap::map(file)? };
+        // Mappings are page aligned, which is enough for f64.
+        assert_eq!(map.as_ptr() as usize % mem::align_of::<f64>(), 0);
+        assert_eq!(map.len() % mem::size_of::<f64>(), 0);
+        Ok(MmapF64 { map: Arc::new(map) })
+    }
+}
+
+// Safety: the map's address and length never change, every bit pattern is a
+// valid f64, and the mapping is read-only and lives as long as the `Arc`.
+#[cfg(feature = "std")]
+unsafe impl ExternalStorage for MmapF64
+{
+    type Elem = f64;
+
+    fn as_ptr(&self) -> NonNull<f64>
+    {
+        if self.map.is_empty() {
+            NonNull::dangling()
+        } else {
+            NonNull::new(self.map.as_ptr() as *mut f64).unwrap()
+        }
+    }
+
+    fn len(&self) -> usize
+    {
+        self.map.len() / mem::size_of::<f64>()
+    }
+}
+
+#[cfg(feature = "std")]
+fn main() -> std::io::Result<()>
+{
+    let path = std::env::temp_dir().join(format!("ndarray-mmap-example-{}.bin", std::process::id()));
+    let (rows, cols) = (100, 8);
+    {
+        let mut file = File::create(&path)?;
+        for i in 0..rows * cols {
+            file.write_all(&(i as f64).to_ne_bytes())?;
+        }
+    }
+
+    let storage = MmapF64::open(&File::open(&path)?)?;
+    let a = ExternalArray::from_storage((rows, cols), storage).expect("file has rows * cols elements");
+
+    // the usual read-only array API works on the mapped data
+    let total: f64 = a.sum();
+    assert_eq!(total, (0..rows * cols).sum::<usize>() as f64);
+    let column_means = a.mean_axis(Axis(0)).unwrap();
+    println!("Column means: {:8.2}", column_means);
+
+    let corner = a.slice(s![..3, ..3]);
+    println!("Top left corner:\n{:6.1}", corner);
+
+    let ones = Array::ones(cols);
+    let row_sums = a.dot(&ones);
+    assert_eq!(row_sums, a.sum_axis(Axis(1)));
+
+    let evens = a.iter().filter(|&&x| x % 2. == 0.).count();
+    assert_eq!(evens, rows * cols / 2);
+
+    // cloning shares the map
+    let b = a.clone();
+    assert_eq!(b.storage().map.as_ptr(), a.storage().map.as_ptr());
+    drop(a);
+    drop(b);
+
+    fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "std"))]
+fn main() {}
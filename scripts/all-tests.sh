@@ -31,9 +31,13 @@ cargo test --verbose --no-default-features
 cargo test --release --verbose --no-default-features
 cargo build --verbose --features "$FEATURES"
 cargo test --verbose --features "$FEATURES"
+# nalgebra needs a newer Rust than the MSRV
+([ "$CHANNEL" = "1.57.0" ] || cargo test --verbose --features nalgebra)
 cargo test --manifest-path=ndarray-rand/Cargo.toml --no-default-features --verbose
 cargo test --manifest-path=ndarray-rand/Cargo.toml --features quickcheck --verbose
 cargo test --manifest-path=xtest-serialization/Cargo.toml --verbose
+# Builds ndarray without the std feature, from a no_std crate
+cargo test --manifest-path=xtest-nostd/Cargo.toml --verbose
 cargo test --manifest-path=xtest-blas/Cargo.toml --verbose --features openblas-system
 cargo test --examples
 cargo test --manifest-path=xtest-numeric/Cargo.toml --verbose
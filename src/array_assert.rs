@@ -0,0 +1,168 @@
+//! Implementation of the array assertion macros.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use std::fmt::{Debug, Write};
+
+use crate::imp_prelude::*;
+
+/// The largest number of mismatching elements listed in a failure message.
+const MAX_REPORTED: usize = 5;
+
+/// Assert that two arrays have the same shape and equal elements.
+///
+/// The arguments can be any mix of owned arrays and array views (anything
+/// that an [`ArrayView`] can be created from by reference), and their
+/// dimension types may differ as long as the shapes are equal.
+///
+/// On failure, the panic message gives the shape, the number of mismatching
+/// elements and the first few mismatching indices with both values, instead
+/// of printing both arrays in full. Arrays of different shapes fail with a
+/// message giving both shapes.
+///
+/// ```
+/// use ndarray::{array, assert_arrays_eq, Array2};
+///
+/// let a = array![[1, 2], [3, 4]];
+/// let b = Array2::from_shape_fn((2, 2), |(i, j)| 2 * i + j + 1);
+/// assert_arrays_eq!(a, b.view());
+/// ```
+///
+/// ```should_panic
+/// use ndarray::{array, assert_arrays_eq};
+///
+/// let a = array![[1, 2], [3, 4]];
+/// let b = array![[1, 2], [3, 5]];
+/// // panics with:
+/// //
+/// // assertion failed: `a == b`: arrays differ in 1 of 4 elements
+/// //   shape: [2, 2]
+/// //   at [1, 1]: left = 4, right = 5
+/// assert_arrays_eq!(a, b);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::array_assert::assert_arrays_eq(
+            $crate::ArrayView::from(&$left),
+            $crate::ArrayView::from(&$right),
+            stringify!($left),
+            stringify!($right),
+        )
+    };
+}
+
+/// Assert that two arrays have the same shape and that their elements are
+/// approximately equal, using an absolute difference of at most `epsilon`.
+///
+/// This is like [`assert_arrays_eq!`], but compares the elements with
+/// [`approx::AbsDiffEq`]; the failure message also gives `epsilon`.
+///
+/// **Requires crate feature `"approx"`**
+///
+/// ```
+/// use ndarray::{array, assert_arrays_abs_diff_eq};
+///
+/// let a = array![0.1 + 0.2, 1.];
+/// assert_arrays_abs_diff_eq!(a, array![0.3, 1.], 1e-12);
+/// ```
+#[cfg(feature = "approx")]
+#[macro_export]
+macro_rules! assert_arrays_abs_diff_eq {
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        $crate::array_assert::assert_arrays_abs_diff_eq(
+            $crate::ArrayView::from(&$left),
+            $crate::ArrayView::from(&$right),
+            $epsilon,
+            stringify!($left),
+            stringify!($right),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_arrays_eq<A, B, D, E>(
+    left: ArrayView<'_, A, D>, right: ArrayView<'_, B, E>, left_expr: &str, right_expr: &str,
+) where
+    A: PartialEq<B> + Debug,
+    B: Debug,
+    D: Dimension,
+    E: Dimension,
+{
+    let header = format!("assertion failed: `{} == {}`", left_expr, right_expr);
+    if let Some(msg) = compare(left.into_dyn(), right.into_dyn(), &header, |a, b| a == b) {
+        panic!("{}", msg);
+    }
+}
+
+#[cfg(feature = "approx")]
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_arrays_abs_diff_eq<A, B, D, E>(
+    left: ArrayView<'_, A, D>, right: ArrayView<'_, B, E>, epsilon: A::Epsilon, left_expr: &str, right_expr: &str,
+) where
+    A: approx::AbsDiffEq<B> + Debug,
+    A::Epsilon: Clone + Debug,
+    B: Debug,
+    D: Dimension,
+    E: Dimension,
+{
+    let header = format!(
+        "assertion failed: `abs_diff_eq({}, {}, epsilon = {:?})`",
+        left_expr, right_expr, epsilon
+    );
+    if let Some(msg) = compare(left.into_dyn(), right.into_dyn(), &header, |a, b| a.abs_diff_eq(b, epsilon.clone())) {
+        panic!("{}", msg);
+    }
+}
+
+/// Compare `left` and `right` elementwise with `eq`, and return the failure
+/// message if they differ.
+fn compare<A, B, F>(left: ArrayViewD<'_, A>, right: ArrayViewD<'_, B>, header: &str, mut eq: F) -> Option<String>
+where
+    A: Debug,
+    B: Debug,
+    F: FnMut(&A, &B) -> bool,
+{
+    let mut msg = String::new();
+    if left.shape() != right.shape() {
+        let _ = write!(
+            msg,
+            "{}: arrays have different shapes\n  left shape:  {:?}\n  right shape: {:?}",
+            header,
+            left.shape(),
+            right.shape()
+        );
+        return Some(msg);
+    }
+
+    let mut mismatches = 0;
+    let mut reported = String::new();
+    for ((index, a), b) in left.indexed_iter().zip(&right) {
+        if !eq(a, b) {
+            mismatches += 1;
+            if mismatches <= MAX_REPORTED {
+                let _ = write!(reported, "\n  at {:?}: left = {:?}, right = {:?}", index.slice(), a, b);
+            }
+        }
+    }
+    if mismatches == 0 {
+        return None;
+    }
+    let _ = write!(
+        msg,
+        "{}: arrays differ in {} of {} elements\n  shape: {:?}{}",
+        header,
+        mismatches,
+        left.len(),
+        left.shape(),
+        reported
+    );
+    if mismatches > MAX_REPORTED {
+        let _ = write!(msg, "\n  ... and {} more", mismatches - MAX_REPORTED);
+    }
+    Some(msg)
+}
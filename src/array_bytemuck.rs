@@ -0,0 +1,226 @@
+use bytemuck::{Pod, PodCastError};
+#[cfg(feature = "std")]
+use std::{io, mem};
+
+#[cfg(feature = "std")]
+use crate::dimension::{size_of_shape_checked, IntoDimension};
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::StrideShape;
+
+/// # Byte Views
+///
+/// **Requires crate feature `"bytemuck"`**
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Pod,
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Return the memory of the array as a byte slice, if the array is
+    /// contiguous in memory (in any order).
+    ///
+    /// The bytes are in memory order, which is the logical order only if the
+    /// array is in standard layout. Return `None` if the array is not
+    /// contiguous.
+    ///
+    /// **Requires crate feature `"bytemuck"`**
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1u16, 2, 3];
+    /// let bytes = a.as_bytes().unwrap();
+    /// assert_eq!(bytes.len(), 6);
+    /// assert_eq!(&bytes[..2], &1u16.to_ne_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> Option<&[u8]>
+    where S: Data
+    {
+        self.as_slice_memory_order().map(bytemuck::cast_slice)
+    }
+
+    /// Return the memory of the array as a mutable byte slice, if the array
+    /// is contiguous in memory (in any order).
+    ///
+    /// See [`.as_bytes()`](Self::as_bytes) for the order of the bytes.
+    ///
+    /// **Requires crate feature `"bytemuck"`**
+    pub fn as_bytes_mut(&mut self) -> Option<&mut [u8]>
+    where S: DataMut
+    {
+        self.as_slice_memory_order_mut()
+            .map(bytemuck::cast_slice_mut)
+    }
+}
+
+/// # Byte Views
+///
+/// **Requires crate feature `"bytemuck"`**
+impl<'a, A, D> ArrayView<'a, A, D>
+where
+    A: Pod,
+    D: Dimension,
+{
+    /// Create a read-only array view of the elements stored in `bytes`.
+    ///
+    /// The bytes are reinterpreted in place, without copying, and the shape
+    /// and strides are then checked like in
+    /// [`ArrayView::from_shape`](ArrayView::from_shape).
+    ///
+    /// **Errors** with `ErrorKind::IncompatibleLayout` if `bytes` is not
+    /// aligned for `A`, with `ErrorKind::IncompatibleShape` if the length of
+    /// `bytes` is not a multiple of the size of `A`, and with
+    /// `ErrorKind::OutOfBounds` if there are too few elements for the shape.
+    ///
+    /// **Requires crate feature `"bytemuck"`**
+    ///
+    /// ```
+    /// use ndarray::{array, ArrayView};
+    ///
+    /// let a = array![[1.5f32, 2.], [3., 4.]];
+    /// let bytes = a.as_bytes().unwrap();
+    /// let b = ArrayView::<f32, _>::from_bytes((2, 2), bytes).unwrap();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn from_bytes<Sh>(shape: Sh, bytes: &'a [u8]) -> Result<Self, ShapeError>
+    where Sh: Into<StrideShape<D>>
+    {
+        let xs = bytemuck::try_cast_slice(bytes).map_err(pod_cast_error)?;
+        Self::from_shape(shape, xs)
+    }
+}
+
+/// # Byte Views
+///
+/// **Requires crate feature `"bytemuck"`**
+impl<'a, A, D> ArrayViewMut<'a, A, D>
+where
+    A: Pod,
+    D: Dimension,
+{
+    /// Create a read-write array view of the elements stored in `bytes`.
+    ///
+    /// See [`ArrayView::from_bytes`](ArrayView::from_bytes) for the errors.
+    ///
+    /// **Requires crate feature `"bytemuck"`**
+    pub fn from_bytes_mut<Sh>(shape: Sh, bytes: &'a mut [u8]) -> Result<Self, ShapeError>
+    where Sh: Into<StrideShape<D>>
+    {
+        let xs = bytemuck::try_cast_slice_mut(bytes).map_err(pod_cast_error)?;
+        Self::from_shape(shape, xs)
+    }
+}
+
+/// # Raw Binary I/O
+///
+/// **Requires crate features `"bytemuck"` and `"std"`**
+#[cfg(feature = "std")]
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Pod,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Write the elements of the array to `writer` as native-endian bytes, in
+    /// logical order (row major order).
+    ///
+    /// This is a plain dump of the elements, without a header; the shape must
+    /// be recorded separately to read the elements back with
+    /// [`Array::read_raw`](ArrayBase::read_raw). Arrays that are not in
+    /// standard layout are written row by row, without copying the whole array.
+    ///
+    /// **Errors** with any error returned by `writer`.
+    ///
+    /// **Requires crate features `"bytemuck"` and `"std"`**
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = array![[1u16, 2], [3, 4]];
+    /// let mut buf = Vec::new();
+    /// a.t().write_raw(&mut buf).unwrap();
+    /// assert_eq!(buf.len(), 8);
+    ///
+    /// let b = Array2::<u16>::read_raw((2, 2), &mut &buf[..]).unwrap();
+    /// assert_eq!(b, a.t());
+    /// ```
+    pub fn write_raw<W>(&self, writer: &mut W) -> io::Result<()>
+    where W: io::Write + ?Sized
+    {
+        if mem::size_of::<A>() == 0 {
+            // there are no bytes to write, and casting zero-sized elements panics
+            return Ok(());
+        }
+        if let Some(slice) = self.as_slice() {
+            return writer.write_all(bytemuck::cast_slice(slice));
+        }
+        let mut buf = Vec::new();
+        for row in self.rows() {
+            if let Some(slice) = row.as_slice() {
+                writer.write_all(bytemuck::cast_slice(slice))?;
+            } else {
+                buf.clear();
+                for elt in row {
+                    buf.extend_from_slice(bytemuck::bytes_of(elt));
+                }
+                writer.write_all(&buf)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// # Raw Binary I/O
+///
+/// **Requires crate features `"bytemuck"` and `"std"`**
+#[cfg(feature = "std")]
+impl<A, D> Array<A, D>
+where
+    A: Pod,
+    D: Dimension,
+{
+    /// Create an array with the given shape, in standard layout, by reading
+    /// exactly `shape.size() * size_of::<A>()` bytes from `reader`.
+    ///
+    /// The bytes are the native-endian elements in logical order (row major
+    /// order), as written by [`.write_raw()`](ArrayBase::write_raw).
+    ///
+    /// **Errors** with an error of kind `io::ErrorKind::UnexpectedEof` if
+    /// `reader` ends before all the elements are read (including inside an
+    /// element), with `io::ErrorKind::InvalidInput` if the number of bytes
+    /// overflows `isize`, and with any other error returned by `reader`. No
+    /// array is returned in these cases.
+    ///
+    /// **Requires crate features `"bytemuck"` and `"std"`**
+    pub fn read_raw<Sh, R>(shape: Sh, reader: &mut R) -> io::Result<Self>
+    where
+        Sh: IntoDimension<Dim = D>,
+        R: io::Read + ?Sized,
+    {
+        let dim = shape.into_dimension();
+        let size = size_of_shape_checked(&dim)
+            .ok()
+            .filter(|&size| {
+                size.checked_mul(mem::size_of::<A>())
+                    .map_or(false, |n| n <= isize::MAX as usize)
+            })
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "ndarray: shape too large for read_raw"))?;
+        let mut v = vec![A::zeroed(); size];
+        // there are no bytes to read for zero-sized elements, and casting them panics
+        if mem::size_of::<A>() != 0 {
+            reader.read_exact(bytemuck::cast_slice_mut(&mut v))?;
+        }
+        // Safety: the length of `v` is the size of `dim`, checked above
+        unsafe { Ok(Self::from_shape_vec_unchecked(dim, v)) }
+    }
+}
+
+fn pod_cast_error(err: PodCastError) -> ShapeError
+{
+    match err {
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned | PodCastError::AlignmentMismatch =>
+            from_kind(ErrorKind::IncompatibleLayout),
+        PodCastError::OutputSliceWouldHaveSlop | PodCastError::SizeMismatch => from_kind(ErrorKind::IncompatibleShape),
+    }
+}
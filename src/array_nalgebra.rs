@@ -0,0 +1,174 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::convert::TryFrom;
+
+use nalgebra::{DMatrix, DVector, Scalar};
+
+use crate::error::{incompatible_shapes, ShapeError};
+use crate::imp_prelude::*;
+use crate::ShapeBuilder;
+
+/// Convert the matrix into an array in column major layout, without copying
+/// the elements.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<A> From<DMatrix<A>> for Array2<A>
+where A: Scalar
+{
+    fn from(m: DMatrix<A>) -> Self
+    {
+        let (rows, cols) = m.shape();
+        let v: Vec<A> = m.data.into();
+        // a DMatrix has exactly rows * cols elements, which fit in isize
+        unsafe { Array2::from_shape_vec_unchecked((rows, cols).f(), v) }
+    }
+}
+
+/// Convert the array into a matrix.
+///
+/// The elements are not copied if the array is in column major layout and
+/// owns no elements outside of it (for example after slicing); otherwise
+/// they are copied in column major order.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<A> From<Array2<A>> for DMatrix<A>
+where A: Scalar
+{
+    fn from(a: Array2<A>) -> Self
+    {
+        let (rows, cols) = a.dim();
+        if a.t().is_standard_layout() && a.data.len() == a.len() {
+            let (v, _) = a.into_raw_vec_and_offset();
+            DMatrix::from_vec(rows, cols, v)
+        } else {
+            DMatrix::from(a.view())
+        }
+    }
+}
+
+/// Copy the elements of the array view into a matrix.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<'a, A> From<ArrayView2<'a, A>> for DMatrix<A>
+where A: Scalar
+{
+    fn from(a: ArrayView2<'a, A>) -> Self
+    {
+        let (rows, cols) = a.dim();
+        DMatrix::from_iterator(rows, cols, a.t().iter().cloned())
+    }
+}
+
+/// Create an array view of the elements of the matrix, in column major
+/// layout.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<'a, A> From<&'a DMatrix<A>> for ArrayView2<'a, A>
+where A: Scalar
+{
+    fn from(m: &'a DMatrix<A>) -> Self
+    {
+        let (rows, cols) = m.shape();
+        // a DMatrix has exactly rows * cols elements, in column major order
+        unsafe { ArrayView2::from_shape_ptr((rows, cols).f(), m.as_ptr()) }
+    }
+}
+
+/// Convert the vector into an array, without copying the elements.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<A> From<DVector<A>> for Array1<A>
+where A: Scalar
+{
+    fn from(v: DVector<A>) -> Self
+    {
+        let v: Vec<A> = v.data.into();
+        Array1::from(v)
+    }
+}
+
+/// Convert the array into a vector.
+///
+/// The elements are not copied if the array is contiguous and owns no
+/// elements outside of it; otherwise they are copied.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<A> From<Array1<A>> for DVector<A>
+where A: Scalar
+{
+    fn from(a: Array1<A>) -> Self
+    {
+        if a.is_standard_layout() && a.data.len() == a.len() {
+            let (v, _) = a.into_raw_vec_and_offset();
+            DVector::from_vec(v)
+        } else {
+            DVector::from(a.view())
+        }
+    }
+}
+
+/// Copy the elements of the array view into a vector.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<'a, A> From<ArrayView1<'a, A>> for DVector<A>
+where A: Scalar
+{
+    fn from(a: ArrayView1<'a, A>) -> Self
+    {
+        DVector::from_iterator(a.len(), a.iter().cloned())
+    }
+}
+
+/// Convert the array into a matrix, like the conversion from `Array2`.
+///
+/// **Errors** if the array does not have two axes.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<A> TryFrom<ArrayD<A>> for DMatrix<A>
+where A: Scalar
+{
+    type Error = ShapeError;
+
+    fn try_from(a: ArrayD<A>) -> Result<Self, ShapeError>
+    {
+        a.into_dimensionality::<Ix2>().map(DMatrix::from)
+    }
+}
+
+/// Convert the array into a vector, like the conversion from `Array1`.
+///
+/// **Errors** if the array does not have one axis.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<A> TryFrom<ArrayD<A>> for DVector<A>
+where A: Scalar
+{
+    type Error = ShapeError;
+
+    fn try_from(a: ArrayD<A>) -> Result<Self, ShapeError>
+    {
+        a.into_dimensionality::<Ix1>().map(DVector::from)
+    }
+}
+
+/// Convert the single column of the matrix into an array, without copying
+/// the elements.
+///
+/// **Errors** if the matrix does not have exactly one column.
+///
+/// **Requires crate feature `"nalgebra"`**
+impl<A> TryFrom<DMatrix<A>> for Array1<A>
+where A: Scalar
+{
+    type Error = ShapeError;
+
+    fn try_from(m: DMatrix<A>) -> Result<Self, ShapeError>
+    {
+        let (rows, cols) = m.shape();
+        if cols != 1 {
+            return Err(incompatible_shapes(&Ix2(rows, cols), &Ix2(rows, 1)));
+        }
+        let v: Vec<A> = m.data.into();
+        Ok(Array1::from(v))
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::imp_prelude::*;
+use crate::{ShapeBuilder, Slice};
+
+/// The largest number of axes of a generated array with dynamic dimension.
+const MAX_DYN_NDIM: usize = 4;
+
+/// Generate owned arrays for property testing with [`quickcheck`].
+///
+/// The number of axes is random (up to 4) for a dynamic dimension type, and
+/// the total number of elements is bounded by the size of the generator. The
+/// elements are generated with `A::arbitrary`. To also exercise code paths
+/// for other memory layouts, arrays are generated in either C or F order, and
+/// some axes are stepped or inverted, so that the result is not always
+/// contiguous.
+///
+/// Shrinking first shortens the axes, then shrinks the elements one at a
+/// time. Shrunk arrays are in standard layout.
+///
+/// **Requires crate feature `"quickcheck"`**
+impl<A, D> Arbitrary for Array<A, D>
+where
+    A: Arbitrary,
+    D: Dimension + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self
+    {
+        let ndim = D::NDIM.unwrap_or_else(|| usize::arbitrary(g) % (MAX_DYN_NDIM + 1));
+        let max_size = g.size().max(1);
+        let mut dim = D::zeros(ndim);
+        for ax in dim.slice_mut() {
+            *ax = usize::arbitrary(g) % (max_size + 1);
+        }
+        while dim.size() > max_size {
+            let longest = dim.slice_mut().iter_mut().max().unwrap();
+            *longest /= 2;
+        }
+
+        // the step used along each axis when slicing the stored array
+        let steps: Vec<isize> = (0..ndim)
+            .map(|_| *g.choose(&[1, 1, 2, -1, -2]).unwrap())
+            .collect();
+        let mut stored_dim = dim.clone();
+        for (ax, &step) in stored_dim.slice_mut().iter_mut().zip(&steps) {
+            *ax *= step.unsigned_abs();
+        }
+        let f_order = bool::arbitrary(g);
+        let mut array = Array::from_shape_simple_fn(stored_dim.set_f(f_order), || A::arbitrary(g));
+        array.slice_each_axis_inplace(|ax| Slice::new(0, None, steps[ax.axis.index()]));
+        debug_assert_eq!(array.raw_dim(), dim);
+        array
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>>
+    {
+        let array = self.as_standard_layout().into_owned();
+
+        let mut shorter = Vec::new();
+        for (i, &len) in array.shape().iter().enumerate() {
+            let mut lengths = vec![len / 2, len.saturating_sub(1)];
+            lengths.dedup();
+            for new_len in lengths.into_iter().filter(|&l| l < len) {
+                shorter.push(array.slice_axis(Axis(i), Slice::from(..new_len)).to_owned());
+            }
+        }
+
+        let elements = (0..array.len()).flat_map(move |i| {
+            let array = array.clone();
+            let elt = &array.as_slice().unwrap()[i];
+            elt.shrink().map(move |shrunk| {
+                let mut array = array.clone();
+                array.as_slice_mut().unwrap()[i] = shrunk;
+                array
+            })
+        });
+        Box::new(shorter.into_iter().chain(elements))
+    }
+}
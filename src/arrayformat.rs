@@ -5,10 +5,13 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-use super::{ArrayBase, ArrayView, Axis, Data, Dimension, NdProducer};
+use super::{ArrayBase, ArrayView, Axis, Data, Dimension};
 use crate::aliases::{Ix1, IxDyn};
+use alloc::borrow::Cow;
 use alloc::format;
-use std::fmt;
+use alloc::string::String;
+use std::fmt::{self, Write as _};
+use std::mem;
 
 /// Default threshold, below this element count, we don't ellipsize
 const ARRAY_MANY_ELEMENT_LIMIT: usize = 500;
@@ -28,33 +31,148 @@ const AXIS_2D_OVERFLOW_LIMIT: usize = 22;
 /// The string used as an ellipsis.
 const ELLIPSIS: &str = "...";
 
+/// Options for [`ArrayBase::write_formatted`] and
+/// [`ArrayBase::write_formatted_with`].
+///
+/// `FormatOptions::new()` gives the same output as the array's `Display`
+/// implementation; the builder methods adjust it from there.
+///
+/// ```
+/// use ndarray::{array, FormatOptions};
+///
+/// let a = array![[1.5, 2.25], [-3., 4.125]];
+/// let opts = FormatOptions::new().precision(2).width(6).separator(" ");
+///
+/// let mut s = String::new();
+/// a.write_formatted(&mut s, &opts).unwrap();
+/// assert_eq!(s, "[[  1.50   2.25]\n [ -3.00   4.12]]");
+/// ```
 #[derive(Clone, Debug)]
-struct FormatOptions
+pub struct FormatOptions
 {
     axis_collapse_limit: usize,
     axis_collapse_limit_next_last: usize,
     axis_collapse_limit_last: usize,
+    /// Arrays with fewer elements than this are never ellipsized
+    many_element_limit: usize,
+    precision: Option<usize>,
+    width: Option<usize>,
+    separator: Cow<'static, str>,
+    ellipsis: Cow<'static, str>,
+    line_width: Option<usize>,
+}
+
+impl Default for FormatOptions
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
 }
 
 impl FormatOptions
 {
-    pub(crate) fn default_for_array(nelem: usize, no_limit: bool) -> Self
+    /// Create the default options, matching the array's `Display` output:
+    /// elements separated by `", "`, and arrays of 500 elements or more
+    /// elided with `"..."` along their longer axes.
+    pub fn new() -> Self
     {
-        let default = Self {
+        FormatOptions {
             axis_collapse_limit: AXIS_LIMIT_STACKED,
             axis_collapse_limit_next_last: AXIS_LIMIT_COL,
             axis_collapse_limit_last: AXIS_LIMIT_ROW,
-        };
-        default.set_no_limit(no_limit || nelem < ARRAY_MANY_ELEMENT_LIMIT)
+            many_element_limit: ARRAY_MANY_ELEMENT_LIMIT,
+            precision: None,
+            width: None,
+            separator: Cow::Borrowed(", "),
+            ellipsis: Cow::Borrowed(ELLIPSIS),
+            line_width: None,
+        }
     }
 
-    fn set_no_limit(mut self, no_limit: bool) -> Self
+    /// Options for the `fmt` traits: the defaults, without elision when the
+    /// alternate flag (`{:#}`) is set.
+    fn for_formatter(f: &fmt::Formatter<'_>) -> Self
     {
-        if no_limit {
-            self.axis_collapse_limit = usize::MAX;
-            self.axis_collapse_limit_next_last = usize::MAX;
-            self.axis_collapse_limit_last = usize::MAX;
+        let opts = Self::new();
+        if f.alternate() {
+            opts.no_elision()
+        } else {
+            opts
         }
+    }
+
+    /// Format each element with `precision` digits after the decimal point,
+    /// as with `{:.precision$}`.
+    ///
+    /// Only used by [`ArrayBase::write_formatted`].
+    pub fn precision(mut self, precision: usize) -> Self
+    {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Pad each element to at least `width` characters, as with `{:width$}`.
+    ///
+    /// Only used by [`ArrayBase::write_formatted`].
+    pub fn width(mut self, width: usize) -> Self
+    {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the string written between elements of a row (default `", "`).
+    ///
+    /// Between rows and higher dimensional blocks the separator is written
+    /// with trailing whitespace removed, followed by a line break.
+    pub fn separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Set the string written in place of elided elements (default `"..."`).
+    pub fn ellipsis(mut self, ellipsis: impl Into<Cow<'static, str>>) -> Self
+    {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Elide every axis longer than `max_len`, whatever the total number of
+    /// elements; only the first and last `max_len / 2` entries of such an
+    /// axis are written, around the ellipsis.
+    ///
+    /// **Panics** if `max_len` is less than 2.
+    #[track_caller]
+    pub fn max_axis_len(mut self, max_len: usize) -> Self
+    {
+        assert!(max_len >= 2, "max_axis_len: max_len must be at least 2, got {}", max_len);
+        self.axis_collapse_limit = max_len;
+        self.axis_collapse_limit_next_last = max_len;
+        self.axis_collapse_limit_last = max_len;
+        self.many_element_limit = 0;
+        self
+    }
+
+    /// Never elide elements, however large the array.
+    pub fn no_elision(mut self) -> Self
+    {
+        self.axis_collapse_limit = usize::MAX;
+        self.axis_collapse_limit_next_last = usize::MAX;
+        self.axis_collapse_limit_last = usize::MAX;
+        self
+    }
+
+    /// Wrap rows so that lines are at most `line_width` characters long
+    /// where possible; continuation lines are indented to line up with the
+    /// first element of the row.
+    ///
+    /// A single element wider than the line is never split.
+    /// Only used by [`ArrayBase::write_formatted`] and
+    /// [`ArrayBase::write_formatted_with`].
+    pub fn line_width(mut self, line_width: usize) -> Self
+    {
+        self.line_width = Some(line_width);
         self
     }
 
@@ -70,21 +188,126 @@ impl FormatOptions
     }
 }
 
+/// A writer the array formatting code can write to.
+///
+/// Separators between the elements of a row go through `write_separator`, so
+/// that a writer can decide where to break lines.
+trait FormatSink: fmt::Write
+{
+    /// Write `separator`; `row_indent` is the column of the first element
+    /// when this separates elements of a row, and `None` otherwise.
+    fn write_separator(&mut self, separator: &str, row_indent: Option<usize>) -> fmt::Result
+    {
+        let _ = row_indent;
+        self.write_str(separator)
+    }
+}
+
+impl FormatSink for fmt::Formatter<'_> {}
+
+/// Writer used by `write_formatted`, which tracks the current column to
+/// implement `FormatOptions::line_width`.
+struct WrappingWriter<'a, W: ?Sized>
+{
+    out: &'a mut W,
+    separator: &'a str,
+    line_width: Option<usize>,
+    column: usize,
+    /// Indent of the row whose separator is held back until the width of the
+    /// next element is known
+    pending: Option<usize>,
+    /// Buffer for rendering an element before deciding where it goes
+    elem: String,
+}
+
+impl<'a, W: fmt::Write + ?Sized> WrappingWriter<'a, W>
+{
+    fn new(out: &'a mut W, opts: &'a FormatOptions) -> Self
+    {
+        WrappingWriter {
+            out,
+            separator: &opts.separator,
+            line_width: opts.line_width,
+            column: 0,
+            pending: None,
+            elem: String::new(),
+        }
+    }
+
+    fn write_elem<A, F>(&mut self, elem: &A, format: &mut F) -> fmt::Result
+    where F: FnMut(&A, &mut dyn fmt::Write) -> fmt::Result
+    {
+        let (indent, line_width) = match (self.pending.take(), self.line_width) {
+            (Some(indent), Some(line_width)) => (indent, line_width),
+            _ => return format(elem, self),
+        };
+        let mut buf = mem::take(&mut self.elem);
+        buf.clear();
+        format(elem, &mut buf)?;
+        let separator = self.separator;
+        let width = separator.chars().count() + buf.chars().count();
+        if self.column + width <= line_width || self.column <= indent {
+            self.write_str(separator)?;
+        } else {
+            self.write_str(separator.trim_end())?;
+            self.write_str("\n")?;
+            for _ in 0..indent {
+                self.write_str(" ")?;
+            }
+        }
+        self.write_str(&buf)?;
+        self.elem = buf;
+        Ok(())
+    }
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for WrappingWriter<'_, W>
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result
+    {
+        if self.pending.take().is_some() {
+            let separator = self.separator;
+            self.write_str(separator)?;
+        }
+        self.out.write_str(s)?;
+        match s.rfind('\n') {
+            Some(i) => self.column = s[i + 1..].chars().count(),
+            None => self.column += s.chars().count(),
+        }
+        Ok(())
+    }
+}
+
+impl<W: fmt::Write + ?Sized> FormatSink for WrappingWriter<'_, W>
+{
+    fn write_separator(&mut self, separator: &str, row_indent: Option<usize>) -> fmt::Result
+    {
+        match row_indent {
+            Some(indent) if self.line_width.is_some() => {
+                self.pending = Some(indent);
+                Ok(())
+            }
+            _ => self.write_str(separator),
+        }
+    }
+}
+
 /// Formats the contents of a list of items, using an ellipsis to indicate when
 /// the `length` of the list is greater than `limit`.
 ///
 /// # Parameters
 ///
-/// * `f`: The formatter.
+/// * `f`: The writer.
 /// * `length`: The length of the list.
 /// * `limit`: The maximum number of items before overflow.
 /// * `separator`: Separator to write between items.
+/// * `row_indent`: Column of the first item if the list is a row of elements.
 /// * `ellipsis`: Ellipsis for indicating overflow.
 /// * `fmt_elem`: A function that formats an element in the list, given the
-///   formatter and the index of the item in the list.
-fn format_with_overflow(
-    f: &mut fmt::Formatter<'_>, length: usize, limit: usize, separator: &str, ellipsis: &str,
-    fmt_elem: &mut dyn FnMut(&mut fmt::Formatter, usize) -> fmt::Result,
+///   writer and the index of the item in the list.
+fn format_with_overflow<W: FormatSink + ?Sized>(
+    f: &mut W, length: usize, limit: usize, separator: &str, row_indent: Option<usize>, ellipsis: &str,
+    fmt_elem: &mut dyn FnMut(&mut W, usize) -> fmt::Result,
 ) -> fmt::Result
 {
     if length == 0 {
@@ -92,45 +315,48 @@ fn format_with_overflow(
     } else if length <= limit {
         fmt_elem(f, 0)?;
         for i in 1..length {
-            f.write_str(separator)?;
+            f.write_separator(separator, row_indent)?;
             fmt_elem(f, i)?
         }
     } else {
         let edge = limit / 2;
         fmt_elem(f, 0)?;
         for i in 1..edge {
-            f.write_str(separator)?;
+            f.write_separator(separator, row_indent)?;
             fmt_elem(f, i)?;
         }
-        f.write_str(separator)?;
+        f.write_separator(separator, row_indent)?;
         f.write_str(ellipsis)?;
         for i in length - edge..length {
-            f.write_str(separator)?;
+            f.write_separator(separator, row_indent)?;
             fmt_elem(f, i)?
         }
     }
     Ok(())
 }
 
-fn format_array<A, S, D, F>(
-    array: &ArrayBase<S, D>, f: &mut fmt::Formatter<'_>, format: F, fmt_opt: &FormatOptions,
+fn format_array<A, S, D, W, F>(
+    array: &ArrayBase<S, D>, f: &mut W, mut format: F, fmt_opt: &FormatOptions,
 ) -> fmt::Result
 where
-    F: FnMut(&A, &mut fmt::Formatter<'_>) -> fmt::Result + Clone,
+    F: FnMut(&A, &mut W) -> fmt::Result,
     D: Dimension,
     S: Data<Elem = A>,
+    W: FormatSink + ?Sized,
 {
+    let elide = array.len() >= fmt_opt.many_element_limit;
     // Cast into a dynamically dimensioned view
     // This is required to be able to use `index_axis` for the recursive case
-    format_array_inner(array.view().into_dyn(), f, format, fmt_opt, 0, array.ndim())
+    format_array_inner(array.view().into_dyn(), f, &mut format, fmt_opt, elide, 0, array.ndim())
 }
 
-fn format_array_inner<A, F>(
-    view: ArrayView<A, IxDyn>, f: &mut fmt::Formatter<'_>, mut format: F, fmt_opt: &FormatOptions, depth: usize,
+fn format_array_inner<A, W, F>(
+    view: ArrayView<A, IxDyn>, f: &mut W, format: &mut F, fmt_opt: &FormatOptions, elide: bool, depth: usize,
     full_ndim: usize,
 ) -> fmt::Result
 where
-    F: FnMut(&A, &mut fmt::Formatter<'_>) -> fmt::Result + Clone,
+    F: FnMut(&A, &mut W) -> fmt::Result,
+    W: FormatSink + ?Sized,
 {
     // If any of the axes has 0 length, we return the same empty array representation
     // e.g. [[]] for 2-d arrays
@@ -138,6 +364,13 @@ where
         write!(f, "{}{}", "[".repeat(view.ndim()), "]".repeat(view.ndim()))?;
         return Ok(());
     }
+    let limit = |axis_rindex| {
+        if elide {
+            fmt_opt.collapse_limit(axis_rindex)
+        } else {
+            usize::MAX
+        }
+    };
     match view.shape() {
         // If it's 0 dimensional, we just print out the scalar
         &[] => format(&view[[]], f)?,
@@ -145,21 +378,27 @@ where
         &[len] => {
             let view = view.view().into_dimensionality::<Ix1>().unwrap();
             f.write_str("[")?;
-            format_with_overflow(f, len, fmt_opt.collapse_limit(0), ", ", ELLIPSIS, &mut |f, index| {
-                format(&view[index], f)
-            })?;
+            format_with_overflow(
+                f,
+                len,
+                limit(0),
+                &fmt_opt.separator,
+                Some(depth + 1),
+                &fmt_opt.ellipsis,
+                &mut |f, index| format(&view[index], f),
+            )?;
             f.write_str("]")?;
         }
         // For n-dimensional arrays, we proceed recursively
         shape => {
             let blank_lines = "\n".repeat(shape.len() - 2);
             let indent = " ".repeat(depth + 1);
-            let separator = format!(",\n{}{}", blank_lines, indent);
+            let separator = format!("{}\n{}{}", fmt_opt.separator.trim_end(), blank_lines, indent);
 
             f.write_str("[")?;
-            let limit = fmt_opt.collapse_limit(full_ndim - depth - 1);
-            format_with_overflow(f, shape[0], limit, &separator, ELLIPSIS, &mut |f, index| {
-                format_array_inner(view.index_axis(Axis(0), index), f, format.clone(), fmt_opt, depth + 1, full_ndim)
+            let limit = limit(full_ndim - depth - 1);
+            format_with_overflow(f, shape[0], limit, &separator, None, &fmt_opt.ellipsis, &mut |f, index| {
+                format_array_inner(view.index_axis(Axis(0), index), f, format, fmt_opt, elide, depth + 1, full_ndim)
             })?;
             f.write_str("]")?;
         }
@@ -167,6 +406,113 @@ where
     Ok(())
 }
 
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Write the array to `writer` in the same multiline style as its
+    /// `Display` implementation, using the precision, width, separators,
+    /// elision and line wrapping set in `options`.
+    ///
+    /// Elements are written one at a time, so the whole array is never
+    /// rendered into a string first. To write to an `io::Write`, see
+    /// [`.write_formatted_io()`](Self::write_formatted_io).
+    ///
+    /// ```
+    /// use ndarray::{Array, FormatOptions};
+    ///
+    /// let a = Array::from_iter(0..10);
+    /// let opts = FormatOptions::new().max_axis_len(6).separator(" ").ellipsis("..");
+    ///
+    /// let mut s = String::new();
+    /// a.write_formatted(&mut s, &opts).unwrap();
+    /// assert_eq!(s, "[0 1 2 .. 7 8 9]");
+    /// ```
+    pub fn write_formatted<W>(&self, writer: &mut W, options: &FormatOptions) -> fmt::Result
+    where
+        A: fmt::Display,
+        W: fmt::Write + ?Sized,
+    {
+        let precision = options.precision;
+        let width = options.width;
+        self.write_formatted_with(writer, options, |elt, w| match (width, precision) {
+            (None, None) => write!(w, "{}", elt),
+            (Some(width), None) => write!(w, "{:width$}", elt, width = width),
+            (None, Some(prec)) => write!(w, "{:.prec$}", elt, prec = prec),
+            (Some(width), Some(prec)) => write!(w, "{:width$.prec$}", elt, width = width, prec = prec),
+        })
+    }
+
+    /// Write the array to `writer` like [`.write_formatted()`](Self::write_formatted),
+    /// formatting each element with `format` instead of its `Display`
+    /// implementation.
+    ///
+    /// The precision and width of `options` are not used.
+    ///
+    /// ```
+    /// use ndarray::{array, FormatOptions};
+    ///
+    /// let a = array![1200., 0.5, -3.];
+    ///
+    /// let mut s = String::new();
+    /// a.write_formatted_with(&mut s, &FormatOptions::new(), |x, w| write!(w, "{:+.1e}", x))
+    ///     .unwrap();
+    /// assert_eq!(s, "[+1.2e3, +5.0e-1, -3.0e0]");
+    /// ```
+    pub fn write_formatted_with<W, F>(&self, writer: &mut W, options: &FormatOptions, mut format: F) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+        F: FnMut(&A, &mut dyn fmt::Write) -> fmt::Result,
+    {
+        let mut writer = WrappingWriter::new(writer, options);
+        format_array(self, &mut writer, |elt, w| w.write_elem(elt, &mut format), options)
+    }
+
+    /// Write the array to the `io::Write` `writer` like
+    /// [`.write_formatted()`](Self::write_formatted).
+    ///
+    /// **Errors** with the error of `writer`, or with an error of kind
+    /// `io::ErrorKind::Other` if formatting an element fails.
+    #[cfg(feature = "std")]
+    pub fn write_formatted_io<W>(&self, writer: &mut W, options: &FormatOptions) -> std::io::Result<()>
+    where
+        A: fmt::Display,
+        W: std::io::Write + ?Sized,
+    {
+        let mut adaptor = IoAdaptor {
+            inner: writer,
+            error: None,
+        };
+        match self.write_formatted(&mut adaptor, options) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(adaptor
+                .error
+                .unwrap_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "formatter error"))),
+        }
+    }
+}
+
+/// Adapts an `io::Write` to `fmt::Write`, keeping the first I/O error.
+#[cfg(feature = "std")]
+struct IoAdaptor<'a, W: ?Sized>
+{
+    inner: &'a mut W,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> fmt::Write for IoAdaptor<'_, W>
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result
+    {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
 // NOTE: We can impl other fmt traits here
 /// Format the array using `Display` and apply the formatting parameters used
 /// to each element.
@@ -177,7 +523,7 @@ where S: Data<Elem = A>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let fmt_opt = FormatOptions::default_for_array(self.len(), f.alternate());
+        let fmt_opt = FormatOptions::for_formatter(f);
         format_array(self, f, <_>::fmt, &fmt_opt)
     }
 }
@@ -191,7 +537,7 @@ where S: Data<Elem = A>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let fmt_opt = FormatOptions::default_for_array(self.len(), f.alternate());
+        let fmt_opt = FormatOptions::for_formatter(f);
         format_array(self, f, <_>::fmt, &fmt_opt)?;
 
         // Add extra information for Debug
@@ -219,7 +565,7 @@ where S: Data<Elem = A>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let fmt_opt = FormatOptions::default_for_array(self.len(), f.alternate());
+        let fmt_opt = FormatOptions::for_formatter(f);
         format_array(self, f, <_>::fmt, &fmt_opt)
     }
 }
@@ -233,7 +579,7 @@ where S: Data<Elem = A>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let fmt_opt = FormatOptions::default_for_array(self.len(), f.alternate());
+        let fmt_opt = FormatOptions::for_formatter(f);
         format_array(self, f, <_>::fmt, &fmt_opt)
     }
 }
@@ -246,7 +592,7 @@ where S: Data<Elem = A>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let fmt_opt = FormatOptions::default_for_array(self.len(), f.alternate());
+        let fmt_opt = FormatOptions::for_formatter(f);
         format_array(self, f, <_>::fmt, &fmt_opt)
     }
 }
@@ -260,7 +606,7 @@ where S: Data<Elem = A>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let fmt_opt = FormatOptions::default_for_array(self.len(), f.alternate());
+        let fmt_opt = FormatOptions::for_formatter(f);
         format_array(self, f, <_>::fmt, &fmt_opt)
     }
 }
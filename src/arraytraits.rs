@@ -25,11 +25,26 @@ use crate::{
     Zip,
 };
 
+/// Panic for an `index` that is not valid for an array of shape `dim`,
+/// saying if the index has the wrong number of axes.
 #[cold]
 #[inline(never)]
-pub(crate) fn array_out_of_bounds() -> !
+#[track_caller]
+pub(crate) fn index_out_of_bounds<D, I>(index: &I, dim: &D) -> !
+where
+    D: Dimension,
+    I: NdIndex<D>,
 {
-    panic!("ndarray: index out of bounds");
+    match index.index_ndim() {
+        Some(ndim) if ndim != dim.ndim() => panic!(
+            "ndarray: index {:?} has {} axes, but the array of shape {:?} has {} axes",
+            index,
+            ndim,
+            dim.slice(),
+            dim.ndim()
+        ),
+        _ => panic!("ndarray: index {:?} is out of bounds for array of shape {:?}", index, dim.slice()),
+    }
 }
 
 #[inline(always)]
@@ -60,7 +75,7 @@ where
             &*self.ptr.as_ptr().offset(
                 index
                     .index_checked(&self.dim, &self.strides)
-                    .unwrap_or_else(|| array_out_of_bounds()),
+                    .unwrap_or_else(|| index_out_of_bounds(&index, &self.dim)),
             )
         }
     }
@@ -83,7 +98,7 @@ where
             &mut *self.as_mut_ptr().offset(
                 index
                     .index_checked(&self.dim, &self.strides)
-                    .unwrap_or_else(|| array_out_of_bounds()),
+                    .unwrap_or_else(|| index_out_of_bounds(&index, &self.dim)),
             )
         }
     }
@@ -191,6 +206,57 @@ where S: DataOwned<Elem = A>
     }
 }
 
+impl<A, S, const N: usize> From<[A; N]> for ArrayBase<S, Ix1>
+where S: DataOwned<Elem = A>
+{
+    /// Create a one-dimensional array from the elements of an array (the
+    /// elements are moved into a new allocation).
+    ///
+    /// ```rust
+    /// use ndarray::{arr1, Array1};
+    ///
+    /// assert_eq!(Array1::from([1, 2, 3]), arr1(&[1, 2, 3]));
+    /// ```
+    fn from(xs: [A; N]) -> Self
+    {
+        Self::from_vec(Vec::from(xs))
+    }
+}
+
+impl<A> From<Array1<A>> for Vec<A>
+{
+    /// Convert a one-dimensional array to a vector of its elements, in
+    /// logical order.
+    ///
+    /// The allocation of the array is reused when the array is in standard
+    /// layout, which is the case for arrays created from vectors. Elements
+    /// outside the array (left over from slicing) are then dropped, and the
+    /// others are moved to the start of the vector if needed. Otherwise, the
+    /// elements are moved into a new vector.
+    ///
+    /// ```rust
+    /// use ndarray::Array1;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// let ptr = v.as_ptr();
+    /// let w = Vec::from(Array1::from(v));
+    /// assert_eq!(w, [1, 2, 3]);
+    /// assert_eq!(w.as_ptr(), ptr);
+    /// ```
+    fn from(array: Array1<A>) -> Self
+    {
+        if !array.is_standard_layout() {
+            return array.into_iter().collect();
+        }
+        let len = array.len();
+        let (mut v, offset) = array.into_raw_vec_and_offset();
+        let offset = offset.unwrap_or(0);
+        v.truncate(offset + len);
+        v.drain(..offset);
+        v
+    }
+}
+
 impl<A, S> FromIterator<A> for ArrayBase<S, Ix1>
 where S: DataOwned<Elem = A>
 {
@@ -212,6 +278,35 @@ where S: DataOwned<Elem = A>
     }
 }
 
+impl<A> Extend<A> for Array<A, Ix1>
+{
+    /// Append the elements of an iterable to the end of a one-dimensional array.
+    ///
+    /// See [`.push_element()`](Array::push_element) for how the array grows.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array1};
+    ///
+    /// let mut a = Array1::from(vec![0, 1]);
+    /// a.extend(2..5);
+    /// assert_eq!(a, array![0, 1, 2, 3, 4]);
+    /// ```
+    fn extend<I>(&mut self, iterable: I)
+    where I: IntoIterator<Item = A>
+    {
+        let iter = iterable.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.make_growable();
+        if lower > 0 {
+            // only an optimization; if the reservation fails, the pushes will report it
+            let _ = self.reserve(Axis(0), lower);
+        }
+        for elt in iter {
+            self.push_element(elt);
+        }
+    }
+}
+
 impl<'a, S, D> IntoIterator for &'a ArrayBase<S, D>
 where
     D: Dimension,
@@ -296,6 +391,25 @@ where
 // Vec, &[] and &mut []
 
 /// `ArrayBase` is `Sync` when the storage type is.
+///
+/// Like for `Vec<A>`, `&A` and `&mut A`: arrays and array views of both
+/// kinds are `Sync` when `A: Sync`, and `ArcArray` when `A: Send + Sync`.
+/// Raw views are neither `Send` nor `Sync`.
+///
+/// ```compile_fail
+/// use std::cell::Cell;
+/// use ndarray::ArrayViewMut1;
+///
+/// fn is_sync<T: Sync>() {}
+/// is_sync::<ArrayViewMut1<Cell<i32>>>();
+/// ```
+///
+/// ```compile_fail
+/// use ndarray::{Ix1, RawArrayView};
+///
+/// fn is_sync<T: Sync>() {}
+/// is_sync::<RawArrayView<f64, Ix1>>();
+/// ```
 unsafe impl<S, D> Sync for ArrayBase<S, D>
 where
     S: Sync + Data,
@@ -304,6 +418,33 @@ where
 }
 
 /// `ArrayBase` is `Send` when the storage type is.
+///
+/// Like for `Vec<A>`, `&A` and `&mut A`: arrays and mutable views are `Send`
+/// when `A: Send`, views when `A: Sync`, and `ArcArray` when
+/// `A: Send + Sync`. Raw views are neither `Send` nor `Sync`.
+///
+/// ```compile_fail
+/// use std::cell::Cell;
+/// use ndarray::ArrayView1;
+///
+/// fn is_send<T: Send>() {}
+/// is_send::<ArrayView1<Cell<i32>>>();
+/// ```
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use ndarray::ArcArray1;
+///
+/// fn is_send<T: Send>() {}
+/// is_send::<ArcArray1<Rc<i32>>>();
+/// ```
+///
+/// ```compile_fail
+/// use ndarray::{Ix1, RawArrayViewMut};
+///
+/// fn is_send<T: Send>() {}
+/// is_send::<RawArrayViewMut<f64, Ix1>>();
+/// ```
 unsafe impl<S, D> Send for ArrayBase<S, D>
 where
     S: Send + Data,
@@ -503,8 +644,11 @@ where
 /// elements). And the default for the dimension `()` is `()` (array has
 /// one element).
 ///
-/// Since arrays cannot grow, the intention is to use the default value as
-/// placeholder.
+/// Except for the zero-dimensional case, the default array is the same as
+/// [`Array::empty()`]: it has no elements and does not allocate, and can be
+/// used as a placeholder, for example in a struct that derives `Default`.
+/// The bound `A: Default` is only needed to create the single element of a
+/// zero-dimensional array.
 impl<A, S, D> Default for ArrayBase<S, D>
 where
     S: DataOwned<Elem = A>,
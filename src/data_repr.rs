@@ -54,6 +54,12 @@ impl<A> OwnedRepr<A>
         self.len
     }
 
+    /// Return the number of elements the allocation can hold
+    pub(crate) fn capacity(&self) -> usize
+    {
+        self.capacity
+    }
+
     pub(crate) fn as_ptr(&self) -> *const A
     {
         self.ptr.as_ptr()
@@ -23,7 +23,19 @@ use std::mem::MaybeUninit;
 use std::mem::{self, size_of};
 use std::ptr::NonNull;
 
-use crate::{ArcArray, Array, ArrayBase, CowRepr, Dimension, OwnedArcRepr, OwnedRepr, RawViewRepr, ViewRepr};
+use crate::{
+    ArcArray,
+    Array,
+    ArrayBase,
+    CowRepr,
+    Dimension,
+    ExternalRepr,
+    OwnedArcRepr,
+    OwnedRepr,
+    RawViewRepr,
+    ViewRepr,
+    Zip,
+};
 
 /// Array representation trait.
 ///
@@ -34,6 +46,9 @@ use crate::{ArcArray, Array, ArrayBase, CowRepr, Dimension, OwnedArcRepr, OwnedR
 /// ***Note:*** `RawData` is not an extension interface at this point.
 /// Traits in Rust can serve many different roles. This trait is public because
 /// it is used as a bound on public methods.
+/// To back an array with storage defined outside of ndarray, implement
+/// [`ExternalStorage`] instead and use the [`ExternalArray`](crate::ExternalArray)
+/// type.
 #[allow(clippy::missing_safety_doc)] // not implementable downstream
 pub unsafe trait RawData: Sized
 {
@@ -171,6 +186,19 @@ pub unsafe trait DataMut: Data + RawDataMut
     {
         self.try_is_unique().unwrap()
     }
+
+    /// Exchange the contents of two arrays of the same shape.
+    ///
+    /// Swaps the elements one by one unless overridden.
+    #[doc(hidden)]
+    #[track_caller]
+    fn swap_contents<D>(a: &mut ArrayBase<Self, D>, b: &mut ArrayBase<Self, D>, method: &str)
+    where
+        Self: Sized,
+        D: Dimension,
+    {
+        crate::free_functions::swap_contents_elementwise(a, b, method)
+    }
 }
 
 unsafe impl<A> RawData for RawViewRepr<*const A>
@@ -272,10 +300,16 @@ where A: Clone
         if Arc::get_mut(&mut self_.data.0).is_some() {
             return;
         }
-        if self_.dim.size() <= self_.data.0.len() / 2 {
-            // Clone only the visible elements if the current view is less than
-            // half of backing data.
-            *self_ = self_.to_owned().into_shared();
+        if self_.dim.size() < self_.data.0.len() {
+            // Clone only the visible elements if the current view does not
+            // cover all of the backing data. Keep the memory order of the
+            // elements: a contiguous view keeps its strides in `to_owned`, and
+            // otherwise the copy is in F order if the view prefers it.
+            *self_ = if self_.is_contiguous() {
+                self_.to_owned().into_shared()
+            } else {
+                Zip::from(&*self_).map_collect_owned(A::clone)
+            };
             return;
         }
         let rcvec = &mut self_.data.0;
@@ -338,7 +372,17 @@ unsafe impl<A> Data for OwnedArcRepr<A>
     }
 }
 
-unsafe impl<A> DataMut for OwnedArcRepr<A> where A: Clone {}
+unsafe impl<A> DataMut for OwnedArcRepr<A>
+where A: Clone
+{
+    #[track_caller]
+    fn swap_contents<D>(a: &mut ArrayBase<Self, D>, b: &mut ArrayBase<Self, D>, method: &str)
+    where D: Dimension
+    {
+        crate::free_functions::check_same_shape(a.shape(), b.shape(), method);
+        mem::swap(a, b);
+    }
+}
 
 unsafe impl<A> RawDataClone for OwnedArcRepr<A>
 {
@@ -405,7 +449,16 @@ unsafe impl<A> Data for OwnedRepr<A>
     }
 }
 
-unsafe impl<A> DataMut for OwnedRepr<A> {}
+unsafe impl<A> DataMut for OwnedRepr<A>
+{
+    #[track_caller]
+    fn swap_contents<D>(a: &mut ArrayBase<Self, D>, b: &mut ArrayBase<Self, D>, method: &str)
+    where D: Dimension
+    {
+        crate::free_functions::check_same_shape(a.shape(), b.shape(), method);
+        mem::swap(a, b);
+    }
+}
 
 unsafe impl<A> RawDataClone for OwnedRepr<A>
 where A: Clone
@@ -720,6 +773,139 @@ unsafe impl<'a, A> Data for CowRepr<'a, A>
 
 unsafe impl<'a, A> DataMut for CowRepr<'a, A> where A: Clone {}
 
+/// Element storage defined outside of ndarray, for arrays of type
+/// [`ExternalArray`](crate::ExternalArray).
+///
+/// Implement this trait to back an array with memory that ndarray does not
+/// allocate, for example a memory-mapped file. The array keeps the storage
+/// value alive and reads its elements through the pointer returned by
+/// [`as_ptr`](Self::as_ptr). All read-only operations of [`ArrayBase`] are
+/// available for such an array; implement [`ExternalStorageMut`] as well to
+/// make its elements writable.
+///
+/// # Safety
+///
+/// The implementer must ensure that:
+///
+/// * `as_ptr` and `len` return the same values every time they are called
+///   on the same storage value, also after the value has been moved (so
+///   inline buffers, such as a fixed size array field, can not be used).
+/// * The pointer is aligned, and the `len` elements starting at it are
+///   initialized and valid for reads for as long as the storage value lives.
+/// * The elements are not mutated by anyone else while the storage value
+///   lives, except through interior mutability of `Elem`.
+/// * If the storage implements `Clone`, the clone's elements are equal to
+///   the original's (they may be the same memory, or a copy) and the clone
+///   has the same `len`.
+/// * If the storage is `Send` or `Sync`, the elements may be read from
+///   another thread.
+pub unsafe trait ExternalStorage
+{
+    /// The element type.
+    type Elem;
+
+    /// Return a pointer to the first element of the storage.
+    fn as_ptr(&self) -> NonNull<Self::Elem>;
+
+    /// Return the number of elements of the storage.
+    fn len(&self) -> usize;
+
+    /// Return `true` if the storage has no elements.
+    fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+}
+
+/// Element storage defined outside of ndarray, with writable elements.
+///
+/// # Safety
+///
+/// In addition to the requirements of [`ExternalStorage`], the implementer
+/// must ensure that:
+///
+/// * The elements are valid for writes through the pointer returned by
+///   `as_ptr` for as long as the storage value lives.
+/// * The storage value has exclusive access to its elements: no one else
+///   reads or writes them while it lives. In particular, if the storage
+///   implements `Clone`, the clone must not share memory with the original.
+pub unsafe trait ExternalStorageMut: ExternalStorage {}
+
+unsafe impl<S> RawData for ExternalRepr<S>
+where S: ExternalStorage
+{
+    type Elem = S::Elem;
+
+    fn _data_slice(&self) -> Option<&[S::Elem]>
+    {
+        Some(self.as_slice())
+    }
+
+    fn _is_pointer_inbounds(&self, self_ptr: *const Self::Elem) -> bool
+    {
+        let slc = self.as_slice();
+        let ptr = slc.as_ptr() as *mut S::Elem;
+        let end = unsafe { ptr.add(slc.len()) };
+        self_ptr >= ptr && self_ptr <= end
+    }
+
+    private_impl! {}
+}
+
+unsafe impl<S> RawDataMut for ExternalRepr<S>
+where S: ExternalStorageMut
+{
+    #[inline]
+    fn try_ensure_unique<D>(_: &mut ArrayBase<Self, D>)
+    where
+        Self: Sized,
+        D: Dimension,
+    {
+    }
+
+    #[inline]
+    fn try_is_unique(&mut self) -> Option<bool>
+    {
+        Some(true)
+    }
+}
+
+unsafe impl<S> Data for ExternalRepr<S>
+where S: ExternalStorage
+{
+    fn into_owned<D>(self_: ArrayBase<Self, D>) -> Array<Self::Elem, D>
+    where
+        Self::Elem: Clone,
+        D: Dimension,
+    {
+        self_.to_owned()
+    }
+
+    fn try_into_owned_nocopy<D>(self_: ArrayBase<Self, D>) -> Result<Array<Self::Elem, D>, ArrayBase<Self, D>>
+    where D: Dimension
+    {
+        Err(self_)
+    }
+}
+
+unsafe impl<S> DataMut for ExternalRepr<S> where S: ExternalStorageMut {}
+
+unsafe impl<S> RawDataClone for ExternalRepr<S>
+where S: ExternalStorage + Clone
+{
+    unsafe fn clone_with_ptr(&self, ptr: NonNull<Self::Elem>) -> (Self, NonNull<Self::Elem>)
+    {
+        let u = self.clone();
+        let mut new_ptr = u.as_nonnull();
+        if size_of::<S::Elem>() != 0 {
+            let our_off =
+                (ptr.as_ptr() as isize - self.as_nonnull().as_ptr() as isize) / mem::size_of::<S::Elem>() as isize;
+            new_ptr = new_ptr.offset(our_off);
+        }
+        (u, new_ptr)
+    }
+}
+
 /// Array representation trait.
 ///
 /// The RawDataSubst trait maps the element type of array storage, while
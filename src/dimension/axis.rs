@@ -6,6 +6,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::fmt;
+
+use crate::error::{axis_out_of_bounds, axis_out_of_bounds_panic, ShapeError};
+
 /// An axis index.
 ///
 /// An axis one of an array’s “dimensions”; an *n*-dimensional array has *n*
@@ -23,6 +27,13 @@
 ///
 /// `Axis(1)` in itself is a very clear code style and the style that should be
 /// avoided is code like `1.into()`.
+///
+/// For axis numbers that come from elsewhere, for example from a user of a
+/// program, where negative numbers count from the last axis, see
+/// [`Axis::from_signed`].
+///
+/// The array methods that take an axis panic if it is out of bounds, with a
+/// message that names the axis and the number of axes of the array.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Axis(pub usize);
 
@@ -34,4 +45,59 @@ impl Axis
     {
         self.0
     }
+
+    /// Resolve a signed axis number for an array with `ndim` axes.
+    ///
+    /// Non-negative numbers are axis indices, and negative numbers count from
+    /// the last axis, like in NumPy: `-1` is the last axis and `-ndim` the
+    /// first.
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if the axis is not in the
+    /// range `-ndim .. ndim`; the error message names the axis and `ndim`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let last = Axis::from_signed(-1, a.ndim()).unwrap();
+    /// assert_eq!(last, Axis(1));
+    /// assert_eq!(a.sum_axis(last), array![6, 15]);
+    /// assert!(Axis::from_signed(2, a.ndim()).is_err());
+    /// ```
+    pub fn from_signed(axis: isize, ndim: usize) -> Result<Axis, ShapeError>
+    {
+        let index = if axis < 0 {
+            ndim.checked_sub(axis.unsigned_abs())
+        } else if (axis as usize) < ndim {
+            Some(axis as usize)
+        } else {
+            None
+        };
+        index
+            .map(Axis)
+            .ok_or_else(|| axis_out_of_bounds(axis, ndim))
+    }
+
+    /// Return the index of the axis, checked to be less than `ndim`.
+    ///
+    /// **Panics** if the axis is out of bounds, with the same message for all
+    /// the axis methods that use this check.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn checked_index(self, ndim: usize) -> usize
+    {
+        if self.0 >= ndim {
+            axis_out_of_bounds_panic(self.0, ndim)
+        }
+        self.0
+    }
+}
+
+/// Format the axis like `Axis(1)`, as in the panic messages of axis methods.
+impl fmt::Display for Axis
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "Axis({})", self.0)
+    }
 }
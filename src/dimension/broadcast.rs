@@ -27,13 +27,60 @@ where
             if *out == 1 {
                 *out = *s2
             } else if *s2 != 1 {
-                return Err(from_kind(ErrorKind::IncompatibleShape));
+                return Err(incompatible_shapes(shape1, shape2));
             }
         }
     }
     Ok(out)
 }
 
+/// How an axis of the result of broadcasting an array was obtained from the
+/// axes of the array, see [`.broadcast_with_report()`](crate::ArrayBase::broadcast_with_report).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BroadcastAxis
+{
+    /// The array has an axis of the same length, which is kept.
+    Matched,
+    /// The array has an axis of length one, which is repeated.
+    Stretched,
+    /// The array has no corresponding axis, since the result has more axes;
+    /// the array is repeated along it.
+    Prepended,
+}
+
+/// Return how `axis` of the shape `to` is obtained by broadcasting the shape
+/// `from`, comparing the shapes beginning with their last axes. Return
+/// `None` if `from` has more axes than `to`, or if the corresponding axis of
+/// `from` has a different length that is not one.
+pub(crate) fn broadcast_axis(from: &[usize], to: &[usize], axis: usize) -> Option<BroadcastAxis>
+{
+    let k = to.len().checked_sub(from.len())?;
+    if axis < k {
+        return Some(BroadcastAxis::Prepended);
+    }
+    let len = from[axis - k];
+    if len == to[axis] {
+        Some(BroadcastAxis::Matched)
+    } else if len == 1 {
+        Some(BroadcastAxis::Stretched)
+    } else {
+        None
+    }
+}
+
+/// Return the first axes of `a` and `b`, beginning with their last axes,
+/// whose lengths prevent broadcasting `a` to `b`, or if `both_ways`, `a` and
+/// `b` to a common shape. The lengths are compatible if they are equal, or
+/// the length in `a` (or with `both_ways`, in either shape) is one.
+pub(crate) fn broadcast_mismatch(a: &[usize], b: &[usize], both_ways: bool) -> Option<(usize, usize)>
+{
+    let n = a.len().min(b.len());
+    (1..=n).map(|i| (a.len() - i, b.len() - i)).find(|&(i, j)| {
+        let (x, y) = (a[i], b[j]);
+        x != y && x != 1 && (!both_ways || y != 1)
+    })
+}
+
 pub trait DimMax<Other: Dimension>
 {
     /// The resulting dimension type after broadcasting.
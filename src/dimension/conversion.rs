@@ -142,6 +142,14 @@ macro_rules! tuple_to_array {
             }
         }
 
+        impl<'a> IntoDimension for &'a [Ix; $n] {
+            type Dim = Dim<[Ix; $n]>;
+            #[inline(always)]
+            fn into_dimension(self) -> Self::Dim {
+                Dim::new(*self)
+            }
+        }
+
         impl IntoDimension for index!(tuple_type [Ix] $n) {
             type Dim = Dim<[Ix; $n]>;
             #[inline(always)]
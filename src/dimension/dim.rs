@@ -11,6 +11,7 @@ use super::IntoDimension;
 use crate::itertools::zip;
 use crate::Ix;
 use std::fmt;
+use std::slice;
 
 /// Dimension description.
 ///
@@ -59,6 +60,52 @@ impl<I> Dim<I>
     }
 }
 
+impl<I> Dim<I>
+where Dim<I>: Dimension
+{
+    /// Subtract `rhs` from `self` elementwise, returning `None` if any axis
+    /// length would underflow.
+    ///
+    /// **Panics** if the number of axes is not the same.
+    ///
+    /// ```
+    /// use ndarray::Dim;
+    ///
+    /// // output shape of a "valid" convolution
+    /// let input = Dim([10, 8]);
+    /// let kernel = Dim([3, 3]);
+    /// assert_eq!(input.checked_sub(&kernel).map(|d| d + Dim([1, 1])), Some(Dim([8, 6])));
+    /// assert_eq!(kernel.checked_sub(&input), None);
+    /// ```
+    #[track_caller]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self>
+    {
+        assert_eq!(
+            self.ndim(),
+            rhs.ndim(),
+            "ndarray: elementwise dimension arithmetic requires the same number of axes"
+        );
+        let mut res = self.clone();
+        for (x, &y) in zip(res.slice_mut(), rhs.slice()) {
+            *x = x.checked_sub(y)?;
+        }
+        Some(res)
+    }
+}
+
+impl<'a, I> IntoIterator for &'a Dim<I>
+where Dim<I>: Dimension
+{
+    type Item = &'a Ix;
+    type IntoIter = slice::Iter<'a, Ix>;
+
+    /// Iterate over the axis lengths (or index components).
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.slice().iter()
+    }
+}
+
 /// Create a new dimension value.
 #[allow(non_snake_case)]
 pub fn Dim<T>(index: T) -> T::Dim
@@ -94,6 +141,7 @@ macro_rules! impl_op {
             Dim<I>: Dimension,
         {
             type Output = Self;
+            #[track_caller]
             fn $op_m(mut self, rhs: Self) -> Self {
                 $expr!(self, &rhs);
                 self
@@ -104,6 +152,7 @@ macro_rules! impl_op {
         where
             Dim<I>: Dimension,
         {
+            #[track_caller]
             fn $opassign_m(&mut self, rhs: Self) {
                 $expr!(*self, &rhs);
             }
@@ -113,7 +162,13 @@ macro_rules! impl_op {
         where
             Dim<I>: Dimension,
         {
+            #[track_caller]
             fn $opassign_m(&mut self, rhs: &Self) {
+                assert_eq!(
+                    self.ndim(),
+                    rhs.ndim(),
+                    "ndarray: elementwise dimension arithmetic requires the same number of axes"
+                );
                 for (x, &y) in zip(self.slice_mut(), rhs.slice()) {
                     $expr!(*x, y);
                 }
@@ -189,3 +244,65 @@ impl_op!(Sub, sub, SubAssign, sub_assign, sub);
 impl_single_op!(Sub, sub, SubAssign, sub_assign, sub);
 impl_op!(Mul, mul, MulAssign, mul_assign, mul);
 impl_scalar_op!(Mul, mul, MulAssign, mul_assign, mul);
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{Dim, Dimension, IxDyn};
+
+    #[test]
+    fn elementwise_ops()
+    {
+        assert_eq!(Dim([4, 5]) + Dim([1, 2]), Dim([5, 7]));
+        assert_eq!(Dim([4, 5]) - Dim([1, 2]), Dim([3, 3]));
+        assert_eq!(Dim([4, 5]) * Dim([2, 3]), Dim([8, 15]));
+        assert_eq!(Dim([4, 5]) * 2, Dim([8, 10]));
+        assert_eq!(Dim([4]) + 1, Dim([5]));
+        assert_eq!(Dim([4]) - 1, Dim([3]));
+
+        let mut d = IxDyn(&[4, 5, 6]);
+        d += &IxDyn(&[1, 1, 1]);
+        assert_eq!(d, IxDyn(&[5, 6, 7]));
+        d -= IxDyn(&[5, 0, 1]);
+        assert_eq!(d, IxDyn(&[0, 6, 6]));
+        d *= 2;
+        assert_eq!(d, IxDyn(&[0, 12, 12]));
+        assert_eq!(d.clone() * d, IxDyn(&[0, 144, 144]));
+    }
+
+    #[test]
+    #[should_panic(expected = "same number of axes")]
+    fn dyn_rank_mismatch()
+    {
+        let _ = IxDyn(&[1, 2]) + IxDyn(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn checked_sub()
+    {
+        assert_eq!(Dim([10, 8]).checked_sub(&Dim([3, 8])), Some(Dim([7, 0])));
+        assert_eq!(Dim([10, 8]).checked_sub(&Dim([3, 9])), None);
+        assert_eq!(IxDyn(&[2]).checked_sub(&IxDyn(&[3])), None);
+    }
+
+    #[test]
+    fn index_and_iterate()
+    {
+        let mut d = Dim([2, 3, 4]);
+        d[1] = 5;
+        assert_eq!(d[1], 5);
+        assert_eq!((&d).into_iter().product::<usize>(), d.size());
+        let mut e = IxDyn(&[2, 3]);
+        e[0] += 1;
+        assert_eq!(e.into_iter().copied().collect::<alloc::vec::Vec<_>>(), [3, 3]);
+    }
+
+    #[test]
+    fn zeros_and_ones()
+    {
+        assert_eq!(IxDyn::zeros(3), IxDyn(&[0, 0, 0]));
+        assert_eq!(<Dim<[usize; 2]>>::ones(2), Dim([1, 1]));
+        assert_eq!(IxDyn::ones(5).size(), 1);
+        assert_eq!(IxDyn::ones(0).ndim(), 0);
+    }
+}
@@ -18,6 +18,7 @@ use super::ops::DimAdd;
 use super::{stride_offset, stride_offset_checked};
 use crate::itertools::{enumerate, zip};
 use crate::IntoDimension;
+use crate::Order;
 use crate::RemoveAxis;
 use crate::{ArrayView1, ArrayViewMut1};
 use crate::{Axis, DimMax};
@@ -83,12 +84,35 @@ pub trait Dimension:
     fn into_pattern(self) -> Self::Pattern;
 
     /// Compute the size of the dimension (number of elements)
+    ///
+    /// This is the product of the axis lengths: 1 for zero dimensions, and 0
+    /// if any axis has length zero. The product is not checked for overflow,
+    /// see [`.size_checked()`](Self::size_checked).
+    ///
+    /// ```
+    /// use ndarray::{Dimension, Ix0, Ix3};
+    ///
+    /// assert_eq!(Ix3(2, 3, 4).size(), 24);
+    /// assert_eq!(Ix3(2, 0, 4).size(), 0);
+    /// assert_eq!(Ix0().size(), 1);
+    /// ```
     fn size(&self) -> usize
     {
         self.slice().iter().product()
     }
 
     /// Compute the size while checking for overflow.
+    ///
+    /// Return `None` if the product of the axis lengths overflows `usize`.
+    /// Note that arrays are further limited to `isize::MAX` elements (and
+    /// bytes), which the array constructors check separately.
+    ///
+    /// ```
+    /// use ndarray::{Dimension, Ix2};
+    ///
+    /// assert_eq!(Ix2(3, 4).size_checked(), Some(12));
+    /// assert_eq!(Ix2(usize::MAX, 2).size_checked(), None);
+    /// ```
     fn size_checked(&self) -> Option<usize>
     {
         self.slice()
@@ -124,7 +148,20 @@ pub trait Dimension:
     ///
     /// If the array is non-empty, the strides result in contiguous layout; if
     /// the array is empty, the strides are all zeros.
-    #[doc(hidden)]
+    ///
+    /// The strides are in units of elements. An axis of length 1 gets the
+    /// same stride as any other axis, while a single axis of length zero
+    /// makes *all* strides zero, since no element is ever addressed.
+    /// These are the strides the array constructors use, see also
+    /// [`.strides_for()`](Self::strides_for).
+    ///
+    /// ```
+    /// use ndarray::{Dimension, Ix3};
+    ///
+    /// assert_eq!(Ix3(2, 3, 4).default_strides(), Ix3(12, 4, 1));
+    /// assert_eq!(Ix3(2, 1, 4).default_strides(), Ix3(4, 4, 1));
+    /// assert_eq!(Ix3(2, 0, 4).default_strides(), Ix3(0, 0, 0));
+    /// ```
     fn default_strides(&self) -> Self
     {
         // Compute default array strides
@@ -150,7 +187,17 @@ pub trait Dimension:
     ///
     /// If the array is non-empty, the strides result in contiguous layout; if
     /// the array is empty, the strides are all zeros.
-    #[doc(hidden)]
+    ///
+    /// This follows the same conventions as
+    /// [`.default_strides()`](Self::default_strides), with the first axis
+    /// varying fastest.
+    ///
+    /// ```
+    /// use ndarray::{Dimension, Ix3};
+    ///
+    /// assert_eq!(Ix3(2, 3, 4).fortran_strides(), Ix3(1, 2, 6));
+    /// assert_eq!(Ix3(2, 0, 4).fortran_strides(), Ix3(0, 0, 0));
+    /// ```
     fn fortran_strides(&self) -> Self
     {
         // Compute fortran array strides
@@ -172,6 +219,29 @@ pub trait Dimension:
         strides
     }
 
+    /// Returns the strides for a contiguous array with the given shape,
+    /// in row major or column major `order`.
+    ///
+    /// This is [`.default_strides()`](Self::default_strides) for
+    /// `Order::RowMajor` and [`.fortran_strides()`](Self::fortran_strides)
+    /// for `Order::ColumnMajor`; in particular, the strides of an empty
+    /// shape are all zeros.
+    ///
+    /// ```
+    /// use ndarray::{Dimension, Ix2, Order};
+    ///
+    /// let shape = Ix2(3, 4);
+    /// assert_eq!(shape.strides_for(Order::RowMajor), Ix2(4, 1));
+    /// assert_eq!(shape.strides_for(Order::ColumnMajor), Ix2(1, 3));
+    /// ```
+    fn strides_for(&self, order: Order) -> Self
+    {
+        match order {
+            Order::RowMajor => self.default_strides(),
+            Order::ColumnMajor => self.fortran_strides(),
+        }
+    }
+
     /// Creates a dimension of all zeros with the specified ndim.
     ///
     /// This method is useful for generalizing over fixed-size and
@@ -180,6 +250,18 @@ pub trait Dimension:
     /// **Panics** if `Self` has a fixed size that is not `ndim`.
     fn zeros(ndim: usize) -> Self;
 
+    /// Creates a dimension of all ones with the specified ndim.
+    ///
+    /// **Panics** if `Self` has a fixed size that is not `ndim`.
+    fn ones(ndim: usize) -> Self
+    {
+        let mut dim = Self::zeros(ndim);
+        for ax in dim.slice_mut() {
+            *ax = 1;
+        }
+        dim
+    }
+
     #[doc(hidden)]
     #[inline]
     fn first_index(&self) -> Option<Self>
@@ -217,6 +299,48 @@ pub trait Dimension:
         }
     }
 
+    #[doc(hidden)]
+    /// Iteration -- Use self as size, and return the last index, or None if
+    /// any axis has length zero.
+    #[inline]
+    fn last_index(&self) -> Option<Self>
+    {
+        let mut index = self.clone();
+        for ix in index.slice_mut() {
+            if *ix == 0 {
+                return None;
+            }
+            *ix -= 1;
+        }
+        Some(index)
+    }
+
+    #[doc(hidden)]
+    /// Iteration -- Use self as size, and return the index before `index`
+    /// or None if `index` is the first index.
+    ///
+    /// This is the reverse of `next_for`.
+    #[inline]
+    fn prev_for(&self, index: Self) -> Option<Self>
+    {
+        let mut index = index;
+        let mut done = false;
+        for (&dim, ix) in zip(self.slice(), index.slice_mut()).rev() {
+            if *ix == 0 {
+                *ix = dim - 1;
+            } else {
+                *ix -= 1;
+                done = true;
+                break;
+            }
+        }
+        if done {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
     #[doc(hidden)]
     /// Iteration -- Use self as size, and create the next index after `index`
     /// Return false if iteration is done
@@ -291,6 +415,8 @@ pub trait Dimension:
         self.slice_mut()[nd - 1] = i;
     }
 
+    /// Return `true` if the elements cover a contiguous block of memory,
+    /// in any order of axes and with any stride signs.
     #[doc(hidden)]
     fn is_contiguous(dim: &Self, strides: &Self) -> bool
     {
@@ -321,7 +447,7 @@ pub trait Dimension:
     }
 
     /// Return the axis ordering corresponding to the fastest variation
-    /// (in ascending order).
+    /// (in ascending order of absolute stride).
     ///
     /// Assumes that no stride value appears twice.
     #[doc(hidden)]
@@ -454,6 +580,11 @@ impl Dimension for Dim<[Ix; 0]>
     {
         None
     }
+    #[inline]
+    fn prev_for(&self, _index: Self) -> Option<Self>
+    {
+        None
+    }
     impl_insert_axis_array!(0);
     #[inline]
     fn try_remove_axis(&self, _ignore: Axis) -> Self::Smaller
@@ -506,6 +637,15 @@ impl Dimension for Dim<[Ix; 1]>
             None
         }
     }
+    #[inline]
+    fn prev_for(&self, mut index: Self) -> Option<Self>
+    {
+        if get!(&index, 0) == 0 {
+            return None;
+        }
+        getm!(index, 0) -= 1;
+        Some(index)
+    }
 
     #[inline]
     fn equal(&self, rhs: &Self) -> bool
@@ -562,6 +702,15 @@ impl Dimension for Dim<[Ix; 1]>
         }
     }
 
+    #[inline]
+    fn last_index(&self) -> Option<Self>
+    {
+        match get!(self, 0) {
+            0 => None,
+            n => Some(Ix1(n - 1)),
+        }
+    }
+
     /// Self is an index, return the stride offset
     #[inline(always)]
     fn stride_offset(index: &Self, stride: &Self) -> isize
@@ -647,6 +796,23 @@ impl Dimension for Dim<[Ix; 2]>
         Some(Ix2(i, j))
     }
 
+    #[inline]
+    fn prev_for(&self, index: Self) -> Option<Self>
+    {
+        let mut i = get!(&index, 0);
+        let mut j = get!(&index, 1);
+        let jmax = get!(self, 1);
+        if j == 0 {
+            if i == 0 {
+                return None;
+            }
+            i -= 1;
+            j = jmax;
+        }
+        j -= 1;
+        Some(Ix2(i, j))
+    }
+
     #[inline]
     fn equal(&self, rhs: &Self) -> bool
     {
@@ -736,6 +902,18 @@ impl Dimension for Dim<[Ix; 2]>
         }
     }
 
+    #[inline]
+    fn last_index(&self) -> Option<Self>
+    {
+        let m = get!(self, 0);
+        let n = get!(self, 1);
+        if m != 0 && n != 0 {
+            Some(Ix2(m - 1, n - 1))
+        } else {
+            None
+        }
+    }
+
     /// Self is an index, return the stride offset
     #[inline(always)]
     fn stride_offset(index: &Self, strides: &Self) -> isize
@@ -839,6 +1017,29 @@ impl Dimension for Dim<[Ix; 3]>
         Some(Ix3(i, j, k))
     }
 
+    #[inline]
+    fn prev_for(&self, index: Self) -> Option<Self>
+    {
+        let mut i = get!(&index, 0);
+        let mut j = get!(&index, 1);
+        let mut k = get!(&index, 2);
+        let jmax = get!(self, 1);
+        let kmax = get!(self, 2);
+        if k == 0 {
+            if j == 0 {
+                if i == 0 {
+                    return None;
+                }
+                i -= 1;
+                j = jmax;
+            }
+            j -= 1;
+            k = kmax;
+        }
+        k -= 1;
+        Some(Ix3(i, j, k))
+    }
+
     /// Self is an index, return the stride offset
     #[inline]
     fn stride_offset(index: &Self, strides: &Self) -> isize
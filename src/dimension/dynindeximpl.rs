@@ -253,8 +253,7 @@ impl RemoveAxis for Dim<IxDynImpl>
 {
     fn remove_axis(&self, axis: Axis) -> Self
     {
-        debug_assert!(axis.index() < self.ndim());
-        Dim::new(self.ix().remove(axis.index()))
+        Dim::new(self.ix().remove(axis.checked_index(self.ndim())))
     }
 }
 
@@ -271,4 +270,25 @@ impl IxDyn
             Dim(vec![0; n])
         }
     }
+
+    /// Create a new dimension value from the axis lengths in `ix`.
+    ///
+    /// This is the same as [`IxDyn(ix)`](crate::IxDyn()); the lengths are
+    /// copied into the dimension, and shapes of up to four axes are stored
+    /// inline, without allocating.
+    ///
+    /// ```
+    /// use ndarray::{ArrayD, IxDyn, ShapeBuilder};
+    ///
+    /// // shape from a file header
+    /// let header: &[usize] = &[2, 3, 4];
+    /// let a = ArrayD::<f32>::zeros(IxDyn::from_slice(header).f());
+    /// assert_eq!(a.shape(), header);
+    /// assert_eq!(a.strides(), &[1, 2, 6]);
+    /// ```
+    #[inline]
+    pub fn from_slice(ix: &[Ix]) -> IxDyn
+    {
+        Dim(ix)
+    }
 }
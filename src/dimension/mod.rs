@@ -6,7 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::error::{self, from_kind, ErrorKind, ShapeError};
 use crate::shape_builder::Strides;
 use crate::slice::SliceArg;
 use crate::{Ix, Ixs, Slice, SliceInfoElem};
@@ -14,7 +14,7 @@ use num_integer::div_floor;
 
 pub use self::axes::{Axes, AxisDescription};
 pub use self::axis::Axis;
-pub use self::broadcast::DimMax;
+pub use self::broadcast::{BroadcastAxis, DimMax};
 pub use self::conversion::IntoDimension;
 pub use self::dim::*;
 pub use self::dimension_trait::Dimension;
@@ -53,8 +53,9 @@ pub fn stride_offset(n: Ix, stride: Ix) -> isize
 /// Check whether the given `dim` and `stride` lead to overlapping indices
 ///
 /// There is overlap if, when iterating through the dimensions in order of
-/// increasing stride, the current stride is less than or equal to the maximum
-/// possible offset along the preceding axes. (Axes of length ≤1 are ignored.)
+/// increasing absolute stride, the current absolute stride is less than or
+/// equal to the maximum possible distance along the preceding axes. (Axes of
+/// length ≤1 are ignored.) The sign of a stride does not matter.
 pub fn dim_stride_overlap<D: Dimension>(dim: &D, strides: &D) -> bool
 {
     let order = strides._fastest_varying_stride_order();
@@ -100,6 +101,25 @@ pub fn size_of_shape_checked<D: Dimension>(dim: &D) -> Result<usize, ShapeError>
     }
 }
 
+/// Returns the number of elements of an owned array with shape `dim` and
+/// elements of type `A`, if it can be allocated.
+///
+/// In addition to the checks of `size_of_shape_checked`, this checks that the
+/// size of the elements in bytes does not exceed `isize::MAX`, which is the
+/// largest allocation that is allowed. Returns `ErrorKind::Overflow` if
+/// either check fails.
+///
+/// All constructors that allocate the elements of a new array should use
+/// this function (or a wrapper around it) to compute the length.
+pub(crate) fn size_of_array_checked<A, D: Dimension>(dim: &D) -> Result<usize, ShapeError>
+{
+    let size = size_of_shape_checked(dim)?;
+    match size.checked_mul(mem::size_of::<A>()) {
+        Some(bytes) if bytes <= isize::MAX as usize => Ok(size),
+        _ => Err(from_kind(ErrorKind::Overflow)),
+    }
+}
+
 /// Checks whether the given data and dimension meet the invariants of the
 /// `ArrayBase` type, assuming the strides are created using
 /// `dim.default_strides()` or `dim.fortran_strides()`.
@@ -141,11 +161,31 @@ pub(crate) fn can_index_slice_not_custom<D: Dimension>(data_len: usize, dim: &D)
     let len = size_of_shape_checked(dim)?;
     // Condition 2.
     if len > data_len {
-        return Err(from_kind(ErrorKind::OutOfBounds));
+        return Err(error::data_too_short(dim, None, len, data_len));
     }
     Ok(())
 }
 
+/// Return a mask of the listed `axes` of an array with `ndim` axes: one at
+/// each listed axis and zero elsewhere.
+///
+/// **Errors** if an axis is out of bounds or listed more than once.
+pub(crate) fn axes_mask<D: Dimension>(ndim: usize, axes: &[Axis]) -> Result<D, ShapeError>
+{
+    let mut mask = D::zeros(ndim);
+    for &axis in axes {
+        let index = axis.index();
+        if index >= ndim {
+            return Err(error::axis_out_of_bounds(index as isize, ndim));
+        }
+        if mask[index] != 0 {
+            return Err(error::repeated_axis(index));
+        }
+        mask[index] = 1;
+    }
+    Ok(mask)
+}
+
 /// Returns the absolute difference in units of `A` between least and greatest
 /// address accessible by moving along all axes.
 ///
@@ -251,15 +291,10 @@ fn can_index_slice_impl<D: Dimension>(
 ) -> Result<(), ShapeError>
 {
     // Check condition 3.
-    let is_empty = dim.slice().iter().any(|&d| d == 0);
-    if is_empty && max_offset > data_len {
-        return Err(from_kind(ErrorKind::OutOfBounds));
-    }
-    if !is_empty && max_offset >= data_len {
-        return Err(from_kind(ErrorKind::OutOfBounds));
-    }
+    can_index_slice_bounds(max_offset, data_len, dim, strides)?;
 
     // Check condition 4.
+    let is_empty = dim.slice().contains(&0);
     if !is_empty && dim_stride_overlap(dim, strides) {
         return Err(from_kind(ErrorKind::Unsupported));
     }
@@ -267,6 +302,31 @@ fn can_index_slice_impl<D: Dimension>(
     Ok(())
 }
 
+/// Checks conditions 1 to 3 of `can_index_slice`, but allows elements to be
+/// referenced by more than one index.
+///
+/// This is only sufficient for read-only views, where it is fine for several
+/// indices to refer to the same element.
+pub(crate) fn can_index_slice_overlapping<A, D: Dimension>(data: &[A], dim: &D, strides: &D) -> Result<(), ShapeError>
+{
+    // Check conditions 1 and 2 and calculate `max_offset`.
+    let max_offset = max_abs_offset_check_overflow::<A, _>(dim, strides)?;
+    can_index_slice_bounds(max_offset, data.len(), dim, strides)
+}
+
+/// Checks condition 3 of `can_index_slice`.
+fn can_index_slice_bounds<D: Dimension>(
+    max_offset: usize, data_len: usize, dim: &D, strides: &D,
+) -> Result<(), ShapeError>
+{
+    let is_empty = dim.slice().contains(&0);
+    let needed = if is_empty { max_offset } else { max_offset + 1 };
+    if needed > data_len {
+        return Err(error::data_too_short(dim, Some(strides), needed, data_len));
+    }
+    Ok(())
+}
+
 /// Stride offset checked general version (slices)
 #[inline]
 pub fn stride_offset_checked(dim: &[Ix], strides: &[Ix], index: &[Ix]) -> Option<isize>
@@ -320,13 +380,14 @@ where D: Dimension
     #[inline]
     fn axis(&self, axis: Axis) -> Ix
     {
-        self[axis.index()]
+        self[axis.checked_index(self.ndim())]
     }
 
     #[inline]
     fn set_axis(&mut self, axis: Axis, value: Ix)
     {
-        self[axis.index()] = value;
+        let index = axis.checked_index(self.ndim());
+        self[index] = value;
     }
 }
 
@@ -335,13 +396,13 @@ impl DimensionExt for [Ix]
     #[inline]
     fn axis(&self, axis: Axis) -> Ix
     {
-        self[axis.index()]
+        self[axis.checked_index(self.len())]
     }
 
     #[inline]
     fn set_axis(&mut self, axis: Axis, value: Ix)
     {
-        self[axis.index()] = value;
+        self[axis.checked_index(self.len())] = value;
     }
 }
 
@@ -353,14 +414,16 @@ impl DimensionExt for [Ix]
 // FIXME: Move to Dimension trait
 pub fn do_collapse_axis<D: Dimension>(dims: &mut D, strides: &D, axis: usize, index: usize) -> isize
 {
+    let axis = Axis(axis).checked_index(dims.ndim());
     let dim = dims.slice()[axis];
     let stride = strides.slice()[axis];
     ndassert!(
         index < dim,
-        "collapse_axis: Index {} must be less than axis length {} for \
-         array with shape {:?}",
+        "collapse_axis: Index {} must be less than axis length {} along \
+         Axis({}) for array with shape {:?}",
         index,
         dim,
+        axis,
         *dims
     );
     dims.slice_mut()[axis] = 1;
@@ -387,24 +450,25 @@ pub fn abs_index(len: Ix, index: Ixs) -> Ix
 fn to_abs_slice(axis_len: usize, slice: Slice) -> (usize, usize, isize)
 {
     let Slice { start, end, step } = slice;
-    let start = abs_index(axis_len, start);
-    let mut end = abs_index(axis_len, end.unwrap_or(axis_len as isize));
-    if end < start {
-        end = start;
-    }
+    let end = end.unwrap_or(axis_len as isize);
+    // check the bounds before resolving negative indices, which would
+    // otherwise overflow
+    let in_bounds = |i: isize| i.unsigned_abs() <= axis_len;
     ndassert!(
-        start <= axis_len,
-        "Slice begin {} is past end of axis of length {}",
+        in_bounds(start),
+        "Slice begin {} is out of bounds for axis of length {}",
         start,
         axis_len,
     );
     ndassert!(
-        end <= axis_len,
-        "Slice end {} is past end of axis of length {}",
+        in_bounds(end),
+        "Slice end {} is out of bounds for axis of length {}",
         end,
         axis_len,
     );
     ndassert!(step != 0, "Slice stride must not be zero");
+    let start = abs_index(axis_len, start);
+    let end = Ord::max(abs_index(axis_len, end), start);
     (start, end, step)
 }
 
@@ -744,32 +808,6 @@ where D: Dimension
     }
 }
 
-/// Move the axis which has the smallest absolute stride and a length
-/// greater than one to be the last axis.
-pub fn move_min_stride_axis_to_last<D>(dim: &mut D, strides: &mut D)
-where D: Dimension
-{
-    debug_assert_eq!(dim.ndim(), strides.ndim());
-    match dim.ndim() {
-        0 | 1 => {}
-        2 =>
-            if dim[1] <= 1 || dim[0] > 1 && (strides[0] as isize).abs() < (strides[1] as isize).abs() {
-                dim.slice_mut().swap(0, 1);
-                strides.slice_mut().swap(0, 1);
-            },
-        n => {
-            if let Some(min_stride_axis) = (0..n)
-                .filter(|&ax| dim[ax] > 1)
-                .min_by_key(|&ax| (strides[ax] as isize).abs())
-            {
-                let last = n - 1;
-                dim.slice_mut().swap(last, min_stride_axis);
-                strides.slice_mut().swap(last, min_stride_axis);
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod test
 {
@@ -779,9 +817,11 @@ mod test
         can_index_slice_not_custom,
         extended_gcd,
         max_abs_offset_check_overflow,
+        size_of_array_checked,
         slice_min_max,
         slices_intersect,
         solve_linear_diophantine_eq,
+        stride_offset_checked,
         IntoDimension,
     };
     use crate::error::{from_kind, ErrorKind};
@@ -845,6 +885,39 @@ mod test
         max_abs_offset_check_overflow::<i32, _>(&dim, &strides).unwrap_err();
     }
 
+    #[test]
+    fn size_of_array_checked_limits()
+    {
+        let max = isize::MAX as usize;
+        assert_eq!(size_of_array_checked::<u8, _>(&Ix1(max)), Ok(max));
+        assert_eq!(size_of_array_checked::<u16, _>(&Ix1(max / 2)), Ok(max / 2));
+        assert_eq!(size_of_array_checked::<u16, _>(&Ix1(max / 2 + 1)), Err(from_kind(ErrorKind::Overflow)));
+        assert_eq!(size_of_array_checked::<u64, _>(&Ix2(max / 8, 8)), Err(from_kind(ErrorKind::Overflow)));
+        assert_eq!(size_of_array_checked::<u64, _>(&Ix2(usize::MAX, 2)), Err(from_kind(ErrorKind::Overflow)));
+        assert_eq!(size_of_array_checked::<u64, _>(&Ix2(max, 0)), Ok(0));
+        assert_eq!(size_of_array_checked::<(), _>(&Ix2(max, 1)), Ok(max));
+        assert_eq!(size_of_array_checked::<(), _>(&Ix2(max, 2)), Err(from_kind(ErrorKind::Overflow)));
+    }
+
+    #[test]
+    fn stride_offset_checked_near_overflow()
+    {
+        let max = isize::MAX as usize;
+        // the largest valid offset along an axis
+        assert_eq!(stride_offset_checked(&[max], &[1], &[max - 1]), Some(isize::MAX - 1));
+        assert_eq!(stride_offset_checked(&[max], &[1], &[max]), None);
+        // negative strides
+        let neg_one = -1isize as usize;
+        assert_eq!(stride_offset_checked(&[max], &[neg_one], &[max - 1]), Some(1 - isize::MAX));
+        assert_eq!(
+            stride_offset_checked(&[2, max / 2], &[max / 2, neg_one], &[1, max / 2 - 1]),
+            Some(1)
+        );
+        assert_eq!(stride_offset_checked(&[2, 3], &[3, 1], &[2, 0]), None);
+        assert_eq!(stride_offset_checked(&[2, 3], &[3, 1], &[1]), None);
+        assert_eq!(Ix2(2, max / 2).stride_offset_checked(&Ix2(max / 2, 1), &Ix2(1, max / 2 - 1)), Some(isize::MAX - 2));
+    }
+
     #[test]
     fn can_index_slice_ix0()
     {
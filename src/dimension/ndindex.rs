@@ -23,6 +23,12 @@ pub unsafe trait NdIndex<E>: Debug
     fn index_checked(&self, dim: &E, strides: &E) -> Option<isize>;
     #[doc(hidden)]
     fn index_unchecked(&self, strides: &E) -> isize;
+    /// Return the number of axes of the index, if it is not implied by `E`.
+    #[doc(hidden)]
+    fn index_ndim(&self) -> Option<usize>
+    {
+        None
+    }
 }
 
 unsafe impl<D> NdIndex<D> for D
@@ -36,6 +42,10 @@ where D: Dimension
     {
         D::stride_offset(self, strides)
     }
+    fn index_ndim(&self) -> Option<usize>
+    {
+        Some(self.ndim())
+    }
 }
 
 unsafe impl NdIndex<Ix0> for ()
@@ -132,7 +142,6 @@ unsafe impl NdIndex<IxDyn> for Ix
     #[inline]
     fn index_checked(&self, dim: &IxDyn, strides: &IxDyn) -> Option<isize>
     {
-        debug_assert_eq!(dim.ndim(), 1);
         stride_offset_checked(dim.ix(), strides.ix(), &[*self])
     }
     #[inline(always)]
@@ -141,6 +150,53 @@ unsafe impl NdIndex<IxDyn> for Ix
         debug_assert_eq!(strides.ndim(), 1);
         stride_offset(*self, get!(strides, 0))
     }
+    fn index_ndim(&self) -> Option<usize>
+    {
+        Some(1)
+    }
+}
+
+macro_rules! ndindex_dyn_with_tuple {
+    ($([$n:expr, $($index:tt)*])+) => {
+        $(
+        // implement NdIndex<IxDyn> for (Ix, Ix) and so on
+        unsafe impl NdIndex<IxDyn> for ($(ndindex_dyn_with_tuple!(@ix $index),)*) {
+            #[inline]
+            fn index_checked(&self, dim: &IxDyn, strides: &IxDyn) -> Option<isize> {
+                stride_offset_checked(dim.ix(), strides.ix(), &[$(self.$index),*])
+            }
+
+            #[inline]
+            fn index_unchecked(&self, strides: &IxDyn) -> isize {
+                debug_assert_eq!(
+                    strides.ndim(),
+                    $n,
+                    "Attempted to index with {:?} in array with {} axes",
+                    self,
+                    strides.ndim()
+                );
+                $(
+                stride_offset(self.$index, get!(strides, $index)) +
+                )*
+                0
+            }
+
+            fn index_ndim(&self) -> Option<usize> {
+                Some($n)
+            }
+        }
+        )+
+    };
+    (@ix $index:tt) => { Ix };
+}
+
+ndindex_dyn_with_tuple! {
+    [1, 0]
+    [2, 0 1]
+    [3, 0 1 2]
+    [4, 0 1 2 3]
+    [5, 0 1 2 3 4]
+    [6, 0 1 2 3 4 5]
 }
 
 macro_rules! ndindex_with_array {
@@ -181,16 +237,14 @@ unsafe impl<const N: usize> NdIndex<IxDyn> for Dim<[Ix; N]>
     #[inline]
     fn index_checked(&self, dim: &IxDyn, strides: &IxDyn) -> Option<isize>
     {
-        debug_assert_eq!(
-            strides.ndim(),
-            N,
-            "Attempted to index with {:?} in array with {} axes",
-            self,
-            strides.ndim()
-        );
         stride_offset_checked(dim.ix(), strides.ix(), self.ix())
     }
 
+    fn index_ndim(&self) -> Option<usize>
+    {
+        Some(N)
+    }
+
     #[inline]
     fn index_unchecked(&self, strides: &IxDyn) -> isize
     {
@@ -213,16 +267,14 @@ unsafe impl<const N: usize> NdIndex<IxDyn> for [Ix; N]
     #[inline]
     fn index_checked(&self, dim: &IxDyn, strides: &IxDyn) -> Option<isize>
     {
-        debug_assert_eq!(
-            strides.ndim(),
-            N,
-            "Attempted to index with {:?} in array with {} axes",
-            self,
-            strides.ndim()
-        );
         stride_offset_checked(dim.ix(), strides.ix(), self)
     }
 
+    fn index_ndim(&self) -> Option<usize>
+    {
+        Some(N)
+    }
+
     #[inline]
     fn index_unchecked(&self, strides: &IxDyn) -> isize
     {
@@ -258,6 +310,10 @@ unsafe impl<'a> NdIndex<IxDyn> for &'a IxDyn
     {
         (**self).index_unchecked(strides)
     }
+    fn index_ndim(&self) -> Option<usize>
+    {
+        Some(self.ndim())
+    }
 }
 
 unsafe impl<'a> NdIndex<IxDyn> for &'a [Ix]
@@ -272,4 +328,8 @@ unsafe impl<'a> NdIndex<IxDyn> for &'a [Ix]
             .map(|(&s, &i)| stride_offset(i, s))
             .sum()
     }
+    fn index_ndim(&self) -> Option<usize>
+    {
+        Some(self.len())
+    }
 }
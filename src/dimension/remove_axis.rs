@@ -22,7 +22,7 @@ impl RemoveAxis for Dim<[Ix; 1]>
     #[inline]
     fn remove_axis(&self, axis: Axis) -> Ix0
     {
-        debug_assert!(axis.index() < self.ndim());
+        axis.checked_index(self.ndim());
         Ix0()
     }
 }
@@ -32,8 +32,7 @@ impl RemoveAxis for Dim<[Ix; 2]>
     #[inline]
     fn remove_axis(&self, axis: Axis) -> Ix1
     {
-        let axis = axis.index();
-        debug_assert!(axis < self.ndim());
+        let axis = axis.checked_index(self.ndim());
         if axis == 0 {
             Ix1(get!(self, 1))
         } else {
@@ -49,13 +48,13 @@ macro_rules! impl_remove_axis_array(
         {
             #[inline]
             fn remove_axis(&self, axis: Axis) -> Self::Smaller {
-                debug_assert!(axis.index() < self.ndim());
+                let axis = axis.checked_index(self.ndim());
                 let mut result = Dim([0; $n - 1]);
                 {
                     let src = self.slice();
                     let dst = result.slice_mut();
-                    dst[..axis.index()].copy_from_slice(&src[..axis.index()]);
-                    dst[axis.index()..].copy_from_slice(&src[axis.index() + 1..]);
+                    dst[..axis].copy_from_slice(&src[..axis]);
+                    dst[axis..].copy_from_slice(&src[axis + 1..]);
                 }
                 result
             }
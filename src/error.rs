@@ -6,16 +6,81 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 use super::Dimension;
+use crate::dimension::broadcast::broadcast_mismatch;
+use crate::IxDyn;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
 #[cfg(feature = "std")]
 use std::error::Error;
 use std::fmt;
 
 /// An error related to array shape or layout.
+///
+/// Errors compare equal if they have the same [`ErrorKind`]. When they are
+/// known, the shapes and the name of the operation involved are included in
+/// the error message.
 #[derive(Clone)]
 pub struct ShapeError
 {
     // we want to be able to change this representation later
     repr: ErrorKind,
+    // boxed so that the error stays small when there is no context
+    context: Option<Box<Context>>,
+}
+
+/// Details about what caused a `ShapeError`, used in its message.
+#[derive(Clone, Default)]
+struct Context
+{
+    operation: Option<&'static str>,
+    detail: Option<Detail>,
+}
+
+#[derive(Clone)]
+enum Detail
+{
+    /// The two shapes that are not compatible
+    Shapes(IxDyn, IxDyn),
+    /// A shape (and custom strides) that needs more elements than the data has
+    DataLen
+    {
+        shape: IxDyn,
+        strides: Option<IxDyn>,
+        needed: usize,
+        len: usize,
+    },
+    /// The first shape can not be broadcast to the second
+    BroadcastTo(IxDyn, IxDyn),
+    /// An axis number, and the number of axes of the array
+    Axis(isize, usize),
+    /// A key at an index that is not less than the number of groups
+    Key
+    {
+        index: usize,
+        key: usize,
+        n_groups: usize,
+    },
+    /// A block of a block matrix, at block row `row` and block column
+    /// `column`, with a length along `axis` other than that of the blocks in
+    /// the same block row (axis 0) or block column (axis 1)
+    Block
+    {
+        row: usize,
+        column: usize,
+        axis: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// A block row of a block matrix with another number of blocks than the
+    /// first block row
+    BlockRow
+    {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// An axis that is listed more than once
+    RepeatedAxis(usize),
 }
 
 impl ShapeError
@@ -32,34 +97,86 @@ impl ShapeError
     {
         from_kind(error)
     }
+
+    /// Record the name of the operation that failed, for the error message.
+    pub(crate) fn in_operation(mut self, operation: &'static str) -> Self
+    {
+        self.context.get_or_insert_with(Default::default).operation = Some(operation);
+        self
+    }
+
+    fn with_detail(k: ErrorKind, detail: Detail) -> Self
+    {
+        ShapeError {
+            repr: k,
+            context: Some(Box::new(Context {
+                operation: None,
+                detail: Some(detail),
+            })),
+        }
+    }
 }
 
 /// Error code for an error related to array shape or layout.
 ///
 /// This enumeration is not exhaustive. The representation of the enum
 /// is not guaranteed.
+///
+/// New kinds may be added in any release, so a `match` on an `ErrorKind`
+/// outside of this crate must have a wildcard arm:
+///
+/// ```
+/// use ndarray::{Array2, ErrorKind};
+///
+/// let err = Array2::from_shape_vec((2, 2), vec![1, 2, 3]).unwrap_err();
+/// let retry = match err.kind() {
+///     ErrorKind::IncompatibleShape | ErrorKind::OutOfBounds => true,
+///     _ => false,
+/// };
+/// assert!(retry);
+/// ```
+///
+/// A kind says what went wrong, not where; for the same kind of failure,
+/// different operations return the same kind. The `Display` output of a
+/// [`ShapeError`] also gives the shapes and operation involved, when known.
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug)]
 pub enum ErrorKind
 {
     /// incompatible shape
+    ///
+    /// The shapes of the operands do not match, and can not be broadcast
+    /// to each other if the operation broadcasts.
     IncompatibleShape = 1,
     /// incompatible memory layout
+    ///
+    /// The shape is fine, but the operation needs a memory layout (for
+    /// example, contiguous rows or matching alignment) that the array does
+    /// not have; a copy of the array in standard layout usually works.
     IncompatibleLayout,
     /// the shape does not fit inside type limits
     RangeLimited,
     /// out of bounds indexing
+    ///
+    /// An index, or the elements needed by a shape and strides, are not
+    /// within the data.
     OutOfBounds,
-    /// aliasing array elements
+    /// aliasing array elements, or an otherwise unsupported input
+    ///
+    /// For example, strides that make several indices refer to the same
+    /// element of a mutable array, or stacking an empty list of arrays.
     Unsupported,
     /// overflow when computing offset, length, etc.
+    ///
+    /// The number of elements, or their size in bytes, would overflow
+    /// `isize`.
     Overflow,
 }
 
 #[inline(always)]
 pub fn from_kind(k: ErrorKind) -> ShapeError
 {
-    ShapeError { repr: k }
+    ShapeError { repr: k, context: None }
 }
 
 impl PartialEq for ErrorKind
@@ -95,7 +212,70 @@ impl fmt::Display for ShapeError
             ErrorKind::Unsupported => "unsupported operation",
             ErrorKind::Overflow => "arithmetic overflow",
         };
-        write!(f, "ShapeError/{:?}: {}", self.kind(), description)
+        write!(f, "ShapeError/{:?}: {}", self.kind(), description)?;
+        if let Some(context) = &self.context {
+            match &context.detail {
+                Some(Detail::Shapes(a, b)) => write!(f, ": {:?} and {:?}", a.slice(), b.slice())?,
+                Some(Detail::DataLen {
+                    shape,
+                    strides,
+                    needed,
+                    len,
+                }) => {
+                    write!(f, ": shape {:?}", shape.slice())?;
+                    if let Some(strides) = strides {
+                        write!(f, " with strides [")?;
+                        for (i, &s) in strides.slice().iter().enumerate() {
+                            let sep = if i == 0 { "" } else { ", " };
+                            write!(f, "{}{}", sep, s as isize)?;
+                        }
+                        write!(f, "]")?;
+                    }
+                    write!(f, " needs {} elements, but the data has length {}", needed, len)?;
+                }
+                Some(Detail::BroadcastTo(a, b)) => {
+                    let axes = MismatchAxes {
+                        how: ShapeMismatch::BroadcastTo,
+                        a: a.slice(),
+                        b: b.slice(),
+                        names: ("the array", "the shape"),
+                        prefix: ", because ",
+                    };
+                    write!(f, ": can not broadcast {:?} to {:?}{}", a.slice(), b.slice(), axes)?;
+                }
+                Some(Detail::Axis(axis, ndim)) => write!(f, ": {}", AxisOutOfBounds(*axis, *ndim))?,
+                Some(Detail::Key { index, key, n_groups }) => write!(
+                    f,
+                    ": the key {} at index {} is not less than the number of groups {}",
+                    key, index, n_groups
+                )?,
+                Some(Detail::Block {
+                    row,
+                    column,
+                    axis,
+                    len,
+                    expected,
+                }) => {
+                    let (what, group) = if *axis == 0 { ("rows", "row") } else { ("columns", "column") };
+                    write!(
+                        f,
+                        ": the block at ({}, {}) has {} {}, but the blocks in its block {} have {}",
+                        row, column, len, what, group, expected
+                    )?;
+                }
+                Some(Detail::BlockRow { row, len, expected }) => write!(
+                    f,
+                    ": block row {} has {} blocks, but block row 0 has {}",
+                    row, len, expected
+                )?,
+                Some(Detail::RepeatedAxis(axis)) => write!(f, ": Axis({}) is listed more than once", axis)?,
+                None => {}
+            }
+            if let Some(operation) = context.operation {
+                write!(f, " (in {})", operation)?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -107,10 +287,280 @@ impl fmt::Debug for ShapeError
     }
 }
 
-pub fn incompatible_shapes<D, E>(_a: &D, _b: &E) -> ShapeError
+/// An error from converting the elements of an array to another type, see
+/// [`ArrayBase::cast`](crate::ArrayBase::cast).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CastError
+{
+    index: IxDyn,
+}
+
+impl CastError
+{
+    pub(crate) fn new(index: IxDyn) -> Self
+    {
+        CastError { index }
+    }
+
+    /// Return the index of the first element that could not be converted.
+    pub fn index(&self) -> &[usize]
+    {
+        self.index.slice()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CastError {}
+
+impl fmt::Display for CastError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "CastError: the element at index {:?} can not be represented in the target type", self.index())
+    }
+}
+
+/// An error from element-wise arithmetic that overflows, see for example
+/// [`ArrayBase::checked_add`](crate::ArrayBase::checked_add).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverflowError
+{
+    index: IxDyn,
+}
+
+impl OverflowError
+{
+    pub(crate) fn new(index: IxDyn) -> Self
+    {
+        OverflowError { index }
+    }
+
+    /// Return the index of the first result element that overflows.
+    pub fn index(&self) -> &[usize]
+    {
+        self.index.slice()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for OverflowError {}
+
+impl fmt::Display for OverflowError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "OverflowError: the result at index {:?} overflows the element type", self.index())
+    }
+}
+
+/// An error from solving a linear system with a singular matrix, see
+/// [`ArrayBase::solve_triangular`](crate::ArrayBase::solve_triangular).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SingularError
+{
+    index: usize,
+}
+
+impl SingularError
+{
+    pub(crate) fn new(index: usize) -> Self
+    {
+        SingularError { index }
+    }
+
+    /// Return the index of the first zero element on the diagonal.
+    pub fn index(&self) -> usize
+    {
+        self.index
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for SingularError {}
+
+impl fmt::Display for SingularError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "SingularError: the diagonal element at index {} is zero", self.index)
+    }
+}
+
+pub fn incompatible_shapes<D, E>(a: &D, b: &E) -> ShapeError
+where
+    D: Dimension,
+    E: Dimension,
+{
+    ShapeError::with_detail(ErrorKind::IncompatibleShape, Detail::Shapes(a.clone().into_dyn(), b.clone().into_dyn()))
+}
+
+/// Error for an array of shape `a` that can not be broadcast to shape `b`.
+pub(crate) fn broadcast_to<D, E>(a: &D, b: &E) -> ShapeError
 where
     D: Dimension,
     E: Dimension,
 {
-    from_kind(ErrorKind::IncompatibleShape)
+    ShapeError::with_detail(
+        ErrorKind::IncompatibleShape,
+        Detail::BroadcastTo(a.clone().into_dyn(), b.clone().into_dyn()),
+    )
+}
+
+/// Error for a shape, with custom `strides` if any, that needs `needed`
+/// elements from data of length `len`.
+pub(crate) fn data_too_short<D: Dimension>(shape: &D, strides: Option<&D>, needed: usize, len: usize) -> ShapeError
+{
+    ShapeError::with_detail(ErrorKind::OutOfBounds, Detail::DataLen {
+        shape: shape.clone().into_dyn(),
+        strides: strides.map(|s| s.clone().into_dyn()),
+        needed,
+        len,
+    })
+}
+
+/// Error for the axis number `axis` of an array with `ndim` axes.
+pub(crate) fn axis_out_of_bounds(axis: isize, ndim: usize) -> ShapeError
+{
+    ShapeError::with_detail(ErrorKind::OutOfBounds, Detail::Axis(axis, ndim))
+}
+
+/// Error for the `key` at `index` of the keys of a grouping operation, that
+/// is not less than `n_groups`.
+pub(crate) fn key_out_of_bounds(index: usize, key: usize, n_groups: usize) -> ShapeError
+{
+    ShapeError::with_detail(ErrorKind::OutOfBounds, Detail::Key { index, key, n_groups })
+}
+
+/// Error for the block at (`row`, `column`) of a block matrix, that has
+/// length `len` along `axis` instead of `expected`.
+pub(crate) fn block_mismatch(row: usize, column: usize, axis: usize, len: usize, expected: usize) -> ShapeError
+{
+    ShapeError::with_detail(ErrorKind::IncompatibleShape, Detail::Block {
+        row,
+        column,
+        axis,
+        len,
+        expected,
+    })
+}
+
+/// Error for the block row `row` of a block matrix, that has `len` blocks
+/// instead of `expected`.
+pub(crate) fn block_row_mismatch(row: usize, len: usize, expected: usize) -> ShapeError
+{
+    ShapeError::with_detail(ErrorKind::IncompatibleShape, Detail::BlockRow { row, len, expected })
+}
+
+/// Error for the axis number `axis`, that is listed more than once.
+pub(crate) fn repeated_axis(axis: usize) -> ShapeError
+{
+    ShapeError::with_detail(ErrorKind::Unsupported, Detail::RepeatedAxis(axis))
+}
+
+/// Panic because `axis` is out of bounds for an array with `ndim` axes.
+///
+/// The axis methods of arrays check their axis arguments with
+/// [`Axis::checked_index`](crate::Axis::checked_index), so that the message
+/// is the same for all of them and names the number of axes.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn axis_out_of_bounds_panic(axis: usize, ndim: usize) -> !
+{
+    panic!("ndarray: {}", AxisOutOfBounds(axis as isize, ndim))
+}
+
+/// Describes an axis number that is out of bounds for an array with a
+/// number of axes.
+struct AxisOutOfBounds(isize, usize);
+
+impl fmt::Display for AxisOutOfBounds
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "Axis({}) is out of bounds for an array with {} axes", self.0, self.1)
+    }
+}
+
+/// How the two shapes of a [`shape_mismatch_panic`] were to be combined.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum ShapeMismatch
+{
+    /// The first shape was to be broadcast to the second.
+    BroadcastTo,
+    /// The shapes were to be broadcast to a common shape.
+    CoBroadcast,
+    /// The shapes were the operands of a matrix or vector product.
+    Dot,
+}
+
+/// Panic because shapes `a` and `b` can not be combined in operation `op`.
+///
+/// The shape mismatches of the arithmetic operators, `assign`,
+/// `zip_mut_with` and `dot` all panic here, so that the message always
+/// names the operation and both shapes.
+///
+/// For broadcasting, the message also names the first axes (from the back)
+/// whose lengths do not match. The shape that is broadcast to the other is
+/// the right hand side operand.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn shape_mismatch_panic(how: ShapeMismatch, a: &[usize], b: &[usize], op: &str) -> !
+{
+    let names = match how {
+        ShapeMismatch::BroadcastTo => ("rhs", "lhs"),
+        _ => ("lhs", "rhs"),
+    };
+    let axes = MismatchAxes {
+        how,
+        a,
+        b,
+        names,
+        prefix: ": ",
+    };
+    match how {
+        ShapeMismatch::BroadcastTo => panic!("ndarray: could not broadcast {:?} to {:?} in '{}'{}", a, b, op, axes),
+        ShapeMismatch::CoBroadcast => {
+            panic!("ndarray: could not broadcast {:?} and {:?} together in '{}'{}", a, b, op, axes)
+        }
+        ShapeMismatch::Dot => panic!("ndarray: shapes {:?} and {:?} are not compatible in '{}'", a, b, op),
+    }
+}
+
+/// Describes, after `prefix`, the first axes of shapes `a` and `b` (named
+/// by `names`) that can not be broadcast as in `how`; writes nothing if
+/// there are none.
+struct MismatchAxes<'a>
+{
+    how: ShapeMismatch,
+    a: &'a [usize],
+    b: &'a [usize],
+    names: (&'a str, &'a str),
+    prefix: &'a str,
+}
+
+impl fmt::Display for MismatchAxes<'_>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let (a, b) = (self.a, self.b);
+        let (a_name, b_name) = self.names;
+        let both_ways = match self.how {
+            ShapeMismatch::BroadcastTo => false,
+            ShapeMismatch::CoBroadcast => true,
+            ShapeMismatch::Dot => return Ok(()),
+        };
+        if let Some((i, j)) = broadcast_mismatch(a, b, both_ways) {
+            write!(
+                f,
+                "{}axis {} of {} has length {} but axis {} of {} has length {}",
+                self.prefix, i, a_name, a[i], j, b_name, b[j]
+            )
+        } else if !both_ways && a.len() > b.len() {
+            write!(f, "{}{} has {} axes but {} has only {}", self.prefix, a_name, a.len(), b_name, b.len())
+        } else {
+            Ok(())
+        }
+    }
 }
@@ -9,11 +9,11 @@
 use alloc::vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
-use std::mem::{forget, size_of};
+use std::mem::{self, forget, size_of};
 use std::ptr::NonNull;
 
 use crate::imp_prelude::*;
-use crate::{dimension, ArcArray1, ArcArray2};
+use crate::{dimension, ArcArray1, ArcArray2, Zip};
 
 /// Create an **[`Array`]** with one, two or
 /// three dimensions.
@@ -235,11 +235,18 @@ pub fn arr2<A: Clone, const N: usize>(xs: &[[A; N]]) -> Array2<A>
 
 impl<A, const N: usize> From<Vec<[A; N]>> for Array2<A>
 {
-    /// Converts the `Vec` of arrays to an owned 2-D array.
+    /// Converts the `Vec` of arrays to an owned 2-D array, with one row per
+    /// array.
+    ///
+    /// The allocation of `xs` is reused, without copying the elements; see
+    /// [`Array2::try_into_fixed_rows`] for the conversion back.
     ///
     /// **Panics** if the product of non-zero axis lengths overflows `isize`.
     fn from(mut xs: Vec<[A; N]>) -> Self
     {
+        // `[A; N]` has the alignment of `A` and the size of `N` elements of
+        // `A`, so the allocation of `cap` rows is an allocation of `cap * N`
+        // elements.
         let dim = Ix2(xs.len(), N);
         let ptr = xs.as_mut_ptr();
         let cap = xs.capacity();
@@ -325,3 +332,56 @@ pub fn rcarr3<A: Clone, const N: usize, const M: usize>(xs: &[[[A; M]; N]]) -> A
 {
     arr3(xs).into_shared()
 }
+
+/// Exchange the contents of the arrays `a` and `b`.
+///
+/// If the arrays own their data (for example `Array` or `ArcArray`), the
+/// arrays themselves are swapped, which takes constant time and does not
+/// touch the elements. Otherwise, for example for array views, the elements
+/// are swapped one by one, like with
+/// [`ArrayViewMut::swap_with`](crate::ArrayViewMut::swap_with).
+///
+/// **Panics** if the shapes of `a` and `b` are not equal.
+///
+/// ```
+/// use ndarray::{array, swap_contents};
+///
+/// let mut current = array![1., 2., 3.];
+/// let mut next = array![0., 0., 0.];
+/// for _ in 0..2 {
+///     next.assign(&(&current * 2.));
+///     swap_contents(&mut current, &mut next);
+/// }
+/// assert_eq!(current, array![4., 8., 12.]);
+/// ```
+#[track_caller]
+pub fn swap_contents<S, D>(a: &mut ArrayBase<S, D>, b: &mut ArrayBase<S, D>)
+where
+    S: DataMut,
+    D: Dimension,
+{
+    S::swap_contents(a, b, "swap_contents")
+}
+
+#[track_caller]
+pub(crate) fn swap_contents_elementwise<A, S, S2, D>(a: &mut ArrayBase<S, D>, b: &mut ArrayBase<S2, D>, method: &str)
+where
+    S: DataMut<Elem = A>,
+    S2: DataMut<Elem = A>,
+    D: Dimension,
+{
+    check_same_shape(a.shape(), b.shape(), method);
+    Zip::from(a).and(b).for_each(mem::swap);
+}
+
+#[track_caller]
+pub(crate) fn check_same_shape(a: &[usize], b: &[usize], method: &str)
+{
+    assert!(
+        a == b,
+        "ndarray: {} requires arrays of the same shape, got {:?} and {:?}",
+        method,
+        a,
+        b
+    );
+}
@@ -31,6 +31,81 @@ where S: RawData<Elem = A>
         }
     }
 
+    /// Return a new array with clones of the elements for which `f` returns
+    /// `true`, in order.
+    ///
+    /// To filter an owned array in place, see [`.retain()`](Array::retain).
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![1, 2, 3, 4, 5, 6];
+    /// assert_eq!(a.slice(s![1..]).filtered(|&x| x % 2 == 0), array![2, 4, 6]);
+    /// ```
+    pub fn filtered<F>(&self, mut f: F) -> Array<A, Ix1>
+    where
+        F: FnMut(&A) -> bool,
+        A: Clone,
+        S: Data,
+    {
+        self.iter().filter(|x| f(x)).cloned().collect()
+    }
+
+    /// Return a new array with the distinct elements of the array, in
+    /// ascending order.
+    ///
+    /// For elements that are only `PartialOrd`, such as floats, see
+    /// [`.unique_axis()`](ArrayBase::unique_axis) along `Axis(0)`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![3, 1, 3, 2, 1];
+    /// assert_eq!(a.unique(), array![1, 2, 3]);
+    /// ```
+    pub fn unique(&self) -> Array<A, Ix1>
+    where
+        A: Ord + Clone,
+        S: Data,
+    {
+        let mut elements: Vec<&A> = self.iter().collect();
+        elements.sort_unstable();
+        elements.dedup();
+        elements.into_iter().cloned().collect()
+    }
+
+    /// Return the distinct elements of the array, in ascending order, and
+    /// the number of times each of them occurs.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array!["b", "a", "b", "c", "b"];
+    /// let (values, counts) = a.unique_counts();
+    /// assert_eq!(values, array!["a", "b", "c"]);
+    /// assert_eq!(counts, array![1, 3, 1]);
+    /// ```
+    pub fn unique_counts(&self) -> (Array<A, Ix1>, Array<usize, Ix1>)
+    where
+        A: Ord + Clone,
+        S: Data,
+    {
+        let mut elements: Vec<&A> = self.iter().collect();
+        elements.sort_unstable();
+        let mut values: Vec<&A> = Vec::new();
+        let mut counts = Vec::new();
+        for elt in elements {
+            match values.last() {
+                Some(&last) if *last == *elt => *counts.last_mut().unwrap() += 1,
+                _ => {
+                    values.push(elt);
+                    counts.push(1);
+                }
+            }
+        }
+        (values.into_iter().cloned().collect(), Array::from(counts))
+    }
+
     /// Rotate the elements of the array by 1 element towards the front;
     /// the former first element becomes the last.
     pub(crate) fn rotate1_front(&mut self)
@@ -7,7 +7,12 @@
 // except according to those terms.
 
 //! Methods for two-dimensional arrays.
+use num_traits::{Signed, Zero};
+use std::mem::MaybeUninit;
+use std::ops::Add;
+
 use crate::imp_prelude::*;
+use crate::Zip;
 
 /// # Methods For 2-D Arrays
 impl<A, S> ArrayBase<S, Ix2>
@@ -143,4 +148,255 @@ where S: RawData<Elem = A>
         let (m, n) = self.dim();
         m == n
     }
+
+    /// Return the sum of the elements on the main diagonal.
+    ///
+    /// For a rectangular array of shape `(m, n)`, the main diagonal has
+    /// `min(m, n)` elements, like in NumPy.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.trace(), 6);
+    /// ```
+    pub fn trace(&self) -> A
+    where
+        S: Data,
+        A: Clone + Zero + Add<Output = A>,
+    {
+        self.diag().fold(A::zero(), |acc, x| acc + x.clone())
+    }
+
+    /// Return true if the array is square and equal to its transpose, with
+    /// an absolute difference of at most `tol` between each pair of mirrored
+    /// elements.
+    ///
+    /// Rectangular arrays are never symmetric. The transpose is not
+    /// computed, the elements are compared through a transposed view.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.],
+    ///                [2.001, 3.]];
+    /// assert!(a.is_symmetric(1e-2));
+    /// assert!(!a.is_symmetric(1e-4));
+    /// ```
+    pub fn is_symmetric(&self, tol: A) -> bool
+    where
+        S: Data,
+        A: Clone + Signed + PartialOrd,
+    {
+        self.is_square()
+            && Zip::from(self)
+                .and(self.t())
+                .all(|a, b| (a.clone() - b.clone()).abs() <= tol)
+    }
+
+    /// Transpose the square array in place, without allocating.
+    ///
+    /// Each element is swapped with its mirror image across the main
+    /// diagonal, so the shape and strides of the array are unchanged. Use
+    /// [`.reversed_axes()`](Self::reversed_axes) or [`.t()`](Self::t) instead
+    /// to transpose just by changing the strides.
+    ///
+    /// **Panics** if the array is not square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6],
+    ///                    [7, 8, 9]];
+    /// a.transpose_inplace();
+    /// assert_eq!(a, array![[1, 4, 7],
+    ///                      [2, 5, 8],
+    ///                      [3, 6, 9]]);
+    /// assert!(a.is_standard_layout());
+    /// ```
+    #[track_caller]
+    pub fn transpose_inplace(&mut self)
+    where S: DataMut
+    {
+        assert!(self.is_square(), "transpose_inplace: the array must be square");
+        let n = self.nrows();
+        let mut view = self.view_mut();
+        // Swap in tiles so that both the rows and the columns that are being
+        // swapped stay in cache.
+        for i0 in (0..n).step_by(TRANSPOSE_TILE) {
+            let i1 = usize::min(i0 + TRANSPOSE_TILE, n);
+            for j0 in (i0..n).step_by(TRANSPOSE_TILE) {
+                let j1 = usize::min(j0 + TRANSPOSE_TILE, n);
+                for i in i0..i1 {
+                    for j in usize::max(j0, i + 1)..j1 {
+                        // Safe because i, j < n and the view holds its data uniquely.
+                        unsafe {
+                            view.uswap((i, j), (j, i));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return a view of the array with the order of the columns reversed
+    /// (flipped left to right).
+    ///
+    /// This does not copy any elements; the view has a negative column stride.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.fliplr(), array![[3, 2, 1],
+    ///                               [6, 5, 4]]);
+    /// ```
+    pub fn fliplr(&self) -> ArrayView2<'_, A>
+    where S: Data
+    {
+        let mut view = self.view();
+        view.invert_axis(Axis(1));
+        view
+    }
+
+    /// Return a view of the array with the order of the rows reversed
+    /// (flipped upside down).
+    ///
+    /// This does not copy any elements; the view has a negative row stride.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.flipud(), array![[4, 5, 6],
+    ///                               [1, 2, 3]]);
+    /// ```
+    pub fn flipud(&self) -> ArrayView2<'_, A>
+    where S: Data
+    {
+        let mut view = self.view();
+        view.invert_axis(Axis(0));
+        view
+    }
+
+    /// Return a view of the array rotated by `k` quarter turns counterclockwise.
+    ///
+    /// `k` is taken modulo 4, and negative values rotate clockwise. The
+    /// rotation is done by inverting and swapping axes, so no elements are
+    /// copied. Use [`.rot90_owned()`](Self::rot90_owned) to get the result in
+    /// standard layout.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.rot90(1), array![[3, 6],
+    ///                               [2, 5],
+    ///                               [1, 4]]);
+    /// assert_eq!(a.rot90(-1), a.rot90(3));
+    /// ```
+    pub fn rot90(&self, k: i32) -> ArrayView2<'_, A>
+    where S: Data
+    {
+        match k.rem_euclid(4) {
+            0 => self.view(),
+            1 => self.fliplr().reversed_axes(),
+            2 => {
+                let mut view = self.view();
+                view.invert_axis(Axis(0));
+                view.invert_axis(Axis(1));
+                view
+            }
+            _ => self.flipud().reversed_axes(),
+        }
+    }
+
+    /// Return a new array in standard layout that is the array rotated by `k`
+    /// quarter turns counterclockwise.
+    ///
+    /// See [`.rot90()`](Self::rot90) for the meaning of `k`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2],
+    ///                [3, 4]];
+    /// let r = a.rot90_owned(2);
+    /// assert_eq!(r, array![[4, 3],
+    ///                      [2, 1]]);
+    /// assert!(r.is_standard_layout());
+    /// ```
+    pub fn rot90_owned(&self, k: i32) -> Array2<A>
+    where
+        S: Data,
+        A: Clone,
+    {
+        self.rot90(k).as_standard_layout().into_owned()
+    }
+}
+
+/// Side length of the square tiles used by the blocked transposing routines.
+///
+/// Small tiles avoid cache set conflicts between the rows of a tile when the
+/// row stride is a large power of two.
+const TRANSPOSE_TILE: usize = 8;
+
+/// Return `true` if copying `a` to standard layout in logical order would read
+/// it mostly across its rows, so that a blocked copy is preferable.
+pub(crate) fn prefers_blocked_copy<A>(a: &ArrayView2<'_, A>) -> bool
+{
+    let (m, n) = a.dim();
+    let s = a.strides();
+    m > 1 && n > 1 && s[0].unsigned_abs() < s[1].unsigned_abs()
+}
+
+/// Copy `a` into a new array in standard layout.
+///
+/// The source is visited in square tiles, which keeps both the reads and the
+/// writes cache friendly when `a` is stored column major, such as the
+/// transpose of a standard layout array.
+pub(crate) fn to_standard_layout_blocked<A, F>(a: &ArrayView2<'_, A>, f: F) -> Array2<A>
+where F: FnMut(&A) -> A
+{
+    let mut out = Array2::uninit(a.raw_dim());
+    copy_blocked(a, &mut out.view_mut(), f);
+    // Safe because every element was written by `copy_blocked`.
+    unsafe { out.assume_init() }
+}
+
+/// Write `f` of each element of `src` to the same position of `dst`, which
+/// must have the same shape, visiting them in square tiles.
+///
+/// The tiles keep the accesses cache friendly when `src` is stored column
+/// major and `dst` row major, or the other way around.
+pub(crate) fn copy_blocked<A, F>(src: &ArrayView2<'_, A>, dst: &mut ArrayViewMut2<'_, MaybeUninit<A>>, mut f: F)
+where F: FnMut(&A) -> A
+{
+    debug_assert_eq!(src.dim(), dst.dim());
+    let (m, n) = src.dim();
+    let (row_stride, col_stride) = (src.strides()[0], src.strides()[1]);
+    let (dst_row_stride, dst_col_stride) = (dst.strides()[0], dst.strides()[1]);
+    let src = src.as_ptr();
+    let dst = dst.as_mut_ptr();
+    for i0 in (0..m).step_by(TRANSPOSE_TILE) {
+        let i1 = usize::min(i0 + TRANSPOSE_TILE, m);
+        for j0 in (0..n).step_by(TRANSPOSE_TILE) {
+            let j1 = usize::min(j0 + TRANSPOSE_TILE, n);
+            for j in j0..j1 {
+                for i in i0..i1 {
+                    // Safe because i < m and j < n, so both offsets are in bounds of their
+                    // arrays.
+                    unsafe {
+                        let elt = &*src.offset(i as isize * row_stride + j as isize * col_stride);
+                        (*dst.offset(i as isize * dst_row_stride + j as isize * dst_col_stride)).write(f(elt));
+                    }
+                }
+            }
+        }
+    }
 }
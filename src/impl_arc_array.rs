@@ -0,0 +1,101 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+use crate::imp_prelude::*;
+use crate::SliceArg;
+
+/// Methods specific to `ArcArray`.
+///
+/// The slicing and subview methods that take the array by value, like
+/// [`.slice_move()`](ArrayBase::slice_move) and
+/// [`.index_axis_move()`](ArrayBase::index_axis_move), return an `ArcArray`
+/// that still shares the reference-counted buffer; the methods here are
+/// shorthands for calling them on a clone. The buffer is freed when the last
+/// array that shares it is dropped. Mutating a shared array copies only the
+/// elements it can see, unless it covers the whole buffer.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<A, D> ArcArray<A, D>
+where D: Dimension
+{
+    /// Return a slice of the array that shares its buffer, without copying
+    /// the elements and without borrowing the array.
+    ///
+    /// See [*Slicing*](ArrayBase#slicing) for the slicing argument, and
+    /// [`.slice()`](ArrayBase::slice) for the borrowing version.
+    ///
+    /// **Panics** if an index is out of bounds or step size is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `info` does not match the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{array, s, ArcArray2};
+    ///
+    /// let a: ArcArray2<_> = array![[1, 2, 3], [4, 5, 6]].into_shared();
+    /// let mut corner = a.slice_shared(s![1.., ..2]);
+    /// assert_eq!(corner, array![[4, 5]]);
+    /// assert_eq!(a.strong_count(), 2);
+    ///
+    /// // mutation copies only the elements of the slice
+    /// corner[[0, 0]] = 0;
+    /// assert_eq!(corner, array![[0, 5]]);
+    /// assert_eq!(a[[1, 0]], 4);
+    /// assert_eq!(a.strong_count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn slice_shared<I>(&self, info: I) -> ArcArray<A, I::OutDim>
+    where I: SliceArg<D>
+    {
+        self.clone().slice_move(info)
+    }
+
+    /// Return the subview at `index` along `axis`, sharing the buffer of the
+    /// array, without copying the elements and without borrowing the array.
+    ///
+    /// See [`.index_axis()`](ArrayBase::index_axis) for the borrowing version.
+    ///
+    /// **Panics** if `axis` or `index` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, ArcArray2, Axis};
+    ///
+    /// let a: ArcArray2<_> = array![[1, 2, 3], [4, 5, 6]].into_shared();
+    /// let column = a.index_axis_shared(Axis(1), 2);
+    /// assert_eq!(column, array![3, 6]);
+    /// assert_eq!(a.strong_count(), 2);
+    /// ```
+    #[track_caller]
+    pub fn index_axis_shared(&self, axis: Axis, index: usize) -> ArcArray<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        self.clone().index_axis_move(axis, index)
+    }
+
+    /// Return the number of arrays that share the buffer of this array,
+    /// including this one.
+    ///
+    /// ```
+    /// use ndarray::{ArcArray, s};
+    ///
+    /// let a = ArcArray::<f64, _>::zeros((3, 4));
+    /// assert_eq!(a.strong_count(), 1);
+    /// let row = a.slice_shared(s![0, ..]);
+    /// assert_eq!(a.strong_count(), 2);
+    /// drop(row);
+    /// assert_eq!(a.strong_count(), 1);
+    /// ```
+    pub fn strong_count(&self) -> usize
+    {
+        Arc::strong_count(&self.data.0)
+    }
+}
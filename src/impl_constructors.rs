@@ -22,7 +22,7 @@ use std::mem::MaybeUninit;
 
 use crate::dimension;
 use crate::dimension::offset_from_low_addr_ptr_to_logical_ptr;
-use crate::error::{self, ShapeError};
+use crate::error::{self, ErrorKind, ShapeError};
 use crate::extension::nonnull::nonnull_from_vec_data;
 use crate::imp_prelude::*;
 use crate::indexes;
@@ -34,6 +34,7 @@ use crate::iterators::TrustedIterator;
 use crate::StrideShape;
 #[cfg(feature = "std")]
 use crate::{geomspace, linspace, logspace};
+use crate::{IntoDimension, Order};
 #[allow(unused_imports)]
 use rawpointer::PointerExt;
 
@@ -250,28 +251,103 @@ where S: DataOwned<Elem = A>
         }
         eye
     }
+
+    /// Create a one-hot encoding of `labels`: a matrix of shape
+    /// (`labels.len()`, `n_classes`) where row `i` is one in column
+    /// `labels[i]` and zero elsewhere.
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if a label is not less than
+    /// `n_classes`, or with `ErrorKind::Overflow` if the number of elements
+    /// would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let labels = array![2, 0, 1, 2];
+    /// let encoded = Array2::<f32>::one_hot(&labels, 3).unwrap();
+    /// assert_eq!(encoded, array![[0., 0., 1.],
+    ///                            [1., 0., 0.],
+    ///                            [0., 1., 0.],
+    ///                            [0., 0., 1.]]);
+    /// assert!(Array2::<f32>::one_hot(&labels, 2).is_err());
+    /// ```
+    pub fn one_hot<S2>(labels: &ArrayBase<S2, Ix1>, n_classes: usize) -> Result<Self, ShapeError>
+    where
+        S: DataMut,
+        S2: Data<Elem = usize>,
+        A: Clone + Zero + One,
+    {
+        Self::one_hot_with(labels, n_classes, A::zero(), A::one(), "one_hot")
+    }
+
+    /// Fill a matrix of shape (`labels.len()`, `n_classes`) with `off`, and
+    /// set the element of each row at column `labels[i]` to `on`.
+    ///
+    /// `operation` is the name of the calling method, for the errors.
+    fn one_hot_with<S2>(
+        labels: &ArrayBase<S2, Ix1>, n_classes: usize, off: A, on: A, operation: &'static str,
+    ) -> Result<Self, ShapeError>
+    where
+        S: DataMut,
+        S2: Data<Elem = usize>,
+        A: Clone,
+    {
+        let shape = Ix2(labels.len(), n_classes);
+        dimension::size_of_array_checked::<A, _>(&shape)?;
+        if let Some((index, &label)) = labels.iter().enumerate().find(|&(_, &label)| label >= n_classes) {
+            return Err(error::key_out_of_bounds(index, label, n_classes).in_operation(operation));
+        }
+        let mut encoded = Self::from_elem(shape, off);
+        for (mut row, &label) in encoded.rows_mut().into_iter().zip(labels) {
+            row[label] = on.clone();
+        }
+        Ok(encoded)
+    }
+}
+
+impl<S> ArrayBase<S, Ix2>
+where S: DataOwned<Elem = bool> + DataMut
+{
+    /// Create a one-hot encoding of `labels` as a boolean mask: a matrix of
+    /// shape (`labels.len()`, `n_classes`) where row `i` is `true` only in
+    /// column `labels[i]`.
+    ///
+    /// See [`one_hot`](Self::one_hot) for the errors.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let mask = Array2::one_hot_mask(&array![1, 0], 2).unwrap();
+    /// assert_eq!(mask, array![[false, true],
+    ///                         [true, false]]);
+    /// ```
+    pub fn one_hot_mask<S2>(labels: &ArrayBase<S2, Ix1>, n_classes: usize) -> Result<Self, ShapeError>
+    where S2: Data<Elem = usize>
+    {
+        Self::one_hot_with(labels, n_classes, false, true, "one_hot_mask")
+    }
 }
 
 #[cfg(not(debug_assertions))]
 #[allow(clippy::match_wild_err_arm)]
-macro_rules! size_of_shape_checked_unwrap {
-    ($dim:expr) => {
-        match dimension::size_of_shape_checked($dim) {
+macro_rules! size_of_array_checked_unwrap {
+    ($elem:ty, $dim:expr) => {
+        match dimension::size_of_array_checked::<$elem, _>($dim) {
             Ok(sz) => sz,
             Err(_) => {
-                panic!("ndarray: Shape too large, product of non-zero axis lengths overflows isize")
+                panic!("ndarray: Shape too large, number of elements or bytes overflows isize")
             }
         }
     };
 }
 
 #[cfg(debug_assertions)]
-macro_rules! size_of_shape_checked_unwrap {
-    ($dim:expr) => {
-        match dimension::size_of_shape_checked($dim) {
+macro_rules! size_of_array_checked_unwrap {
+    ($elem:ty, $dim:expr) => {
+        match dimension::size_of_array_checked::<$elem, _>($dim) {
             Ok(sz) => sz,
             Err(_) => panic!(
-                "ndarray: Shape too large, product of non-zero axis lengths \
+                "ndarray: Shape too large, number of elements or bytes \
                  overflows isize in shape {:?}",
                 $dim
             ),
@@ -302,7 +378,8 @@ where
 {
     /// Create an array with copies of `elem`, shape `shape`.
     ///
-    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// **Panics** if the product of non-zero axis lengths, or the size of the
+    /// elements in bytes, overflows `isize`.
     ///
     /// ```
     /// use ndarray::{Array, arr3, ShapeBuilder};
@@ -326,14 +403,19 @@ where
         Sh: ShapeBuilder<Dim = D>,
     {
         let shape = shape.into_shape_with_order();
-        let size = size_of_shape_checked_unwrap!(&shape.dim);
+        let size = size_of_array_checked_unwrap!(A, &shape.dim);
         let v = vec![elem; size];
         unsafe { Self::from_shape_vec_unchecked(shape, v) }
     }
 
     /// Create an array with zeros, shape `shape`.
     ///
-    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// The element type can be any type that implements `num_traits::Zero`,
+    /// for example `Wrapping<u32>`. For a mask of `bool`, see
+    /// [`falses`](Self::falses).
+    ///
+    /// **Panics** if the product of non-zero axis lengths, or the size of the
+    /// elements in bytes, overflows `isize`.
     pub fn zeros<Sh>(shape: Sh) -> Self
     where
         A: Clone + Zero,
@@ -344,7 +426,12 @@ where
 
     /// Create an array with ones, shape `shape`.
     ///
-    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// The element type can be any type that implements `num_traits::One`,
+    /// for example `Wrapping<u32>`. For a mask of `bool`, see
+    /// [`trues`](Self::trues).
+    ///
+    /// **Panics** if the product of non-zero axis lengths, or the size of the
+    /// elements in bytes, overflows `isize`.
     pub fn ones<Sh>(shape: Sh) -> Self
     where
         A: Clone + One,
@@ -355,7 +442,19 @@ where
 
     /// Create an array with default values, shape `shape`
     ///
-    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// `A::default()` is called once for each element, so the element type
+    /// does not need to implement `Clone`.
+    ///
+    /// **Panics** if the product of non-zero axis lengths, or the size of the
+    /// elements in bytes, overflows `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{Array, ShapeBuilder};
+    ///
+    /// let a = Array::<Option<String>, _>::default((2, 3).f());
+    /// assert!(a.iter().all(Option::is_none));
+    /// assert_eq!(a.strides(), &[1, 2]);
+    /// ```
     pub fn default<Sh>(shape: Sh) -> Self
     where
         A: Default,
@@ -373,14 +472,15 @@ where
     /// This constructor can be useful if the element order is not important,
     /// for example if they are identical or random.
     ///
-    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// **Panics** if the product of non-zero axis lengths, or the size of the
+    /// elements in bytes, overflows `isize`.
     pub fn from_shape_simple_fn<Sh, F>(shape: Sh, mut f: F) -> Self
     where
         Sh: ShapeBuilder<Dim = D>,
         F: FnMut() -> A,
     {
         let shape = shape.into_shape_with_order();
-        let len = size_of_shape_checked_unwrap!(&shape.dim);
+        let len = size_of_array_checked_unwrap!(A, &shape.dim);
         let v = to_vec_mapped(0..len, move |_| f());
         unsafe { Self::from_shape_vec_unchecked(shape, v) }
     }
@@ -390,7 +490,8 @@ where
     /// `f` is called with the index of the element to create; the elements are
     /// visited in arbitrary order.
     ///
-    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// **Panics** if the product of non-zero axis lengths, or the size of the
+    /// elements in bytes, overflows `isize`.
     ///
     /// ```
     /// use ndarray::{Array, arr2};
@@ -411,7 +512,7 @@ where
         F: FnMut(D::Pattern) -> A,
     {
         let shape = shape.into_shape_with_order();
-        let _ = size_of_shape_checked_unwrap!(&shape.dim);
+        let _ = size_of_array_checked_unwrap!(A, &shape.dim);
         if shape.is_c() {
             let v = to_vec_mapped(indices(shape.dim.clone()).into_iter(), f);
             unsafe { Self::from_shape_vec_unchecked(shape, v) }
@@ -440,6 +541,11 @@ where
     /// strides allow multiple indices to point to the same element, or if the
     /// shape/strides would result in overflowing `isize`.
     ///
+    /// Elements of `v` that are not part of the array are allowed, for
+    /// example padding at the end of each row or trailing unused elements.
+    /// They are kept in the allocation and returned again by
+    /// [`.into_raw_vec_and_offset()`](ArrayBase::into_raw_vec_and_offset).
+    ///
     /// ```
     /// use ndarray::Array;
     /// use ndarray::ShapeBuilder; // Needed for .strides() method
@@ -454,6 +560,18 @@ where
     ///     b == arr2(&[[1., 3.],
     ///                 [2., 4.]])
     /// );
+    ///
+    /// // The same elements in column-major order, without copying
+    /// let c = Array::from_shape_vec((2, 2).f(), vec![1., 2., 3., 4.]).unwrap();
+    /// assert_eq!(b, c);
+    ///
+    /// // Rows of length 2, padded to 3 elements, with an unused element at the end
+    /// let d = Array::from_shape_vec((2, 2).strides((3, 1)),
+    ///                               vec![1., 2., 0., 3., 4., 0., 0.]).unwrap();
+    /// assert_eq!(d, arr2(&[[1., 2.], [3., 4.]]));
+    /// let (v, offset) = d.into_raw_vec_and_offset();
+    /// assert_eq!(v.len(), 7);
+    /// assert_eq!(offset, Some(0));
     /// ```
     pub fn from_shape_vec<Sh>(shape: Sh, v: Vec<A>) -> Result<Self, ShapeError>
     where Sh: Into<StrideShape<D>>
@@ -462,18 +580,90 @@ where
         Self::from_shape_vec_impl(shape.into(), v)
     }
 
+    /// Create an array from a vector, with the elements in `order`.
+    ///
+    /// This is the same as [`.from_shape_vec()`](Self::from_shape_vec) with
+    /// `shape` or `shape.f()`: the vector is used as the array's storage
+    /// without copying.
+    ///
+    /// **Errors** if `shape` does not correspond to the number of elements in
+    /// `v` or if the shape would result in overflowing `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array, Order};
+    ///
+    /// let a = Array::from_shape_vec_with_order((2, 3), Order::ColumnMajor, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(a, arr2(&[[1, 3, 5], [2, 4, 6]]));
+    /// assert_eq!(a.to_vec_with_order(Order::ColumnMajor), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn from_shape_vec_with_order<Sh>(shape: Sh, order: Order, v: Vec<A>) -> Result<Self, ShapeError>
+    where Sh: IntoDimension<Dim = D>
+    {
+        Self::from_shape_vec_impl(shape.set_f(order.is_column_major()).into(), v)
+    }
+
     fn from_shape_vec_impl(shape: StrideShape<D>, v: Vec<A>) -> Result<Self, ShapeError>
     {
         let dim = shape.dim;
         let is_custom = shape.strides.is_custom();
-        dimension::can_index_slice_with_strides(&v, &dim, &shape.strides)?;
+        dimension::can_index_slice_with_strides(&v, &dim, &shape.strides)
+            .map_err(|err| err.in_operation("from_shape_vec"))?;
         if !is_custom && dim.size() != v.len() {
-            return Err(error::incompatible_shapes(&Ix1(v.len()), &dim));
+            return Err(error::incompatible_shapes(&Ix1(v.len()), &dim).in_operation("from_shape_vec"));
         }
         let strides = shape.strides.strides_for_dim(&dim);
         unsafe { Ok(Self::from_vec_dim_stride_unchecked(dim, strides, v)) }
     }
 
+    /// Create an array with shape `shape` from the elements of `iter`, in
+    /// the order given by the shape (row major by default, or column major
+    /// with `.f()`).
+    ///
+    /// This is like [`.from_shape_vec()`](Self::from_shape_vec) of the
+    /// collected elements, but the length of `iter` is checked before any
+    /// element is consumed, and the elements are moved directly into an
+    /// allocation of exactly the right size.
+    ///
+    /// **Errors** if `iter.len()` is not the number of elements of `shape`,
+    /// if the iterator then yields a different number of elements than its
+    /// `len()`, or if the shape would result in overflowing `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array, ShapeBuilder};
+    ///
+    /// let a = Array::from_shape_exact_iter((2, 3), 0..6).unwrap();
+    /// assert_eq!(a, arr2(&[[0, 1, 2], [3, 4, 5]]));
+    ///
+    /// let b = Array::from_shape_exact_iter((2, 3).f(), 0..6).unwrap();
+    /// assert_eq!(b, arr2(&[[0, 2, 4], [1, 3, 5]]));
+    ///
+    /// assert!(Array::from_shape_exact_iter((2, 3), 0..5).is_err());
+    /// ```
+    pub fn from_shape_exact_iter<Sh, I>(shape: Sh, iter: I) -> Result<Self, ShapeError>
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        I: IntoIterator<Item = A>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let shape = shape.into_shape_with_order();
+        let mut iter = iter.into_iter();
+        let size = dimension::size_of_array_checked::<A, _>(&shape.dim)
+            .map_err(|err| err.in_operation("from_shape_exact_iter"))?;
+        if iter.len() != size {
+            return Err(error::incompatible_shapes(&Ix1(iter.len()), &shape.dim).in_operation("from_shape_exact_iter"));
+        }
+        let mut v = Vec::with_capacity(size);
+        v.extend(iter.by_ref().take(size));
+        // the iterator can have given a wrong length
+        if v.len() != size {
+            return Err(error::incompatible_shapes(&Ix1(v.len()), &shape.dim).in_operation("from_shape_exact_iter"));
+        }
+        if iter.next().is_some() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).in_operation("from_shape_exact_iter"));
+        }
+        unsafe { Ok(Self::from_shape_vec_unchecked(shape, v)) }
+    }
+
     /// Creates an array from a vector and interpret it according to the
     /// provided shape and strides. (No cloning of elements needed.)
     ///
@@ -585,7 +775,7 @@ where
     {
         unsafe {
             let shape = shape.into_shape_with_order();
-            let size = size_of_shape_checked_unwrap!(&shape.dim);
+            let size = size_of_array_checked_unwrap!(A, &shape.dim);
             let mut v = Vec::with_capacity(size);
             v.set_len(size);
             ArrayBase::from_shape_vec_unchecked(shape, v)
@@ -660,13 +850,51 @@ where
         Sh: ShapeBuilder<Dim = D>,
     {
         let shape = shape.into_shape_with_order();
-        let size = size_of_shape_checked_unwrap!(&shape.dim);
+        let size = size_of_array_checked_unwrap!(A, &shape.dim);
         let mut v = Vec::with_capacity(size);
         v.set_len(size);
         Self::from_shape_vec_unchecked(shape, v)
     }
 }
 
+impl<S, D> ArrayBase<S, D>
+where
+    S: DataOwned<Elem = bool>,
+    D: Dimension,
+{
+    /// Create a boolean mask with all elements `false`, shape `shape`.
+    ///
+    /// This is the counterpart of [`zeros`](Self::zeros) for `bool`, which
+    /// does not implement `Zero`.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let mut mask = Array2::falses((2, 3));
+    /// mask[[1, 2]] = true;
+    /// assert_eq!(mask.iter().filter(|&&x| x).count(), 1);
+    /// ```
+    pub fn falses<Sh>(shape: Sh) -> Self
+    where Sh: ShapeBuilder<Dim = D>
+    {
+        Self::from_elem(shape, false)
+    }
+
+    /// Create a boolean mask with all elements `true`, shape `shape`.
+    ///
+    /// This is the counterpart of [`ones`](Self::ones) for `bool`, which
+    /// does not implement `One`.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    pub fn trues<Sh>(shape: Sh) -> Self
+    where Sh: ShapeBuilder<Dim = D>
+    {
+        Self::from_elem(shape, true)
+    }
+}
+
 impl<S, A, D> ArrayBase<S, D>
 where
     S: DataOwned<Elem = MaybeUninit<A>>,
@@ -681,7 +909,7 @@ where
     {
         unsafe {
             let shape = shape.into_shape_with_order();
-            let size = size_of_shape_checked_unwrap!(&shape.dim);
+            let size = size_of_array_checked_unwrap!(A, &shape.dim);
             let mut v = Vec::with_capacity(size);
             v.set_len(size);
             Self::from_shape_vec_unchecked(shape, v)
@@ -10,13 +10,20 @@
 use crate::imp_prelude::*;
 
 /// # Methods for Dynamic-Dimensional Arrays
+///
+/// The number of axes of an `ArrayD` can change without changing its type,
+/// so these methods add or remove axes in place, without reallocating. They
+/// also work for array views and raw array views with dynamic dimension.
 impl<A, S> ArrayBase<S, IxDyn>
-where S: Data<Elem = A>
+where S: RawData<Elem = A>
 {
     /// Insert new array axis of length 1 at `axis`, modifying the shape and
     /// strides in-place.
     ///
-    /// **Panics** if the axis is out of bounds.
+    /// An axis equal to the number of axes appends the new axis at the end.
+    ///
+    /// **Panics** if the axis is out of bounds, i.e. if `axis.index() >
+    /// self.ndim()`.
     ///
     /// ```
     /// use ndarray::{Axis, arr2, arr3};
@@ -31,7 +38,12 @@ where S: Data<Elem = A>
     #[track_caller]
     pub fn insert_axis_inplace(&mut self, axis: Axis)
     {
-        assert!(axis.index() <= self.ndim());
+        assert!(
+            axis.index() <= self.ndim(),
+            "ndarray: cannot insert axis {} in an array with {} axes",
+            axis.index(),
+            self.ndim()
+        );
         self.dim = self.dim.insert_axis(axis);
         self.strides = self.strides.insert_axis(axis);
     }
@@ -0,0 +1,83 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::dimension;
+use crate::dimension::offset_from_low_addr_ptr_to_logical_ptr;
+use crate::error::{self, ShapeError};
+use crate::imp_prelude::*;
+use crate::{ExternalArray, ExternalRepr, ExternalStorage, StrideShape};
+
+/// Methods specific to `ExternalArray`.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<S, D> ExternalArray<S, D>
+where
+    S: ExternalStorage,
+    D: Dimension,
+{
+    /// Create an array with shape `shape` backed by the elements of
+    /// `storage`, without copying them.
+    ///
+    /// The shape and strides are interpreted like in
+    /// [`Array::from_shape_vec`], with the elements of `storage` in place of
+    /// the vector.
+    ///
+    /// **Errors** if `shape` does not correspond to the number of elements in
+    /// `storage` or if the shape/strides would result in overflowing `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, ExternalArray, ExternalStorage};
+    /// use std::ptr::NonNull;
+    ///
+    /// // A boxed slice does not move its elements when it is moved.
+    /// struct Boxed(Box<[i32]>);
+    ///
+    /// unsafe impl ExternalStorage for Boxed {
+    ///     type Elem = i32;
+    ///     fn as_ptr(&self) -> NonNull<i32> { NonNull::new(self.0.as_ptr() as *mut i32).unwrap() }
+    ///     fn len(&self) -> usize { self.0.len() }
+    /// }
+    ///
+    /// let storage = Boxed(vec![1, 2, 3, 4, 5, 6].into_boxed_slice());
+    /// let a = ExternalArray::from_storage((2, 3), storage).unwrap();
+    /// assert_eq!(a, arr2(&[[1, 2, 3], [4, 5, 6]]));
+    /// assert_eq!(a.sum(), 21);
+    /// ```
+    pub fn from_storage<Sh>(shape: Sh, storage: S) -> Result<Self, ShapeError>
+    where Sh: Into<StrideShape<D>>
+    {
+        let shape = shape.into();
+        let data = ExternalRepr(storage);
+        let dim = shape.dim;
+        let is_custom = shape.strides.is_custom();
+        dimension::can_index_slice_with_strides(data.as_slice(), &dim, &shape.strides)
+            .map_err(|err| err.in_operation("from_storage"))?;
+        if !is_custom && dim.size() != data.as_slice().len() {
+            return Err(error::incompatible_shapes(&Ix1(data.as_slice().len()), &dim).in_operation("from_storage"));
+        }
+        let strides = shape.strides.strides_for_dim(&dim);
+        unsafe {
+            let ptr = data
+                .as_nonnull()
+                .add(offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides));
+            Ok(ArrayBase::from_data_ptr(data, ptr).with_strides_dim(strides, dim))
+        }
+    }
+
+    /// Return a reference to the storage of the array.
+    pub fn storage(&self) -> &S
+    {
+        &self.data.0
+    }
+
+    /// Return the storage of the array.
+    pub fn into_storage(self) -> S
+    {
+        self.data.0
+    }
+}
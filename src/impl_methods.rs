@@ -12,13 +12,15 @@ use alloc::vec;
 use alloc::vec::Vec;
 #[allow(unused_imports)]
 use rawpointer::PointerExt;
+use std::cmp::Ordering;
 use std::mem::{size_of, ManuallyDrop};
+use std::ops::Add;
 
 use crate::imp_prelude::*;
 
 use crate::argument_traits::AssignElem;
 use crate::dimension;
-use crate::dimension::broadcast::co_broadcast;
+use crate::dimension::broadcast::{broadcast_axis, co_broadcast};
 use crate::dimension::reshape_dim;
 use crate::dimension::IntoDimension;
 use crate::dimension::{
@@ -26,7 +28,6 @@ use crate::dimension::{
     axes_of,
     do_slice,
     merge_axes,
-    move_min_stride_axis_to_last,
     offset_from_low_addr_ptr_to_logical_ptr,
     size_of_shape_checked,
     stride_offset,
@@ -34,12 +35,13 @@ use crate::dimension::{
 };
 use crate::error::{self, from_kind, ErrorKind, ShapeError};
 use crate::itertools::zip;
+use crate::layout::Layout;
 use crate::math_cell::MathCell;
 use crate::order::Order;
 use crate::shape_builder::ShapeArg;
 use crate::zip::{IntoNdProducer, Zip};
 use crate::AxisDescription;
-use crate::{arraytraits, DimMax};
+use crate::{arraytraits, BroadcastAxis, DimMax};
 
 use crate::iter::{
     AxisChunksIter,
@@ -50,10 +52,14 @@ use crate::iter::{
     ExactChunksMut,
     IndexedIter,
     IndexedIterMut,
+    InnerSlices,
+    InnerSlicesMut,
     Iter,
     IterMut,
     Lanes,
     LanesMut,
+    MultiAxisIter,
+    MultiAxisIterMut,
     Windows,
 };
 use crate::slice::{MultiSliceArg, SliceArg};
@@ -81,7 +87,7 @@ where
     #[track_caller]
     pub fn len_of(&self, axis: Axis) -> usize
     {
-        self.dim[axis.index()]
+        self.dim.axis(axis)
     }
 
     /// Return whether the array has any elements
@@ -99,6 +105,22 @@ where
     /// Return the shape of the array in its “pattern” form,
     /// an integer in the one-dimensional case, tuple in the n-dimensional cases
     /// and so on.
+    ///
+    /// The pattern is `()` for a zero-dimensional array, and the dimension
+    /// itself (`IxDyn`) for a dynamic-dimensional array. Use
+    /// [`.raw_dim()`](Self::raw_dim) for the shape as the dimension type `D`.
+    ///
+    /// ```
+    /// use ndarray::{arr0, Array, Array2, IxDyn};
+    ///
+    /// let a = Array2::<f64>::zeros((3, 4));
+    /// let (m, n) = a.dim();
+    /// assert_eq!((m, n), (3, 4));
+    ///
+    /// assert_eq!(a.row(0).dim(), 4);
+    /// assert_eq!(arr0(1.).dim(), ());
+    /// assert_eq!(Array::<f64, _>::zeros(IxDyn(&[2, 3, 4])).dim(), IxDyn(&[2, 3, 4]));
+    /// ```
     pub fn dim(&self) -> D::Pattern
     {
         self.dim.clone().into_pattern()
@@ -153,6 +175,24 @@ where
     }
 
     /// Return the strides of the array as a slice.
+    ///
+    /// The slice has one stride per axis, in the same order as
+    /// [`.shape()`](Self::shape). The stride of an axis is the distance, in
+    /// number of elements, between the elements at consecutive indices along
+    /// that axis. Strides are signed: they are negative along inverted axes,
+    /// and the element at index `[i, j, ...]` is found at
+    /// `.as_ptr().offset(i * strides[0] + j * strides[1] + ...)`.
+    ///
+    /// ```
+    /// use ndarray::{s, Array2, ShapeBuilder};
+    ///
+    /// let a = Array2::<f64>::zeros((3, 4));
+    /// assert_eq!(a.strides(), &[4, 1]);
+    /// assert_eq!(a.slice(s![..;-1, ..;2]).strides(), &[-4, 2]);
+    ///
+    /// let f = Array2::<f64>::zeros((3, 4).f());
+    /// assert_eq!(f.strides(), &[1, 3]);
+    /// ```
     pub fn strides(&self) -> &[isize]
     {
         let s = self.strides.slice();
@@ -162,6 +202,8 @@ where
 
     /// Return the stride of `axis`.
     ///
+    /// This is the same as `self.strides()[axis.index()]`.
+    ///
     /// The axis should be in the range `Axis(` 0 .. *n* `)` where *n* is the
     /// number of dimensions (axes) of the array.
     ///
@@ -170,7 +212,7 @@ where
     pub fn stride_of(&self, axis: Axis) -> isize
     {
         // strides are reinterpreted as isize
-        self.strides[axis.index()] as isize
+        self.strides.axis(axis) as isize
     }
 
     /// Return a read-only view of the array
@@ -239,11 +281,81 @@ where
     {
         if let Some(slc) = self.as_slice_memory_order() {
             unsafe { Array::from_shape_vec_unchecked(self.dim.clone().strides(self.strides.clone()), slc.to_vec()) }
+        } else if let Some(owned) = self.to_owned_blocked() {
+            owned
         } else {
             self.map(A::clone)
         }
     }
 
+    /// Copy the array into standard layout using a blocked copy, or return
+    /// `None` if that does not apply.
+    fn to_owned_blocked(&self) -> Option<Array<A, D>>
+    where
+        A: Clone,
+        S: Data,
+    {
+        self.map_blocked(A::clone)
+    }
+
+    /// Map the array into a new array in standard layout using a blocked
+    /// copy, or return `None` if that does not apply.
+    ///
+    /// The blocked copy is used when some axis other than the last has a
+    /// smaller stride than the last axis, so that copying in logical order
+    /// would read the array across its memory order. In more than two
+    /// dimensions, the 2-D subviews of that axis and the last axis are copied
+    /// one by one.
+    pub(crate) fn map_blocked<F>(&self, mut f: F) -> Option<Array<A, D>>
+    where
+        S: Data,
+        F: FnMut(&A) -> A,
+    {
+        if let Ok(view) = self.view().into_dimensionality::<Ix2>() {
+            if !crate::impl_2d::prefers_blocked_copy(&view) {
+                return None;
+            }
+            let owned = crate::impl_2d::to_standard_layout_blocked(&view, f);
+            return owned.into_dimensionality::<D>().ok();
+        }
+        let ndim = self.ndim();
+        if ndim < 3 || self.dim[ndim - 1] <= 1 {
+            return None;
+        }
+        let last = ndim - 1;
+        let last_stride = (self.strides[last] as isize).unsigned_abs();
+        let fast = (0..last)
+            .filter(|&ax| self.dim[ax] > 1)
+            .min_by_key(|&ax| (self.strides[ax] as isize).unsigned_abs())?;
+        if (self.strides[fast] as isize).unsigned_abs() >= last_stride {
+            return None;
+        }
+
+        // Move the axes `fast` and `last` to the back, in both arrays, and copy the 2-D
+        // subviews for each index of the other axes.
+        let mut perm: Vec<usize> = (0..last).filter(|&ax| ax != fast).collect();
+        perm.push(fast);
+        perm.push(last);
+        let mut out = Array::uninit(self.raw_dim());
+        {
+            let src = self.view().into_dyn().permuted_axes(&perm[..]);
+            let mut dst = out.view_mut().into_dyn().permuted_axes(&perm[..]);
+            for index in crate::indices(&src.shape()[..ndim - 2]) {
+                let mut src = src.view();
+                let mut dst = dst.view_mut();
+                for &i in index.slice() {
+                    src = src.index_axis_move(Axis(0), i);
+                    dst = dst.index_axis_move(Axis(0), i);
+                }
+                let src = src.into_dimensionality::<Ix2>().unwrap();
+                let mut dst = dst.into_dimensionality::<Ix2>().unwrap();
+                crate::impl_2d::copy_blocked(&src, &mut dst, &mut f);
+            }
+        }
+        // Safe because every element was written by `copy_blocked`.
+        unsafe { Some(out.assume_init()) }
+    }
+
     /// Return a shared ownership (copy on write) array, cloning the array
     /// elements if necessary.
     pub fn to_shared(&self) -> ArcArray<A, D>
@@ -412,7 +524,10 @@ where
     /// Return an iterator of references to the elements of the array.
     ///
     /// Elements are visited in the *logical order* of the array, which
-    /// is where the rightmost index is varying the fastest.
+    /// is where the rightmost index is varying the fastest. This is the
+    /// case whatever the memory layout of the array; use
+    /// [`.iter_memory_order()`](Self::iter_memory_order) or
+    /// [`.for_each()`](Self::for_each) when the order does not matter.
     ///
     /// Iterator element type is `&A`.
     pub fn iter(&self) -> Iter<'_, A, D>
@@ -434,6 +549,60 @@ where
         self.view_mut().into_iter_()
     }
 
+    /// Return an iterator of references to the elements of the array, in
+    /// the order that they are stored in memory.
+    ///
+    /// The axes are traversed from the one with the largest stride to the one
+    /// with the smallest stride, and axes with negative strides are traversed
+    /// backwards, so that the elements are visited by increasing address when
+    /// the array is contiguous or strided without overlap. This is faster
+    /// than [`.iter()`](Self::iter) for arrays that are not in standard
+    /// layout, but the order is not the logical order of the array.
+    ///
+    /// Iterator element type is `&A`.
+    ///
+    /// ```
+    /// use ndarray::{Array, ShapeBuilder};
+    ///
+    /// let a = Array::from_shape_vec((2, 3).f(), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5, 2, 4, 6]);
+    /// assert_eq!(a.iter_memory_order().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn iter_memory_order(&self) -> Iter<'_, A, D>
+    where S: Data
+    {
+        self.view().into_memory_order().into_iter_()
+    }
+
+    /// Return an iterator of mutable references to the elements of the array,
+    /// in the order that they are stored in memory.
+    ///
+    /// See [`.iter_memory_order()`](Self::iter_memory_order) for the order.
+    ///
+    /// Iterator element type is `&mut A`.
+    pub fn iter_memory_order_mut(&mut self) -> IterMut<'_, A, D>
+    where S: DataMut
+    {
+        self.view_mut().into_memory_order().into_iter_()
+    }
+
+    /// Reorder and invert the axes so that the logical order of the array is
+    /// its memory order, as far as the strides allow.
+    fn into_memory_order(mut self) -> Self
+    {
+        for ax in 0..self.ndim() {
+            if (self.strides[ax] as isize) < 0 {
+                self.invert_axis(Axis(ax));
+            }
+        }
+        let mut order = self.strides._fastest_varying_stride_order();
+        order.slice_mut().reverse();
+        // axes of length one go first, so that they are never the inner loop
+        let dim = &self.dim;
+        order.slice_mut().sort_by_key(|&ax| dim[ax] > 1);
+        self.permuted_axes(order)
+    }
+
     /// Return an iterator of indexes and references to the elements of the array.
     ///
     /// Elements are visited in the *logical order* of the array, which
@@ -538,7 +707,10 @@ where
         assert_eq!(
             info.in_ndim(),
             self.ndim(),
-            "The input dimension of `info` must match the array to be sliced.",
+            "The input dimension of `info` must match the array to be sliced: \
+             `info` has {} axes, but the array has shape {:?}.",
+            info.in_ndim(),
+            self.shape(),
         );
         let out_ndim = info.out_ndim();
         let mut new_dim = I::OutDim::zeros(out_ndim);
@@ -607,7 +779,10 @@ where
         assert_eq!(
             info.in_ndim(),
             self.ndim(),
-            "The input dimension of `info` must match the array to be sliced.",
+            "The input dimension of `info` must match the array to be sliced: \
+             `info` has {} axes, but the array has shape {:?}.",
+            info.in_ndim(),
+            self.shape(),
         );
         let mut axis = 0;
         info.as_ref().iter().for_each(|&ax_info| match ax_info {
@@ -660,8 +835,8 @@ where
     #[track_caller]
     pub fn slice_axis_inplace(&mut self, axis: Axis, indices: Slice)
     {
-        let offset =
-            do_slice(&mut self.dim.slice_mut()[axis.index()], &mut self.strides.slice_mut()[axis.index()], indices);
+        let axis = axis.checked_index(self.ndim());
+        let offset = do_slice(&mut self.dim.slice_mut()[axis], &mut self.strides.slice_mut()[axis], indices);
         unsafe {
             self.ptr = self.ptr.offset(offset);
         }
@@ -1014,7 +1189,18 @@ where
 
     /// Selects `index` along the axis, collapsing the axis into length one.
     ///
+    /// Unlike [`.index_axis_move()`](Self::index_axis_move), this keeps the
+    /// number of axes, and modifies the array in place.
+    ///
     /// **Panics** if `axis` or `index` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// a.collapse_axis(Axis(1), 2);
+    /// assert_eq!(a, array![[3], [6]]);
+    /// ```
     #[track_caller]
     pub fn collapse_axis(&mut self, axis: Axis, index: usize)
     {
@@ -1083,6 +1269,276 @@ where
         }
     }
 
+    /// Along `axis`, select the subviews for which `predicate` returns `true`
+    /// and copy them, in order, into a new array.
+    ///
+    /// The length of `axis` in the result is the number of subviews that
+    /// match, which may be zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2.], [f64::NAN, 0.], [3., 4.]];
+    /// let clean = a.filter_axis(Axis(0), |row| !row.iter().any(|x| x.is_nan()));
+    /// assert_eq!(clean, array![[1., 2.], [3., 4.]]);
+    /// ```
+    #[track_caller]
+    pub fn filter_axis<F>(&self, axis: Axis, mut predicate: F) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
+        F: FnMut(ArrayView<'_, A, D::Smaller>) -> bool,
+    {
+        let indices: Vec<Ix> = self
+            .axis_iter(axis)
+            .enumerate()
+            .filter_map(|(i, view)| if predicate(view) { Some(i) } else { None })
+            .collect();
+        self.select(axis, &indices)
+    }
+
+    /// Along `axis`, split the subviews into those for which `predicate`
+    /// returns `true` and those for which it returns `false`, and copy them,
+    /// in order, into two new arrays.
+    ///
+    /// Return `(matching, not_matching)`; either may have a zero-length
+    /// `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let samples = array![[0, 10], [1, 11], [2, 12], [3, 13], [4, 14]];
+    /// let (test, train) = samples.partition_axis(Axis(0), |row| row[0] % 3 == 0);
+    /// assert_eq!(test, array![[0, 10], [3, 13]]);
+    /// assert_eq!(train, array![[1, 11], [2, 12], [4, 14]]);
+    /// ```
+    #[track_caller]
+    pub fn partition_axis<F>(&self, axis: Axis, mut predicate: F) -> (Array<A, D>, Array<A, D>)
+    where
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
+        F: FnMut(ArrayView<'_, A, D::Smaller>) -> bool,
+    {
+        let mut matching = Vec::new();
+        let mut not_matching = Vec::new();
+        for (i, view) in self.axis_iter(axis).enumerate() {
+            if predicate(view) {
+                matching.push(i);
+            } else {
+                not_matching.push(i);
+            }
+        }
+        (self.select(axis, &matching), self.select(axis, &not_matching))
+    }
+
+    /// Along `axis`, remove the duplicate subviews and copy the distinct ones
+    /// into a new array, sorted in lexicographic order.
+    ///
+    /// The subviews are compared element by element, in logical order.
+    /// Elements that are not equal to themselves, such as `NaN`, are equal to
+    /// each other and greater than all other elements, so subviews with
+    /// `NaN` at the same positions and equal elements elsewhere are
+    /// duplicates. All other elements must be comparable with each other.
+    ///
+    /// The subviews are sorted as views, by index, so that each distinct
+    /// subview is copied only once, into the result.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if two elements that are
+    /// equal to themselves are not comparable.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[2, 1], [0, 5], [2, 1], [0, 3], [0, 5]];
+    /// assert_eq!(a.unique_axis(Axis(0)), array![[0, 3], [0, 5], [2, 1]]);
+    /// assert_eq!(a.unique_axis(Axis(1)), array![[1, 2], [5, 0], [1, 2], [3, 0], [5, 0]]);
+    /// ```
+    #[track_caller]
+    pub fn unique_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.unique_axis_inverse(axis).0
+    }
+
+    /// Along `axis`, remove the duplicate subviews like
+    /// [`.unique_axis()`](Self::unique_axis), and also return the index of
+    /// each subview of `self` in the result.
+    ///
+    /// Return `(unique, inverse)`, where `inverse` has the length of `axis`
+    /// in `self`; selecting `inverse` along `axis` of `unique` recreates
+    /// `self`.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if two elements that are
+    /// equal to themselves are not comparable.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[2., 1.], [0., f64::NAN], [2., 1.], [0., f64::NAN]];
+    /// let (unique, inverse) = a.unique_axis_inverse(Axis(0));
+    /// assert_eq!(unique.row(1), array![2., 1.]);
+    /// assert_eq!(inverse, array![1, 0, 1, 0]);
+    /// ```
+    #[track_caller]
+    pub fn unique_axis_inverse(&self, axis: Axis) -> (Array<A, D>, Array<usize, Ix1>)
+    where
+        A: Clone + PartialOrd,
+        S: Data,
+        D: RemoveAxis,
+    {
+        let subviews: Vec<_> = self.axis_iter(axis).collect();
+        let cmp_subviews = |&i: &Ix, &j: &Ix| {
+            subviews[i]
+                .iter()
+                .zip(subviews[j].iter())
+                .map(|(a, b)| nan_last_order(a, b))
+                .find(|&ord| ord != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        };
+        let mut order: Vec<Ix> = (0..subviews.len()).collect();
+        order.sort_by(cmp_subviews);
+
+        let mut unique = Vec::new();
+        let mut inverse = vec![0; subviews.len()];
+        for (k, &i) in order.iter().enumerate() {
+            if k == 0 || cmp_subviews(&order[k - 1], &i) != Ordering::Equal {
+                unique.push(i);
+            }
+            inverse[i] = unique.len() - 1;
+        }
+        (self.select(axis, &unique), Array::from(inverse))
+    }
+
+    /// Along `axis`, set every element of the subviews at `indices` to
+    /// `value`.
+    ///
+    /// This is the inverse of [`.select()`](Self::select) for a single value.
+    /// Repeated indices are allowed.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds; in
+    /// that case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// a.index_fill(Axis(1), &[0, 2], 0);
+    /// assert_eq!(a, array![[0, 2, 0],
+    ///                      [0, 5, 0]]);
+    /// ```
+    #[track_caller]
+    pub fn index_fill(&mut self, axis: Axis, indices: &[Ix], value: A)
+    where
+        A: Clone,
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        check_axis_indices(indices, self.len_of(axis));
+        for &index in indices {
+            self.index_axis_mut(axis, index).fill(value.clone());
+        }
+    }
+
+    /// Along `axis`, assign the subviews of `src` to the subviews of `self`
+    /// at `indices`: subview `k` of `src` is written to subview `indices[k]`.
+    ///
+    /// This is the inverse of [`.select()`](Self::select). If an index is
+    /// repeated, the last subview written to it wins; see
+    /// [`.scatter_add()`](Self::scatter_add) to accumulate instead.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds, or
+    /// if the shape of `src` is not the shape of `self` with the length of
+    /// `axis` replaced by `indices.len()`; in that case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[0, 0],
+    ///                    [0, 0],
+    ///                    [0, 0]];
+    /// a.index_assign(Axis(0), &[2, 0], &array![[1, 2], [3, 4]]);
+    /// assert_eq!(a, array![[3, 4],
+    ///                      [0, 0],
+    ///                      [1, 2]]);
+    /// ```
+    #[track_caller]
+    pub fn index_assign<S2>(&mut self, axis: Axis, indices: &[Ix], src: &ArrayBase<S2, D>)
+    where
+        A: Clone,
+        S: DataMut,
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        self.check_scatter_source(axis, indices, src);
+        for (&index, sub) in zip(indices, src.axis_iter(axis)) {
+            self.index_axis_mut(axis, index).assign(&sub);
+        }
+    }
+
+    /// Along `axis`, add the subviews of `src` to the subviews of `self` at
+    /// `indices`: subview `k` of `src` is added to subview `indices[k]`.
+    ///
+    /// Repeated indices accumulate: every subview of `src` is added, in
+    /// order, so the result is deterministic. This makes it suitable for
+    /// histograms or for the gradient of an embedding lookup.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds, or
+    /// if the shape of `src` is not the shape of `self` with the length of
+    /// `axis` replaced by `indices.len()`; in that case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut counts = array![0, 0, 0];
+    /// counts.scatter_add(Axis(0), &[1, 2, 1, 1], &array![1, 1, 1, 1]);
+    /// assert_eq!(counts, array![0, 3, 1]);
+    /// ```
+    #[track_caller]
+    pub fn scatter_add<S2>(&mut self, axis: Axis, indices: &[Ix], src: &ArrayBase<S2, D>)
+    where
+        A: Clone + Add<Output = A>,
+        S: DataMut,
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        self.check_scatter_source(axis, indices, src);
+        for (&index, sub) in zip(indices, src.axis_iter(axis)) {
+            self.index_axis_mut(axis, index)
+                .zip_mut_with(&sub, |x, y| *x = x.clone() + y.clone());
+        }
+    }
+
+    /// Check the arguments of `index_assign` and `scatter_add`.
+    #[track_caller]
+    fn check_scatter_source<S2>(&self, axis: Axis, indices: &[Ix], src: &ArrayBase<S2, D>)
+    where
+        S2: RawData,
+        D: RemoveAxis,
+    {
+        check_axis_indices(indices, self.len_of(axis));
+        let mut expected = self.raw_dim();
+        expected.set_axis(axis, indices.len());
+        if src.raw_dim() != expected {
+            panic!(
+                "ndarray: source of shape {:?} does not match {} indices along axis {} of array of shape {:?}",
+                src.shape(),
+                indices.len(),
+                axis.index(),
+                self.shape()
+            );
+        }
+    }
+
     /// Return a producer and iterable that traverses over the *generalized*
     /// rows of the array. For a 2D array these are the regular rows.
     ///
@@ -1255,6 +1711,62 @@ where
         LanesMut::new(self.view_mut(), axis)
     }
 
+    /// Return an iterator over the elements of the array as contiguous
+    /// slices, that are as long as possible.
+    ///
+    /// The slices are the maximal runs of elements that are adjacent in
+    /// memory, and all have the same length, see
+    /// [`.run_len()`](crate::iter::InnerSlices::run_len). The elements are
+    /// visited in memory order: axes of length one are ignored, inverted axes
+    /// are traversed backwards, and the axes are traversed from the largest to
+    /// the smallest stride. For example:
+    ///
+    /// - A contiguous array (in any order) is one slice of all elements, like
+    ///   [`.as_slice_memory_order()`](Self::as_slice_memory_order).
+    /// - A slice of some of the columns of a C order matrix is one slice per
+    ///   row.
+    /// - If no two elements are adjacent in memory, the slices have length one.
+    ///
+    /// An array with no elements has no slices.
+    ///
+    /// ```
+    /// use ndarray::{s, Array2};
+    ///
+    /// let a = Array2::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    /// assert_eq!(a.inner_slices().len(), 1);
+    ///
+    /// let cols = a.slice(s![.., 1..3]);
+    /// let runs = cols.inner_slices();
+    /// assert_eq!(runs.run_len(), 2);
+    /// assert_eq!(runs.collect::<Vec<_>>(), vec![&[1, 2], &[11, 12], &[21, 22]]);
+    /// ```
+    pub fn inner_slices(&self) -> InnerSlices<'_, A>
+    where S: Data
+    {
+        InnerSlices::new(self.view())
+    }
+
+    /// Return an iterator over the elements of the array as contiguous
+    /// mutable slices, that are as long as possible.
+    ///
+    /// The slices are disjoint. See [`.inner_slices()`](Self::inner_slices)
+    /// for the order and length of the slices.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// for run in a.slice_mut(s![.., 1..]).inner_slices_mut() {
+    ///     run.reverse();
+    /// }
+    /// assert_eq!(a, array![[1, 3, 2], [4, 6, 5]]);
+    /// ```
+    pub fn inner_slices_mut(&mut self) -> InnerSlicesMut<'_, A>
+    where S: DataMut
+    {
+        InnerSlicesMut::new(self.view_mut())
+    }
+
     /// Return an iterator that traverses over the outermost dimension
     /// and yields each subview.
     ///
@@ -1294,12 +1806,23 @@ where
     /// in the picture below.
     ///
     /// Iterator element is `ArrayView<A, D::Smaller>` (read-only array view).
+    /// The iterator implements `DoubleEndedIterator` and `ExactSizeIterator`.
     ///
     /// See [*Subviews*](#subviews) for full documentation.
     ///
     /// **Panics** if `axis` is out of bounds.
     ///
     /// <img src="https://rust-ndarray.github.io/ndarray/images/axis_iter_3_4_5.svg" height="250px">
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// let mut rows = a.axis_iter(Axis(0));
+    /// assert_eq!(rows.len(), 3);
+    /// assert_eq!(rows.next_back().unwrap(), array![5, 6]);
+    /// assert_eq!(rows.len(), 2);
+    /// ```
     #[track_caller]
     pub fn axis_iter(&self, axis: Axis) -> AxisIter<'_, A, D::Smaller>
     where
@@ -1313,9 +1836,21 @@ where
     /// and yields each mutable subview along it.
     ///
     /// Iterator element is `ArrayViewMut<A, D::Smaller>`
-    /// (read-write array view).
+    /// (read-write array view). The subviews are disjoint, so they can all be
+    /// held at the same time. Like [`.axis_iter()`](Self::axis_iter), the
+    /// iterator is double-ended and has an exact length.
     ///
     /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Axis};
+    ///
+    /// let mut a = Array2::zeros((2, 3));
+    /// for (i, mut column) in a.axis_iter_mut(Axis(1)).enumerate().rev() {
+    ///     column.fill(i);
+    /// }
+    /// assert_eq!(a, array![[0, 1, 2], [0, 1, 2]]);
+    /// ```
     #[track_caller]
     pub fn axis_iter_mut(&mut self, axis: Axis) -> AxisIterMut<'_, A, D::Smaller>
     where
@@ -1325,6 +1860,71 @@ where
         AxisIterMut::new(self.view_mut(), axis)
     }
 
+    /// Return an iterator that traverses over all the indices along the
+    /// `axes` and yields the subview with those axes removed at each of them.
+    ///
+    /// This generalizes [`.axis_iter()`](Self::axis_iter) to several axes:
+    /// for example, in an `N × H × W × C` array, with `axes` equal to
+    /// `&[Axis(0), Axis(3)]`, the iterator yields the `N · C` subviews of
+    /// shape `H × W`. The listed axes are traversed in row major order of
+    /// their indices in `self`, whatever their order in `axes`.
+    ///
+    /// Listing all the axes yields a zero-dimensional view of each element,
+    /// in logical order, and listing no axis yields the whole array once.
+    ///
+    /// Iterator element is `ArrayView<A, IxDyn>` (read-only array view).
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+    /// let mut iter = a.multi_axis_iter(&[Axis(2), Axis(0)]).unwrap();
+    /// assert_eq!(iter.len(), 8);
+    ///
+    /// // the subviews for (i, k) = (0, 0), (0, 1), ...
+    /// assert_eq!(iter.next().unwrap(), ndarray::arr1(&[0, 10, 20]).into_dyn());
+    /// assert_eq!(iter.next().unwrap(), ndarray::arr1(&[1, 11, 21]).into_dyn());
+    ///
+    /// assert!(a.multi_axis_iter(&[Axis(1), Axis(1)]).is_err());
+    /// ```
+    pub fn multi_axis_iter(&self, axes: &[Axis]) -> Result<MultiAxisIter<'_, A>, ShapeError>
+    where S: Data
+    {
+        MultiAxisIter::new(self.view(), axes)
+    }
+
+    /// Return an iterator that traverses over all the indices along the
+    /// `axes` and yields the mutable subview with those axes removed at each
+    /// of them.
+    ///
+    /// See [`.multi_axis_iter()`](Self::multi_axis_iter) for the order and
+    /// shape of the subviews. The subviews are disjoint, like those of
+    /// [`.axis_iter_mut()`](Self::axis_iter_mut): each is at a different
+    /// index along the `axes`, so they can all be held at the same time.
+    ///
+    /// Iterator element is `ArrayViewMut<A, IxDyn>` (read-write array view).
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let mut images = Array::<f32, _>::ones((2, 4, 4, 3));
+    /// for (i, mut image) in images.multi_axis_iter_mut(&[Axis(0), Axis(3)]).unwrap().enumerate() {
+    ///     image *= i as f32;
+    /// }
+    /// assert_eq!(images[[1, 2, 2, 1]], 4.);
+    /// ```
+    pub fn multi_axis_iter_mut(&mut self, axes: &[Axis]) -> Result<MultiAxisIterMut<'_, A>, ShapeError>
+    where S: DataMut
+    {
+        MultiAxisIterMut::new(self.view_mut(), axes)
+    }
+
     /// Return an iterator that traverses over `axis` by chunks of `size`,
     /// yielding non-overlapping views along that axis.
     ///
@@ -1379,9 +1979,24 @@ where
     /// The produced element is a `ArrayView<A, D>` with exactly the dimension
     /// `chunk_size`.
     ///
+    /// The chunks are also a producer for [`Zip`], whose shape is the grid of
+    /// whole chunks.
+    ///
     /// **Panics** if any dimension of `chunk_size` is zero<br>
     /// (**Panics** if `D` is `IxDyn` and `chunk_size` does not match the
     /// number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Zip};
+    ///
+    /// // sums of the 2 × 2 blocks; the last column does not fit in a block
+    /// let a = Array2::from_shape_fn((4, 5), |(i, j)| i * 5 + j);
+    /// let mut sums = Array2::zeros((2, 2));
+    /// Zip::from(&mut sums)
+    ///     .and(a.exact_chunks((2, 2)))
+    ///     .for_each(|s, chunk| *s = chunk.sum());
+    /// assert_eq!(sums, array![[12, 20], [52, 60]]);
+    /// ```
     #[track_caller]
     pub fn exact_chunks<E>(&self, chunk_size: E) -> ExactChunks<'_, A, D>
     where
@@ -1438,8 +2053,22 @@ where
     /// that fit into the array's shape.
     ///
     /// This is essentially equivalent to [`.windows_with_stride()`] with unit stride.
-    #[track_caller]
-    pub fn windows<E>(&self, window_size: E) -> Windows<'_, A, D>
+    ///
+    /// The windows are also a producer for [`Zip`], whose shape is the grid of
+    /// window positions. For example, a 3 × 3 box filter:
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Zip};
+    ///
+    /// let a = Array2::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as f64);
+    /// let mut out = Array2::zeros((2, 3));
+    /// Zip::from(&mut out)
+    ///     .and(a.windows((3, 3)))
+    ///     .for_each(|o, w| *o = w.sum() / 9.);
+    /// assert_eq!(out, array![[6., 7., 8.], [11., 12., 13.]]);
+    /// ```
+    #[track_caller]
+    pub fn windows<E>(&self, window_size: E) -> Windows<'_, A, D>
     where
         E: IntoDimension<Dim = D>,
         S: Data,
@@ -1491,6 +2120,19 @@ where
     ///          ┃ a₂₀ ┃ a₂₁ ┃     │     │   │     │     ┃ a₂₂ ┃ a₂₃ ┃
     ///          ┗━━━━━┻━━━━━┹─────┴─────┘   └─────┴─────┺━━━━━┻━━━━━┛
     /// ```
+    ///
+    /// For example, to extract 4×4 patches every 2 pixels of an image:
+    ///
+    /// ```
+    /// use ndarray::{Array2, Zip};
+    ///
+    /// let image = Array2::from_shape_fn((8, 10), |(i, j)| (i * 10 + j) as f32);
+    /// let patches = image.windows_with_stride((4, 4), (2, 2));
+    /// let means = Zip::from(patches).map_collect(|patch| patch.mean().unwrap());
+    /// // windows start at rows 0, 2, 4 and columns 0, 2, 4, 6
+    /// assert_eq!(means.shape(), &[3, 4]);
+    /// assert_eq!(means[[1, 2]], image.slice(ndarray::s![2..6, 4..8]).mean().unwrap());
+    /// ```
     #[track_caller]
     pub fn windows_with_stride<E>(&self, window_size: E, stride: E) -> Windows<'_, A, D>
     where
@@ -1611,6 +2253,45 @@ where
         dimension::is_layout_c(&self.dim, &self.strides)
     }
 
+    /// Return `true` if the array data is laid out in contiguous “F order” in
+    /// memory (where the first index is the most rapidly varying).
+    ///
+    /// Return `false` otherwise. See also
+    /// [`.is_standard_layout()`](Self::is_standard_layout).
+    ///
+    /// ```
+    /// use ndarray::{Array2, ShapeBuilder};
+    ///
+    /// let a = Array2::<f64>::zeros((3, 4).f());
+    /// assert!(a.is_fortran_layout());
+    /// assert!(!a.is_standard_layout());
+    /// assert!(a.t().is_standard_layout());
+    /// ```
+    pub fn is_fortran_layout(&self) -> bool
+    {
+        dimension::is_layout_f(&self.dim, &self.strides)
+    }
+
+    /// Return a description of the memory layout of the array.
+    ///
+    /// The [`Layout`] tells if the array is contiguous in C order, F order,
+    /// both or neither. Arrays with at most one axis of length greater than
+    /// one, and arrays without elements, are in both C and F order.
+    ///
+    /// ```
+    /// use ndarray::{s, Array2};
+    ///
+    /// let a = Array2::<f64>::zeros((3, 4));
+    /// assert!(a.layout().is_c() && !a.layout().is_f());
+    /// assert!(a.t().layout().is_f());
+    /// assert!(a.row(1).layout().is_c() && a.row(1).layout().is_f());
+    /// assert!(!a.slice(s![.., ..2]).layout().is_contiguous());
+    /// ```
+    pub fn layout(&self) -> Layout
+    {
+        self.layout_impl()
+    }
+
     /// Return true if the array is known to be contiguous.
     pub(crate) fn is_contiguous(&self) -> bool
     {
@@ -1646,6 +2327,8 @@ where
     {
         if self.is_standard_layout() {
             CowArray::from(self.view())
+        } else if let Some(owned) = self.to_owned_blocked() {
+            CowArray::from(owned)
         } else {
             let v = crate::iterators::to_vec_mapped(self.iter(), A::clone);
             let dim = self.dim.clone();
@@ -1864,7 +2547,7 @@ where
     {
         let len = self.dim.size();
         if size_of_shape_checked(&shape) != Ok(len) {
-            return Err(error::incompatible_shapes(&self.dim, &shape));
+            return Err(error::incompatible_shapes(&self.dim, &shape).in_operation("to_shape"));
         }
 
         // Create a view if the length is 0, safe because the array and new shape is empty.
@@ -1880,7 +2563,7 @@ where
                 return Ok(CowArray::from(ArrayView::new(self.ptr, shape, to_strides)));
             },
             Err(err) if err.kind() == ErrorKind::IncompatibleShape => {
-                return Err(error::incompatible_shapes(&self.dim, &shape));
+                return Err(error::incompatible_shapes(&self.dim, &shape).in_operation("to_shape"));
             }
             _otherwise => {}
         }
@@ -1895,6 +2578,75 @@ where
         }
     }
 
+    /// Flatten the array to a one-dimensional array, in row major order.
+    ///
+    /// This is the same as [`.flatten_with_order(Order::RowMajor)`](Self::flatten_with_order).
+    ///
+    /// ```
+    /// use ndarray::{array, arr3};
+    ///
+    /// let a = arr3(&[[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+    /// assert_eq!(a.flatten(), array![1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn flatten(&self) -> CowArray<'_, A, Ix1>
+    where
+        A: Clone,
+        S: Data,
+    {
+        self.flatten_with_order(Order::RowMajor)
+    }
+
+    /// Flatten the array to a one-dimensional array, with the elements in
+    /// `order`.
+    ///
+    /// The result is a view of the array if its elements can be traversed
+    /// in `order` with a single stride, for example if the array is
+    /// contiguous in memory in that order; otherwise the elements are
+    /// cloned into a new array.
+    ///
+    /// ```
+    /// use ndarray::{array, Order};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.flatten_with_order(Order::RowMajor), array![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(a.flatten_with_order(Order::ColumnMajor), array![1, 4, 2, 5, 3, 6]);
+    /// assert!(a.flatten_with_order(Order::RowMajor).is_view());
+    /// assert!(a.flatten_with_order(Order::ColumnMajor).is_owned());
+    /// ```
+    pub fn flatten_with_order(&self, order: Order) -> CowArray<'_, A, Ix1>
+    where
+        A: Clone,
+        S: Data,
+    {
+        self.to_shape((self.len(), order)).unwrap()
+    }
+
+    /// Return a vector with clones of the elements of the array, in `order`.
+    ///
+    /// The elements are copied straight from memory if the array is
+    /// contiguous in that order.
+    ///
+    /// ```
+    /// use ndarray::{array, Order};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.to_vec_with_order(Order::C), vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(a.to_vec_with_order(Order::F), vec![1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn to_vec_with_order(&self, order: Order) -> Vec<A>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let flat = self.flatten_with_order(order);
+        if flat.is_view() {
+            flat.to_vec()
+        } else {
+            // a new array in standard layout
+            flat.into_owned().into_raw_vec_and_offset().0
+        }
+    }
+
     /// Transform the array into `shape`; any shape with the same number of
     /// elements is accepted, but the source array must be contiguous.
     ///
@@ -1952,7 +2704,7 @@ where
     {
         let shape = shape.into_dimension();
         if size_of_shape_checked(&shape) != Ok(self.dim.size()) {
-            return Err(error::incompatible_shapes(&self.dim, &shape));
+            return Err(error::incompatible_shapes(&self.dim, &shape).in_operation("into_shape_with_order"));
         }
 
         // Check if contiguous, then we can change shape
@@ -1997,7 +2749,7 @@ where
     {
         let shape = shape.into_dimension();
         if size_of_shape_checked(&shape) != Ok(self.dim.size()) {
-            return Err(error::incompatible_shapes(&self.dim, &shape));
+            return Err(error::incompatible_shapes(&self.dim, &shape).in_operation("into_shape"));
         }
         // Check if contiguous, if not => copy all, else just adapt strides
         unsafe {
@@ -2046,7 +2798,7 @@ where
     {
         let len = self.dim.size();
         if size_of_shape_checked(&shape) != Ok(len) {
-            return Err(error::incompatible_shapes(&self.dim, &shape));
+            return Err(error::incompatible_shapes(&self.dim, &shape).in_operation("into_shape_clone"));
         }
 
         // Safe because the array and new shape is empty.
@@ -2062,7 +2814,7 @@ where
                 return Ok(self.with_strides_dim(to_strides, shape));
             },
             Err(err) if err.kind() == ErrorKind::IncompatibleShape => {
-                return Err(error::incompatible_shapes(&self.dim, &shape));
+                return Err(error::incompatible_shapes(&self.dim, &shape).in_operation("into_shape_clone"));
             }
             _otherwise => {}
         }
@@ -2236,38 +2988,17 @@ where
             // to be met since we're starting from a valid `ArrayBase`.
             let _ = size_of_shape_checked(to).ok()?;
 
-            let mut new_stride = to.clone();
             // begin at the back (the least significant dimension)
             // size of the axis has to either agree or `from` has to be 1
-            if to.ndim() < from.ndim() {
-                return None;
-            }
-
-            {
-                let mut new_stride_iter = new_stride.slice_mut().iter_mut().rev();
-                for ((er, es), dr) in from
-                    .slice()
-                    .iter()
-                    .rev()
-                    .zip(stride.slice().iter().rev())
-                    .zip(new_stride_iter.by_ref())
-                {
-                    /* update strides */
-                    if *dr == *er {
-                        /* keep stride */
-                        *dr = *es;
-                    } else if *er == 1 {
-                        /* dead dimension, zero stride */
-                        *dr = 0
-                    } else {
-                        return None;
-                    }
-                }
-
-                /* set remaining strides to zero */
-                for dr in new_stride_iter {
-                    *dr = 0;
-                }
+            let k = to.ndim().checked_sub(from.ndim())?;
+            let mut new_stride = to.clone();
+            for (axis, dr) in new_stride.slice_mut().iter_mut().enumerate() {
+                *dr = match broadcast_axis(from.slice(), to.slice(), axis)? {
+                    /* keep stride */
+                    BroadcastAxis::Matched => stride[axis - k],
+                    /* dead or new dimension, zero stride */
+                    BroadcastAxis::Stretched | BroadcastAxis::Prepended => 0,
+                };
             }
             Some(new_stride)
         }
@@ -2281,6 +3012,47 @@ where
         unsafe { Some(ArrayView::new(self.ptr, dim, broadcast_strides)) }
     }
 
+    /// Act like [`.broadcast()`](Self::broadcast), and also report how each
+    /// axis of the result was obtained from the axes of the array: kept
+    /// ([`Matched`](BroadcastAxis::Matched)), repeated from an axis of
+    /// length one ([`Stretched`](BroadcastAxis::Stretched)) or added in
+    /// front ([`Prepended`](BroadcastAxis::Prepended)).
+    ///
+    /// **Errors** if the shapes can not be broadcast together, with a message
+    /// that names the axes that do not match, or if the number of elements
+    /// of `dim` would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{Array, BroadcastAxis};
+    ///
+    /// let a = Array::<f64, _>::zeros((3, 1));
+    /// let (view, report) = a.broadcast_with_report((2, 3, 4)).unwrap();
+    /// assert_eq!(view.shape(), &[2, 3, 4]);
+    /// assert_eq!(report, [BroadcastAxis::Prepended, BroadcastAxis::Matched, BroadcastAxis::Stretched]);
+    ///
+    /// let err = a.broadcast_with_report((4, 2)).unwrap_err();
+    /// assert!(err.to_string().contains("axis 0 of the array has length 3 but axis 0 of the shape has length 4"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn broadcast_with_report<E>(&self, dim: E) -> Result<(ArrayView<'_, A, E::Dim>, Vec<BroadcastAxis>), ShapeError>
+    where
+        E: IntoDimension,
+        S: Data,
+    {
+        let dim = dim.into_dimension();
+        match self.broadcast(dim.clone()) {
+            Some(view) => {
+                let report = (0..dim.ndim())
+                    .map(|axis| broadcast_axis(self.shape(), dim.slice(), axis).unwrap())
+                    .collect();
+                Ok((view, report))
+            }
+            None if size_of_shape_checked(&dim).is_err() =>
+                Err(from_kind(ErrorKind::Overflow).in_operation("broadcast_with_report")),
+            None => Err(error::broadcast_to(&self.dim, &dim).in_operation("broadcast_with_report")),
+        }
+    }
+
     /// For two arrays or views, find their common shape if possible and
     /// broadcast them as array views into that shape.
     ///
@@ -2295,7 +3067,8 @@ where
         D: Dimension + DimMax<E>,
         E: Dimension,
     {
-        let shape = co_broadcast::<D, E, <D as DimMax<E>>::Output>(&self.dim, &other.dim)?;
+        let shape = co_broadcast::<D, E, <D as DimMax<E>>::Output>(&self.dim, &other.dim)
+            .map_err(|_| error::incompatible_shapes(&self.dim, &other.dim).in_operation("broadcast"))?;
         let view1 = if shape.slice() == self.dim.slice() {
             self.view()
                 .into_dimensionality::<<D as DimMax<E>>::Output>()
@@ -2553,7 +3326,38 @@ where
         A: Clone,
         S2: Data<Elem = A>,
     {
-        self.zip_mut_with(rhs, |x, y| x.clone_from(y));
+        self.zip_mut_with_in(rhs, |x, y| x.clone_from(y), "assign");
+    }
+
+    /// Perform an elementwise assigment to `self` from `rhs`, returning an
+    /// error if `rhs` can not be broadcast to the shape of `self`.
+    ///
+    /// This is like [`.assign()`](Self::assign): if the shapes disagree,
+    /// `rhs` is broadcast to the shape of `self`. For example, a row or a
+    /// column can be assigned to every row or column of a matrix.
+    ///
+    /// **Errors** with `ErrorKind::IncompatibleShape` if broadcasting isn’t
+    /// possible, in which case `self` is left unchanged.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, ErrorKind};
+    ///
+    /// let mut a = Array2::zeros((2, 3));
+    /// a.try_assign(&array![1, 2, 3]).unwrap();
+    /// assert_eq!(a, array![[1, 2, 3], [1, 2, 3]]);
+    /// a.try_assign(&array![[4], [5]]).unwrap();
+    /// assert_eq!(a, array![[4, 4, 4], [5, 5, 5]]);
+    ///
+    /// let err = a.try_assign(&array![1, 2]).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    /// ```
+    pub fn try_assign<E: Dimension, S2>(&mut self, rhs: &ArrayBase<S2, E>) -> Result<(), ShapeError>
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = A>,
+    {
+        self.try_zip_mut_with_in(rhs, |x, y| x.clone_from(y), "assign")
     }
 
     /// Perform an elementwise assigment of values cloned from `self` into array or producer `to`.
@@ -2574,6 +3378,19 @@ where
     }
 
     /// Perform an elementwise assigment to `self` from element `x`.
+    ///
+    /// The elements are visited in memory order where possible, and each one
+    /// is overwritten with a clone of `x`.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// a.slice_mut(s![.., 1..]).fill(0);
+    /// assert_eq!(a, array![[1, 0, 0],
+    ///                      [4, 0, 0]]);
+    /// ```
     pub fn fill(&mut self, x: A)
     where
         S: DataMut,
@@ -2621,9 +3438,19 @@ where
         // break the arrays up into their inner rows
         let n = self.ndim();
         let dim = self.raw_dim();
-        Zip::from(LanesMut::new(self.view_mut(), Axis(n - 1)))
-            .and(Lanes::new(rhs.broadcast_assume(dim), Axis(n - 1)))
-            .for_each(move |s_row, r_row| Zip::from(s_row).and(r_row).for_each(|a, b| f(a, b)));
+        let lanes = Zip::from(LanesMut::new(self.view_mut(), Axis(n - 1)))
+            .and(Lanes::new(rhs.broadcast_assume(dim), Axis(n - 1)));
+        if rhs.strides[n - 1] == 0 {
+            // each rhs row repeats a single element (like a broadcast column),
+            // so load it once per row
+            lanes.for_each(move |s_row, r_row| {
+                if let Some(b) = r_row.first() {
+                    s_row.into_iter().for_each(|a| f(a, b));
+                }
+            });
+        } else {
+            lanes.for_each(move |s_row, r_row| Zip::from(s_row).and(r_row).for_each(|a, b| f(a, b)));
+        }
     }
 
     fn zip_mut_with_elem<B, F>(&mut self, rhs_elem: &B, mut f: F)
@@ -2643,6 +3470,19 @@ where
     #[track_caller]
     #[inline]
     pub fn zip_mut_with<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, f: F)
+    where
+        S: DataMut,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(&mut A, &B),
+    {
+        self.zip_mut_with_in(rhs, f, "zip_mut_with")
+    }
+
+    /// Like `zip_mut_with`, naming operation `op` if broadcasting fails.
+    #[track_caller]
+    #[inline]
+    pub(crate) fn zip_mut_with_in<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, f: F, op: &str)
     where
         S: DataMut,
         S2: Data<Elem = B>,
@@ -2655,11 +3495,127 @@ where
         } else if self.dim.ndim() == rhs.dim.ndim() && self.shape() == rhs.shape() {
             self.zip_mut_with_same_shape(rhs, f);
         } else {
-            let rhs_broadcast = rhs.broadcast_unwrap(self.raw_dim());
+            let rhs_broadcast = rhs.broadcast_unwrap(self.raw_dim(), op);
             self.zip_mut_with_by_rows(&rhs_broadcast, f);
         }
     }
 
+    /// Traverse two arrays in unspecified order, in lock step,
+    /// calling the closure `f` on each element pair, or return an error if
+    /// `rhs` can not be broadcast to the shape of `self`.
+    ///
+    /// This is like [`.zip_mut_with()`](Self::zip_mut_with): if their shapes
+    /// disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Errors** with `ErrorKind::IncompatibleShape` if broadcasting isn’t
+    /// possible, in which case `f` is not called.
+    pub fn try_zip_mut_with<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, f: F) -> Result<(), ShapeError>
+    where
+        S: DataMut,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(&mut A, &B),
+    {
+        self.try_zip_mut_with_in(rhs, f, "zip_mut_with")
+    }
+
+    /// Like `try_zip_mut_with`, naming operation `op` in the error.
+    fn try_zip_mut_with_in<B, S2, E, F>(
+        &mut self, rhs: &ArrayBase<S2, E>, f: F, op: &'static str,
+    ) -> Result<(), ShapeError>
+    where
+        S: DataMut,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(&mut A, &B),
+    {
+        if rhs.dim.ndim() == 0 {
+            self.zip_mut_with_elem(rhs.get_0d(), f);
+        } else if self.dim.ndim() == rhs.dim.ndim() && self.shape() == rhs.shape() {
+            self.zip_mut_with_same_shape(rhs, f);
+        } else {
+            match rhs.broadcast(self.raw_dim()) {
+                Some(rhs_broadcast) => self.zip_mut_with_by_rows(&rhs_broadcast, f),
+                None => return Err(error::incompatible_shapes(&rhs.dim, &self.dim).in_operation(op)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Call `f` by reference on each pair of elements of `self` and `rhs`,
+    /// in lock step, and create a new array with the results.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    /// The elements of the result are written once, into a single new
+    /// allocation.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// Return an array with the same shape as `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1.5, 2.5], [3.5, 4.5]];
+    /// let b = array![2, 4];
+    /// assert_eq!(a.zip_map(&b, |&x, &y| x > y as f64),
+    ///            array![[false, false], [true, true]]);
+    /// ```
+    #[track_caller]
+    pub fn zip_map<B, C, S2, E, F>(&self, rhs: &ArrayBase<S2, E>, mut f: F) -> Array<C, D>
+    where
+        S: Data,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(&A, &B) -> C,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim(), "zip_map");
+        Zip::from(self).and(&rhs).map_collect(|a, b| f(a, b))
+    }
+
+    /// Call the fallible `f` by reference on each pair of elements of `self`
+    /// and `rhs`, in lock step, and create a new array with the results, or
+    /// return the first error from `f`.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// Elements are visited in logical order, and `f` is not called again
+    /// after it returns an error.
+    ///
+    /// Return an array with the same shape as `self`, in standard (row major)
+    /// memory layout.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let digits = array![["1", "2"], ["3", "x"]];
+    /// let radix = array![10, 16];
+    /// let parse = |s: &&str, &r: &u32| u32::from_str_radix(s, r);
+    ///
+    /// assert!(digits.try_zip_map(&radix, parse).is_err());
+    /// assert_eq!(digits.slice(ndarray::s![..1, ..]).try_zip_map(&radix, parse),
+    ///            Ok(array![[1, 2]]));
+    /// ```
+    #[track_caller]
+    pub fn try_zip_map<B, C, Er, S2, E, F>(&self, rhs: &ArrayBase<S2, E>, mut f: F) -> Result<Array<C, D>, Er>
+    where
+        S: Data,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(&A, &B) -> Result<C, Er>,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim(), "try_zip_map");
+        let mut v = Vec::with_capacity(self.len());
+        for (a, b) in self.iter().zip(rhs.iter()) {
+            v.push(f(a, b)?);
+        }
+        unsafe { Ok(ArrayBase::from_shape_vec_unchecked(self.raw_dim(), v)) }
+    }
+
     /// Traverse the array elements and apply a fold,
     /// returning the resulting value.
     ///
@@ -2673,9 +3629,10 @@ where
         if let Some(slc) = self.as_slice_memory_order() {
             slc.iter().fold(init, f)
         } else {
-            let mut v = self.view();
-            move_min_stride_axis_to_last(&mut v.dim, &mut v.strides);
-            v.into_elements_base().fold(init, f)
+            self.view()
+                .into_memory_order()
+                .into_elements_base()
+                .fold(init, f)
         }
     }
 
@@ -2738,6 +3695,43 @@ where
         }
     }
 
+    /// Call `f` with the index and a reference to each element and create a
+    /// new array with the new values.
+    ///
+    /// The index is given as `D::Pattern`, like for
+    /// [`indexed_iter`](Self::indexed_iter), and the element of the result
+    /// at each index is `f` of the index and the element of `self` at the
+    /// same index, whatever the memory layout of `self`.
+    ///
+    /// Elements are visited in logical order.
+    ///
+    /// Return an array with the same shape as `self`, in standard (row major)
+    /// memory layout.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(
+    ///     a.t().map_indexed(|(i, j), &x| 10 * x + i * 2 + j),
+    ///     arr2(&[[10, 31],
+    ///            [22, 43]])
+    /// );
+    /// ```
+    pub fn map_indexed<'a, B, F>(&'a self, mut f: F) -> Array<B, D>
+    where
+        F: FnMut(D::Pattern, &'a A) -> B,
+        A: 'a,
+        S: Data,
+    {
+        unsafe {
+            ArrayBase::from_shape_trusted_iter_unchecked(self.dim.clone(), self.indexed_iter(), move |(index, elt)| {
+                f(index, elt)
+            })
+        }
+    }
+
     /// Call `f` by **v**alue on each element and create a new array
     /// with the new values.
     ///
@@ -2785,8 +3779,13 @@ where
     /// to [`mapv_into`] and then converting into an owned array. This avoids
     /// unnecessary memory allocations in [`mapv`].
     ///
-    /// If `A` and `B` are different types then a new array is allocated and the
-    /// map is performed as in [`mapv`].
+    /// If `A` and `B` are different types with the same size and alignment,
+    /// such as `f32` and `u32`, and the array owns its elements and is
+    /// contiguous without any unused elements in its allocation, then the
+    /// allocation is reused for the result.
+    ///
+    /// Otherwise a new array is allocated and the map is performed as in
+    /// [`mapv`].
     ///
     /// Elements are visited in arbitrary order.
     ///
@@ -2813,6 +3812,14 @@ where
             // Change the return type from Array<A, D> to Array<B, D>.
             // Again, safe because A and B are the same type.
             unsafe { unlimited_transmute::<Array<A, D>, Array<B, D>>(output) }
+        } else if size_of::<A>() == size_of::<B>() && std::mem::align_of::<A>() == std::mem::align_of::<B>() {
+            // A and B have the same layout, so an owned array that uses its
+            // whole allocation can be mapped in place.
+            match S::try_into_owned_nocopy(self) {
+                Ok(owned) if owned.uses_whole_allocation() => owned.mapv_into_same_layout(f),
+                Ok(owned) => owned.mapv(f),
+                Err(other) => other.mapv(f),
+            }
         } else {
             // A and B are not the same type.
             // Fallback to mapv().
@@ -2831,11 +3838,11 @@ where
     {
         match self.try_as_slice_memory_order_mut() {
             Ok(slc) => slc.iter_mut().for_each(f),
-            Err(arr) => {
-                let mut v = arr.view_mut();
-                move_min_stride_axis_to_last(&mut v.dim, &mut v.strides);
-                v.into_elements_base().for_each(f);
-            }
+            Err(arr) => arr
+                .view_mut()
+                .into_memory_order()
+                .into_elements_base()
+                .for_each(f),
         }
     }
 
@@ -2871,7 +3878,12 @@ where
 
     /// Call `f` for each element in the array.
     ///
-    /// Elements are visited in arbitrary order.
+    /// Elements are visited in arbitrary order: the traversal follows the
+    /// memory layout of the array, like
+    /// [`.iter_memory_order()`](Self::iter_memory_order), which is faster
+    /// than `.iter().for_each(f)` for arrays that are not in standard layout.
+    /// Use [`.iter()`](Self::iter) if the elements must be visited in logical
+    /// order.
     pub fn for_each<'a, F>(&'a self, mut f: F)
     where
         F: FnMut(&'a A),
@@ -2900,7 +3912,8 @@ where
     /// Combine the elements of each subview with the previous using the `fold`
     /// function and initial value `init`.
     ///
-    /// Return the result as an `Array`.
+    /// Return the result as an `Array`. If the length of `axis` is zero, every
+    /// element of the result is `init`.
     ///
     /// **Panics** if `axis` is out of bounds.
     #[track_caller]
@@ -2918,6 +3931,156 @@ where
         res
     }
 
+    /// Fold over several axes at once.
+    ///
+    /// Combine all elements that share the same index along the axes not in
+    /// `axes` using the `fold` function and initial value `init`, in one pass
+    /// over the array. The order in which the elements are visited is
+    /// unspecified; it follows the memory layout of the array.
+    ///
+    /// Return the result as an `ArrayD`, which has the axes not in `axes`, in
+    /// the same order as in `self`. See
+    /// [`.fold_axes_keepdims()`](Self::fold_axes_keepdims) to keep the
+    /// reduced axes. If any of the reduced axes has length zero, every
+    /// element of the result is `init`.
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+    /// let max = a.fold_axes(&[Axis(0), Axis(2)], 0, |&acc, &x| acc.max(x)).unwrap();
+    /// assert_eq!(max, ndarray::arr1(&[103, 113, 123]).into_dyn());
+    /// assert!(a.fold_axes(&[Axis(2), Axis(2)], 0, |&acc, &x| acc.max(x)).is_err());
+    /// ```
+    pub fn fold_axes<B, F>(&self, axes: &[Axis], init: B, fold: F) -> Result<Array<B, IxDyn>, ShapeError>
+    where
+        F: FnMut(&B, &A) -> B,
+        B: Clone,
+        S: Data,
+    {
+        let mut res = self.fold_axes_keepdims(axes, init, fold)?.into_dyn();
+        let mut axes = axes.to_vec();
+        axes.sort_by_key(|axis| core::cmp::Reverse(axis.index()));
+        for axis in axes {
+            res.index_axis_inplace(axis, 0);
+        }
+        Ok(res)
+    }
+
+    /// Fold over several axes at once, keeping the reduced axes.
+    ///
+    /// Like [`.fold_axes()`](Self::fold_axes), but the reduced axes are kept
+    /// with length one, so that the result has the same dimensionality as
+    /// `self` and can be broadcast against it.
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 5, 2], [4, 3, 6]];
+    /// let max = a.fold_axes_keepdims(&[Axis(1)], 0, |&acc, &x| acc.max(x)).unwrap();
+    /// assert_eq!(max, array![[5], [6]]);
+    /// assert_eq!(&a - &max, array![[-4, 0, -3], [-2, -3, 0]]);
+    /// ```
+    pub fn fold_axes_keepdims<B, F>(&self, axes: &[Axis], init: B, mut fold: F) -> Result<Array<B, D>, ShapeError>
+    where
+        F: FnMut(&B, &A) -> B,
+        B: Clone,
+        S: Data,
+    {
+        let usage_counts: D = dimension::axes_mask(self.ndim(), axes)?;
+        let mut res_dim = self.raw_dim();
+        for &axis in axes {
+            res_dim[axis.index()] = 1;
+        }
+        let mut res = Array::from_elem(res_dim, init);
+        // View the result with the shape of `self`, with zero strides along
+        // the reduced axes, so that each element of `self` is zipped with its
+        // accumulator.
+        let mut acc_strides = res.strides.clone();
+        for (stride, &count) in acc_strides.slice_mut().iter_mut().zip(usage_counts.slice()) {
+            if count != 0 {
+                *stride = 0;
+            }
+        }
+        let acc = unsafe { RawArrayViewMut::new(res.raw_view_mut().ptr, self.raw_dim(), acc_strides) };
+        // Order the axes from largest to smallest stride in `self`, so that
+        // the innermost loop is along the axis with the smallest stride,
+        // whether it is reduced or not.
+        let mut order = self.strides._fastest_varying_stride_order();
+        order.slice_mut().reverse();
+        Zip::from(self.view().permuted_axes(order.clone()))
+            .and(acc.permuted_axes(order))
+            .for_each(|x, acc| unsafe {
+                // Safe because the accumulators are elements of `res`, and
+                // each is borrowed only during one call to `fold`
+                *acc = fold(&*acc, x);
+            });
+        Ok(res)
+    }
+
+    /// Fold the subviews along `axis` into groups given by `keys`.
+    ///
+    /// The subview at index `i` along `axis` is combined into group
+    /// `keys[i]` using the `fold` function, in order of `i`. Return the
+    /// result as an `Array` like `self`, except that the length of `axis`
+    /// is `n_groups`: the subview at index `g` is the fold of group `g`.
+    /// Every element of a group that no subview is folded into is `init`.
+    ///
+    /// **Errors** with `ErrorKind::IncompatibleShape` if the length of `keys`
+    /// is not the length of `axis`, with `ErrorKind::OutOfBounds` if a key
+    /// is not less than `n_groups` (the message names the first such key and
+    /// its index), and with `ErrorKind::Overflow` if the
+    /// size of the result would overflow `isize`.<br>
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let features = array![[1, 2],
+    ///                       [3, 4],
+    ///                       [5, 6]];
+    /// let classes = array![1, 0, 1];
+    ///
+    /// let max = features.fold_by_key_axis(Axis(0), &classes, 3, 0, |&m, &x| m.max(x)).unwrap();
+    /// assert_eq!(max, array![[3, 4],
+    ///                        [5, 6],
+    ///                        [0, 0]]);
+    /// ```
+    #[track_caller]
+    pub fn fold_by_key_axis<B, S2, F>(
+        &self, axis: Axis, keys: &ArrayBase<S2, Ix1>, n_groups: usize, init: B, mut fold: F,
+    ) -> Result<Array<B, D>, ShapeError>
+    where
+        D: RemoveAxis,
+        F: FnMut(&B, &A) -> B,
+        B: Clone,
+        S: Data,
+        S2: Data<Elem = usize>,
+    {
+        let mut dim = self.raw_dim();
+        if keys.len() != dim[axis.index()] {
+            return Err(error::incompatible_shapes(&keys.raw_dim(), &dim).in_operation("fold_by_key_axis"));
+        }
+        if let Some((index, &key)) = keys.iter().enumerate().find(|&(_, &key)| key >= n_groups) {
+            return Err(error::key_out_of_bounds(index, key, n_groups).in_operation("fold_by_key_axis"));
+        }
+        dim[axis.index()] = n_groups;
+        dimension::size_of_array_checked::<B, _>(&dim)?;
+
+        let mut res = Array::from_elem(dim, init);
+        for (subview, &key) in self.axis_iter(axis).zip(keys) {
+            res.index_axis_mut(axis, key)
+                .zip_mut_with(&subview, |x, y| *x = fold(x, y));
+        }
+        Ok(res)
+    }
+
     /// Reduce the values along an axis into just one value, producing a new
     /// array with one less dimension.
     ///
@@ -2952,6 +4115,22 @@ where
     /// Return the result as an `Array`.
     ///
     /// **Panics** if `axis` is out of bounds.
+    ///
+    /// To modify the lanes in place, return `()`. This normalizes each row:
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1f64, 2., 3.],
+    ///                    [2., 4., 12.]];
+    /// a.map_axis_mut(Axis(1), |mut row| {
+    ///     let mean = row.mean().unwrap();
+    ///     let std = row.std(0.);
+    ///     row.mapv_inplace(|x| (x - mean) / std);
+    /// });
+    /// assert!(a.sum_axis(Axis(1)).iter().all(|&x| x.abs() < 1e-12));
+    /// assert!(a.std_axis(Axis(1), 0.).iter().all(|&x| (x - 1.).abs() < 1e-12));
+    /// ```
     #[track_caller]
     pub fn map_axis_mut<'a, B, F>(&'a mut self, axis: Axis, mut mapping: F) -> Array<B, D::Smaller>
     where
@@ -2968,6 +4147,149 @@ where
         }
     }
 
+    /// Map each lane along `axis` to a new lane of length `out_len`,
+    /// producing a new array with the same shape except that the length of
+    /// `axis` is `out_len`.
+    ///
+    /// This is the lane-to-lane version of [`.map_axis()`](Self::map_axis).
+    /// Lanes are visited in arbitrary order, and `mapping` is called once
+    /// for each lane, also if `axis` has length zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `mapping` returns a lane
+    /// whose length is not `out_len`.
+    ///
+    /// Resample each lane by averaging pairs of elements:
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((3, 100), |(i, j)| (i * j) as f64);
+    /// let b = a.apply_along_axis(Axis(1), 50, |lane| {
+    ///     lane.exact_chunks(2).into_iter().map(|pair| pair.mean().unwrap()).collect()
+    /// });
+    /// assert_eq!(b.shape(), &[3, 50]);
+    /// assert_eq!(b[[2, 0]], 1.);
+    /// assert_eq!(b[[2, 49]], 197.);
+    /// ```
+    #[track_caller]
+    pub fn apply_along_axis<'a, B, F>(&'a self, axis: Axis, out_len: usize, mut mapping: F) -> Array<B, D>
+    where
+        D: RemoveAxis,
+        F: FnMut(ArrayView1<'a, A>) -> Array1<B>,
+        A: 'a,
+        S: Data,
+    {
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = out_len;
+        let mut res = Array::uninit(dim);
+        Zip::from(res.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|out, lane| {
+                let new_lane = mapping(lane);
+                assert_eq!(
+                    new_lane.len(),
+                    out_len,
+                    "ndarray: apply_along_axis expected a lane of length {}, got {}",
+                    out_len,
+                    new_lane.len()
+                );
+                new_lane.move_into_uninit(out);
+            });
+        unsafe {
+            // Safe because each lane of `res` was moved into
+            res.assume_init()
+        }
+    }
+
+    /// Return the `count` largest elements of each lane along `axis`.
+    ///
+    /// The result has the same shape as the array, except that the length of
+    /// `axis` is `count`, or the length of `axis` if `count` is greater (the
+    /// count is clamped). Within each lane the selected elements are in
+    /// descending order, and equal elements are in order of increasing index.
+    /// Elements that are not comparable with themselves (for example `NaN`)
+    /// are treated as smaller than every other element, so they are selected
+    /// last.
+    ///
+    /// This uses a partial selection per lane, which is faster than sorting
+    /// each lane when `count` is small compared to the length of `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1, 4, 1, 5],
+    ///                [9, 2, 6, 5, 3]];
+    /// assert_eq!(a.topk_axis(Axis(1), 2), array![[5, 4], [9, 6]]);
+    /// assert_eq!(a.topk_axis(Axis(0), 1), array![[9, 2, 6, 5, 5]]);
+    /// ```
+    #[track_caller]
+    pub fn topk_axis(&self, axis: Axis, count: usize) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        S: Data,
+    {
+        let indices = self.topk_argmax_axis(axis, count);
+        Array::from_shape_fn(indices.raw_dim(), |index| {
+            let mut index = index.into_dimension();
+            index[axis.index()] = indices[index.clone()];
+            self[index].clone()
+        })
+    }
+
+    /// Return the indices of the `count` largest elements of each lane along
+    /// `axis`.
+    ///
+    /// The indices are positions along `axis` and are ordered like the
+    /// elements returned by [`.topk_axis()`](Self::topk_axis): by descending
+    /// element value, with ties broken by increasing index and non-comparable
+    /// elements (for example `NaN`) last. `count` is clamped to the length of
+    /// `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3., f64::NAN, 4., 3.],
+    ///                [1., 2., 2., 0.]];
+    /// assert_eq!(a.topk_argmax_axis(Axis(1), 3), array![[2, 0, 3], [1, 2, 0]]);
+    /// assert_eq!(a.topk_argmax_axis(Axis(1), 10).shape(), &[2, 4]);
+    /// ```
+    #[track_caller]
+    pub fn topk_argmax_axis(&self, axis: Axis, count: usize) -> Array<usize, D>
+    where
+        A: PartialOrd,
+        S: Data,
+    {
+        let len = self.len_of(axis);
+        let count = count.min(len);
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = count;
+        let mut res = Array::zeros(dim);
+        if count == 0 {
+            return res;
+        }
+        let mut scratch = Vec::with_capacity(len);
+        Zip::from(res.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut out, lane| {
+                let order = |&i: &usize, &j: &usize| topk_order(&lane[i], &lane[j]).then(i.cmp(&j));
+                scratch.clear();
+                scratch.extend(0..len);
+                if count < len {
+                    scratch.select_nth_unstable_by(count - 1, order);
+                }
+                let selected = &mut scratch[..count];
+                selected.sort_unstable_by(order);
+                for (out, &i) in out.iter_mut().zip(&*selected) {
+                    *out = i;
+                }
+            });
+        res
+    }
+
     /// Remove the `index`th elements along `axis` and shift down elements from higher indexes.
     ///
     /// Note that this "removes" the elements by swapping them around to the end of the axis and
@@ -2982,8 +4304,8 @@ where
     pub fn remove_index(&mut self, axis: Axis, index: usize)
     where S: DataOwned + DataMut
     {
-        assert!(index < self.len_of(axis), "index {} must be less than length of Axis({})",
-                index, axis.index());
+        assert!(index < self.len_of(axis), "index {} must be less than length of {}",
+                index, axis);
         let (_, mut tail) = self.view_mut().split_at(axis, index);
         // shift elements to the front
         Zip::from(tail.lanes_mut(axis)).for_each(|mut lane| lane.rotate1_front());
@@ -3046,6 +4368,101 @@ where
             f(&*prev, &mut *curr)
         });
     }
+
+    /// Compute a running fold of the elements along `axis`.
+    ///
+    /// Return an array with the same shape as `self`, where each lane along
+    /// `axis` is the scan of the corresponding lane of `self` with the
+    /// binary operation `f`. The fold associates to the left: the running
+    /// value is always the first argument to `f`, and the next element is the
+    /// second.
+    ///
+    /// - If `init` is `None`, the scan is *inclusive*: the first element of a
+    ///   lane is the first input element, and element `i > 0` is
+    ///   `f(&out[i - 1], &x[i])`, so that it includes the elements `0..=i`.
+    /// - If `init` is `Some(init)`, the scan is *exclusive*: the first element
+    ///   of a lane is `init`, and element `i > 0` is `f(&out[i - 1], &x[i - 1])`,
+    ///   so that it includes the elements `0..i`. The last input element of
+    ///   each lane is not used.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let prices = array![[3., 5., 4., 6.], [2., 1., 3., 2.]];
+    /// let peak = prices.scan_axis(Axis(1), None, |&a, &b| f64::max(a, b));
+    /// assert_eq!(peak, array![[3., 5., 5., 6.], [2., 2., 3., 3.]]);
+    ///
+    /// let before = prices.scan_axis(Axis(1), Some(0.), |&a, &b| a + b);
+    /// assert_eq!(before, array![[0., 3., 8., 12.], [0., 2., 3., 6.]]);
+    /// ```
+    #[track_caller]
+    pub fn scan_axis<F>(&self, axis: Axis, init: Option<A>, mut f: F) -> Array<A, D>
+    where
+        F: FnMut(&A, &A) -> A,
+        A: Clone,
+        S: Data,
+    {
+        let mut out = Array::uninit(self.raw_dim());
+        Zip::from(self.lanes(axis))
+            .and(out.lanes_mut(axis))
+            .for_each(|lane, out_lane| {
+                let mut elems = lane.into_iter();
+                let mut prev: Option<&A> = None;
+                for out_elem in out_lane {
+                    let value = match (prev, &init) {
+                        (Some(prev), _) => f(prev, elems.next().unwrap()),
+                        (None, Some(init)) => init.clone(),
+                        (None, None) => elems.next().unwrap().clone(),
+                    };
+                    prev = Some(out_elem.write(value));
+                }
+            });
+        // Safe because every element was written in the lanes above
+        unsafe { out.assume_init() }
+    }
+}
+
+/// **Panics** if an element of `indices` is not less than `len`.
+#[track_caller]
+fn check_axis_indices(indices: &[Ix], len: usize)
+{
+    if let Some(&index) = indices.iter().find(|&&index| index >= len) {
+        panic!("ndarray: index {} is out of bounds in array of len {}", index, len);
+    }
+}
+
+/// Order for `topk_axis`: larger elements first, and elements that are not
+/// comparable with themselves (such as `NaN`) after all others.
+fn topk_order<A: PartialOrd>(a: &A, b: &A) -> Ordering
+{
+    #[allow(clippy::eq_op)]
+    match (a.partial_cmp(a).is_none(), b.partial_cmp(b).is_none()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => b.partial_cmp(a).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Ascending order for `unique_axis`, with elements that are not equal to
+/// themselves (such as `NaN`) equal to each other and after all others.
+///
+/// **Panics** if two elements that are equal to themselves are not
+/// comparable, since there is no order to sort them by.
+fn nan_last_order<A: PartialOrd>(a: &A, b: &A) -> Ordering
+{
+    #[allow(clippy::eq_op)]
+    match (a != a, b != b) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => match a.partial_cmp(b) {
+            Some(ord) => ord,
+            None => panic!("ndarray: unique_axis requires elements that are comparable with each other, except NaN"),
+        },
+    }
 }
 
 /// Transmute from A to B.
@@ -3056,7 +4473,7 @@ where
 /// **Panics** if the size of A and B are different.
 #[track_caller]
 #[inline]
-unsafe fn unlimited_transmute<A, B>(data: A) -> B
+pub(crate) unsafe fn unlimited_transmute<A, B>(data: A) -> B
 {
     // safe when sizes are equal and caller guarantees that representations are equal
     assert_eq!(size_of::<A>(), size_of::<B>());
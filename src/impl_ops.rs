@@ -7,6 +7,7 @@
 // except according to those terms.
 
 use crate::dimension::DimMax;
+use crate::error::{shape_mismatch_panic, ShapeMismatch};
 use crate::Zip;
 use num_complex::Complex;
 
@@ -108,7 +109,10 @@ where
             out.zip_mut_with_same_shape(rhs, clone_iopf(A::$mth));
             out
         } else {
-            let (lhs_view, rhs_view) = self.broadcast_with(&rhs).unwrap();
+            let (lhs_view, rhs_view) = match self.broadcast_with(&rhs) {
+                Ok(views) => views,
+                Err(_) => shape_mismatch_panic(ShapeMismatch::CoBroadcast, self.shape(), rhs.shape(), stringify!($operator)),
+            };
             if lhs_view.shape() == self.shape() {
                 let mut out = self.into_dimensionality::<<D as DimMax<E>>::Output>().unwrap();
                 out.zip_mut_with_same_shape(&rhs_view, clone_iopf(A::$mth));
@@ -150,7 +154,10 @@ where
             out.zip_mut_with_same_shape(self, clone_iopf_rev(A::$mth));
             out
         } else {
-            let (rhs_view, lhs_view) = rhs.broadcast_with(self).unwrap();
+            let (rhs_view, lhs_view) = match rhs.broadcast_with(self) {
+                Ok(views) => views,
+                Err(_) => shape_mismatch_panic(ShapeMismatch::CoBroadcast, self.shape(), rhs.shape(), stringify!($operator)),
+            };
             if rhs_view.shape() == rhs.shape() {
                 let mut out = rhs.into_dimensionality::<<E as DimMax<D>>::Output>().unwrap();
                 out.zip_mut_with_same_shape(&lhs_view, clone_iopf_rev(A::$mth));
@@ -188,7 +195,10 @@ where
             let rhs = rhs.view().into_dimensionality::<<D as DimMax<E>>::Output>().unwrap();
             (lhs, rhs)
         } else {
-            self.broadcast_with(rhs).unwrap()
+            match self.broadcast_with(rhs) {
+                Ok(views) => views,
+                Err(_) => shape_mismatch_panic(ShapeMismatch::CoBroadcast, self.shape(), rhs.shape(), stringify!($operator)),
+            }
         };
         Zip::from(lhs).and(rhs).map_collect(clone_opf(A::$mth))
     }
@@ -445,7 +455,7 @@ mod assign_ops
     use crate::imp_prelude::*;
 
     macro_rules! impl_assign_op {
-        ($trt:ident, $method:ident, $doc:expr) => {
+        ($trt:ident, $method:ident, $operator:tt, $doc:expr) => {
             use std::ops::$trt;
 
             #[doc=$doc]
@@ -462,9 +472,13 @@ mod assign_ops
             {
                 #[track_caller]
                 fn $method(&mut self, rhs: &ArrayBase<S2, E>) {
-                    self.zip_mut_with(rhs, |x, y| {
-                        x.$method(y.clone());
-                    });
+                    self.zip_mut_with_in(
+                        rhs,
+                        |x, y| {
+                            x.$method(y.clone());
+                        },
+                        stringify!($operator),
+                    );
                 }
             }
 
@@ -487,51 +501,61 @@ mod assign_ops
     impl_assign_op!(
         AddAssign,
         add_assign,
+        +=,
         "Perform `self += rhs` as elementwise addition (in place).\n"
     );
     impl_assign_op!(
         SubAssign,
         sub_assign,
+        -=,
         "Perform `self -= rhs` as elementwise subtraction (in place).\n"
     );
     impl_assign_op!(
         MulAssign,
         mul_assign,
+        *=,
         "Perform `self *= rhs` as elementwise multiplication (in place).\n"
     );
     impl_assign_op!(
         DivAssign,
         div_assign,
+        /=,
         "Perform `self /= rhs` as elementwise division (in place).\n"
     );
     impl_assign_op!(
         RemAssign,
         rem_assign,
+        %=,
         "Perform `self %= rhs` as elementwise remainder (in place).\n"
     );
     impl_assign_op!(
         BitAndAssign,
         bitand_assign,
+        &=,
         "Perform `self &= rhs` as elementwise bit and (in place).\n"
     );
     impl_assign_op!(
         BitOrAssign,
         bitor_assign,
+        |=,
         "Perform `self |= rhs` as elementwise bit or (in place).\n"
     );
     impl_assign_op!(
         BitXorAssign,
         bitxor_assign,
+        ^=,
         "Perform `self ^= rhs` as elementwise bit xor (in place).\n"
     );
     impl_assign_op!(
         ShlAssign,
         shl_assign,
+        <<=,
         "Perform `self <<= rhs` as elementwise left shift (in place).\n"
     );
     impl_assign_op!(
         ShrAssign,
         shr_assign,
+        >>=,
         "Perform `self >>= rhs` as elementwise right shift (in place).\n"
     );
 }
@@ -1,7 +1,10 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
+use std::marker::PhantomData;
 use std::mem;
-use std::mem::MaybeUninit;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr;
+use std::ptr::NonNull;
 
 #[allow(unused_imports)]
 use rawpointer::PointerExt;
@@ -9,10 +12,13 @@ use rawpointer::PointerExt;
 use crate::imp_prelude::*;
 
 use crate::dimension;
-use crate::error::{ErrorKind, ShapeError};
+use crate::error::{incompatible_shapes, ErrorKind, ShapeError};
+use crate::extension::nonnull::nonnull_from_vec_data;
 use crate::iterators::Baseiter;
 use crate::low_level_util::AbortIfPanic;
+use crate::IntoDimension;
 use crate::OwnedRepr;
+use crate::Slice;
 use crate::Zip;
 
 /// Methods specific to `Array0`.
@@ -156,6 +162,313 @@ where D: Dimension
     {
         self.into_raw_vec_and_offset().0
     }
+
+    /// Return the parts that the array is made of: the vector of the
+    /// elements in the way they are stored internally, the index in the
+    /// vector of the logically first element (or `None` if the array is
+    /// empty), the shape, and the strides.
+    ///
+    /// The array can be put together again without copying the elements with
+    /// [`Array::from_raw_parts`]. This is useful to pass the elements to a
+    /// function that needs a `Vec<A>` and then continue with the array.
+    ///
+    /// ```
+    /// use ndarray::{array, s, Array};
+    ///
+    /// let mut a = array![[1., 2., 3.], [4., 5., 6.]];
+    /// a.slice_collapse(s![.., 1..]);
+    ///
+    /// let (mut v, offset, dim, strides) = a.into_raw_parts();
+    /// assert_eq!(offset, Some(1));
+    /// v.iter_mut().for_each(|x| *x *= 10.);
+    ///
+    /// let a = Array::from_raw_parts(v, offset, dim, strides).unwrap();
+    /// assert_eq!(a, array![[20., 30.], [50., 60.]]);
+    /// ```
+    pub fn into_raw_parts(self) -> (Vec<A>, Option<usize>, D, D)
+    {
+        let dim = self.dim.clone();
+        let strides = self.strides.clone();
+        let (v, offset) = self.into_raw_vec_and_offset();
+        (v, offset, dim, strides)
+    }
+
+    /// Create an array from a vector of elements, the index in the vector of
+    /// the logically first element, the shape, and the strides, as returned
+    /// by [`.into_raw_parts()`](Self::into_raw_parts). The elements are not
+    /// copied.
+    ///
+    /// If `offset` is `None`, the element of the array with the lowest
+    /// address is the first element of the vector.
+    ///
+    /// **Errors** if `dim` and `strides` don't have the same number of axes,
+    /// if the array would point out of bounds of `v` from `offset`, if the
+    /// strides allow multiple indices to point to the same element, or if
+    /// the shape/strides would result in overflowing `isize`.
+    pub fn from_raw_parts(mut v: Vec<A>, offset: Option<usize>, dim: D, strides: D) -> Result<Self, ShapeError>
+    {
+        // Check the strides before using them to compute the offset of the
+        // logically first element.
+        dimension::max_abs_offset_check_overflow::<A, _>(&dim, &strides)?;
+        let to_logical = dimension::offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides);
+        let low = match offset {
+            Some(offset) => offset.checked_sub(to_logical),
+            None => Some(0),
+        };
+        match low {
+            Some(low) if low <= v.len() => dimension::can_index_slice(&v[low..], &dim, &strides)?,
+            _ => return Err(ShapeError::from_kind(ErrorKind::OutOfBounds)),
+        }
+        let logical = offset.unwrap_or(to_logical);
+        unsafe {
+            // safe because the array was checked to be within the vector
+            let ptr = nonnull_from_vec_data(&mut v).add(logical);
+            Ok(ArrayBase::from_data_ptr(OwnedRepr::from(v), ptr).with_strides_dim(strides, dim))
+        }
+    }
+
+    /// Drop the elements of the allocation that are not part of the array,
+    /// and release their memory.
+    ///
+    /// Slicing an owned array in place, for example with
+    /// [`.slice_move()`](ArrayBase::slice_move), keeps its whole allocation,
+    /// so that a small slice of a large array keeps all of its memory in use.
+    /// This returns the same array in an allocation of the size of the array.
+    ///
+    /// If the array is contiguous in memory, its elements are moved to the
+    /// front of the allocation, which is then shrunk, and the memory layout
+    /// is kept. Otherwise the elements are moved into a new allocation, in
+    /// standard layout.
+    ///
+    /// ```
+    /// use ndarray::{s, Array};
+    ///
+    /// let a = Array::from_iter(0..10_000).into_shape_with_order((100, 100)).unwrap();
+    /// let a = a.slice_move(s![10..12, ..]).into_shrunk();
+    /// assert_eq!(a.shape(), &[2, 100]);
+    /// assert_eq!(a[[0, 0]], 1000);
+    ///
+    /// let (v, _) = a.into_raw_vec_and_offset();
+    /// assert_eq!(v.len(), 200);
+    /// assert!(v.capacity() < 1000);
+    /// ```
+    pub fn into_shrunk(self) -> Self
+    {
+        if !self.is_contiguous() {
+            let mut new_array = Self::uninit(self.raw_dim());
+            self.move_into_uninit(&mut new_array);
+            return unsafe { new_array.assume_init() };
+        }
+        let len = self.len();
+        let (mut v, offset, dim, strides) = self.into_raw_parts();
+        let to_logical = match offset {
+            Some(_) => dimension::offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides),
+            None => 0,
+        };
+        let low = offset.map_or(0, |offset| offset - to_logical);
+        v.truncate(low + len);
+        v.drain(..low);
+        v.shrink_to_fit();
+        unsafe {
+            // safe because the elements were moved together with the array
+            let ptr = nonnull_from_vec_data(&mut v).add(to_logical);
+            ArrayBase::from_data_ptr(OwnedRepr::from(v), ptr).with_strides_dim(strides, dim)
+        }
+    }
+
+    /// Return the array in standard layout, moving its elements into a new
+    /// allocation unless it already is in standard layout.
+    ///
+    /// If the array is in standard layout, it is returned unchanged, with its
+    /// allocation. This is the consuming version of
+    /// [`.as_standard_layout()`](ArrayBase::as_standard_layout), and like it,
+    /// transposed arrays of elements that do not need to be dropped are
+    /// copied with a cache friendly blocked copy.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_iter(0..6).into_shape_with_order((2, 3)).unwrap();
+    /// let ptr = a.as_ptr();
+    /// let a = a.into_standard_layout();
+    /// assert_eq!(a.as_ptr(), ptr);
+    ///
+    /// let t = a.reversed_axes().into_standard_layout();
+    /// assert!(t.is_standard_layout());
+    /// assert_eq!(t.as_slice(), Some(&[0, 3, 1, 4, 2, 5][..]));
+    /// ```
+    pub fn into_standard_layout(self) -> Self
+    {
+        if self.is_standard_layout() {
+            return self;
+        }
+        if !mem::needs_drop::<A>() {
+            // Safe because the elements are not dropped with `self`, so reading them moves them.
+            if let Some(moved) = self.map_blocked(|elt| unsafe { ptr::read(elt) }) {
+                return moved;
+            }
+        }
+        let mut new_array = Self::uninit(self.raw_dim());
+        self.move_into_uninit(&mut new_array);
+        unsafe { new_array.assume_init() }
+    }
+
+    /// Return true if the elements of the array are exactly the elements of
+    /// its allocation, so that they can be mapped in place.
+    pub(crate) fn uses_whole_allocation(&self) -> bool
+    {
+        self.is_contiguous() && self.len() == self.data.len()
+    }
+
+    /// Call `f` by value on each element and store the new value in its
+    /// place, reusing the allocation of the array for the result.
+    ///
+    /// `A` and `B` must have the same size and alignment, and the array must
+    /// use its whole allocation (see `uses_whole_allocation`).
+    pub(crate) fn mapv_into_same_layout<B, F>(self, mut f: F) -> Array<B, D>
+    where F: FnMut(A) -> B
+    {
+        assert_eq!(mem::size_of::<A>(), mem::size_of::<B>());
+        assert_eq!(mem::align_of::<A>(), mem::align_of::<B>());
+        debug_assert!(self.uses_whole_allocation());
+
+        // Drops the elements and frees the allocation if `f` panics: the
+        // elements before `index` have been mapped, and the element at
+        // `index` has been moved into `f`.
+        struct Guard<A, B>
+        {
+            ptr: *mut A,
+            len: usize,
+            capacity: usize,
+            index: usize,
+            mapped: PhantomData<B>,
+        }
+
+        impl<A, B> Drop for Guard<A, B>
+        {
+            fn drop(&mut self)
+            {
+                unsafe {
+                    for i in 0..self.index {
+                        ptr::drop_in_place(self.ptr.add(i) as *mut B);
+                    }
+                    for i in self.index + 1..self.len {
+                        ptr::drop_in_place(self.ptr.add(i));
+                    }
+                    drop(Vec::from_raw_parts(self.ptr, 0, self.capacity));
+                }
+            }
+        }
+
+        let dim = self.dim.clone();
+        let strides = self.strides.clone();
+        let mut v = ManuallyDrop::new(self.data.into_vec());
+        let mut guard = Guard {
+            ptr: v.as_mut_ptr(),
+            len: v.len(),
+            capacity: v.capacity(),
+            index: 0,
+            mapped: PhantomData::<B>,
+        };
+        while guard.index < guard.len {
+            unsafe {
+                let elt = guard.ptr.add(guard.index);
+                let new = f(elt.read());
+                (elt as *mut B).write(new);
+            }
+            guard.index += 1;
+        }
+        let (ptr, len, capacity) = (guard.ptr as *mut B, guard.len, guard.capacity);
+        mem::forget(guard);
+        unsafe {
+            // safe because all the elements are mapped, and the new array has
+            // the same layout in the same allocation
+            let mut v = Vec::from_raw_parts(ptr, len, capacity);
+            let ptr =
+                nonnull_from_vec_data(&mut v).add(dimension::offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides));
+            ArrayBase::from_data_ptr(OwnedRepr::from(v), ptr).with_strides_dim(strides, dim)
+        }
+    }
+}
+
+/// Methods specific to `Array1`.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<A> Array<A, Ix1>
+{
+    /// Append an element to the end of the array.
+    ///
+    /// The array grows by one, like [`Vec::push`]; the backing storage grows
+    /// with the same amortized strategy, so repeated pushes are O(1) on average.
+    ///
+    /// If the array does not own its whole allocation with unit stride (for
+    /// example after slicing with a step, or after inverting its axis), its
+    /// elements are first moved into a new compact allocation.
+    ///
+    /// To push a whole row to a 2-D array, see [`.push_row()`](Array::push_row).
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array1};
+    ///
+    /// let mut a = Array1::from(vec![1, 2]);
+    /// a.push_element(3);
+    /// a.push_element(4);
+    /// assert_eq!(a, array![1, 2, 3, 4]);
+    /// ```
+    pub fn push_element(&mut self, value: A)
+    {
+        self.make_growable();
+        let len = self.len();
+        unsafe {
+            // the array starts at the start of the allocation and covers all of it
+            self.ptr = self.data.reserve(1);
+            self.ptr.as_ptr().add(len).write(value);
+            self.data.set_len(len + 1);
+        }
+        self.dim[0] = len + 1;
+        self.strides[0] = 1;
+        debug_assert!(self.pointer_is_inbounds());
+    }
+
+    /// Retain only the elements for which `f` returns `true`, removing the
+    /// others in place.
+    ///
+    /// Like [`Vec::retain`], this preserves the order of the retained
+    /// elements, visits each element exactly once and runs in O(n) time. The
+    /// removed elements are dropped.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array1};
+    ///
+    /// let mut a = Array1::from(vec![1, 2, 3, 4, 5, 6]);
+    /// a.retain(|&x| x % 3 != 0);
+    /// assert_eq!(a, array![1, 2, 4, 5]);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where F: FnMut(&A) -> bool
+    {
+        self.make_growable();
+        let (mut v, _) = mem::replace(self, Array::from_vec(Vec::new())).into_raw_vec_and_offset();
+        v.retain(f);
+        *self = Array::from_vec(v);
+    }
+
+    /// Return `true` if the array is laid out exactly like its backing `Vec`,
+    /// so that elements can be added at the end of the allocation.
+    fn is_growable(&self) -> bool
+    {
+        self.data.len() == self.len() && (self.len() <= 1 || self.strides[0] == 1)
+    }
+
+    /// Move the elements into a new allocation if needed, so that the array
+    /// is growable.
+    pub(crate) fn make_growable(&mut self)
+    {
+        if !self.is_growable() {
+            let array = mem::replace(self, Array::from_vec(Vec::new()));
+            *self = Array::from_vec(array.into_iter().collect());
+        }
+    }
 }
 
 /// Methods specific to `Array2`.
@@ -298,11 +611,140 @@ impl<A> Array<A, Ix2>
     {
         self.reserve(Axis(1), additional)
     }
+
+    /// Convert the array into a vector of its rows, each a vector of
+    /// elements.
+    ///
+    /// The rows and elements are in logical order, whatever the memory layout
+    /// of the array. The elements are moved, not cloned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.t().to_owned().into_nested_vec(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    /// ```
+    pub fn into_nested_vec(self) -> Vec<Vec<A>>
+    {
+        let (nrows, ncols) = self.dim();
+        let mut elements = self.into_iter();
+        (0..nrows)
+            .map(|_| elements.by_ref().take(ncols).collect())
+            .collect()
+    }
+
+    /// Convert the array into a vector of rows of `N` elements.
+    ///
+    /// This is the inverse of the conversion `From<Vec<[A; N]>>`, and like
+    /// it, reuses the allocation of the array when possible: the elements are
+    /// not copied if the array uses its whole allocation, and the capacity of
+    /// the allocation is a multiple of `N`.
+    ///
+    /// ***Errors*** with `ErrorKind::IncompatibleShape` if the number of
+    /// columns is not `N`, and with `ErrorKind::IncompatibleLayout` if the
+    /// array is not in standard layout.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let rows = vec![[1., 2.], [3., 4.], [5., 6.]];
+    /// let a = Array2::from(rows.clone());
+    /// assert_eq!(a.shape(), &[3, 2]);
+    /// assert_eq!(a.try_into_fixed_rows::<2>().unwrap(), rows);
+    /// ```
+    pub fn try_into_fixed_rows<const N: usize>(self) -> Result<Vec<[A; N]>, ShapeError>
+    {
+        if self.ncols() != N {
+            return Err(incompatible_shapes(&self.dim, &Ix2(self.nrows(), N)).in_operation("try_into_fixed_rows"));
+        }
+        if !self.is_standard_layout() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleLayout));
+        }
+        let nrows = self.nrows();
+        let (mut v, offset) = self.into_raw_vec_and_offset();
+        // Drop the elements outside of the array, if any.
+        v.truncate(offset.unwrap_or(0) + nrows * N);
+        v.drain(..offset.unwrap_or(0));
+        debug_assert_eq!(v.len(), nrows * N);
+
+        unsafe {
+            if mem::size_of::<[A; N]>() == 0 {
+                // The rows are zero-sized; move the elements (zero-sized too, or
+                // none when N == 0) into the new vector.
+                v.set_len(0);
+                return Ok(Vec::from_raw_parts(NonNull::dangling().as_ptr(), nrows, nrows));
+            }
+            if v.capacity() % N != 0 {
+                v.shrink_to_fit();
+            }
+            let mut v = ManuallyDrop::new(v);
+            if v.capacity() % N == 0 {
+                // Safe because `[A; N]` has the same alignment as `A` and the
+                // size of `N` elements, so the allocation of `cap` elements has
+                // the layout of `cap / N` rows, and the first `nrows * N`
+                // elements are initialized.
+                Ok(Vec::from_raw_parts(v.as_mut_ptr() as *mut [A; N], nrows, v.capacity() / N))
+            } else {
+                let mut rows = Vec::<[A; N]>::with_capacity(nrows);
+                ptr::copy_nonoverlapping(v.as_ptr(), rows.as_mut_ptr() as *mut A, nrows * N);
+                rows.set_len(nrows);
+                // The elements have been moved to `rows`; free only the allocation.
+                v.set_len(0);
+                ManuallyDrop::drop(&mut v);
+                Ok(rows)
+            }
+        }
+    }
 }
 
 impl<A, D> Array<A, D>
 where D: Dimension
 {
+    /// Retain only the subviews along `axis` for which `f` returns `true`,
+    /// removing the others.
+    ///
+    /// `f` is called once for each index along `axis`, in order, with the
+    /// subview at that index; for a matrix and `Axis(0)` this filters the rows.
+    /// The removed elements are dropped, and the result is in standard layout.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2],
+    ///                    [0, 0],
+    ///                    [3, 4]];
+    /// a.retain_axis(Axis(0), |row| row.iter().any(|&x| x != 0));
+    /// assert_eq!(a, array![[1, 2],
+    ///                      [3, 4]]);
+    /// ```
+    #[track_caller]
+    pub fn retain_axis<F>(&mut self, axis: Axis, mut f: F)
+    where
+        F: FnMut(ArrayView<A, D::Smaller>) -> bool,
+        D: RemoveAxis,
+    {
+        let keep: Vec<bool> = self.axis_iter(axis).map(|view| f(view)).collect();
+        let kept = keep.iter().filter(|&&k| k).count();
+        if kept == keep.len() {
+            return;
+        }
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = kept;
+        // the number of elements per index along `axis`, in logical order
+        let inner = self.shape()[axis.index() + 1..].iter().product::<usize>();
+        let empty = Array::from_shape_vec(D::zeros(self.ndim()), Vec::new()).unwrap();
+        let array = mem::replace(self, empty);
+        let v: Vec<A> = array
+            .into_iter()
+            .enumerate()
+            .filter(|&(i, _)| keep[(i / inner) % keep.len()])
+            .map(|(_, elt)| elt)
+            .collect();
+        *self = Array::from_shape_vec(dim, v).unwrap();
+    }
+
     /// Move all elements from self into `new_array`, which must be of the same shape but
     /// can have a different memory layout. The destination is overwritten completely.
     ///
@@ -449,16 +891,138 @@ where D: Dimension
         self.data
     }
 
-    /// Create an empty array with an all-zeros shape
+    /// Create an empty array, with all axes of length zero.
+    ///
+    /// The array has no elements and does not allocate. A dynamic-dimensional
+    /// array is created with one axis. This is the same array as
+    /// [`Default::default()`] gives, except for zero-dimensional arrays.
+    ///
+    /// ***Panics*** if `D` is zero-dimensional, because such an array always
+    /// has one element.
+    ///
+    /// ```
+    /// use ndarray::{Array2, ArrayD};
     ///
-    /// ***Panics*** if D is zero-dimensional, because it can't be empty
-    pub(crate) fn empty() -> Array<A, D>
+    /// let a = Array2::<f64>::empty();
+    /// assert_eq!(a.shape(), &[0, 0]);
+    /// assert!(a.is_empty());
+    ///
+    /// let b = ArrayD::<String>::empty();
+    /// assert_eq!(b.shape(), &[0]);
+    /// ```
+    #[track_caller]
+    pub fn empty() -> Array<A, D>
     {
-        assert_ne!(D::NDIM, Some(0));
+        assert_ne!(D::NDIM, Some(0), "ndarray: a zero-dimensional array cannot be empty");
         let ndim = D::NDIM.unwrap_or(1);
         Array::from_shape_simple_fn(D::zeros(ndim), || unreachable!())
     }
 
+    /// Create an empty array with room to append `capacity` subviews along
+    /// `axis` without reallocating.
+    ///
+    /// `shape` is the shape of the new array, and its length along `axis` must
+    /// be zero. The allocation is sized for an array with `capacity` along
+    /// `axis`; [`.append()`](Self::append) and [`.push()`](Self::push) along
+    /// `axis` fill it in place, and only reallocate (growing the capacity
+    /// geometrically) when it is exhausted.
+    ///
+    /// ***Panics*** if `axis` is out of bounds, if the length of `axis` in
+    /// `shape` is not zero, or if the size of the allocation in elements or in
+    /// bytes overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{Array2, ArrayView, Axis};
+    ///
+    /// let mut a = Array2::<f64>::with_capacity_axis((0, 3), Axis(0), 100);
+    /// assert_eq!(a.capacity_axis(Axis(0)), 100);
+    ///
+    /// let ptr = a.as_ptr();
+    /// for i in 0..100 {
+    ///     a.push(Axis(0), ArrayView::from(&[i as f64; 3])).unwrap();
+    /// }
+    /// assert_eq!(a.as_ptr(), ptr);
+    /// assert_eq!(a.dim(), (100, 3));
+    /// ```
+    #[track_caller]
+    pub fn with_capacity_axis<Sh>(shape: Sh, axis: Axis, capacity: usize) -> Array<A, D>
+    where Sh: IntoDimension<Dim = D>
+    {
+        let dim = shape.into_dimension();
+        assert_eq!(
+            dim[axis.index()],
+            0,
+            "ndarray: with_capacity_axis: the shape must have length zero along the axis"
+        );
+        let mut capacity_dim = dim.clone();
+        capacity_dim[axis.index()] = capacity;
+        let size = match dimension::size_of_array_checked::<A, _>(&capacity_dim) {
+            Ok(size) => size,
+            Err(_) => panic!("ndarray: Shape too large, number of elements or bytes overflows isize"),
+        };
+        unsafe { Array::from_shape_vec_unchecked(dim, Vec::with_capacity(size)) }
+    }
+
+    /// Return the length `axis` can be grown to by appending, without
+    /// reallocating the array.
+    ///
+    /// This is the current length of `axis` if appending along it would need
+    /// to move the elements to a new memory layout (see
+    /// [`.append()`](Self::append)), and `usize::MAX` if the subviews along
+    /// `axis` have no elements.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array2, Axis};
+    ///
+    /// let mut a = Array2::<i32>::zeros((2, 4));
+    /// a.reserve(Axis(0), 10).unwrap();
+    /// assert!(a.capacity_axis(Axis(0)) >= 12);
+    ///
+    /// // Appending along axis 1 would change the memory layout
+    /// assert_eq!(a.capacity_axis(Axis(1)), 4);
+    /// ```
+    #[track_caller]
+    pub fn capacity_axis(&self, axis: Axis) -> usize
+    {
+        let len = self.len_of(axis);
+        if self.append_needs_relayout(axis) {
+            return len;
+        }
+        let mut subview_dim = self.raw_dim();
+        subview_dim[axis.index()] = 1;
+        match subview_dim.size() {
+            0 => usize::MAX,
+            subview_len => len.saturating_add((self.data.capacity() - self.data.len()) / subview_len),
+        }
+    }
+
+    /// Return true if the elements must be moved to a new memory layout before
+    /// appending along `axis`.
+    fn append_needs_relayout(&self, axis: Axis) -> bool
+    {
+        // array must be empty or have `axis` as the outermost (longest stride) axis
+        if !self.is_empty() && self.len_of(axis) > 1 {
+            // `axis` must be max stride axis or equal to its stride
+            let axis_stride = self.stride_of(axis);
+            if axis_stride < 0 {
+                return true;
+            }
+            for ax in self.axes() {
+                if ax.axis == axis {
+                    continue;
+                }
+                if ax.len > 1 && ax.stride.abs() > axis_stride {
+                    return true;
+                }
+            }
+        }
+
+        // array must be be "full" (contiguous and have no exterior holes)
+        self.len() != self.data.len()
+    }
+
     /// Create new_array with the right layout for appending to `growing_axis`
     #[cold]
     fn change_to_contig_append_layout(&mut self, growing_axis: Axis)
@@ -623,7 +1187,7 @@ where D: Dimension
 
         let mut res_dim = self_dim;
         res_dim[axis.index()] += array_dim[axis.index()];
-        let new_len = dimension::size_of_shape_checked(&res_dim)?;
+        let new_len = dimension::size_of_array_checked::<A, _>(&res_dim)?;
 
         if len_to_append == 0 {
             // There are no elements to append and shapes are compatible:
@@ -635,33 +1199,8 @@ where D: Dimension
         }
 
         let self_is_empty = self.is_empty();
-        let mut incompatible_layout = false;
-
-        // array must be empty or have `axis` as the outermost (longest stride) axis
-        if !self_is_empty && current_axis_len > 1 {
-            // `axis` must be max stride axis or equal to its stride
-            let axis_stride = self.stride_of(axis);
-            if axis_stride < 0 {
-                incompatible_layout = true;
-            } else {
-                for ax in self.axes() {
-                    if ax.axis == axis {
-                        continue;
-                    }
-                    if ax.len > 1 && ax.stride.abs() > axis_stride {
-                        incompatible_layout = true;
-                        break;
-                    }
-                }
-            }
-        }
 
-        // array must be be "full" (contiguous and have no exterior holes)
-        if self.len() != self.data.len() {
-            incompatible_layout = true;
-        }
-
-        if incompatible_layout {
+        if self.append_needs_relayout(axis) {
             self.change_to_contig_append_layout(axis);
             // safety-check parameters after remodeling
             debug_assert_eq!(self_is_empty, self.is_empty());
@@ -835,7 +1374,7 @@ where D: Dimension
         // Make sure new capacity is still in bounds
         let mut res_dim = self_dim;
         res_dim[axis.index()] += additional;
-        let new_len = dimension::size_of_shape_checked(&res_dim)?;
+        let new_len = dimension::size_of_array_checked::<A, _>(&res_dim)?;
 
         // Check whether len_to_append would cause an overflow
         debug_assert_eq!(self.len().checked_add(len_to_append).unwrap(), new_len);
@@ -858,6 +1397,107 @@ where D: Dimension
 
         Ok(())
     }
+
+    /// Change the length of `axis` to `new_len`, truncating the array or
+    /// appending subviews filled with clones of `fill`.
+    ///
+    /// Shrinking is the same as [`.truncate_axis(axis, new_len)`](Self::truncate_axis).
+    /// Growing is the same as [`.append()`](Self::append) of an array of the
+    /// missing length along `axis` with all elements equal to `fill`; the
+    /// existing data stays in place if `axis` is a "growing axis" for the
+    /// array (see `append`), and the array is reallocated and its elements
+    /// moved otherwise.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ***Errors*** with a shape error if the new capacity is larger than the
+    /// addressable bounds, like `append`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2],
+    ///                    [3, 4]];
+    /// a.resize_axis(Axis(0), 3, 0).unwrap();
+    /// assert_eq!(a, array![[1, 2], [3, 4], [0, 0]]);
+    /// a.resize_axis(Axis(1), 1, 0).unwrap();
+    /// assert_eq!(a, array![[1], [3], [0]]);
+    /// ```
+    #[track_caller]
+    pub fn resize_axis(&mut self, axis: Axis, new_len: usize, fill: A) -> Result<(), ShapeError>
+    where
+        A: Clone,
+        D: RemoveAxis,
+    {
+        let len = self.len_of(axis);
+        if new_len <= len {
+            self.truncate_axis(axis, new_len);
+            return Ok(());
+        }
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = new_len - len;
+        let fill = ArrayView::from(std::slice::from_ref(&fill));
+        self.append(axis, fill.broadcast(dim).unwrap())
+    }
+
+    /// Shorten `axis` to length `len`, removing the subviews at the
+    /// following indices. Nothing happens if `len` is not less than the
+    /// current length of `axis`.
+    ///
+    /// Like [`Vec::truncate`], this never reallocates and keeps the capacity
+    /// of the array. If `axis` is the "growing axis" of an array that uses
+    /// its whole allocation (see [`.append()`](Self::append)), the removed
+    /// elements are at the end of the allocation and are dropped right away,
+    /// so that the array can grow along `axis` in place again. Otherwise,
+    /// the array is sliced in place, and the removed elements are dropped
+    /// when the array is dropped or reallocated.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// a.truncate_axis(Axis(1), 2);
+    /// assert_eq!(a, array![[1, 2], [4, 5]]);
+    /// a.truncate_axis(Axis(0), 0);
+    /// assert_eq!(a.shape(), &[0, 2]);
+    /// ```
+    #[track_caller]
+    pub fn truncate_axis(&mut self, axis: Axis, len: usize)
+    {
+        let current_len = self.len_of(axis);
+        if len >= current_len {
+            return;
+        }
+        let is_full = self.len() == self.data.len();
+        let is_growing_axis = self.stride_of(axis) > 0
+            && self
+                .axes()
+                .all(|ax| ax.axis == axis || ax.len <= 1 || ax.stride.abs() <= self.stride_of(axis));
+        if !(is_full && (len == 0 || is_growing_axis)) {
+            self.slice_axis_inplace(axis, Slice::from(..len));
+            return;
+        }
+
+        // the removed elements are the tail of the allocation
+        let new_data_len = self.len() / current_len * len;
+        let data_len = self.data.len();
+        self.dim[axis.index()] = len;
+        if new_data_len == 0 {
+            self.ptr = self.data.as_nonnull_mut();
+        }
+        unsafe {
+            // Safety: the array and its storage no longer include the tail
+            // elements when they are dropped, so that a panic in drop leaks
+            // them instead of leaving the array pointing to dropped elements.
+            let tail = self.data.as_ptr_mut().add(new_data_len);
+            self.data.set_len(new_data_len);
+            debug_assert!(self.pointer_is_inbounds());
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, data_len - new_data_len));
+        }
+    }
 }
 
 /// This drops all "unreachable" elements in `self_` given the data pointer and data length.
@@ -13,7 +13,8 @@ use crate::dimension::offset_from_low_addr_ptr_to_logical_ptr;
 use crate::error::ShapeError;
 use crate::extension::nonnull::nonnull_debug_checked_from_ptr;
 use crate::imp_prelude::*;
-use crate::{is_aligned, StrideShape};
+use crate::shape_builder::Strides;
+use crate::{is_aligned, IntoDimension, StrideShape};
 
 /// Methods for read-only array views.
 impl<'a, A, D> ArrayView<'a, A, D>
@@ -56,14 +57,89 @@ where D: Dimension
         let dim = shape.dim;
         dimension::can_index_slice_with_strides(xs, &dim, &shape.strides)?;
         let strides = shape.strides.strides_for_dim(&dim);
-        unsafe {
-            Ok(Self::new_(
-                xs.as_ptr()
-                    .add(offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides)),
-                dim,
-                strides,
-            ))
+        unsafe { Ok(Self::from_slice_unchecked(xs, dim, strides)) }
+    }
+
+    /// Create a read-only array view borrowing its data from a slice, where
+    /// the strides may make several indices refer to the same element.
+    ///
+    /// This is like [`ArrayView::from_shape`], except that overlapping strides
+    /// are accepted (for example repeated or zero strides), which is useful
+    /// for the “stride tricks” of sliding windows. The strides are still
+    /// checked so that every index is within the bounds of `xs`, including
+    /// the element with the largest offset.
+    ///
+    /// This is sound because the view is read-only: shared references to the
+    /// same element are allowed to alias. There is deliberately no mutable
+    /// counterpart, since two mutable references to one element are not
+    /// allowed.
+    ///
+    /// **Errors** if the shape and strides are not compatible with the
+    /// length of `xs`, or if the number of elements or the largest offset
+    /// overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, ArrayView, Axis, ShapeBuilder};
+    ///
+    /// // all windows of length 3 of a signal, without copying
+    /// let signal = [1, 2, 3, 4, 5];
+    /// let w = 3;
+    /// let n = signal.len() - w + 1;
+    /// let windows = ArrayView::from_shape_overlapping((n, w).strides((1, 1)), &signal).unwrap();
+    /// assert_eq!(windows, array![[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    /// assert_eq!(windows.sum_axis(Axis(1)), array![6, 9, 12]);
+    /// ```
+    pub fn from_shape_overlapping<Sh>(shape: Sh, xs: &'a [A]) -> Result<Self, ShapeError>
+    where Sh: Into<StrideShape<D>>
+    {
+        let shape = shape.into();
+        let dim = shape.dim;
+        if let Strides::Custom(strides) = &shape.strides {
+            dimension::can_index_slice_overlapping(xs, &dim, strides)?;
+        } else {
+            dimension::can_index_slice_not_custom(xs.len(), &dim)?;
+        }
+        let strides = shape.strides.strides_for_dim(&dim);
+        unsafe { Ok(Self::from_slice_unchecked(xs, dim, strides)) }
+    }
+
+    /// Create a read-only array view of shape `shape` where every element is
+    /// `x`, using zero strides and without allocating.
+    ///
+    /// To zip a scalar with arrays, prefer [`broadcast_scalar`](crate::broadcast_scalar),
+    /// which does not make a `Zip` fall back to strided traversal.
+    ///
+    /// **Panics** if the number of elements overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, ArrayView};
+    ///
+    /// let v = ArrayView::from_scalar(&7, (2, 3));
+    /// assert_eq!(v, array![[7, 7, 7], [7, 7, 7]]);
+    /// assert_eq!(v.strides(), &[0, 0]);
+    /// ```
+    #[track_caller]
+    pub fn from_scalar<Sh>(x: &'a A, shape: Sh) -> Self
+    where Sh: IntoDimension<Dim = D>
+    {
+        let dim = shape.into_dimension();
+        if dimension::size_of_shape_checked(&dim).is_err() {
+            panic!("ndarray: Shape too large, number of elements overflows isize");
         }
+        let strides = D::zeros(dim.ndim());
+        unsafe { Self::new_(x, dim, strides) }
+    }
+
+    /// Create the view of `xs` with `dim` and `strides`, where the element
+    /// with the lowest address is at the start of `xs`.
+    ///
+    /// Unsafe because `dim` and `strides` must have been checked against `xs`.
+    unsafe fn from_slice_unchecked(xs: &'a [A], dim: D, strides: D) -> Self
+    {
+        let ptr = xs
+            .as_ptr()
+            .add(offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides));
+        Self::new_(ptr, dim, strides)
     }
 
     /// Create an `ArrayView<A, D>` from shape information and a raw pointer to
@@ -6,7 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::arraytraits::array_out_of_bounds;
+use crate::arraytraits::index_out_of_bounds;
 use crate::imp_prelude::*;
 use crate::NdIndex;
 
@@ -123,7 +123,10 @@ where
     fn index(self, index: I) -> &'a A
     {
         debug_bounds_check!(self, index);
-        unsafe { &*self.get_ptr(index).unwrap_or_else(|| array_out_of_bounds()) }
+        match index.index_checked(&self.dim, &self.strides) {
+            Some(offset) => unsafe { &*self.as_ptr().offset(offset) },
+            None => index_out_of_bounds(&index, &self.dim),
+        }
     }
 
     fn get(self, index: I) -> Option<&'a A>
@@ -172,11 +175,9 @@ where
     fn index(mut self, index: I) -> &'a mut A
     {
         debug_bounds_check!(self, index);
-        unsafe {
-            match self.get_mut_ptr(index) {
-                Some(ptr) => &mut *ptr,
-                None => array_out_of_bounds(),
-            }
+        match index.index_checked(&self.dim, &self.strides) {
+            Some(offset) => unsafe { &mut *self.as_mut_ptr().offset(offset) },
+            None => index_out_of_bounds(&index, &self.dim),
         }
     }
 
@@ -8,6 +8,8 @@
 
 use crate::imp_prelude::*;
 use crate::slice::MultiSliceArg;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use num_complex::Complex;
 
 /// Methods for read-only array views.
@@ -96,6 +98,31 @@ where D: Dimension
             (left.deref_into_view(), right.deref_into_view())
         }
     }
+
+    /// Split the array view along `axis` into `n` consecutive views, whose
+    /// lengths along `axis` differ by at most one.
+    ///
+    /// The longer views come first. If `n` is greater than the length of
+    /// `axis`, the last views are empty. Together, the views cover each
+    /// element of the original view exactly once.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `n` is zero.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((7, 2), |(i, j)| i * 2 + j);
+    /// let parts = a.view().split_evenly(Axis(0), 3);
+    /// let lengths: Vec<_> = parts.iter().map(|p| p.len_of(Axis(0))).collect();
+    /// assert_eq!(lengths, [3, 2, 2]);
+    /// assert_eq!(parts[1].row(0), a.row(3));
+    /// ```
+    #[track_caller]
+    pub fn split_evenly(self, axis: Axis, n: usize) -> Vec<Self>
+    {
+        let len = self.len_of(axis);
+        split_evenly_with(self, len, axis, n, Self::split_at)
+    }
 }
 
 impl<'a, T, D> ArrayView<'a, Complex<T>, D>
@@ -147,6 +174,53 @@ where D: Dimension
         }
     }
 
+    /// Split the array view along `axis` into `n` consecutive mutable views,
+    /// whose lengths along `axis` differ by at most one.
+    ///
+    /// The views are disjoint, so they can for example be sent to different
+    /// threads. See [`ArrayView::split_evenly`] for how the lengths are
+    /// chosen.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `n` is zero.
+    ///
+    /// ```
+    /// use ndarray::{Array2, Axis};
+    ///
+    /// let mut a = Array2::zeros((2, 5));
+    /// for (k, mut part) in a.view_mut().split_evenly(Axis(1), 2).into_iter().enumerate() {
+    ///     part.fill(k);
+    /// }
+    /// assert_eq!(a.row(0), ndarray::aview1(&[0, 0, 0, 1, 1]));
+    /// ```
+    #[track_caller]
+    pub fn split_evenly(self, axis: Axis, n: usize) -> Vec<Self>
+    {
+        let len = self.len_of(axis);
+        split_evenly_with(self, len, axis, n, Self::split_at)
+    }
+
+    /// Swap the elements of this view with the elements of `other`.
+    ///
+    /// The views must have the same shape, and must not overlap, which is
+    /// the case for example for the two halves from
+    /// [`.split_at()`](Self::split_at).
+    ///
+    /// **Panics** if the shapes are not equal.
+    ///
+    /// ```
+    /// use ndarray::{array, s, Axis};
+    ///
+    /// let mut a = array![[1, 2], [3, 4], [5, 6], [7, 8]];
+    /// let (mut top, mut bottom) = a.view_mut().split_at(Axis(0), 2);
+    /// top.swap_with(&mut bottom.slice_mut(s![..;-1, ..]));
+    /// assert_eq!(a, array![[7, 8], [5, 6], [3, 4], [1, 2]]);
+    /// ```
+    #[track_caller]
+    pub fn swap_with(&mut self, other: &mut ArrayViewMut<'_, A, D>)
+    {
+        crate::free_functions::swap_contents_elementwise(self, other, "swap_with");
+    }
+
     /// Split the view into multiple disjoint slices.
     ///
     /// This is similar to [`.multi_slice_mut()`], but `.multi_slice_move()`
@@ -209,3 +283,22 @@ where D: Dimension
         }
     }
 }
+
+/// Split `view`, which has length `len` along `axis`, into `n` consecutive
+/// parts using `split_at`.
+#[track_caller]
+fn split_evenly_with<V>(view: V, len: usize, axis: Axis, n: usize, split_at: fn(V, Axis, Ix) -> (V, V)) -> Vec<V>
+{
+    assert!(n != 0, "ndarray: split_evenly requires at least one part");
+    let (quot, rem) = (len / n, len % n);
+    let mut parts = Vec::with_capacity(n);
+    let mut rest = view;
+    for k in 0..n - 1 {
+        let part_len = quot + (k < rem) as usize;
+        let (part, tail) = split_at(rest, axis, part_len);
+        parts.push(part);
+        rest = tail;
+    }
+    parts.push(rest);
+    parts
+}
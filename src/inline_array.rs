@@ -0,0 +1,445 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::fmt;
+use std::iter;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+use std::ptr;
+use std::slice;
+
+use num_traits::Zero;
+
+use crate::dimension::size_of_shape_checked;
+use crate::error::{self, ErrorKind, ShapeError, ShapeMismatch};
+use crate::imp_prelude::*;
+use crate::{indices, IndexLonger, IntoDimension, LinalgScalar, NdIndex, ScalarOperand};
+
+/// An owned array with room for `N` elements, stored inline instead of in a
+/// heap allocation.
+///
+/// `InlineArray` is meant for arrays that are both small and many, like the
+/// 3 × 3 and 4 × 4 matrices of geometry code. Creating one does not
+/// allocate, and neither do [`.map()`](InlineArray::map), the arithmetic
+/// operators and [`.dot()`](InlineArray::dot), which return inline arrays of
+/// the same capacity.
+///
+/// The elements are always in standard (row major) layout. Any shape with
+/// at most `N` elements fits; the constructors return an error if the shape
+/// needs more elements than that.
+///
+/// An `InlineArray` is not an [`ArrayBase`]: an array points to its first
+/// element, and that pointer would dangle when an array with inline elements
+/// is moved. Use [`.view()`](InlineArray::view) and
+/// [`.view_mut()`](InlineArray::view_mut) for the rest of the array API,
+/// [`InlineArray::from_array`] to copy the elements of an array or view, and
+/// [`.into_array()`](InlineArray::into_array) to move them to a heap
+/// allocated array.
+///
+/// ```
+/// use ndarray::{array, InlineArray, Ix2};
+///
+/// type Matrix3 = InlineArray<f64, Ix2, 9>;
+///
+/// let rotate = Matrix3::from_array(&array![[0., -1., 0.], [1., 0., 0.], [0., 0., 1.]]).unwrap();
+/// let scale = Matrix3::from_shape_fn((3, 3), |(i, j)| if i == j { 2. } else { 0. }).unwrap();
+///
+/// let m = rotate.dot(&scale).dot(&rotate);
+/// assert_eq!(m.view(), array![[-2., 0., 0.], [0., -2., 0.], [0., 0., 2.]]);
+/// assert_eq!(m[[2, 2]], 2.);
+///
+/// // a 4 × 4 matrix does not fit
+/// assert!(Matrix3::zeros((4, 4)).is_err());
+/// ```
+pub struct InlineArray<A, D, const N: usize>
+where D: Dimension
+{
+    /// The first `dim.size()` elements are initialized
+    data: [MaybeUninit<A>; N],
+    dim: D,
+}
+
+impl<A, D, const N: usize> InlineArray<A, D, N>
+where D: Dimension
+{
+    /// Create an array with the given shape, with each element set to the
+    /// return value of `f` called with the element's index.
+    ///
+    /// `f` is called in row major order.
+    ///
+    /// **Errors** if the shape has more than `N` elements.
+    pub fn from_shape_fn<Sh, F>(shape: Sh, f: F) -> Result<Self, ShapeError>
+    where
+        Sh: IntoDimension<Dim = D>,
+        F: FnMut(D::Pattern) -> A,
+    {
+        let dim = shape.into_dimension();
+        check_capacity(&dim, N, "from_shape_fn")?;
+        Ok(Self::fill(dim.clone(), indices(dim).into_iter().map(f)))
+    }
+
+    /// Create an array with the given shape, with all elements set to
+    /// `elem`.
+    ///
+    /// **Errors** if the shape has more than `N` elements.
+    pub fn from_elem<Sh>(shape: Sh, elem: A) -> Result<Self, ShapeError>
+    where
+        Sh: IntoDimension<Dim = D>,
+        A: Clone,
+    {
+        let dim = shape.into_dimension();
+        check_capacity(&dim, N, "from_elem")?;
+        Ok(Self::fill(dim, iter::repeat(elem)))
+    }
+
+    /// Create an array with the given shape, with all elements set to zero.
+    ///
+    /// **Errors** if the shape has more than `N` elements.
+    pub fn zeros<Sh>(shape: Sh) -> Result<Self, ShapeError>
+    where
+        Sh: IntoDimension<Dim = D>,
+        A: Clone + Zero,
+    {
+        let dim = shape.into_dimension();
+        check_capacity(&dim, N, "zeros")?;
+        Ok(Self::fill(dim, iter::repeat(A::zero())))
+    }
+
+    /// Create an array with the shape and a copy of the elements of `array`,
+    /// which can be an owned array or a view in any memory layout.
+    ///
+    /// **Errors** if `array` has more than `N` elements.
+    pub fn from_array<S>(array: &ArrayBase<S, D>) -> Result<Self, ShapeError>
+    where
+        S: Data<Elem = A>,
+        A: Clone,
+    {
+        check_capacity(&array.dim, N, "from_array")?;
+        Ok(Self::fill(array.raw_dim(), array.iter().cloned()))
+    }
+
+    /// Move the elements into a new heap allocated array in standard layout.
+    pub fn into_array(self) -> Array<A, D>
+    {
+        let mut v = Vec::with_capacity(self.len());
+        let this = mem::ManuallyDrop::new(self);
+        for elem in this.data[..this.len()].iter() {
+            // the elements are read once, and not dropped with `this`
+            unsafe {
+                v.push(elem.as_ptr().read());
+            }
+        }
+        // the shape is moved out too, so that it is not leaked for `IxDyn`
+        let dim = unsafe { ptr::read(&this.dim) };
+        unsafe { Array::from_shape_vec_unchecked(dim, v) }
+    }
+
+    /// Return a heap allocated array with a copy of the elements.
+    pub fn to_owned(&self) -> Array<A, D>
+    where A: Clone
+    {
+        self.view().to_owned()
+    }
+
+    /// Return a read-only view of the array.
+    pub fn view(&self) -> ArrayView<'_, A, D>
+    {
+        // the first `dim.size()` elements are initialized, in standard layout
+        unsafe { ArrayView::new_(self.data.as_ptr() as *const A, self.dim.clone(), self.dim.default_strides()) }
+    }
+
+    /// Return a read-write view of the array.
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, A, D>
+    {
+        // the first `dim.size()` elements are initialized, in standard layout
+        unsafe { ArrayViewMut::new_(self.data.as_mut_ptr() as *mut A, self.dim.clone(), self.dim.default_strides()) }
+    }
+
+    /// Return the elements in row major order as a slice.
+    pub fn as_slice(&self) -> &[A]
+    {
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const A, self.len()) }
+    }
+
+    /// Return the elements in row major order as a mutable slice.
+    pub fn as_slice_mut(&mut self) -> &mut [A]
+    {
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut A, self.len()) }
+    }
+
+    /// Return the shape of the array in its “pattern” form, an integer in
+    /// the one-dimensional case, tuple in the n-dimensional cases and so on.
+    pub fn dim(&self) -> D::Pattern
+    {
+        self.dim.clone().into_pattern()
+    }
+
+    /// Return the shape of the array as it's stored in the array.
+    pub fn raw_dim(&self) -> D
+    {
+        self.dim.clone()
+    }
+
+    /// Return the shape of the array as a slice.
+    pub fn shape(&self) -> &[usize]
+    {
+        self.dim.slice()
+    }
+
+    /// Return the total number of elements in the array.
+    pub fn len(&self) -> usize
+    {
+        self.dim.size()
+    }
+
+    /// Return whether the array has any elements
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Return the number of elements the array has room for, `N`.
+    pub fn capacity(&self) -> usize
+    {
+        N
+    }
+
+    /// Return a new array of the same shape and capacity, with the elements
+    /// set to the return values of `f` called with references to the
+    /// elements of `self`.
+    pub fn map<'a, B, F>(&'a self, f: F) -> InlineArray<B, D, N>
+    where F: FnMut(&'a A) -> B
+    {
+        InlineArray::fill(self.raw_dim(), self.as_slice().iter().map(f))
+    }
+
+    /// Return a new array of the same shape and capacity, with the elements
+    /// set to the return values of `f` called with copies of the elements of
+    /// `self`.
+    pub fn mapv<B, F>(&self, mut f: F) -> InlineArray<B, D, N>
+    where
+        F: FnMut(A) -> B,
+        A: Clone,
+    {
+        self.map(move |x| f(x.clone()))
+    }
+
+    /// Create an array from the first `dim.size()` elements of `elems`.
+    ///
+    /// **Panics** if `elems` is too short or `dim` has more than `N`
+    /// elements; the elements already written are leaked in that case.
+    fn fill<I>(dim: D, elems: I) -> Self
+    where I: IntoIterator<Item = A>
+    {
+        // an array of uninitialized `MaybeUninit`s needs no initialization
+        let mut data: [MaybeUninit<A>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut elems = elems.into_iter();
+        for slot in data[..dim.size()].iter_mut() {
+            *slot = MaybeUninit::new(elems.next().expect("enough elements"));
+        }
+        InlineArray { data, dim }
+    }
+}
+
+impl<A, const N: usize> InlineArray<A, Ix2, N>
+where A: LinalgScalar
+{
+    /// Perform matrix multiplication of `self` and `rhs`, and return the
+    /// product as a new inline array with the capacity of `self`.
+    ///
+    /// The product is computed with plain loops, which for matrices this
+    /// small is faster than the general [`.dot()`](ArrayBase::dot) of
+    /// arrays.
+    ///
+    /// **Panics** if the shapes are incompatible, or if the product has
+    /// more than `N` elements.
+    #[track_caller]
+    pub fn dot<const M: usize>(&self, rhs: &InlineArray<A, Ix2, M>) -> Self
+    {
+        let (m, k) = self.dim();
+        let (k2, n) = rhs.dim();
+        if k != k2 {
+            error::shape_mismatch_panic(ShapeMismatch::Dot, self.shape(), rhs.shape(), "dot");
+        }
+        if m.checked_mul(n).map_or(true, |size| size > N) {
+            panic!(
+                "ndarray: the product of shapes {:?} and {:?} does not fit in an inline array with capacity {}",
+                self.shape(),
+                rhs.shape(),
+                N
+            );
+        }
+        let a = self.as_slice();
+        let b = rhs.as_slice();
+        let product = (0..m * n).map(|ij| {
+            let (i, j) = (ij / n, ij % n);
+            (0..k).fold(A::zero(), |acc, l| acc + a[i * k + l] * b[l * n + j])
+        });
+        Self::fill(Ix2(m, n), product)
+    }
+}
+
+/// **Errors** if `dim` has more than `capacity` elements, or if it is not
+/// a valid array shape (see [`size_of_shape_checked`]).
+fn check_capacity<D>(dim: &D, capacity: usize, operation: &'static str) -> Result<(), ShapeError>
+where D: Dimension
+{
+    match size_of_shape_checked(dim) {
+        Err(err) => Err(err.in_operation(operation)),
+        Ok(size) if size > capacity => Err(error::from_kind(ErrorKind::OutOfBounds).in_operation(operation)),
+        Ok(_) => Ok(()),
+    }
+}
+
+impl<A, D, const N: usize> Drop for InlineArray<A, D, N>
+where D: Dimension
+{
+    fn drop(&mut self)
+    {
+        unsafe { ptr::drop_in_place(self.as_slice_mut() as *mut [A]) }
+    }
+}
+
+impl<A, D, const N: usize> Clone for InlineArray<A, D, N>
+where
+    A: Clone,
+    D: Dimension,
+{
+    fn clone(&self) -> Self
+    {
+        Self::fill(self.raw_dim(), self.as_slice().iter().cloned())
+    }
+}
+
+impl<A, D, const N: usize> fmt::Debug for InlineArray<A, D, N>
+where
+    A: fmt::Debug,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        self.view().fmt(f)
+    }
+}
+
+impl<A, D, const N: usize> fmt::Display for InlineArray<A, D, N>
+where
+    A: fmt::Display,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        self.view().fmt(f)
+    }
+}
+
+/// Return `true` if the arrays have the same shape and elements, whatever
+/// their capacities.
+impl<A, B, D, const N: usize, const M: usize> PartialEq<InlineArray<B, D, M>> for InlineArray<A, D, N>
+where
+    A: PartialEq<B>,
+    D: Dimension,
+{
+    fn eq(&self, rhs: &InlineArray<B, D, M>) -> bool
+    {
+        self.shape() == rhs.shape() && self.as_slice() == rhs.as_slice()
+    }
+}
+
+impl<A, D, const N: usize> Eq for InlineArray<A, D, N>
+where
+    A: Eq,
+    D: Dimension,
+{
+}
+
+/// Access the element at **index**.
+///
+/// **Panics** if index is out of bounds.
+impl<A, D, I, const N: usize> Index<I> for InlineArray<A, D, N>
+where
+    D: Dimension,
+    I: NdIndex<D>,
+{
+    type Output = A;
+
+    #[track_caller]
+    fn index(&self, index: I) -> &A
+    {
+        IndexLonger::index(&self.view(), index)
+    }
+}
+
+/// Access the element at **index** mutably.
+///
+/// **Panics** if index is out of bounds.
+impl<A, D, I, const N: usize> IndexMut<I> for InlineArray<A, D, N>
+where
+    D: Dimension,
+    I: NdIndex<D>,
+{
+    #[track_caller]
+    fn index_mut(&mut self, index: I) -> &mut A
+    {
+        IndexLonger::index(self.view_mut(), index)
+    }
+}
+
+macro_rules! impl_binary_op(
+    ($trt:ident, $mth:ident, $operator:tt, $doc:expr) => (
+/// Perform elementwise
+#[doc=$doc]
+/// between references `self` and `rhs`,
+/// and return the result as a new inline array with the capacity of `self`.
+///
+/// `rhs` is broadcast to the shape of `self`.
+///
+/// **Panics** if broadcasting isn’t possible.
+impl<'a, 'b, A, D, E, const N: usize, const M: usize> $trt<&'b InlineArray<A, E, M>> for &'a InlineArray<A, D, N>
+where
+    A: Clone + $trt<A, Output = A>,
+    D: Dimension,
+    E: Dimension,
+{
+    type Output = InlineArray<A, D, N>;
+
+    #[track_caller]
+    fn $mth(self, rhs: &'b InlineArray<A, E, M>) -> Self::Output
+    {
+        let rhs = rhs.view();
+        let rhs = rhs.broadcast_unwrap(self.raw_dim(), stringify!($operator));
+        let elems = self.as_slice().iter().zip(rhs.iter());
+        InlineArray::fill(self.raw_dim(), elems.map(|(x, y)| x.clone() $operator y.clone()))
+    }
+}
+
+/// Perform elementwise
+#[doc=$doc]
+/// between the reference `self` and the scalar `x`,
+/// and return the result as a new inline array with the capacity of `self`.
+impl<'a, A, D, const N: usize> $trt<A> for &'a InlineArray<A, D, N>
+where
+    A: ScalarOperand + $trt<A, Output = A>,
+    D: Dimension,
+{
+    type Output = InlineArray<A, D, N>;
+
+    fn $mth(self, x: A) -> Self::Output
+    {
+        self.map(|elt| elt.clone() $operator x.clone())
+    }
+}
+    );
+);
+
+impl_binary_op!(Add, add, +, "addition");
+impl_binary_op!(Sub, sub, -, "subtraction");
+impl_binary_op!(Mul, mul, *, "multiplication");
+impl_binary_op!(Div, div, /, "division");
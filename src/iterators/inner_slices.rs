@@ -0,0 +1,175 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::marker::PhantomData;
+use std::slice;
+
+use super::Baseiter;
+use crate::imp_prelude::*;
+
+/// An iterator over the maximal contiguous runs of the elements of an array,
+/// as slices.
+///
+/// See [`.inner_slices()`](ArrayBase::inner_slices) for more information.
+pub struct InnerSlices<'a, A>
+{
+    iter: Baseiter<A, IxDyn>,
+    run_len: usize,
+    life: PhantomData<&'a A>,
+}
+
+/// An iterator over the maximal contiguous runs of the elements of an array,
+/// as mutable slices.
+///
+/// See [`.inner_slices_mut()`](ArrayBase::inner_slices_mut) for more
+/// information.
+pub struct InnerSlicesMut<'a, A>
+{
+    iter: Baseiter<A, IxDyn>,
+    run_len: usize,
+    life: PhantomData<&'a mut A>,
+}
+
+impl<'a, A> InnerSlices<'a, A>
+{
+    pub(crate) fn new<D: Dimension>(v: ArrayView<'a, A, D>) -> Self
+    {
+        let (iter, run_len) = unsafe { memory_runs(v.as_ptr() as *mut A, &v.dim, &v.strides) };
+        InnerSlices {
+            iter,
+            run_len,
+            life: PhantomData,
+        }
+    }
+
+    /// Return the length of each slice.
+    ///
+    /// All slices have the same length, which is zero only if the array has
+    /// no elements (and then there are no slices).
+    pub fn run_len(&self) -> usize
+    {
+        self.run_len
+    }
+}
+
+impl<'a, A> InnerSlicesMut<'a, A>
+{
+    pub(crate) fn new<D: Dimension>(mut v: ArrayViewMut<'a, A, D>) -> Self
+    {
+        let (iter, run_len) = unsafe { memory_runs(v.as_mut_ptr(), &v.dim, &v.strides) };
+        InnerSlicesMut {
+            iter,
+            run_len,
+            life: PhantomData,
+        }
+    }
+
+    /// Return the length of each slice.
+    ///
+    /// All slices have the same length, which is zero only if the array has
+    /// no elements (and then there are no slices).
+    pub fn run_len(&self) -> usize
+    {
+        self.run_len
+    }
+}
+
+/// Return an iterator over the first element of each contiguous run of the
+/// array with pointer `ptr`, shape `dim` and `strides`, and the length of the
+/// runs.
+///
+/// Axes of length one are skipped and inverted axes are flipped, so that
+/// the runs are visited in order of increasing address, from the largest to
+/// the smallest stride. The trailing axes are merged for as long as their
+/// elements form a contiguous block.
+///
+/// Unsafe because `ptr`, `dim` and `strides` must describe a valid array.
+unsafe fn memory_runs<A, D: Dimension>(mut ptr: *mut A, dim: &D, strides: &D) -> (Baseiter<A, IxDyn>, usize)
+{
+    if dim.size() == 0 {
+        return (Baseiter::new(ptr, IxDyn(&[0]), IxDyn(&[0])), 0);
+    }
+    let mut axes = Vec::with_capacity(dim.ndim());
+    for (&len, &stride) in dim.slice().iter().zip(strides.slice()) {
+        if len <= 1 {
+            continue;
+        }
+        let mut stride = stride as isize;
+        if stride < 0 {
+            ptr = ptr.offset(stride * (len - 1) as isize);
+            stride = -stride;
+        }
+        axes.push((len, stride));
+    }
+    axes.sort_by_key(|&(_, stride)| std::cmp::Reverse(stride));
+    let mut run_len = 1;
+    while let Some(&(len, stride)) = axes.last() {
+        if stride != run_len as isize {
+            break;
+        }
+        run_len *= len;
+        axes.pop();
+    }
+    let outer_dim = IxDyn(&axes.iter().map(|&(len, _)| len).collect::<Vec<_>>());
+    let outer_strides = IxDyn(
+        &axes
+            .iter()
+            .map(|&(_, stride)| stride as usize)
+            .collect::<Vec<_>>(),
+    );
+    (Baseiter::new(ptr, outer_dim, outer_strides), run_len)
+}
+
+impl<'a, A> Iterator for InnerSlices<'a, A>
+{
+    type Item = &'a [A];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.iter
+            .next()
+            .map(|ptr| unsafe { slice::from_raw_parts(ptr, self.run_len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A> ExactSizeIterator for InnerSlices<'a, A>
+{
+    fn len(&self) -> usize
+    {
+        self.iter.len()
+    }
+}
+
+impl<'a, A> Iterator for InnerSlicesMut<'a, A>
+{
+    type Item = &'a mut [A];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.iter
+            .next()
+            .map(|ptr| unsafe { slice::from_raw_parts_mut(ptr, self.run_len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A> ExactSizeIterator for InnerSlicesMut<'a, A>
+{
+    fn len(&self) -> usize
+    {
+        self.iter.len()
+    }
+}
+
+unsafe impl<'a, A> Send for InnerSlices<'a, A> where A: Sync {}
+unsafe impl<'a, A> Sync for InnerSlices<'a, A> where A: Sync {}
+unsafe impl<'a, A> Send for InnerSlicesMut<'a, A> where A: Send {}
+unsafe impl<'a, A> Sync for InnerSlicesMut<'a, A> where A: Sync {}
@@ -19,6 +19,8 @@ pub use crate::iterators::{
     ExactChunksMut,
     IndexedIter,
     IndexedIterMut,
+    InnerSlices,
+    InnerSlicesMut,
     IntoIter,
     Iter,
     IterMut,
@@ -26,5 +28,7 @@ pub use crate::iterators::{
     LanesIter,
     LanesIterMut,
     LanesMut,
+    MultiAxisIter,
+    MultiAxisIterMut,
     Windows,
 };
@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use super::LanesIter;
 use super::LanesIterMut;
 use crate::imp_prelude::*;
-use crate::{Layout, NdProducer};
+use crate::{Layout, NdIndex, NdProducer};
 
 impl_ndproducer! {
     ['a, A, D: Dimension]
@@ -23,6 +23,19 @@ impl_ndproducer! {
     }
 }
 
+/// A producer and iterable of the lanes of an array along an axis, as
+/// one-dimensional array views.
+///
+/// The lanes are indexed by the remaining axes of the array, so `Lanes` has
+/// a dimension `D` with one axis less than the array. [`.len()`](Self::len)
+/// is the number of lanes, [`.get()`](Self::get) returns the lane at an
+/// index, and [`.split_at()`](Self::split_at) divides the lanes in two
+/// parts, for example to hand them to parallel workers.
+///
+/// Iterating (with `.into_iter()`) visits the lanes in the logical order of
+/// their indices, row major, whatever the memory layout of the array. In a
+/// [`Zip`](crate::Zip) the order is unspecified, like for other producers.
+///
 /// See [`.lanes()`](ArrayBase::lanes)
 /// for more information.
 pub struct Lanes<'a, A, D>
@@ -45,7 +58,7 @@ impl<'a, A, D: Dimension> Lanes<'a, A, D>
             stride = 1;
             v.try_remove_axis(Axis(0))
         } else {
-            let i = axis.index();
+            let i = axis.checked_index(ndim);
             len = v.dim[i];
             stride = v.strides[i] as isize;
             v.try_remove_axis(axis)
@@ -58,6 +71,51 @@ impl<'a, A, D: Dimension> Lanes<'a, A, D>
     }
 }
 
+impl<'a, A, D: Dimension> Lanes<'a, A, D>
+{
+    /// Return the number of lanes.
+    pub fn len(&self) -> usize
+    {
+        self.base.len()
+    }
+
+    /// Return `true` if there are no lanes.
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Return the lane at `index`, or `None` if the index is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, aview1};
+    ///
+    /// let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    /// let rows = a.rows();
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows.get(1), Some(aview1(&[4, 5, 6])));
+    /// assert_eq!(rows.get(2), None);
+    /// assert_eq!(a.columns().get(2), Some(aview1(&[3, 6])));
+    /// ```
+    pub fn get<I>(&self, index: I) -> Option<ArrayView<'a, A, Ix1>>
+    where I: NdIndex<D>
+    {
+        self.base
+            .get_ptr(index)
+            .map(|ptr| unsafe { ArrayView::new_(ptr, Ix1(self.inner_len), Ix1(self.inner_stride as Ix)) })
+    }
+
+    /// Split the lanes in two parts at `index` along `axis` of the lane
+    /// indices.
+    ///
+    /// **Panics** if `axis` or `index` is out of bounds.
+    #[track_caller]
+    pub fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    {
+        NdProducer::split_at(self, axis, index)
+    }
+}
+
 impl_ndproducer! {
     ['a, A, D: Dimension]
     [Clone =>]
@@ -92,6 +150,11 @@ where D: Dimension
     }
 }
 
+/// A producer and iterable of the lanes of an array along an axis, as
+/// one-dimensional read-write array views.
+///
+/// Like [`Lanes`], with [`.get_mut()`](Self::get_mut) instead of `.get()`.
+///
 /// See [`.lanes_mut()`](ArrayBase::lanes_mut)
 /// for more information.
 pub struct LanesMut<'a, A, D>
@@ -114,7 +177,7 @@ impl<'a, A, D: Dimension> LanesMut<'a, A, D>
             stride = 1;
             v.try_remove_axis(Axis(0))
         } else {
-            let i = axis.index();
+            let i = axis.checked_index(ndim);
             len = v.dim[i];
             stride = v.strides[i] as isize;
             v.try_remove_axis(axis)
@@ -127,6 +190,42 @@ impl<'a, A, D: Dimension> LanesMut<'a, A, D>
     }
 }
 
+impl<'a, A, D: Dimension> LanesMut<'a, A, D>
+{
+    /// Return the number of lanes.
+    pub fn len(&self) -> usize
+    {
+        self.base.len()
+    }
+
+    /// Return `true` if there are no lanes.
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Return the lane at `index` as a read-write view, or `None` if the
+    /// index is out of bounds.
+    pub fn get_mut<I>(&mut self, index: I) -> Option<ArrayViewMut<'_, A, Ix1>>
+    where I: NdIndex<D>
+    {
+        let (len, stride) = (self.inner_len, self.inner_stride);
+        self.base
+            .get_mut_ptr(index)
+            .map(|ptr| unsafe { ArrayViewMut::new_(ptr, Ix1(len), Ix1(stride as Ix)) })
+    }
+
+    /// Split the lanes in two parts at `index` along `axis` of the lane
+    /// indices.
+    ///
+    /// **Panics** if `axis` or `index` is out of bounds.
+    #[track_caller]
+    pub fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    {
+        NdProducer::split_at(self, axis, index)
+    }
+}
+
 impl<'a, A, D> IntoIterator for LanesMut<'a, A, D>
 where D: Dimension
 {
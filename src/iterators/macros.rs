@@ -71,6 +71,10 @@ impl<$($typarm)*> NdProducer for $fulltype {
         self.$base.layout()
     }
 
+    fn is_writable(&self) -> bool {
+        self.$base.is_writable()
+    }
+
     fn as_ptr(&self) -> *mut A {
         self.$base.as_ptr() as *mut _
     }
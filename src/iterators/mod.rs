@@ -9,9 +9,11 @@
 #[macro_use]
 mod macros;
 mod chunks;
+mod inner_slices;
 mod into_iter;
 pub mod iter;
 mod lanes;
+mod multi_axis;
 mod windows;
 
 #[cfg(not(feature = "std"))]
@@ -26,8 +28,10 @@ use super::{ArrayBase, ArrayView, ArrayViewMut, Axis, Data, NdProducer, RemoveAx
 use super::{Dimension, Ix, Ixs};
 
 pub use self::chunks::{ExactChunks, ExactChunksIter, ExactChunksIterMut, ExactChunksMut};
+pub use self::inner_slices::{InnerSlices, InnerSlicesMut};
 pub use self::into_iter::IntoIter;
 pub use self::lanes::{Lanes, LanesMut};
+pub use self::multi_axis::{MultiAxisIter, MultiAxisIterMut};
 pub use self::windows::Windows;
 
 use std::slice::{self, Iter as SliceIter, IterMut as SliceIterMut};
@@ -1547,6 +1551,7 @@ unsafe impl<F> TrustedIterator for Geomspace<F> {}
 unsafe impl<F> TrustedIterator for Logspace<F> {}
 unsafe impl<'a, A, D> TrustedIterator for Iter<'a, A, D> {}
 unsafe impl<'a, A, D> TrustedIterator for IterMut<'a, A, D> {}
+unsafe impl<'a, A, D> TrustedIterator for IndexedIter<'a, A, D> where D: Dimension {}
 unsafe impl<I> TrustedIterator for std::iter::Cloned<I> where I: TrustedIterator {}
 unsafe impl<I, F> TrustedIterator for std::iter::Map<I, F> where I: TrustedIterator {}
 unsafe impl<'a, A> TrustedIterator for slice::Iter<'a, A> {}
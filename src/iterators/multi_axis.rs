@@ -0,0 +1,152 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::marker::PhantomData;
+
+use super::Baseiter;
+use crate::dimension;
+use crate::imp_prelude::*;
+use crate::ShapeError;
+
+/// An iterator over the subviews of an array with a set of axes removed, one
+/// for each index along those axes.
+///
+/// See [`.multi_axis_iter()`](ArrayBase::multi_axis_iter) for more
+/// information.
+#[derive(Debug)]
+pub struct MultiAxisIter<'a, A>
+{
+    iter: Baseiter<A, IxDyn>,
+    inner_dim: IxDyn,
+    inner_strides: IxDyn,
+    life: PhantomData<&'a A>,
+}
+
+/// An iterator over the mutable subviews of an array with a set of axes
+/// removed, one for each index along those axes.
+///
+/// See [`.multi_axis_iter_mut()`](ArrayBase::multi_axis_iter_mut) for more
+/// information.
+#[derive(Debug)]
+pub struct MultiAxisIterMut<'a, A>
+{
+    iter: Baseiter<A, IxDyn>,
+    inner_dim: IxDyn,
+    inner_strides: IxDyn,
+    life: PhantomData<&'a mut A>,
+}
+
+impl<'a, A> MultiAxisIter<'a, A>
+{
+    pub(crate) fn new<D: Dimension>(v: ArrayView<'a, A, D>, axes: &[Axis]) -> Result<Self, ShapeError>
+    {
+        let (iter, inner_dim, inner_strides) = unsafe { split_axes(v.as_ptr() as *mut A, &v.dim, &v.strides, axes)? };
+        Ok(MultiAxisIter {
+            iter,
+            inner_dim,
+            inner_strides,
+            life: PhantomData,
+        })
+    }
+}
+
+impl<'a, A> MultiAxisIterMut<'a, A>
+{
+    pub(crate) fn new<D: Dimension>(mut v: ArrayViewMut<'a, A, D>, axes: &[Axis]) -> Result<Self, ShapeError>
+    {
+        let (iter, inner_dim, inner_strides) = unsafe { split_axes(v.as_mut_ptr(), &v.dim, &v.strides, axes)? };
+        Ok(MultiAxisIterMut {
+            iter,
+            inner_dim,
+            inner_strides,
+            life: PhantomData,
+        })
+    }
+}
+
+/// Split the axes of the array with pointer `ptr`, shape `dim` and `strides`
+/// into the listed `axes`, which are iterated in the order of their indices,
+/// and the remaining axes, which make up the subviews. Return the iterator
+/// over the first element of each subview, and its shape and strides.
+///
+/// **Errors** if an axis is out of bounds or listed more than once.
+///
+/// Unsafe because `ptr`, `dim` and `strides` must describe a valid array.
+unsafe fn split_axes<A, D: Dimension>(
+    ptr: *mut A, dim: &D, strides: &D, axes: &[Axis],
+) -> Result<(Baseiter<A, IxDyn>, IxDyn, IxDyn), ShapeError>
+{
+    let listed: D = dimension::axes_mask(dim.ndim(), axes)?;
+    let mut outer_dim = Vec::with_capacity(axes.len());
+    let mut outer_strides = Vec::with_capacity(axes.len());
+    let mut inner_dim = Vec::with_capacity(dim.ndim() - axes.len());
+    let mut inner_strides = Vec::with_capacity(dim.ndim() - axes.len());
+    for ((&len, &stride), &count) in dim.slice().iter().zip(strides.slice()).zip(listed.slice()) {
+        if count != 0 {
+            outer_dim.push(len);
+            outer_strides.push(stride);
+        } else {
+            inner_dim.push(len);
+            inner_strides.push(stride);
+        }
+    }
+    Ok((
+        Baseiter::new(ptr, IxDyn(&outer_dim), IxDyn(&outer_strides)),
+        IxDyn(&inner_dim),
+        IxDyn(&inner_strides),
+    ))
+}
+
+impl<'a, A> Iterator for MultiAxisIter<'a, A>
+{
+    type Item = ArrayView<'a, A, IxDyn>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.iter
+            .next()
+            .map(|ptr| unsafe { ArrayView::new_(ptr, self.inner_dim.clone(), self.inner_strides.clone()) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A> ExactSizeIterator for MultiAxisIter<'a, A>
+{
+    fn len(&self) -> usize
+    {
+        self.iter.len()
+    }
+}
+
+impl<'a, A> Iterator for MultiAxisIterMut<'a, A>
+{
+    type Item = ArrayViewMut<'a, A, IxDyn>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.iter
+            .next()
+            .map(|ptr| unsafe { ArrayViewMut::new_(ptr, self.inner_dim.clone(), self.inner_strides.clone()) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A> ExactSizeIterator for MultiAxisIterMut<'a, A>
+{
+    fn len(&self) -> usize
+    {
+        self.iter.len()
+    }
+}
+
+unsafe impl<'a, A> Send for MultiAxisIter<'a, A> where A: Sync {}
+unsafe impl<'a, A> Sync for MultiAxisIter<'a, A> where A: Sync {}
+unsafe impl<'a, A> Send for MultiAxisIterMut<'a, A> where A: Send {}
+unsafe impl<'a, A> Sync for MultiAxisIterMut<'a, A> where A: Sync {}
@@ -2,14 +2,47 @@ mod layoutfmt;
 
 // Layout it a bitset used for internal layout description of
 // arrays, producers and sets of producers.
-// The type is public but users don't interact with it.
-#[doc(hidden)]
 /// Memory layout description
+///
+/// Describes whether the elements of an array are contiguous in memory in
+/// C (row major) or F (column major) order, see
+/// [`ArrayBase::layout`](crate::ArrayBase::layout).
+///
+/// An array can be in both C and F order at the same time, if it has at
+/// most one axis of length greater than one (for example if it is zero or one
+/// dimensional), or if it has no elements.
 #[derive(Copy, Clone)]
 pub struct Layout(u32);
 
 impl Layout
 {
+    /// Return `true` if the elements are contiguous in memory in C (row
+    /// major) order, where the last index is the most rapidly varying.
+    #[inline]
+    pub fn is_c(self) -> bool
+    {
+        self.is(Layout::CORDER)
+    }
+
+    /// Return `true` if the elements are contiguous in memory in F (column
+    /// major) order, where the first index is the most rapidly varying.
+    #[inline]
+    pub fn is_f(self) -> bool
+    {
+        self.is(Layout::FORDER)
+    }
+
+    /// Return `true` if the elements are contiguous in memory in either C or F
+    /// order.
+    ///
+    /// This does not include arrays with inverted axes (negative strides),
+    /// even if their elements occupy a contiguous range of memory.
+    #[inline]
+    pub fn is_contiguous(self) -> bool
+    {
+        self.is(Layout::CORDER | Layout::FORDER)
+    }
+
     pub(crate) const CORDER: u32 = 0b01;
     pub(crate) const FORDER: u32 = 0b10;
     pub(crate) const CPREFER: u32 = 0b0100;
@@ -86,7 +119,6 @@ mod tests
 {
     use super::*;
     use crate::imp_prelude::*;
-    use crate::NdProducer;
 
     type M = Array2<f32>;
     type M1 = Array1<f32>;
@@ -82,6 +82,15 @@
 //! - `serde`: serialization support for serde 1.x
 //! - `rayon`: Parallel iterators, parallelized methods, the [`parallel`] module and [`par_azip!`].
 //! - `approx` Implementations of traits from the [`approx`] crate.
+//! - `bytemuck`: Zero-copy conversion between arrays of plain old data and bytes,
+//!   and reading and writing raw element buffers with `std::io`.
+//! - `nalgebra`: Conversions between arrays and the `DMatrix` and `DVector` types of
+//!   [`nalgebra`](https://docs.rs/nalgebra), with `From` and `TryFrom`. Since `nalgebra`
+//!   matrices are column major, an `Array2` converts to a `DMatrix` without copying only if
+//!   it is in column major (“F”) layout; a `DMatrix` always converts to an `Array2` in column
+//!   major layout without copying. This feature needs a newer Rust than the minimum
+//!   supported Rust version of `ndarray`.
+//! - `quickcheck`: Generate arrays for property tests with [`quickcheck`](https://docs.rs/quickcheck).
 //! - `blas`: transparent BLAS support for matrix multiplication, needs configuration.
 //! - `matrixmultiply-threading`: Use threading from `matrixmultiply`.
 //!
@@ -134,22 +143,31 @@ use std::marker::PhantomData;
 
 pub use crate::dimension::dim::*;
 pub use crate::dimension::{Axis, AxisDescription, Dimension, IntoDimension, RemoveAxis};
-pub use crate::dimension::{DimAdd, DimMax};
+pub use crate::dimension::{BroadcastAxis, DimAdd, DimMax};
 
 pub use crate::dimension::IxDynImpl;
 pub use crate::dimension::NdIndex;
-pub use crate::error::{ErrorKind, ShapeError};
+pub use crate::error::{CastError, ErrorKind, OverflowError, ShapeError};
 pub use crate::indexes::{indices, indices_of};
 pub use crate::order::Order;
-pub use crate::slice::{MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim};
+pub use crate::slice::{
+    MultiSliceArg,
+    NewAxis,
+    Slice,
+    SliceArg,
+    SliceInfo,
+    SliceInfoBuilder,
+    SliceInfoElem,
+    SliceNextDim,
+};
 
 use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut, Iter, IterMut};
 
 pub use crate::arraytraits::AsArray;
-pub use crate::linalg_traits::LinalgScalar;
 #[cfg(feature = "std")]
 pub use crate::linalg_traits::NdFloat;
+pub use crate::linalg_traits::{LinalgScalar, NdInteger};
 
 #[allow(deprecated)] // stack_new_axis
 pub use crate::stacking::{concatenate, stack, stack_new_axis};
@@ -169,6 +187,7 @@ mod argument_traits;
 #[cfg(feature = "serde")]
 mod array_serde;
 mod arrayformat;
+pub use crate::arrayformat::FormatOptions;
 mod arraytraits;
 pub use crate::argument_traits::AssignElem;
 mod data_repr;
@@ -176,7 +195,18 @@ mod data_traits;
 
 pub use crate::aliases::*;
 
-pub use crate::data_traits::{Data, DataMut, DataOwned, DataShared, RawData, RawDataClone, RawDataMut, RawDataSubst};
+pub use crate::data_traits::{
+    Data,
+    DataMut,
+    DataOwned,
+    DataShared,
+    ExternalStorage,
+    ExternalStorageMut,
+    RawData,
+    RawDataClone,
+    RawDataMut,
+    RawDataSubst,
+};
 
 mod free_functions;
 pub use crate::free_functions::*;
@@ -210,7 +240,7 @@ mod zip;
 
 mod dimension;
 
-pub use crate::zip::{FoldWhile, IntoNdProducer, NdProducer, Zip};
+pub use crate::zip::{broadcast_scalar, FoldWhile, IndexedProducer, IntoNdProducer, NdProducer, ScalarProducer, Zip};
 
 pub use crate::layout::Layout;
 
@@ -425,6 +455,12 @@ pub type Ixs = isize;
 /// sequence in the logical order of the array, that means that the elements
 /// will be visited in the sequence that corresponds to increasing the
 /// last index first: *0, ..., 0,  0*; *0, ..., 0, 1*; *0, ...0, 2* and so on.
+/// The order does not depend on the memory layout of the array, so two
+/// arrays with equal elements are always iterated the same way.
+///
+/// [`Zip`], and methods like [`.fold()`](ArrayBase::fold) or
+/// [`.map_inplace()`](ArrayBase::map_inplace), may visit the elements in
+/// another order, for example in memory order, to be faster.
 ///
 /// ### `.outer_iter()` and `.axis_iter()`
 ///
@@ -484,6 +520,25 @@ pub type Ixs = isize;
 /// [l]: Self::lanes
 /// [lm]: Self::lanes_mut
 ///
+/// The lanes producers [`Lanes`](iter::Lanes) and
+/// [`LanesMut`](iter::LanesMut) also work like collections of lanes
+/// indexed by the remaining axes, with `.len()`, `.get(index)` and
+/// `.split_at(axis, index)`. Iterating them visits the lanes in logical
+/// order too.
+///
+/// ```
+/// use ndarray::{arr2, aview1};
+///
+/// let a = arr2(&[[1, 2], [3, 4], [5, 6]]);
+/// let rows = a.rows();
+/// assert_eq!(rows.len(), 3);
+/// assert_eq!(rows.get(1), Some(aview1(&[3, 4])));
+///
+/// let (first, rest) = rows.split_at(ndarray::Axis(0), 1);
+/// assert_eq!(first.len(), 1);
+/// assert_eq!(rest.into_iter().last(), Some(aview1(&[5, 6])));
+/// ```
+///
 /// Yes, for 2D arrays `.rows()` and `.outer_iter()` have about the same
 /// effect:
 ///
@@ -1039,7 +1094,9 @@ pub type Ixs = isize;
 ///
 /// Input | Output | Methods
 /// ------|--------|--------
-/// `Vec<A>` | `ArrayBase<S: DataOwned, Ix1>` | [`::from_vec()`](Self::from_vec)
+/// `Vec<A>` | `ArrayBase<S: DataOwned, Ix1>` | [`::from_vec()`](Self::from_vec), [`::from()`](Array#method.from)<sup>[4](#no_copy)</sup>
+/// `Box<[A]>` | `ArrayBase<S: DataOwned, Ix1>` | [`::from()`](Array#method.from)<sup>[4](#no_copy)</sup>
+/// `[A; N]` | `ArrayBase<S: DataOwned, Ix1>` | [`::from()`](Array#method.from)
 /// `Vec<A>` | `ArrayBase<S: DataOwned, D>` | [`::from_shape_vec()`](Self::from_shape_vec)
 /// `&[A]` | `ArrayView1<A>` | [`::from()`](ArrayView#method.from)
 /// `&[A]` | `ArrayView<A, D>` | [`::from_shape()`](ArrayView#method.from_shape)
@@ -1047,6 +1104,7 @@ pub type Ixs = isize;
 /// `&mut [A]` | `ArrayViewMut<A, D>` | [`::from_shape()`](ArrayViewMut#method.from_shape)
 /// `&ArrayBase<S, Ix1>` | `Vec<A>` | [`.to_vec()`](Self::to_vec)
 /// `Array<A, D>` | `Vec<A>` | [`.into_raw_vec()`](Array#method.into_raw_vec)<sup>[1](#into_raw_vec)</sup>
+/// `Array1<A>` | `Vec<A>` | `Vec::from()`<sup>[5](#vec_from_array1)</sup>
 /// `&ArrayBase<S, D>` | `&[A]` | [`.as_slice()`](Self::as_slice)<sup>[2](#req_contig_std)</sup>, [`.as_slice_memory_order()`](Self::as_slice_memory_order)<sup>[3](#req_contig)</sup>
 /// `&mut ArrayBase<S: DataMut, D>` | `&mut [A]` | [`.as_slice_mut()`](Self::as_slice_mut)<sup>[2](#req_contig_std)</sup>, [`.as_slice_memory_order_mut()`](Self::as_slice_memory_order_mut)<sup>[3](#req_contig)</sup>
 /// `ArrayView<A, D>` | `&[A]` | [`.to_slice()`](ArrayView#method.to_slice)<sup>[2](#req_contig_std)</sup>
@@ -1060,6 +1118,12 @@ pub type Ixs = isize;
 ///
 /// <sup><a name="req_contig">3</a></sup>Works only if the array is contiguous.
 ///
+/// <sup><a name="no_copy">4</a></sup>Reuses the allocation; the elements are
+/// not copied.
+///
+/// <sup><a name="vec_from_array1">5</a></sup>Returns the data in logical
+/// order, and reuses the allocation if the array is in standard layout.
+///
 /// The table above does not include all the constructors; it only shows
 /// conversions to/from `Vec`s/slices. See
 /// [below](#constructor-methods-for-owned-arrays) for more constructors.
@@ -1431,6 +1495,19 @@ pub type RawArrayView<A, D> = ArrayBase<RawViewRepr<*const A>, D>;
 /// [`from_shape_ptr`](#method.from_shape_ptr) for details.
 pub type RawArrayViewMut<A, D> = ArrayBase<RawViewRepr<*mut A>, D>;
 
+/// An array backed by element storage defined outside of ndarray, such as a
+/// memory-mapped file.
+///
+/// The storage type `S` implements [`ExternalStorage`]; see its docs for
+/// the contract an implementation must uphold. Create the array with
+/// [`ExternalArray::from_storage`].
+///
+/// An `ExternalArray` has all the read-only methods of an array (see
+/// [`ArrayBase`]), and the methods that write to elements too if `S`
+/// implements [`ExternalStorageMut`]. It can be cloned if `S` implements
+/// `Clone`.
+pub type ExternalArray<S, D> = ArrayBase<ExternalRepr<S>, D>;
+
 pub use data_repr::OwnedRepr;
 
 /// ArcArray's representation.
@@ -1521,6 +1598,27 @@ impl<'a, A> CowRepr<'a, A>
     }
 }
 
+/// ExternalArray's representation.
+///
+/// *Don't use this type directly—use the type alias
+/// [`ExternalArray`] for the array type!*
+#[derive(Clone, Debug)]
+pub struct ExternalRepr<S>(S);
+
+impl<S> ExternalRepr<S>
+where S: ExternalStorage
+{
+    fn as_nonnull(&self) -> std::ptr::NonNull<S::Elem>
+    {
+        self.0.as_ptr()
+    }
+
+    fn as_slice(&self) -> &[S::Elem]
+    {
+        unsafe { std::slice::from_raw_parts(self.0.as_ptr().as_ptr(), self.0.len()) }
+    }
+}
+
 // NOTE: The order of modules decides in which order methods on the type ArrayBase
 // (mainly mentioning that as the most relevant type) show up in the documentation.
 // Consider the doc effect of ordering modules here.
@@ -1540,26 +1638,13 @@ where
     D: Dimension,
 {
     #[inline]
-    fn broadcast_unwrap<E>(&self, dim: E) -> ArrayView<'_, A, E>
+    #[track_caller]
+    fn broadcast_unwrap<E>(&self, dim: E, op: &str) -> ArrayView<'_, A, E>
     where E: Dimension
     {
-        #[cold]
-        #[inline(never)]
-        fn broadcast_panic<D, E>(from: &D, to: &E) -> !
-        where
-            D: Dimension,
-            E: Dimension,
-        {
-            panic!(
-                "ndarray: could not broadcast array from shape: {:?} to: {:?}",
-                from.slice(),
-                to.slice()
-            )
-        }
-
         match self.broadcast(dim.clone()) {
             Some(it) => it,
-            None => broadcast_panic(&self.dim, &dim),
+            None => error::shape_mismatch_panic(error::ShapeMismatch::BroadcastTo, self.shape(), dim.slice(), op),
         }
     }
 
@@ -1598,6 +1683,8 @@ mod impl_2d;
 mod impl_dyn;
 
 mod numeric;
+#[cfg(feature = "std")]
+pub use crate::numeric::RunningStats;
 
 pub mod linalg;
 
@@ -1607,6 +1694,18 @@ pub use crate::impl_ops::ScalarOperand;
 #[cfg(feature = "approx")]
 mod array_approx;
 
+#[doc(hidden)]
+pub mod array_assert;
+
+#[cfg(feature = "bytemuck")]
+mod array_bytemuck;
+
+#[cfg(feature = "nalgebra")]
+mod array_nalgebra;
+
+#[cfg(feature = "quickcheck")]
+mod array_quickcheck;
+
 // Array view methods
 mod impl_views;
 
@@ -1616,6 +1715,16 @@ mod impl_raw_views;
 // Copy-on-write array methods
 mod impl_cow;
 
+// Shared array methods
+mod impl_arc_array;
+
+// Methods for arrays with external storage
+mod impl_external;
+
+// Small arrays with inline storage
+mod inline_array;
+pub use crate::inline_array::InlineArray;
+
 /// Returns `true` if the pointer is aligned.
 pub(crate) fn is_aligned<T>(ptr: *const T) -> bool
 {
@@ -0,0 +1,123 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::mem::MaybeUninit;
+
+use crate::dimension::DimMax;
+use crate::error::{shape_mismatch_panic, ShapeMismatch};
+use crate::imp_prelude::*;
+use crate::{LinalgScalar, Slice, Zip};
+
+/// Cross product of two vectors of length 3.
+///
+/// **Panics** if `a` or `b` does not have length 3.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::cross;
+///
+/// let x = array![1., 0., 0.];
+/// let y = array![0., 1., 0.];
+/// assert_eq!(cross(&x, &y), array![0., 0., 1.]);
+/// ```
+#[track_caller]
+pub fn cross<A, S1, S2>(a: &ArrayBase<S1, Ix1>, b: &ArrayBase<S2, Ix1>) -> Array<A, Ix1>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    assert!(
+        a.len() == 3 && b.len() == 3,
+        "ndarray: cross product of vectors of lengths {} and {}, but both must have length 3",
+        a.len(),
+        b.len()
+    );
+    let (a0, a1, a2) = (a[0], a[1], a[2]);
+    let (b0, b1, b2) = (b[0], b[1], b[2]);
+    Array::from([a1 * b2 - a2 * b1, a2 * b0 - a0 * b2, a0 * b1 - a1 * b0])
+}
+
+/// Cross products of the lanes of length 3 along `axis` of `a` and `b`.
+///
+/// The arrays are broadcast to a common shape, like for the arithmetic
+/// operators, and `axis` is an axis of that shape. Each operand must have
+/// the vectors along this axis, with length 3: only the other axes can be
+/// broadcast. The result has the broadcast shape, and its lanes along `axis`
+/// are the cross products of the lanes of `a` and `b`.
+///
+/// The products are computed component by component, in lock step over the
+/// subviews of the arrays at each index along `axis`.
+///
+/// **Panics** if `axis` is out of bounds, if the shapes of `a` and `b` can
+/// not be broadcast together, or if one of them does not have length 3 along
+/// `axis`.
+///
+/// ```
+/// use ndarray::{array, Axis};
+/// use ndarray::linalg::cross_axis;
+///
+/// // two vectors as the columns of `a`, crossed with the same vector
+/// let a = array![[1., 0.],
+///                [0., 1.],
+///                [0., 0.]];
+/// let b = array![[0.], [0.], [1.]];
+/// assert_eq!(cross_axis(&a, &b, Axis(0)), array![[0., 1.], [-1., 0.], [0., 0.]]);
+/// ```
+#[track_caller]
+pub fn cross_axis<A, S1, S2, D, E>(
+    a: &ArrayBase<S1, D>, b: &ArrayBase<S2, E>, axis: Axis,
+) -> Array<A, <D as DimMax<E>>::Output>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    A: LinalgScalar,
+{
+    let (av, bv) = match a.broadcast_with(b) {
+        Ok(views) => views,
+        Err(_) => shape_mismatch_panic(ShapeMismatch::CoBroadcast, a.shape(), b.shape(), "cross_axis"),
+    };
+    let ndim = av.ndim();
+    assert!(
+        axis.index() < ndim,
+        "ndarray: cross_axis: axis {} is out of bounds for the broadcast shape {:?}",
+        axis.index(),
+        av.shape()
+    );
+    for shape in [a.shape(), b.shape()] {
+        // the axes of an operand are aligned with the trailing axes of the
+        // broadcast shape
+        let len = (axis.index() + shape.len())
+            .checked_sub(ndim)
+            .map_or(1, |ax| shape[ax]);
+        assert!(
+            len == 3,
+            "ndarray: cross_axis needs length 3 along axis {}, but an operand of shape {:?} has length {}",
+            axis.index(),
+            shape,
+            len
+        );
+    }
+
+    let mut out = Array::uninit(av.raw_dim());
+    let part = |i: usize| Slice::from(i..i + 1);
+    // component i of the product is a[j] * b[k] - a[k] * b[j]
+    for &(i, j, k) in &[(0, 1, 2), (1, 2, 0), (2, 0, 1)] {
+        Zip::from(out.slice_axis_mut(axis, part(i)))
+            .and(av.slice_axis(axis, part(j)))
+            .and(bv.slice_axis(axis, part(k)))
+            .and(av.slice_axis(axis, part(k)))
+            .and(bv.slice_axis(axis, part(j)))
+            .for_each(|out, &aj, &bk, &ak, &bj| {
+                *out = MaybeUninit::new(aj * bk - ak * bj);
+            });
+    }
+    unsafe { out.assume_init() }
+}
@@ -8,8 +8,10 @@
 
 use crate::imp_prelude::*;
 
-#[cfg(feature = "blas")]
+use crate::error::{self, shape_mismatch_panic, ErrorKind, ShapeError, ShapeMismatch};
+
 use crate::dimension::offset_from_low_addr_ptr_to_logical_ptr;
+use crate::dimension::size_of_shape_checked;
 use crate::numeric_util;
 
 use crate::{LinalgScalar, Zip};
@@ -17,10 +19,13 @@ use crate::{LinalgScalar, Zip};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use std::any::TypeId;
-use std::mem::MaybeUninit;
+use std::mem::{self, MaybeUninit};
+use std::ops::Range;
 
 use num_complex::Complex;
 use num_complex::{Complex32 as c32, Complex64 as c64};
+use num_traits::{Num, Zero};
+use std::ops::Neg;
 
 #[cfg(feature = "blas")]
 use libc::c_int;
@@ -83,13 +88,15 @@ where S: Data<Elem = A>
                 return numeric_util::unrolled_dot(self_s, rhs_s);
             }
         }
-        let mut sum = A::zero();
-        for i in 0..self.len() {
-            unsafe {
-                sum = sum + *self.uget(i) * *rhs.uget(i);
-            }
+        unsafe {
+            numeric_util::unrolled_dot_strided(
+                self.as_ptr(),
+                self.strides()[0],
+                rhs.as_ptr(),
+                rhs.strides()[0],
+                self.len(),
+            )
         }
-        sum
     }
 
     #[cfg(not(feature = "blas"))]
@@ -139,6 +146,103 @@ where S: Data<Elem = A>
     }
 }
 
+impl<T, S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = Complex<T>>,
+    T: Clone + Num + Neg<Output = T>,
+{
+    /// Compute the dot product of the complex conjugate of `self` and `rhs`,
+    /// like NumPy's `vdot`.
+    ///
+    /// This is the inner product of `rhs` and `self`, in contrast with
+    /// [`.dot()`](Self::dot), which does not conjugate its operands. So
+    /// `a.vdot(&a)` is the squared norm of `a`, and
+    /// `a.vdot(&b)` is the complex conjugate of `b.vdot(&a)`.
+    ///
+    /// **Panics** if the arrays are not of the same length.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(0., -1.)];
+    /// let b = array![Complex64::new(3., 0.), Complex64::new(1., 1.)];
+    /// assert_eq!(a.vdot(&b), Complex64::new(2., -5.));
+    /// assert_eq!(a.vdot(&a), Complex64::new(6., 0.));
+    /// ```
+    #[track_caller]
+    pub fn vdot<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> Complex<T>
+    where S2: Data<Elem = Complex<T>>
+    {
+        assert!(
+            self.len() == rhs.len(),
+            "ndarray: vdot of arrays of different lengths {} and {}",
+            self.len(),
+            rhs.len()
+        );
+        Zip::from(self)
+            .and(rhs)
+            .fold(Complex::zero(), |acc, a, b| acc + a.conj() * b.clone())
+    }
+}
+
+impl<T, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = Complex<T>>,
+    T: Clone + Num + Neg<Output = T>,
+    D: Dimension,
+{
+    /// Return an array of the complex conjugates of the elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![[Complex64::new(1., 2.)], [Complex64::new(-3., -4.)]];
+    /// assert_eq!(a.conj(), array![[Complex64::new(1., -2.)], [Complex64::new(-3., 4.)]]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn conj(&self) -> Array<Complex<T>, D>
+    {
+        self.map(Complex::conj)
+    }
+}
+
+impl<T, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = Complex<T>>,
+    T: Clone + Num + Neg<Output = T> + PartialOrd,
+{
+    /// Return true if the array is square and equal to its conjugate
+    /// transpose, with an absolute difference of at most `tol` between each
+    /// element and the conjugate of its mirrored element.
+    ///
+    /// Rectangular arrays are never Hermitian. The conjugate transpose is not
+    /// computed, the elements are compared through a transposed view.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![[Complex64::new(2., 0.), Complex64::new(1., -1.)],
+    ///                [Complex64::new(1., 1.), Complex64::new(3., 0.)]];
+    /// assert!(a.is_hermitian(1e-12));
+    ///
+    /// // symmetric, but not Hermitian
+    /// let b = array![[Complex64::new(2., 0.), Complex64::new(1., 1.)],
+    ///                [Complex64::new(1., 1.), Complex64::new(3., 0.)]];
+    /// assert!(!b.is_hermitian(1e-12));
+    /// ```
+    pub fn is_hermitian(&self, tol: T) -> bool
+    {
+        let tol_sqr = tol.clone() * tol;
+        self.is_square()
+            && Zip::from(self)
+                .and(self.t())
+                .all(|a, b| (a.clone() - b.conj()).norm_sqr() <= tol_sqr)
+    }
+}
+
 /// Return a pointer to the starting element in BLAS's view.
 ///
 /// BLAS wants a pointer to the element with lowest address,
@@ -191,6 +295,9 @@ where
     #[track_caller]
     fn dot(&self, rhs: &ArrayBase<S2, Ix1>) -> A
     {
+        if self.len() != rhs.len() {
+            dot_shape_error(self.shape(), rhs.shape());
+        }
         self.dot_impl(rhs)
     }
 }
@@ -215,6 +322,9 @@ where
     #[track_caller]
     fn dot(&self, rhs: &ArrayBase<S2, Ix2>) -> Array<A, Ix1>
     {
+        if self.len() != rhs.nrows() {
+            dot_shape_error(self.shape(), rhs.shape());
+        }
         rhs.t().dot(self)
     }
 }
@@ -235,8 +345,9 @@ where S: Data<Elem = A>
     /// result would overflow `isize`.
     ///
     /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64`
-    /// when memory layout allows. The default matrixmultiply backend
-    /// is otherwise used for `f32, f64` for all memory layouts.
+    /// (and `gemm` for `Complex<f32>, Complex<f64>`) when memory layout
+    /// allows. The default matrixmultiply backend is otherwise used for
+    /// `f32, f64, Complex<f32>, Complex<f64>` for all memory layouts.
     ///
     /// ```
     /// use ndarray::arr2;
@@ -259,6 +370,69 @@ where S: Data<Elem = A>
     }
 }
 
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Perform the matrix or vector product of `self` and `rhs` like
+    /// [`.dot()`](Self::dot), writing the result into `out` instead of a new
+    /// array.
+    ///
+    /// The products of a vector and a matrix, a matrix and a vector, and two
+    /// matrices are supported; `out` must have the shape of the result, and
+    /// may have any strides. Its previous contents are overwritten.
+    ///
+    /// **Panics** if the shapes are incompatible, or if `out` shares memory
+    /// with `self` or `rhs`.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = array![[1., 2.], [0., 1.]];
+    /// let b = array![[1., 2.], [2., 3.]];
+    /// let mut c = Array2::zeros((2, 2));
+    ///
+    /// a.dot_into(&b, &mut c);
+    /// assert_eq!(c, a.dot(&b));
+    ///
+    /// let mut y = array![0., 0.];
+    /// a.dot_into(&array![1., 1.], &mut y);
+    /// assert_eq!(y, array![3., 1.]);
+    /// ```
+    #[track_caller]
+    pub fn dot_into<Rhs, Out>(&self, rhs: &Rhs, out: &mut Out)
+    where Self: DotInto<Rhs, Out>
+    {
+        if let Err(e) = DotInto::try_dot_into(self, rhs, out) {
+            panic!("ndarray: {}", e);
+        }
+    }
+
+    /// Perform the matrix or vector product of `self` and `rhs` into `out`
+    /// like [`.dot_into()`](Self::dot_into), returning an error instead of
+    /// panicking.
+    ///
+    /// **Errors** with `ErrorKind::IncompatibleShape` if the shapes are
+    /// incompatible, or `ErrorKind::Unsupported` if `out` shares memory with
+    /// `self` or `rhs`; `out` is left unchanged in both cases.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1, ErrorKind};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let mut y = Array1::zeros(3);
+    ///
+    /// let err = a.try_dot_into(&array![1, 1, 1], &mut y).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    /// ```
+    pub fn try_dot_into<Rhs, Out>(&self, rhs: &Rhs, out: &mut Out) -> Result<(), ShapeError>
+    where Self: DotInto<Rhs, Out>
+    {
+        DotInto::try_dot_into(self, rhs, out)
+    }
+}
+
 impl<A, S, S2> Dot<ArrayBase<S2, Ix2>> for ArrayBase<S, Ix2>
 where
     S: Data<Elem = A>,
@@ -272,7 +446,7 @@ where
         let b = b.view();
         let ((m, k), (k2, n)) = (a.dim(), b.dim());
         if k != k2 || m.checked_mul(n).is_none() {
-            dot_shape_error(m, k, k2, n);
+            dot_shape_error(a.shape(), b.shape());
         }
 
         let lhs_s0 = a.strides()[0];
@@ -290,23 +464,24 @@ where
     }
 }
 
-/// Assumes that `m` and `n` are ≤ `isize::MAX`.
+/// Assumes that the axis lengths are ≤ `isize::MAX`.
 #[cold]
 #[inline(never)]
-fn dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> !
+#[track_caller]
+fn dot_shape_error(a: &[Ix], b: &[Ix]) -> !
 {
-    match m.checked_mul(n) {
-        Some(len) if len <= isize::MAX as usize => {}
-        _ => panic!("ndarray: shape {} × {} overflows isize", m, n),
+    if let ([m, _], [_, n]) = (a, b) {
+        match m.checked_mul(*n) {
+            Some(len) if len <= isize::MAX as usize => {}
+            _ => panic!("ndarray: shape {} × {} overflows isize", m, n),
+        }
     }
-    panic!(
-        "ndarray: inputs {} × {} and {} × {} are not compatible for matrix multiplication",
-        m, k, k2, n
-    );
+    shape_mismatch_panic(ShapeMismatch::Dot, a, b, "dot")
 }
 
 #[cold]
 #[inline(never)]
+#[track_caller]
 fn general_dot_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> !
 {
     panic!("ndarray: inputs {} × {}, {} × {}, and output {} × {} are not compatible for matrix multiplication",
@@ -334,7 +509,7 @@ where
     {
         let ((m, a), n) = (self.dim(), rhs.dim());
         if a != n {
-            dot_shape_error(m, a, n, 1);
+            dot_shape_error(self.shape(), rhs.shape());
         }
 
         // Avoid initializing the memory in vec -- set it during iteration
@@ -346,6 +521,111 @@ where
     }
 }
 
+/// Matrix and vector products written into an existing array, see
+/// [`.dot_into()`](ArrayBase::dot_into).
+pub trait DotInto<Rhs, Out: ?Sized>
+{
+    /// Compute the product of `self` and `rhs` into `out`, overwriting its
+    /// elements.
+    fn try_dot_into(&self, rhs: &Rhs, out: &mut Out) -> Result<(), ShapeError>;
+}
+
+impl<A, S, S2, S3> DotInto<ArrayBase<S2, Ix2>, ArrayBase<S3, Ix1>> for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    fn try_dot_into(&self, rhs: &ArrayBase<S2, Ix2>, out: &mut ArrayBase<S3, Ix1>) -> Result<(), ShapeError>
+    {
+        if self.len() != rhs.nrows() {
+            return Err(error::incompatible_shapes(&self.raw_dim(), &rhs.raw_dim()).in_operation("dot_into"));
+        }
+        check_dot_output(self, rhs, out, Ix1(rhs.ncols()))?;
+        // Safe because `out` is valid for writing and initialized
+        unsafe { general_mat_vec_mul_impl(A::one(), &rhs.t(), self, A::zero(), out.raw_view_mut()) }
+        Ok(())
+    }
+}
+
+impl<A, S, S2, S3> DotInto<ArrayBase<S2, Ix1>, ArrayBase<S3, Ix1>> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    fn try_dot_into(&self, rhs: &ArrayBase<S2, Ix1>, out: &mut ArrayBase<S3, Ix1>) -> Result<(), ShapeError>
+    {
+        if self.ncols() != rhs.len() {
+            return Err(error::incompatible_shapes(&self.raw_dim(), &rhs.raw_dim()).in_operation("dot_into"));
+        }
+        check_dot_output(self, rhs, out, Ix1(self.nrows()))?;
+        // Safe because `out` is valid for writing and initialized
+        unsafe { general_mat_vec_mul_impl(A::one(), self, rhs, A::zero(), out.raw_view_mut()) }
+        Ok(())
+    }
+}
+
+impl<A, S, S2, S3> DotInto<ArrayBase<S2, Ix2>, ArrayBase<S3, Ix2>> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    fn try_dot_into(&self, rhs: &ArrayBase<S2, Ix2>, out: &mut ArrayBase<S3, Ix2>) -> Result<(), ShapeError>
+    {
+        if self.ncols() != rhs.nrows() {
+            return Err(error::incompatible_shapes(&self.raw_dim(), &rhs.raw_dim()).in_operation("dot_into"));
+        }
+        check_dot_output(self, rhs, out, Ix2(self.nrows(), rhs.ncols()))?;
+        mat_mul_impl(A::one(), &self.view(), &rhs.view(), A::zero(), &mut out.view_mut());
+        Ok(())
+    }
+}
+
+/// Check that `out` has shape `dim` and shares no memory with `a` or `b`.
+fn check_dot_output<A, S1, S2, S3, D1, D2, D3>(
+    a: &ArrayBase<S1, D1>, b: &ArrayBase<S2, D2>, out: &ArrayBase<S3, D3>, dim: D3,
+) -> Result<(), ShapeError>
+where
+    S1: RawData<Elem = A>,
+    S2: RawData<Elem = A>,
+    S3: RawData<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+    D3: Dimension,
+{
+    if out.raw_dim() != dim {
+        return Err(error::incompatible_shapes(&dim, &out.raw_dim()).in_operation("dot_into"));
+    }
+    let out_range = memory_range(out);
+    let overlaps = |r: Range<usize>| r.start < out_range.end && out_range.start < r.end;
+    if overlaps(memory_range(a)) || overlaps(memory_range(b)) {
+        return Err(error::from_kind(ErrorKind::Unsupported).in_operation("dot_into"));
+    }
+    Ok(())
+}
+
+/// Return the range of addresses spanned by the elements of `a`; empty if
+/// `a` has no elements.
+fn memory_range<S, D>(a: &ArrayBase<S, D>) -> Range<usize>
+where
+    S: RawData,
+    D: Dimension,
+{
+    if a.is_empty() {
+        return 0..0;
+    }
+    let elem_size = mem::size_of::<S::Elem>();
+    let low_offset = offset_from_low_addr_ptr_to_logical_ptr(&a.dim, &a.strides);
+    let span = izip!(a.shape(), a.strides()).fold(0, |span, (&d, &s)| span + (d - 1) * s.unsigned_abs());
+    let start = a.as_ptr() as usize - low_offset * elem_size;
+    start..start + (span + 1) * elem_size
+}
+
 impl<A, S, D> ArrayBase<S, D>
 where
     S: Data<Elem = A>,
@@ -625,9 +905,10 @@ fn mat_mul_general<A>(
 /// if `a` is *M* × *N*, then `b` is *N* × *K* and `c` is *M* × *K*.
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` when memory
-/// layout allows.  The default matrixmultiply backend is otherwise used for
-/// `f32, f64` for all memory layouts.
+/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64`,
+/// `Complex<f32>, Complex<f64>` when memory layout allows.  The default
+/// matrixmultiply backend is otherwise used for these types for all memory
+/// layouts.
 #[track_caller]
 pub fn general_mat_mul<A, S1, S2, S3>(
     alpha: A, a: &ArrayBase<S1, Ix2>, b: &ArrayBase<S2, Ix2>, beta: A, c: &mut ArrayBase<S3, Ix2>,
@@ -646,6 +927,122 @@ pub fn general_mat_mul<A, S1, S2, S3>(
     }
 }
 
+/// Batched matrix multiplication.
+///
+/// Compute C\[b\] = A\[b\] B\[b\] for each index *b* along the first (batch)
+/// axis, where `a` is *batch* × *M* × *K* and `b` is *batch* × *K* × *N*.
+/// Return a result array with shape *batch* × *M* × *N*.
+///
+/// If one of the operands has a batch axis of length 1, its single matrix is
+/// used for every index of the other operand's batch axis.
+///
+/// Each product is computed like [`.dot()`](ArrayBase::dot) of two 2D arrays,
+/// with the same matrix multiplication kernels (and BLAS, if enabled).
+///
+/// **Panics** if the batch axes have different lengths and neither of them is
+/// 1, if the inner dimensions *K* do not match, or if the number of elements
+/// in the result would overflow `isize`.
+///
+/// ```
+/// use ndarray::{array, Array3};
+/// use ndarray::linalg::batch_mat_mul;
+///
+/// let a = Array3::from_shape_vec((2, 2, 2), vec![1., 0., 0., 1., 2., 0., 0., 2.]).unwrap();
+/// let b = array![[[1., 2.], [3., 4.]]];
+///
+/// let c = batch_mat_mul(&a, &b);
+/// assert_eq!(c, array![[[1., 2.], [3., 4.]], [[2., 4.], [6., 8.]]]);
+/// ```
+#[track_caller]
+pub fn batch_mat_mul<A, S1, S2>(a: &ArrayBase<S1, Ix3>, b: &ArrayBase<S2, Ix3>) -> Array3<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    let (a, b, mut c) = batch_mat_mul_operands(a, b);
+    Zip::from(c.outer_iter_mut())
+        .and(a.outer_iter())
+        .and(b.outer_iter())
+        .for_each(|mut c, a, b| mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c));
+    c
+}
+
+/// Batched matrix multiplication, in parallel over the batch axis.
+///
+/// This is the same operation as [`batch_mat_mul`], with the products of the
+/// matrices at different batch indices computed in parallel.
+///
+/// **Panics** under the same conditions as [`batch_mat_mul`].
+///
+/// **Requires crate feature `"rayon"`**
+#[cfg(feature = "rayon")]
+#[track_caller]
+pub fn par_batch_mat_mul<A, S1, S2>(a: &ArrayBase<S1, Ix3>, b: &ArrayBase<S2, Ix3>) -> Array3<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar + Send + Sync,
+{
+    let (a, b, mut c) = batch_mat_mul_operands(a, b);
+    Zip::from(c.outer_iter_mut())
+        .and(a.outer_iter())
+        .and(b.outer_iter())
+        .par_for_each(|mut c, a, b| mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c));
+    c
+}
+
+/// Check the shapes of the operands of a batched matrix multiplication, and
+/// return them broadcast to the common batch length, with the (uninitialized)
+/// result array.
+#[track_caller]
+fn batch_mat_mul_operands<'a, 'b, A, S1, S2>(
+    a: &'a ArrayBase<S1, Ix3>, b: &'b ArrayBase<S2, Ix3>,
+) -> (ArrayView3<'a, A>, ArrayView3<'b, A>, Array3<A>)
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    let ((batch_a, m, k), (batch_b, k2, n)) = (a.dim(), b.dim());
+    let batch = match (batch_a, batch_b) {
+        _ if batch_a == batch_b => batch_a,
+        (1, _) => batch_b,
+        (_, 1) => batch_a,
+        _ => batch_dot_shape_error(a.shape(), b.shape()),
+    };
+    if k != k2 {
+        batch_dot_shape_error(a.shape(), b.shape());
+    }
+    let size = match size_of_shape_checked(&Dim([batch, m, n])) {
+        Ok(size) => size,
+        Err(_) => panic!("ndarray: batch_mat_mul: shape {} × {} × {} overflows isize", batch, m, n),
+    };
+    let a = a.broadcast((batch, m, k)).unwrap();
+    let b = b.broadcast((batch, k2, n)).unwrap();
+
+    // A is Copy so this is safe
+    let mut v = Vec::with_capacity(size);
+    let c;
+    unsafe {
+        v.set_len(size);
+        c = Array::from_shape_vec_unchecked((batch, m, n), v);
+    }
+    (a, b, c)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn batch_dot_shape_error(a: &[Ix], b: &[Ix]) -> !
+{
+    panic!(
+        "ndarray: batch_mat_mul: inputs of shape {:?} and {:?} are not compatible; \
+         expected (batch, M, K) and (batch, K, N), where either batch length may be 1",
+        a, b
+    );
+}
+
 /// General matrix-vector multiplication.
 ///
 /// Compute y ← α A x + β y
@@ -669,6 +1066,49 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
     unsafe { general_mat_vec_mul_impl(alpha, a, x, beta, y.raw_view_mut()) }
 }
 
+/// General matrix-vector multiplication, returning an error if the array
+/// shapes are not compatible.
+///
+/// Compute y ← α A x + β y
+///
+/// where A is a *M* × *N* matrix and x is an *N*-element column vector and
+/// y an *M*-element column vector (one dimensional arrays).
+///
+/// **Errors** with `ErrorKind::IncompatibleShape` if array shapes are not
+/// compatible, in which case `y` is left unchanged.
+///
+/// ```
+/// use ndarray::{array, ErrorKind};
+/// use ndarray::linalg::try_general_mat_vec_mul;
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let mut y = array![1., 1.];
+/// try_general_mat_vec_mul(2., &a, &array![1., 0.], 1., &mut y).unwrap();
+/// assert_eq!(y, array![3., 7.]);
+///
+/// let err = try_general_mat_vec_mul(1., &a, &array![1.], 0., &mut y).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+/// ```
+pub fn try_general_mat_vec_mul<A, S1, S2, S3>(
+    alpha: A, a: &ArrayBase<S1, Ix2>, x: &ArrayBase<S2, Ix1>, beta: A, y: &mut ArrayBase<S3, Ix1>,
+) -> Result<(), ShapeError>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let ((m, k), k2) = (a.dim(), x.dim());
+    if k != k2 {
+        return Err(error::incompatible_shapes(&a.raw_dim(), &x.raw_dim()).in_operation("general_mat_vec_mul"));
+    }
+    if m != y.len() {
+        return Err(error::incompatible_shapes(&a.raw_dim(), &y.raw_dim()).in_operation("general_mat_vec_mul"));
+    }
+    unsafe { general_mat_vec_mul_impl(alpha, a, x, beta, y.raw_view_mut()) }
+    Ok(())
+}
+
 /// General matrix-vector multiplication
 ///
 /// Use a raw view for the destination vector, so that it can be uninitialized.
@@ -744,7 +1184,29 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
 
         /* general */
 
-        if beta.is_zero() {
+        let (row_stride, col_stride) = (a.strides()[0], a.strides()[1]);
+        if row_stride == 1 && col_stride != 1 && m > 1 && k > 1 {
+            // column major: accumulate alpha x[j] A[:, j] into y one contiguous column at a time,
+            // so that every element of `a` is read in memory order.
+            if beta.is_zero() {
+                // when beta is zero, c may be uninitialized
+                Zip::from(y).for_each(|elt| {
+                    elt.write(A::zero());
+                });
+            } else {
+                Zip::from(y).for_each(|elt| {
+                    *elt = *elt * beta;
+                });
+            }
+            for (column, &x_j) in a.columns().into_iter().zip(x) {
+                let scale = alpha * x_j;
+                Zip::from(y).and(column).for_each(|elt, &a_ij| {
+                    *elt = *elt + a_ij * scale;
+                });
+            }
+        } else if beta.is_zero() {
+            // row major or arbitrary strides: one dot product per row, using the unrolled
+            // contiguous or strided dot product kernel.
             // when beta is zero, c may be uninitialized
             Zip::from(a.outer_iter()).and(y).for_each(|row, elt| {
                 elt.write(row.dot(x) * alpha);
@@ -767,10 +1229,8 @@ where
     S2: Data<Elem = A>,
     A: LinalgScalar,
 {
-    let dimar = a.shape()[0];
-    let dimac = a.shape()[1];
-    let dimbr = b.shape()[0];
-    let dimbc = b.shape()[1];
+    let (dimar, dimac) = a.dim();
+    let (dimbr, dimbc) = b.dim();
     let mut out: Array2<MaybeUninit<A>> = Array2::uninit((
         dimar
             .checked_mul(dimbr)
@@ -789,6 +1249,73 @@ where
     unsafe { out.assume_init() }
 }
 
+/// Outer product of two vectors.
+///
+/// The outer product of a vector `x` of length M and a vector `y` of length N
+/// is the M × N matrix with elements `x[i] * y[j]`. It is computed as a
+/// broadcast multiplication of `x` as a column and `y` as a row.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::outer;
+///
+/// let x = array![1, 2, 3];
+/// let y = array![1, 10];
+/// assert_eq!(outer(&x, &y), array![[1, 10], [2, 20], [3, 30]]);
+/// ```
+pub fn outer<A, S1, S2>(x: &ArrayBase<S1, Ix1>, y: &ArrayBase<S2, Ix1>) -> Array<A, Ix2>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    &x.view().insert_axis(Axis(1)) * &y.view().insert_axis(Axis(0))
+}
+
+/// Rank-one update *A* += α *x* ⊗ *y*, where *x* ⊗ *y* is the outer
+/// product of the vectors (BLAS `ger`).
+///
+/// The update is done in place, one row (or for column major `a`, one
+/// column) at a time, with [`.scaled_add()`](ArrayBase::scaled_add).
+///
+/// **Panics** if `a` is not of shape (`x.len()`, `y.len()`).
+///
+/// ```
+/// use ndarray::{array, Array2};
+/// use ndarray::linalg::rank1_update;
+///
+/// let mut a = Array2::<f64>::eye(2);
+/// rank1_update(2., &array![1., 2.], &array![3., 4.], &mut a);
+/// assert_eq!(a, array![[7., 8.], [12., 17.]]);
+/// ```
+#[track_caller]
+pub fn rank1_update<A, S1, S2, S3>(alpha: A, x: &ArrayBase<S1, Ix1>, y: &ArrayBase<S2, Ix1>, a: &mut ArrayBase<S3, Ix2>)
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (m, n) = a.dim();
+    assert!(
+        m == x.len() && n == y.len(),
+        "ndarray: rank1_update of vectors of lengths {} and {} into a matrix of shape {} × {}",
+        x.len(),
+        y.len(),
+        m,
+        n
+    );
+    if a.strides()[0] == 1 && n > 1 {
+        for (mut column, &y_j) in a.columns_mut().into_iter().zip(y) {
+            column.scaled_add(alpha * y_j, x);
+        }
+    } else {
+        for (mut row, &x_i) in a.rows_mut().into_iter().zip(x) {
+            row.scaled_add(alpha * x_i, y);
+        }
+    }
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
 fn same_type<A: 'static, B: 'static>() -> bool
@@ -8,9 +8,21 @@
 
 //! Linear algebra.
 
+pub use self::cross::{cross, cross_axis};
+pub use self::impl_linalg::batch_mat_mul;
 pub use self::impl_linalg::general_mat_mul;
 pub use self::impl_linalg::general_mat_vec_mul;
 pub use self::impl_linalg::kron;
+pub use self::impl_linalg::outer;
+#[cfg(feature = "rayon")]
+pub use self::impl_linalg::par_batch_mat_mul;
+pub use self::impl_linalg::rank1_update;
+pub use self::impl_linalg::try_general_mat_vec_mul;
 pub use self::impl_linalg::Dot;
+pub use self::impl_linalg::DotInto;
+pub use self::triangular::{Diag, UpLo};
+pub use crate::error::SingularError;
 
+mod cross;
 mod impl_linalg;
+mod triangular;
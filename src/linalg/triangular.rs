@@ -0,0 +1,131 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+
+use crate::error::SingularError;
+use crate::LinalgScalar;
+
+/// Which triangle of a matrix to use.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UpLo
+{
+    /// The lower triangle, on and below the main diagonal
+    Lower,
+    /// The upper triangle, on and above the main diagonal
+    Upper,
+}
+
+/// Whether the main diagonal of a triangular matrix is taken to be all ones.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Diag
+{
+    /// The diagonal elements are assumed to be one, and are not read
+    Unit,
+    /// The diagonal elements are read from the matrix
+    NonUnit,
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    /// Solve `self · x = b` for `x`, where `self` is the triangular matrix
+    /// given by the `uplo` triangle of the array and `diag`.
+    ///
+    /// The elements outside of the triangle are not read. `b` can be a
+    /// vector, or a matrix with one right-hand side in each column; `x` has
+    /// the same shape as `b`. The solution is computed by forward (lower) or
+    /// back (upper) substitution.
+    ///
+    /// **Errors** if the diagonal has a zero element and `diag` is
+    /// `Diag::NonUnit`, since the matrix is then singular.
+    ///
+    /// **Panics** if the array is not square, if `b` is not 1- or
+    /// 2-dimensional, or if the length of the first axis of `b` is not the
+    /// size of the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::linalg::{Diag, UpLo};
+    ///
+    /// let l = array![[2., 0., 0.],
+    ///                [1., 1., 0.],
+    ///                [4., -1., 4.]];
+    /// let b = array![2., 3., 10.];
+    /// let x = l.solve_triangular(&b, UpLo::Lower, Diag::NonUnit).unwrap();
+    /// assert_eq!(x, array![1., 2., 2.]);
+    /// assert_eq!(l.dot(&x), b);
+    ///
+    /// // the lower triangle is not read when solving with the upper one
+    /// let x = l.solve_triangular(&b, UpLo::Upper, Diag::Unit).unwrap();
+    /// assert_eq!(x, array![2., 3., 10.]);
+    /// ```
+    #[track_caller]
+    pub fn solve_triangular<S2, D>(
+        &self, b: &ArrayBase<S2, D>, uplo: UpLo, diag: Diag,
+    ) -> Result<Array<A, D>, SingularError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        let n = self.nrows();
+        assert!(self.is_square(), "ndarray: solve_triangular needs a square matrix, got shape {:?}", self.shape());
+        assert!(
+            b.ndim() == 1 || b.ndim() == 2,
+            "ndarray: solve_triangular needs a 1-D or 2-D right-hand side, got {} axes",
+            b.ndim()
+        );
+        assert!(
+            b.len_of(Axis(0)) == n,
+            "ndarray: solve_triangular of a matrix of size {} with a right-hand side of length {}",
+            n,
+            b.len_of(Axis(0))
+        );
+        if diag == Diag::NonUnit {
+            if let Some(index) = self.diag().iter().position(|d| d.is_zero()) {
+                return Err(SingularError::new(index));
+            }
+        }
+
+        let mut x = b.to_owned();
+        let mut x2 = x.view_mut().into_dyn();
+        if x2.ndim() == 1 {
+            x2.insert_axis_inplace(Axis(1));
+        }
+        let mut x2 = x2.into_dimensionality::<Ix2>().unwrap();
+        for mut column in x2.columns_mut() {
+            match uplo {
+                UpLo::Lower =>
+                    for i in 0..n {
+                        let row = self.row(i);
+                        let sum = row.slice(s![..i]).dot(&column.slice(s![..i]));
+                        column[i] = self.substitute(column[i] - sum, i, diag);
+                    },
+                UpLo::Upper =>
+                    for i in (0..n).rev() {
+                        let row = self.row(i);
+                        let sum = row.slice(s![i + 1..]).dot(&column.slice(s![i + 1..]));
+                        column[i] = self.substitute(column[i] - sum, i, diag);
+                    },
+            }
+        }
+        Ok(x)
+    }
+
+    /// Return the `i`th unknown, given the right-hand side minus the terms
+    /// of the unknowns that are already known.
+    fn substitute(&self, rest: A, i: usize, diag: Diag) -> A
+    {
+        match diag {
+            Diag::Unit => rest,
+            Diag::NonUnit => rest / self[(i, i)],
+        }
+    }
+}
@@ -16,7 +16,6 @@ use std::ops::{Add, Div, Mul, Sub};
 #[cfg(feature = "std")]
 use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 
-#[cfg(feature = "std")]
 use crate::ScalarOperand;
 
 /// Elements that support linear algebra operations.
@@ -62,3 +61,71 @@ pub trait NdFloat:
 impl NdFloat for f32 {}
 #[cfg(feature = "std")]
 impl NdFloat for f64 {}
+
+/// Primitive integer element types, `i8` to `i128`, `u8` to `u128`, `isize`
+/// and `usize`.
+///
+/// Trait `NdInteger` provides the saturating, wrapping and checked arithmetic
+/// of the [element-wise methods for integer
+/// arrays](crate::ArrayBase#element-wise-methods-for-integer-arrays), and
+/// includes the traits needed for linear algebra and for *right hand side*
+/// scalar operations (`ScalarOperand`).
+///
+/// This trait can only be implemented by the primitive integer types.
+pub trait NdInteger: LinalgScalar + ScalarOperand + Ord
+{
+    private_decl! {}
+    #[doc(hidden)]
+    fn saturating_add(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn saturating_sub(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn saturating_mul(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_add(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_nd_integer {
+    ($($t:ty)*) => {
+        $(
+        impl NdInteger for $t
+        {
+            private_impl! {}
+            impl_nd_integer!(@methods $t;
+                saturating_add saturating_sub saturating_mul
+                wrapping_add wrapping_sub wrapping_mul);
+            impl_nd_integer!(@checked $t; checked_add checked_sub checked_mul);
+        }
+        )*
+    };
+    (@methods $t:ty; $($m:ident)*) => {
+        $(
+        #[inline]
+        fn $m(self, rhs: Self) -> Self
+        {
+            <$t>::$m(self, rhs)
+        }
+        )*
+    };
+    (@checked $t:ty; $($m:ident)*) => {
+        $(
+        #[inline]
+        fn $m(self, rhs: Self) -> Option<Self>
+        {
+            <$t>::$m(self, rhs)
+        }
+        )*
+    };
+}
+
+impl_nd_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
@@ -62,11 +62,7 @@ macro_rules! expand_if {
 macro_rules! debug_bounds_check {
     ($self_:ident, $index:expr) => {
         if $index.index_checked(&$self_.dim, &$self_.strides).is_none() {
-            panic!(
-                "ndarray: index {:?} is out of bounds for array of shape {:?}",
-                $index,
-                $self_.shape()
-            );
+            $crate::arraytraits::index_out_of_bounds(&$index, &$self_.dim);
         }
     };
 }
@@ -0,0 +1,152 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::any::TypeId;
+
+use num_traits::{AsPrimitive, Bounded, NumCast, ToPrimitive, Zero};
+
+use crate::error::CastError;
+use crate::imp_prelude::*;
+use crate::impl_methods::unlimited_transmute;
+use crate::partial::Partial;
+use crate::{indices_of, IntoDimension};
+
+/// # Element Type Conversion
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Convert the elements to type `B`, checking that each value is
+    /// representable in `B`.
+    ///
+    /// The conversion uses [`NumCast`], so for example a float is converted
+    /// to an integer by truncation toward zero, if the result is in the range
+    /// of the integer type.
+    ///
+    /// **Errors** if any element can not be represented in `B`, like `NaN` or
+    /// an out of range value converted to an integer type. The error carries
+    /// the index of the first such element, in logical order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1.5, 2.], [255., 256.]];
+    /// assert_eq!(a.slice(ndarray::s![0, ..]).cast::<u8>().unwrap(), array![1, 2]);
+    /// assert_eq!(a.cast::<u8>().unwrap_err().index(), &[1, 1]);
+    /// assert_eq!(a.cast::<f32>().unwrap(), array![[1.5f32, 2.], [255., 256.]]);
+    /// ```
+    pub fn cast<B>(&self) -> Result<Array<B, D>, CastError>
+    where
+        A: Clone + ToPrimitive,
+        B: NumCast,
+    {
+        // The output is in standard layout, so writing the elements in
+        // logical order fills it from the front, and `partial` drops the
+        // elements written so far if a conversion fails.
+        let mut out = Array::uninit(self.raw_dim());
+        let ptr = out.as_mut_ptr() as *mut B;
+        let mut partial = unsafe { Partial::new(ptr) };
+        for (i, x) in self.iter().enumerate() {
+            match B::from(x.clone()) {
+                Some(b) => unsafe {
+                    ptr.add(i).write(b);
+                    partial.len += 1;
+                },
+                None => {
+                    let index = indices_of(self)
+                        .into_iter()
+                        .nth(i)
+                        .expect("the index of the element is in bounds");
+                    return Err(CastError::new(index.into_dimension().into_dyn()));
+                }
+            }
+        }
+        partial.release_ownership();
+        // Safe because every element was written above
+        unsafe { Ok(out.assume_init()) }
+    }
+
+    /// Convert the elements to type `B`, saturating values that are not
+    /// representable in `B`.
+    ///
+    /// Values that can be converted with [`NumCast`] are converted like in
+    /// [`.cast()`](Self::cast). Values out of range are replaced by the
+    /// minimum value of `B` if they are less than zero, or by the maximum
+    /// value of `B` otherwise. `NaN` is converted to zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-3.5, 1.5, 300., f64::NAN];
+    /// assert_eq!(a.cast_saturating::<u8>(), array![0, 1, 255, 0]);
+    /// ```
+    pub fn cast_saturating<B>(&self) -> Array<B, D>
+    where
+        A: Clone + ToPrimitive + PartialOrd + Zero,
+        B: NumCast + Bounded + Zero,
+    {
+        self.map(|x| {
+            B::from(x.clone()).unwrap_or_else(|| {
+                #[allow(clippy::eq_op)]
+                let is_nan = x != x;
+                if is_nan {
+                    B::zero()
+                } else if *x < A::zero() {
+                    B::min_value()
+                } else {
+                    B::max_value()
+                }
+            })
+        })
+    }
+
+    /// Convert the elements to type `B` with the semantics of an `as` cast.
+    ///
+    /// The conversion never fails, but may lose information: integers are
+    /// truncated or wrap around, floats converted to integers saturate and
+    /// `NaN` becomes zero, and large floats become infinite when converted
+    /// to a smaller float type. This is the same as
+    /// `.mapv(|x| x as B)`, for generic element types.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-1i32, 256, 7];
+    /// assert_eq!(a.cast_lossy::<u8>(), array![255, 0, 7]);
+    /// ```
+    pub fn cast_lossy<B>(&self) -> Array<B, D>
+    where
+        A: AsPrimitive<B>,
+        B: Copy + 'static,
+    {
+        self.mapv(AsPrimitive::as_)
+    }
+
+    /// Convert the elements to type `B` like [`.cast()`](Self::cast), consuming
+    /// the array.
+    ///
+    /// If `A` and `B` are the same type, the conversion is the identity and
+    /// the array is converted into an owned array without allocating (if it
+    /// is not shared).
+    ///
+    /// **Errors** like [`.cast()`](Self::cast).
+    pub fn cast_into<B>(self) -> Result<Array<B, D>, CastError>
+    where
+        S: DataOwned,
+        A: Clone + ToPrimitive + 'static,
+        B: NumCast + 'static,
+    {
+        if TypeId::of::<A>() == TypeId::of::<B>() {
+            // Safe because A and B are the same type.
+            unsafe { Ok(unlimited_transmute::<Array<A, D>, Array<B, D>>(self.into_owned())) }
+        } else {
+            self.cast()
+        }
+    }
+}
@@ -50,9 +50,33 @@ macro_rules! binary_ops {
     };
 }
 
+#[cfg(feature = "std")]
+macro_rules! unary_ops_inplace {
+    ($($(#[$meta:meta])* fn $id:ident => $op:ident)+) => {
+        $($(#[$meta])*
+        pub fn $id(&mut self) {
+            self.mapv_inplace(A::$op)
+        })+
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! binary_ops_inplace {
+    ($($(#[$meta:meta])* fn $id:ident($ty:ty) => $op:ident)+) => {
+        $($(#[$meta])*
+        pub fn $id(&mut self, rhs: $ty) {
+            self.mapv_inplace(|v| A::$op(v, rhs))
+        })+
+    };
+}
+
 /// # Element-wise methods for float arrays
 ///
 /// Element-wise math functions for any array type that contains float number.
+///
+/// These are convenience wrappers for [`.mapv()`](ArrayBase::mapv) with the
+/// corresponding method of [`Float`], and have the same performance.
+/// The in place variants below modify the array instead.
 #[cfg(feature = "std")]
 impl<A, S, D> ArrayBase<S, D>
 where
@@ -141,6 +165,91 @@ where
     {
         self.mapv(|v: A| v * v)
     }
+
+    /// Replace each `NaN` (not a number) element with `replacement`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., f64::NAN, 3.];
+    /// assert_eq!(a.nan_to_num(0.), array![1., 0., 3.]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn nan_to_num(&self, replacement: A) -> Array<A, D>
+    {
+        self.mapv(|v| if v.is_nan() { replacement } else { v })
+    }
+}
+
+/// # In place element-wise methods for float arrays
+///
+/// These are convenience wrappers for
+/// [`.mapv_inplace()`](ArrayBase::mapv_inplace) with the corresponding
+/// method of [`Float`]. Unlike the methods that return a new array, they
+/// reuse the memory of the array.
+///
+/// ```
+/// use ndarray::array;
+///
+/// let mut a = array![1., 4., 9.];
+/// a.sqrt_inplace();
+/// assert_eq!(a, array![1., 2., 3.]);
+/// a.powi_inplace(2);
+/// assert_eq!(a, array![1., 4., 9.]);
+/// ```
+#[cfg(feature = "std")]
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: 'static + Float,
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    unary_ops_inplace! {
+        /// Replace each element with the largest integer less than or equal to it.
+        fn floor_inplace => floor
+        /// Replace each element with the smallest integer greater than or equal to it.
+        fn ceil_inplace => ceil
+        /// Replace each element with the nearest integer.
+        fn round_inplace => round
+        /// Replace each element with its integer part.
+        fn trunc_inplace => trunc
+        /// Replace each element with its fractional part.
+        fn fract_inplace => fract
+        /// Replace each element with its absolute value.
+        fn abs_inplace => abs
+        /// Replace each element with its sign number, see [`.signum()`](Self::signum).
+        fn signum_inplace => signum
+        /// Replace each element with its reciprocal (inverse), `1/x`.
+        fn recip_inplace => recip
+        /// Replace each element with its square root.
+        fn sqrt_inplace => sqrt
+        /// Replace each element with `e^x` (exponential function).
+        fn exp_inplace => exp
+        /// Replace each element with `2^x`.
+        fn exp2_inplace => exp2
+        /// Replace each element with its natural logarithm.
+        fn ln_inplace => ln
+        /// Replace each element with its base 2 logarithm.
+        fn log2_inplace => log2
+        /// Replace each element with its base 10 logarithm.
+        fn log10_inplace => log10
+        /// Replace each element with its cubic root.
+        fn cbrt_inplace => cbrt
+        /// Replace each element with its sine (in radians).
+        fn sin_inplace => sin
+        /// Replace each element with its cosine (in radians).
+        fn cos_inplace => cos
+        /// Replace each element with its tangent (in radians).
+        fn tan_inplace => tan
+    }
+    binary_ops_inplace! {
+        /// Raise each element to an integer power.
+        fn powi_inplace(i32) => powi
+        /// Raise each element to a float power.
+        fn powf_inplace(A) => powf
+        /// Replace each element with its logarithm with respect to an arbitrary base.
+        fn log_inplace(A) => log
+    }
 }
 
 impl<A, S, D> ArrayBase<S, D>
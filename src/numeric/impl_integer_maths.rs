@@ -0,0 +1,211 @@
+// Element-wise integer arithmetic for ndarray
+
+use crate::error::{shape_mismatch_panic, OverflowError, ShapeMismatch};
+use crate::imp_prelude::*;
+use crate::{broadcast_scalar, indices_of, DimMax, IntoDimension, NdInteger, NdProducer, Zip};
+
+macro_rules! integer_ops {
+    ($($(#[$meta:meta])* fn $id:ident
+       $(#[$scalar_meta:meta])* fn $scalar:ident)+) => {
+        $($(#[$meta])*
+        #[track_caller]
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $id<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, <D as DimMax<E>>::Output>
+        where
+            S2: Data<Elem = A>,
+            E: Dimension,
+            D: DimMax<E>,
+        {
+            let (lhs, rhs) = broadcast_with_unwrap(self, rhs, stringify!($id));
+            Zip::from(&lhs).and(&rhs).map_collect(|&x, &y| A::$id(x, y))
+        }
+
+        $(#[$scalar_meta])*
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $scalar(&self, x: A) -> Array<A, D> {
+            Zip::from(self).and(broadcast_scalar(&x, self.raw_dim())).map_collect(|&v, &x| A::$id(v, x))
+        })+
+    };
+}
+
+macro_rules! checked_integer_ops {
+    ($($(#[$meta:meta])* fn $id:ident
+       $(#[$scalar_meta:meta])* fn $scalar:ident)+) => {
+        $($(#[$meta])*
+        #[track_caller]
+        pub fn $id<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, OverflowError>
+        where
+            S2: Data<Elem = A>,
+            E: Dimension,
+            D: DimMax<E>,
+        {
+            let (lhs, rhs) = broadcast_with_unwrap(self, rhs, stringify!($id));
+            checked_map_collect(&lhs, rhs.view(), A::$id).ok_or_else(|| first_overflow(&lhs, &rhs, A::$id))
+        }
+
+        $(#[$scalar_meta])*
+        pub fn $scalar(&self, x: A) -> Result<Array<A, D>, OverflowError> {
+            let lhs = self.view();
+            checked_map_collect(&lhs, broadcast_scalar(&x, self.raw_dim()), A::$id)
+                .ok_or_else(|| first_overflow(&lhs, &ArrayView::from_scalar(&x, self.raw_dim()), A::$id))
+        })+
+    };
+}
+
+/// # Element-wise methods for integer arrays
+///
+/// Element-wise arithmetic with explicit overflow behaviour, for arrays of
+/// the primitive integer types. The methods with an array right hand side
+/// broadcast the two arrays together like the arithmetic operators; the
+/// methods ending in `_scalar` use the same scalar for every element.
+///
+/// The saturating methods clamp each result to the range of the element
+/// type, the wrapping methods wrap around at its boundaries, and the checked
+/// methods return an [`OverflowError`] with the index of the first result
+/// element that overflows.
+///
+/// ```
+/// use ndarray::array;
+///
+/// let a = array![[250u8, 100], [5, 0]];
+/// let b = array![10u8, 200];
+/// assert_eq!(a.saturating_add(&b), array![[255, 255], [15, 200]]);
+/// assert_eq!(a.wrapping_add(&b), array![[4, 44], [15, 200]]);
+/// assert_eq!(a.checked_add(&b).unwrap_err().index(), &[0, 0]);
+/// assert_eq!(a.saturating_sub_scalar(10), array![[240, 90], [0, 0]]);
+/// ```
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: NdInteger,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    integer_ops! {
+        /// Saturating addition of each element of `self` and `rhs`.
+        ///
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn saturating_add
+        /// Saturating addition of `x` to each element.
+        fn saturating_add_scalar
+        /// Saturating subtraction of each element of `rhs` from `self`.
+        ///
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn saturating_sub
+        /// Saturating subtraction of `x` from each element.
+        fn saturating_sub_scalar
+        /// Saturating multiplication of each element of `self` and `rhs`.
+        ///
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn saturating_mul
+        /// Saturating multiplication of each element by `x`.
+        fn saturating_mul_scalar
+        /// Wrapping addition of each element of `self` and `rhs`.
+        ///
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn wrapping_add
+        /// Wrapping addition of `x` to each element.
+        fn wrapping_add_scalar
+        /// Wrapping subtraction of each element of `rhs` from `self`.
+        ///
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn wrapping_sub
+        /// Wrapping subtraction of `x` from each element.
+        fn wrapping_sub_scalar
+        /// Wrapping multiplication of each element of `self` and `rhs`.
+        ///
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn wrapping_mul
+        /// Wrapping multiplication of each element by `x`.
+        fn wrapping_mul_scalar
+    }
+    checked_integer_ops! {
+        /// Checked addition of each element of `self` and `rhs`.
+        ///
+        /// **Errors** if any sum overflows, with the index of the first one
+        /// in logical order.<br>
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn checked_add
+        /// Checked addition of `x` to each element.
+        ///
+        /// **Errors** if any sum overflows, with the index of the first one
+        /// in logical order.
+        fn checked_add_scalar
+        /// Checked subtraction of each element of `rhs` from `self`.
+        ///
+        /// **Errors** if any difference overflows, with the index of the
+        /// first one in logical order.<br>
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn checked_sub
+        /// Checked subtraction of `x` from each element.
+        ///
+        /// **Errors** if any difference overflows, with the index of the
+        /// first one in logical order.
+        fn checked_sub_scalar
+        /// Checked multiplication of each element of `self` and `rhs`.
+        ///
+        /// **Errors** if any product overflows, with the index of the first
+        /// one in logical order.<br>
+        /// **Panics** if broadcasting to the same shape isn’t possible.
+        fn checked_mul
+        /// Checked multiplication of each element by `x`.
+        ///
+        /// **Errors** if any product overflows, with the index of the first
+        /// one in logical order.
+        fn checked_mul_scalar
+    }
+}
+
+#[track_caller]
+#[allow(clippy::type_complexity)]
+fn broadcast_with_unwrap<'a, 'b, A, S, S2, D, E>(
+    lhs: &'a ArrayBase<S, D>, rhs: &'b ArrayBase<S2, E>, op: &str,
+) -> (ArrayView<'a, A, <D as DimMax<E>>::Output>, ArrayView<'b, A, <D as DimMax<E>>::Output>)
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    match lhs.broadcast_with(rhs) {
+        Ok(views) => views,
+        Err(_) => shape_mismatch_panic(ShapeMismatch::CoBroadcast, lhs.shape(), rhs.shape(), op),
+    }
+}
+
+/// Collect `f` of the elements of `lhs` and `rhs` in lock step, or return
+/// `None` if `f` does for any of them.
+fn checked_map_collect<'a, A, D, P>(lhs: &ArrayView<'_, A, D>, rhs: P, f: fn(A, A) -> Option<A>) -> Option<Array<A, D>>
+where
+    A: NdInteger,
+    D: Dimension,
+    P: NdProducer<Dim = D, Item = &'a A>,
+{
+    let mut out = Array::uninit(lhs.raw_dim());
+    let all_in_range = Zip::from(&mut out)
+        .and(lhs)
+        .and(rhs)
+        .all(|out, &x, &y| match f(x, y) {
+            Some(z) => {
+                out.write(z);
+                true
+            }
+            None => false,
+        });
+    // the elements written so far are plain integers, which need no drop
+    all_in_range.then(|| unsafe { out.assume_init() })
+}
+
+/// Return the error for the first element, in logical order, for which `f`
+/// of the elements of `lhs` and `rhs` returns `None`.
+fn first_overflow<A, D>(lhs: &ArrayView<'_, A, D>, rhs: &ArrayView<'_, A, D>, f: fn(A, A) -> Option<A>) -> OverflowError
+where
+    A: NdInteger,
+    D: Dimension,
+{
+    let (index, _) = indices_of(lhs)
+        .into_iter()
+        .zip(lhs.iter().zip(rhs))
+        .find(|&(_, (&x, &y))| f(x, y).is_none())
+        .expect("an overflow was found");
+    OverflowError::new(index.into_dimension().into_dyn())
+}
@@ -10,10 +10,12 @@
 use num_traits::Float;
 use num_traits::One;
 use num_traits::{FromPrimitive, Zero};
+use std::cmp::Ordering;
 use std::ops::{Add, Div, Mul};
 
 use crate::imp_prelude::*;
 use crate::numeric_util;
+use crate::ShapeError;
 
 /// # Numerical Methods for Arrays
 impl<A, S, D> ArrayBase<S, D>
@@ -23,6 +25,8 @@ where
 {
     /// Return the sum of all elements in the array.
     ///
+    /// The sum of an empty array is zero.
+    ///
     /// ```
     /// use ndarray::arr2;
     ///
@@ -84,6 +88,8 @@ where
 
     /// Return the product of all elements in the array.
     ///
+    /// The product of an empty array is one.
+    ///
     /// ```
     /// use ndarray::arr2;
     ///
@@ -135,6 +141,8 @@ where
     ///
     /// and `n` is the length of the array.
     ///
+    /// The variance of an empty array is NaN.
+    ///
     /// **Panics** if `ddof` is less than zero or greater than `n`
     ///
     /// # Example
@@ -222,6 +230,8 @@ where
 
     /// Return sum along `axis`.
     ///
+    /// The sum along an axis of length zero is an array of zeros.
+    ///
     /// ```
     /// use ndarray::{aview0, aview1, arr2, Axis};
     ///
@@ -254,9 +264,43 @@ where
         }
     }
 
+    /// Return the sums of the subviews along `axis` in the groups given by
+    /// `keys`.
+    ///
+    /// The subview at index `i` along `axis` is added to the sum of group
+    /// `keys[i]`. The result has the shape of `self`, except that the length
+    /// of `axis` is `n_groups`; the sum of a group with no subviews is zero.
+    /// See [`.fold_by_key_axis()`](Self::fold_by_key_axis) for the errors.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let features = array![[1., 2.],
+    ///                       [3., 4.],
+    ///                       [5., 6.]];
+    /// let classes = array![1, 0, 1];
+    ///
+    /// let sums = features.sum_by_key_axis(Axis(0), &classes, 2).unwrap();
+    /// assert_eq!(sums, array![[3., 4.],
+    ///                         [6., 8.]]);
+    /// ```
+    #[track_caller]
+    pub fn sum_by_key_axis<S2>(
+        &self, axis: Axis, keys: &ArrayBase<S2, Ix1>, n_groups: usize,
+    ) -> Result<Array<A, D>, ShapeError>
+    where
+        A: Clone + Zero + Add<Output = A>,
+        D: RemoveAxis,
+        S2: Data<Elem = usize>,
+    {
+        self.fold_by_key_axis(axis, keys, n_groups, A::zero(), |sum, x| sum.clone() + x.clone())
+    }
+
     /// Return product along `axis`.
     ///
-    /// The product of an empty array is 1.
+    /// The product along an axis of length zero is an array of ones.
     ///
     /// ```
     /// use ndarray::{aview0, aview1, arr2, Axis};
@@ -293,7 +337,9 @@ where
 
     /// Return mean along `axis`.
     ///
-    /// Return `None` if the length of the axis is zero.
+    /// Return `None` if the length of the axis is zero. If the axis has
+    /// nonzero length but some other axis is empty, the result is an empty
+    /// array wrapped in `Some`.
     ///
     /// **Panics** if `axis` is out of bounds or if `A::from_usize()`
     /// fails for the axis length.
@@ -326,6 +372,111 @@ where
         }
     }
 
+    /// Return sum over all of `axes`.
+    ///
+    /// The sum is computed in one pass over the array, and the result has
+    /// the remaining axes in the same order as in `self`. The sum over an
+    /// axis of length zero is an array of zeros.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i + j + k) as f64);
+    /// let s = a.sum_axes(&[Axis(0), Axis(2)]).unwrap();
+    /// assert_eq!(s, a.sum_axis(Axis(2)).sum_axis(Axis(0)).into_dyn());
+    /// ```
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    pub fn sum_axes(&self, axes: &[Axis]) -> Result<Array<A, IxDyn>, ShapeError>
+    where A: Clone + Zero + Add<Output = A>
+    {
+        self.fold_axes(axes, A::zero(), |acc, x| acc.clone() + x.clone())
+    }
+
+    /// Return sum over all of `axes`, keeping the reduced axes with length
+    /// one.
+    ///
+    /// The result has the same dimensionality as `self` and can be broadcast
+    /// against it. See [`.sum_axes()`](Self::sum_axes).
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    pub fn sum_axes_keepdims(&self, axes: &[Axis]) -> Result<Array<A, D>, ShapeError>
+    where A: Clone + Zero + Add<Output = A>
+    {
+        self.fold_axes_keepdims(axes, A::zero(), |acc, x| acc.clone() + x.clone())
+    }
+
+    /// Return mean over all of `axes`.
+    ///
+    /// Return `Ok(None)` if any of the axes has length zero.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// // per channel mean of an image batch in NHWC layout
+    /// let images = Array::from_shape_fn((2, 4, 4, 3), |(n, h, w, c)| (n + h + w + c) as f64);
+    /// let mean = images.mean_axes(&[Axis(0), Axis(1), Axis(2)]).unwrap().unwrap();
+    /// assert_eq!(mean, ndarray::arr1(&[3.5, 4.5, 5.5]).into_dyn());
+    /// ```
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    ///
+    /// **Panics** if `A::from_usize()` fails for the number of reduced
+    /// elements.
+    #[track_caller]
+    pub fn mean_axes(&self, axes: &[Axis]) -> Result<Option<Array<A, IxDyn>>, ShapeError>
+    where A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>
+    {
+        let sum = self.sum_axes(axes)?;
+        Ok(self.axes_len(axes).map(|count| sum / aview0(&count)))
+    }
+
+    /// Return mean over all of `axes`, keeping the reduced axes with length
+    /// one.
+    ///
+    /// Return `Ok(None)` if any of the axes has length zero. The result has
+    /// the same dimensionality as `self` and can be broadcast against it.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[[1., 2.], [3., 4.]], [[5., 6.], [7., 8.]]];
+    /// let mean = a.mean_axes_keepdims(&[Axis(0), Axis(2)]).unwrap().unwrap();
+    /// assert_eq!(mean, array![[[3.5], [5.5]]]);
+    /// let centered = &a - &mean;
+    /// assert_eq!(centered.sum_axes(&[Axis(0), Axis(2)]).unwrap(), array![0., 0.].into_dyn());
+    /// ```
+    ///
+    /// **Errors** with `ErrorKind::OutOfBounds` if an axis is out of bounds,
+    /// or with `ErrorKind::Unsupported` if an axis is listed more than once.
+    ///
+    /// **Panics** if `A::from_usize()` fails for the number of reduced
+    /// elements.
+    #[track_caller]
+    pub fn mean_axes_keepdims(&self, axes: &[Axis]) -> Result<Option<Array<A, D>>, ShapeError>
+    where A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>
+    {
+        let sum = self.sum_axes_keepdims(axes)?;
+        Ok(self.axes_len(axes).map(|count| sum / aview0(&count)))
+    }
+
+    /// Return the number of elements along all of `axes` as `A`, or `None`
+    /// if it is zero.
+    #[track_caller]
+    fn axes_len(&self, axes: &[Axis]) -> Option<A>
+    where A: FromPrimitive
+    {
+        let count: usize = axes.iter().map(|&axis| self.len_of(axis)).product();
+        if count == 0 {
+            None
+        } else {
+            Some(A::from_usize(count).expect("Converting the number of elements to `A` must not fail."))
+        }
+    }
+
     /// Return variance along `axis`.
     ///
     /// The variance is computed using the [Welford one-pass
@@ -447,4 +598,548 @@ where
     {
         self.var_axis(axis, ddof).mapv_into(|x| x.sqrt())
     }
+
+    /// Return the weighted [arithmetic mean] of all elements in the array.
+    ///
+    /// `weights` must have the same shape as the array; each element is
+    /// multiplied by its weight and the total is divided by the sum of the
+    /// weights. Negative weights are allowed and used as given.
+    ///
+    /// Return `None` if the sum of the weights is zero, which includes the
+    /// case of an empty array.
+    ///
+    /// **Panics** if the shapes of the array and `weights` are not equal.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.],
+    ///                [3., 4.]];
+    /// let w = array![[1., 0.],
+    ///                [1., 2.]];
+    /// assert_eq!(a.weighted_mean(&w), Some(3.));
+    /// assert_eq!(a.weighted_mean(&(&w * 0.)), None);
+    /// ```
+    ///
+    /// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
+    #[track_caller]
+    pub fn weighted_mean<S2>(&self, weights: &ArrayBase<S2, D>) -> Option<A>
+    where
+        S2: Data<Elem = A>,
+        A: Clone + Zero + Add<Output = A> + Mul<Output = A> + Div<Output = A>,
+    {
+        assert_eq!(self.shape(), weights.shape(), "`weights` must have the same shape as the array");
+        let (sum, weight_sum) = crate::Zip::from(self)
+            .and(weights)
+            .fold((A::zero(), A::zero()), |(sum, weight_sum), x, w| {
+                (sum + x.clone() * w.clone(), weight_sum + w.clone())
+            });
+        if weight_sum.is_zero() {
+            None
+        } else {
+            Some(sum / weight_sum)
+        }
+    }
+
+    /// Return the weighted sum along `axis`.
+    ///
+    /// `weights` holds one weight per index along `axis` and is applied to
+    /// every lane of the array along that axis. Negative weights are allowed
+    /// and used as given. The sum along an axis of length zero is an array of
+    /// zeros.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of `weights`
+    /// is not equal to the length of `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// assert_eq!(a.weighted_sum_axis(Axis(0), &array![1., -1.]), array![-3., -3., -3.]);
+    /// assert_eq!(a.weighted_sum_axis(Axis(1), &array![0., 1., 2.]), array![8., 17.]);
+    /// ```
+    #[track_caller]
+    pub fn weighted_sum_axis<S2>(&self, axis: Axis, weights: &ArrayBase<S2, Ix1>) -> Array<A, D::Smaller>
+    where
+        S2: Data<Elem = A>,
+        A: Clone + Zero + Add<Output = A> + Mul<Output = A>,
+        D: RemoveAxis,
+    {
+        assert_eq!(
+            self.len_of(axis),
+            weights.len(),
+            "the length of `weights` must be equal to the length of the axis"
+        );
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            crate::Zip::from(self.lanes(axis)).map_collect(|lane| {
+                crate::Zip::from(&lane)
+                    .and(weights)
+                    .fold(A::zero(), |sum, x, w| sum + x.clone() * w.clone())
+            })
+        } else {
+            let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+            for (subview, w) in self.axis_iter(axis).zip(weights) {
+                res.zip_mut_with(&subview, |sum: &mut A, x| *sum = sum.clone() + x.clone() * w.clone());
+            }
+            res
+        }
+    }
+
+    /// Return the weighted mean along `axis`.
+    ///
+    /// `weights` holds one weight per index along `axis` and is applied to
+    /// every lane of the array along that axis; each weighted lane sum is
+    /// divided by the sum of the weights. Negative weights are allowed and
+    /// used as given.
+    ///
+    /// Return `None` if the sum of the weights is zero, which includes the
+    /// case of an axis of length zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of `weights`
+    /// is not equal to the length of `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// let mean = a.weighted_mean_axis(Axis(1), &array![0., 1., 3.]);
+    /// assert_eq!(mean, Some(array![2.75, 5.75]));
+    /// assert_eq!(a.weighted_mean_axis(Axis(0), &array![1., -1.]), None);
+    /// ```
+    #[track_caller]
+    pub fn weighted_mean_axis<S2>(&self, axis: Axis, weights: &ArrayBase<S2, Ix1>) -> Option<Array<A, D::Smaller>>
+    where
+        S2: Data<Elem = A>,
+        A: Clone + Zero + Add<Output = A> + Mul<Output = A> + Div<Output = A>,
+        D: RemoveAxis,
+    {
+        let sum = self.weighted_sum_axis(axis, weights);
+        let weight_sum = weights.sum();
+        if weight_sum.is_zero() {
+            None
+        } else {
+            Some(sum.mapv_into(|x| x / weight_sum.clone()))
+        }
+    }
+
+    /// Return the sum of all elements in the array, ignoring `NaN` values.
+    ///
+    /// The sum of an empty array, or of an array where every element is `NaN`,
+    /// is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., f64::NAN],
+    ///                [3., 4.]];
+    /// assert_eq!(a.nansum(), 8.);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn nansum(&self) -> A
+    where A: Float
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return numeric_util::unrolled_fold_skip(slc, A::zero, A::add, |x| x.is_nan());
+        }
+        let mut sum = A::zero();
+        for row in self.rows() {
+            if let Some(slc) = row.as_slice() {
+                sum = sum + numeric_util::unrolled_fold_skip(slc, A::zero, A::add, |x| x.is_nan());
+            } else {
+                sum = sum + row.fold(A::zero(), |acc, &elt| if elt.is_nan() { acc } else { acc + elt });
+            }
+        }
+        sum
+    }
+
+    /// Return the [arithmetic mean] of all elements in the array, ignoring
+    /// `NaN` values.
+    ///
+    /// The sum of the non-`NaN` elements is divided by their count. If there
+    /// are no such elements (the array is empty or every element is `NaN`),
+    /// `NaN` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., f64::NAN, 3., 4.];
+    /// assert_eq!(a.nanmean(), 8. / 3.);
+    /// assert!(array![f64::NAN].nanmean().is_nan());
+    /// ```
+    ///
+    /// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
+    #[cfg(feature = "std")]
+    pub fn nanmean(&self) -> A
+    where A: Float + FromPrimitive
+    {
+        let count = self.fold(0, |count, elt| if elt.is_nan() { count } else { count + 1 });
+        if count == 0 {
+            A::nan()
+        } else {
+            let count = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+            self.nansum() / count
+        }
+    }
+
+    /// Return the smallest element in the array, ignoring `NaN` values.
+    ///
+    /// If there are no non-`NaN` elements (the array is empty or every
+    /// element is `NaN`), `NaN` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![3., f64::NAN, -1., 4.];
+    /// assert_eq!(a.nanmin(), -1.);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn nanmin(&self) -> A
+    where A: Float
+    {
+        self.nanfold(nanmin_step)
+    }
+
+    /// Return the largest element in the array, ignoring `NaN` values.
+    ///
+    /// If there are no non-`NaN` elements (the array is empty or every
+    /// element is `NaN`), `NaN` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![3., f64::NAN, -1., 4.];
+    /// assert_eq!(a.nanmax(), 4.);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn nanmax(&self) -> A
+    where A: Float
+    {
+        self.nanfold(nanmax_step)
+    }
+
+    #[cfg(feature = "std")]
+    fn nanfold(&self, step: fn(A, A) -> A) -> A
+    where A: Float
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return numeric_util::unrolled_fold(slc, A::nan, step);
+        }
+        self.fold(A::nan(), |acc, &elt| step(acc, elt))
+    }
+
+    /// Return sum along `axis`, ignoring `NaN` values.
+    ///
+    /// Lanes that are empty or where every element is `NaN` sum to zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., f64::NAN, 3.],
+    ///                [4., f64::NAN, f64::NAN]];
+    /// assert_eq!(a.nansum_axis(Axis(0)), array![5., 0., 3.]);
+    /// assert_eq!(a.nansum_axis(Axis(1)), array![4., 4.]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn nansum_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            crate::Zip::from(self.lanes(axis)).map_collect(|lane| lane.nansum())
+        } else {
+            let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+            for subview in self.axis_iter(axis) {
+                res.zip_mut_with(&subview, |acc, &x| {
+                    if !x.is_nan() {
+                        *acc = *acc + x;
+                    }
+                });
+            }
+            res
+        }
+    }
+
+    /// Return mean along `axis`, ignoring `NaN` values.
+    ///
+    /// Each lane is averaged over its own count of non-`NaN` elements. Lanes
+    /// that are empty or where every element is `NaN` produce `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., f64::NAN, 3.],
+    ///                [4., f64::NAN, f64::NAN]];
+    /// let mean = a.nanmean_axis(Axis(0));
+    /// assert_eq!(mean[0], 2.5);
+    /// assert!(mean[1].is_nan());
+    /// assert_eq!(mean[2], 3.);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()`
+    /// fails for the axis length.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn nanmean_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        crate::Zip::from(self.lanes(axis)).map_collect(|lane| lane.nanmean())
+    }
+
+    /// Return the smallest element of each lane along `axis`, ignoring `NaN`
+    /// values.
+    ///
+    /// Lanes that are empty or where every element is `NaN` produce `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., f64::NAN, 3.],
+    ///                [4., f64::NAN, -2.]];
+    /// assert_eq!(a.nanmin_axis(Axis(1)), array![1., -2.]);
+    /// assert!(a.nanmin_axis(Axis(0))[1].is_nan());
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn nanmin_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        self.fold_axis(axis, A::nan(), |&acc, &x| nanmin_step(acc, x))
+    }
+
+    /// Return the largest element of each lane along `axis`, ignoring `NaN`
+    /// values.
+    ///
+    /// Lanes that are empty or where every element is `NaN` produce `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., f64::NAN, 3.],
+    ///                [4., f64::NAN, -2.]];
+    /// assert_eq!(a.nanmax_axis(Axis(1)), array![3., 4.]);
+    /// assert!(a.nanmax_axis(Axis(0))[1].is_nan());
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn nanmax_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        self.fold_axis(axis, A::nan(), |&acc, &x| nanmax_step(acc, x))
+    }
+
+    /// Return the smallest element of each lane along `axis`.
+    ///
+    /// Return `None` if the length of the axis is zero. If the axis has
+    /// nonzero length but some other axis is empty, the result is an empty
+    /// array wrapped in `Some`.
+    ///
+    /// Elements that are not comparable with themselves, like `NaN`,
+    /// propagate: the result for a lane with such elements is the first of
+    /// them. See [`.nanmin_axis()`](Self::nanmin_axis) to ignore `NaN`
+    /// instead.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 5., 3.],
+    ///                [4., 2., f64::NAN]];
+    /// let min = a.min_axis(Axis(0)).unwrap();
+    /// assert_eq!(min.slice(ndarray::s![..2]), array![1., 2.]);
+    /// assert!(min[2].is_nan());
+    /// assert_eq!(a.slice(ndarray::s![.., ..2]).min_axis(Axis(1)), Some(array![1., 2.]));
+    /// assert_eq!(a.slice(ndarray::s![..0, ..]).min_axis(Axis(0)), None);
+    /// ```
+    #[track_caller]
+    pub fn min_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: PartialOrd + Clone,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, Ordering::Less)
+    }
+
+    /// Return the largest element of each lane along `axis`.
+    ///
+    /// Return `None` if the length of the axis is zero. If the axis has
+    /// nonzero length but some other axis is empty, the result is an empty
+    /// array wrapped in `Some`.
+    ///
+    /// Elements that are not comparable with themselves, like `NaN`,
+    /// propagate: the result for a lane with such elements is the first of
+    /// them. See [`.nanmax_axis()`](Self::nanmax_axis) to ignore `NaN`
+    /// instead.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 5, 3],
+    ///                [4, 2, 6]];
+    /// assert_eq!(a.max_axis(Axis(0)), Some(array![4, 5, 6]));
+    /// assert_eq!(a.max_axis(Axis(1)), Some(array![5, 6]));
+    /// ```
+    #[track_caller]
+    pub fn max_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: PartialOrd + Clone,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, Ordering::Greater)
+    }
+
+    #[track_caller]
+    fn extremum_axis(&self, axis: Axis, want: Ordering) -> Option<Array<A, D::Smaller>>
+    where
+        A: PartialOrd + Clone,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return None;
+        }
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            Some(crate::Zip::from(self.lanes(axis)).map_collect(|lane| {
+                let mut iter = lane.iter();
+                let first = iter.next().unwrap();
+                iter.fold(first, |acc, x| if replaces(acc, x, want) { x } else { acc })
+                    .clone()
+            }))
+        } else {
+            let mut res = self.index_axis(axis, 0).to_owned();
+            for subview in self.axis_iter(axis).skip(1) {
+                res.zip_mut_with(&subview, |acc, x| {
+                    if replaces(acc, x, want) {
+                        *acc = x.clone();
+                    }
+                });
+            }
+            Some(res)
+        }
+    }
+
+    /// Return the elementwise maximum of `self` and `rhs`.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// Elements that are not comparable with themselves, like `NaN`,
+    /// propagate: if either element is `NaN`, so is the result (the one of
+    /// `self` if both are).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // ReLU
+    /// let a = array![[-1., 2.], [3., -4.]];
+    /// assert_eq!(a.maximum(&ndarray::arr0(0.)), array![[0., 2.], [3., 0.]]);
+    ///
+    /// // with a row broadcast against each row of `a`
+    /// assert_eq!(a.maximum(&array![1., 0.]), array![[1., 2.], [3., 0.]]);
+    /// ```
+    #[track_caller]
+    pub fn maximum<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+    where
+        A: PartialOrd + Clone,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.extremum_with(rhs, Ordering::Greater, "maximum")
+    }
+
+    /// Return the elementwise minimum of `self` and `rhs`.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// Elements that are not comparable with themselves, like `NaN`,
+    /// propagate: if either element is `NaN`, so is the result (the one of
+    /// `self` if both are).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[-1., 2.], [3., -4.]];
+    /// assert_eq!(a.minimum(&array![[0.], [1.]]), array![[-1., 0.], [1., -4.]]);
+    /// ```
+    #[track_caller]
+    pub fn minimum<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+    where
+        A: PartialOrd + Clone,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.extremum_with(rhs, Ordering::Less, "minimum")
+    }
+
+    #[track_caller]
+    fn extremum_with<S2, E>(&self, rhs: &ArrayBase<S2, E>, want: Ordering, op: &str) -> Array<A, D>
+    where
+        A: PartialOrd + Clone,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim(), op);
+        crate::Zip::from(self)
+            .and(&rhs)
+            .map_collect(|a, b| if replaces(a, b, want) { b.clone() } else { a.clone() })
+    }
+}
+
+/// Return `true` if `x` should replace `acc` as the extremum in the
+/// direction `want`: if `x` is strictly smaller (`Less`) or larger
+/// (`Greater`) than `acc`, or if it is the first element that is not
+/// comparable with itself (like `NaN`).
+fn replaces<A: PartialOrd>(acc: &A, x: &A, want: Ordering) -> bool
+{
+    match x.partial_cmp(acc) {
+        Some(ord) => ord == want,
+        None => acc.partial_cmp(acc).is_some(),
+    }
+}
+
+/// Pick the smaller of `acc` and `x`, where `NaN` stands for "no value yet".
+#[cfg(feature = "std")]
+fn nanmin_step<A: Float>(acc: A, x: A) -> A
+{
+    if acc.is_nan() || x < acc {
+        x
+    } else {
+        acc
+    }
+}
+
+/// Pick the larger of `acc` and `x`, where `NaN` stands for "no value yet".
+#[cfg(feature = "std")]
+fn nanmax_step<A: Float>(acc: A, x: A) -> A
+{
+    if acc.is_nan() || x > acc {
+        x
+    } else {
+        acc
+    }
 }
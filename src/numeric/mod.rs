@@ -1,3 +1,11 @@
 mod impl_numeric;
 
+mod impl_cast;
+
 mod impl_float_maths;
+
+mod impl_integer_maths;
+
+mod running_stats;
+#[cfg(feature = "std")]
+pub use self::running_stats::RunningStats;
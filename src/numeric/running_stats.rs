@@ -0,0 +1,175 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![cfg(feature = "std")]
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::error::{self, ShapeError};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Streaming accumulator for the elementwise mean and variance of a sequence
+/// of same-shaped arrays.
+///
+/// Each pushed array is one sample; the statistics are computed per element
+/// position using the [Welford one-pass algorithm](https://www.jstor.org/stable/1266577),
+/// so the samples do not need to be kept in memory. Accumulators that were
+/// filled independently (for example by parallel workers) can be combined
+/// with [`.merge()`](Self::merge).
+///
+/// ```
+/// use ndarray::{array, RunningStats};
+///
+/// let mut stats = RunningStats::new(2);
+/// stats.push(&array![1., 10.]).unwrap();
+/// stats.push(&array![3., 20.]).unwrap();
+/// assert_eq!(stats.count(), 2);
+/// assert_eq!(stats.mean().unwrap(), array![2., 15.]);
+/// assert_eq!(stats.var(1.), array![2., 50.]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RunningStats<A, D>
+where D: Dimension
+{
+    count: usize,
+    mean: Array<A, D>,
+    m2: Array<A, D>,
+}
+
+impl<A, D> RunningStats<A, D>
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Create an empty accumulator for arrays of the given shape.
+    pub fn new<Sh>(shape: Sh) -> Self
+    where Sh: ShapeBuilder<Dim = D>
+    {
+        let mean = Array::zeros(shape);
+        let m2 = Array::zeros(mean.raw_dim());
+        RunningStats { count: 0, mean, m2 }
+    }
+
+    /// Return the number of arrays accumulated so far.
+    pub fn count(&self) -> usize
+    {
+        self.count
+    }
+
+    /// Return the shape of the accumulated arrays.
+    pub fn shape(&self) -> &[usize]
+    {
+        self.mean.shape()
+    }
+
+    /// Add one sample to the accumulator.
+    ///
+    /// **Errors** if the shape of `x` is not equal to the shape of the
+    /// accumulator.
+    pub fn push<S>(&mut self, x: &ArrayBase<S, D>) -> Result<(), ShapeError>
+    where S: Data<Elem = A>
+    {
+        if x.shape() != self.mean.shape() {
+            return Err(error::incompatible_shapes(&self.mean.raw_dim(), &x.raw_dim()));
+        }
+        self.count += 1;
+        let count = A::from_usize(self.count).expect("Converting count to `A` must not fail.");
+        Zip::from(&mut self.mean)
+            .and(&mut self.m2)
+            .and(x)
+            .for_each(|mean, m2, &x| {
+                let delta = x - *mean;
+                *mean = *mean + delta / count;
+                *m2 = (x - *mean).mul_add(delta, *m2);
+            });
+        Ok(())
+    }
+
+    /// Combine the samples accumulated in `other` into `self`.
+    ///
+    /// The result is the same (up to rounding) as if every sample pushed to
+    /// `other` had been pushed to `self`. The combination uses the pairwise
+    /// update of Chan, Golub and LeVeque.
+    ///
+    /// **Errors** if the shapes of the two accumulators are not equal.
+    pub fn merge(&mut self, other: &Self) -> Result<(), ShapeError>
+    {
+        if other.mean.shape() != self.mean.shape() {
+            return Err(error::incompatible_shapes(&self.mean.raw_dim(), &other.mean.raw_dim()));
+        }
+        if other.count == 0 {
+            return Ok(());
+        }
+        if self.count == 0 {
+            self.clone_from(other);
+            return Ok(());
+        }
+        let total = self.count + other.count;
+        let n_a = A::from_usize(self.count).expect("Converting count to `A` must not fail.");
+        let n_b = A::from_usize(other.count).expect("Converting count to `A` must not fail.");
+        let n = A::from_usize(total).expect("Converting count to `A` must not fail.");
+        Zip::from(&mut self.mean)
+            .and(&mut self.m2)
+            .and(&other.mean)
+            .and(&other.m2)
+            .for_each(|mean_a, m2_a, &mean_b, &m2_b| {
+                let delta = mean_b - *mean_a;
+                *mean_a = *mean_a + delta * n_b / n;
+                *m2_a = *m2_a + m2_b + delta * delta * n_a * n_b / n;
+            });
+        self.count = total;
+        Ok(())
+    }
+
+    /// Return the elementwise mean of the accumulated arrays, or `None` if
+    /// nothing has been accumulated yet.
+    pub fn mean(&self) -> Option<Array<A, D>>
+    {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean.clone())
+        }
+    }
+
+    /// Return the elementwise variance of the accumulated arrays.
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom", as in
+    /// [`ArrayBase::var`]. If no arrays have been accumulated, the result is
+    /// all `NaN`, whatever the value of `ddof`.
+    ///
+    /// **Panics** if `ddof` is less than zero, or greater than the count if
+    /// any arrays have been accumulated.
+    #[track_caller]
+    pub fn var(&self, ddof: A) -> Array<A, D>
+    {
+        let zero = A::zero();
+        let n = A::from_usize(self.count).expect("Converting count to `A` must not fail.");
+        assert!(
+            !(ddof < zero || (self.count > 0 && ddof > n)),
+            "`ddof` must not be less than zero or greater than the number of samples",
+        );
+        if self.count == 0 {
+            return self.m2.mapv(|_| A::nan());
+        }
+        let dof = n - ddof;
+        self.m2.mapv(|m2| m2 / dof)
+    }
+
+    /// Return the elementwise standard deviation of the accumulated arrays.
+    ///
+    /// See [`.var()`](Self::var) for the meaning of `ddof`.
+    ///
+    /// **Panics** if `ddof` is less than zero, or greater than the count if
+    /// any arrays have been accumulated.
+    #[track_caller]
+    pub fn std(&self, ddof: A) -> Array<A, D>
+    {
+        self.var(ddof).mapv_into(|x| x.sqrt())
+    }
+}
@@ -50,6 +50,46 @@ where
     acc
 }
 
+/// Fold over the manually unrolled `xs` with `f`, skipping elements for which
+/// `skip` returns `true`.
+///
+/// Unlike folding with a closure that ignores skipped elements itself, the
+/// partial accumulators are combined with `f` unconditionally.
+#[cfg(feature = "std")]
+pub fn unrolled_fold_skip<A, I, F, P>(mut xs: &[A], init: I, f: F, skip: P) -> A
+where
+    A: Clone,
+    I: Fn() -> A,
+    F: Fn(A, A) -> A,
+    P: Fn(&A) -> bool,
+{
+    let g = |acc: A, x: &A| if skip(x) { acc } else { f(acc, x.clone()) };
+    let mut acc = init();
+    let (mut p0, mut p1, mut p2, mut p3, mut p4, mut p5, mut p6, mut p7) =
+        (init(), init(), init(), init(), init(), init(), init(), init());
+    while xs.len() >= 8 {
+        p0 = g(p0, &xs[0]);
+        p1 = g(p1, &xs[1]);
+        p2 = g(p2, &xs[2]);
+        p3 = g(p3, &xs[3]);
+        p4 = g(p4, &xs[4]);
+        p5 = g(p5, &xs[5]);
+        p6 = g(p6, &xs[6]);
+        p7 = g(p7, &xs[7]);
+
+        xs = &xs[8..];
+    }
+    acc = f(acc.clone(), f(p0, p4));
+    acc = f(acc.clone(), f(p1, p5));
+    acc = f(acc.clone(), f(p2, p6));
+    acc = f(acc.clone(), f(p3, p7));
+
+    for x in xs {
+        acc = g(acc, x);
+    }
+    acc
+}
+
 /// Compute the dot product.
 ///
 /// `xs` and `ys` must be the same length
@@ -92,6 +132,52 @@ where A: LinalgScalar
     sum
 }
 
+/// Compute the dot product of two strided vectors of length `len`.
+///
+/// The elements of `xs` are at `xs + i * xstride` for `i` in `0..len`, and
+/// likewise for `ys`.
+///
+/// **Safety:** all these elements must be valid for reads.
+pub unsafe fn unrolled_dot_strided<A>(
+    mut xs: *const A, xstride: isize, mut ys: *const A, ystride: isize, len: usize,
+) -> A
+where A: LinalgScalar
+{
+    // eightfold unrolled like `unrolled_dot`, stepping the pointers instead
+    // of computing each element's offset
+    let mut sum = A::zero();
+    let (mut p0, mut p1, mut p2, mut p3, mut p4, mut p5, mut p6, mut p7) =
+        (A::zero(), A::zero(), A::zero(), A::zero(), A::zero(), A::zero(), A::zero(), A::zero());
+    let mut n = len;
+    while n >= 8 {
+        p0 = p0 + *xs * *ys;
+        p1 = p1 + *xs.offset(xstride) * *ys.offset(ystride);
+        p2 = p2 + *xs.offset(2 * xstride) * *ys.offset(2 * ystride);
+        p3 = p3 + *xs.offset(3 * xstride) * *ys.offset(3 * ystride);
+        p4 = p4 + *xs.offset(4 * xstride) * *ys.offset(4 * ystride);
+        p5 = p5 + *xs.offset(5 * xstride) * *ys.offset(5 * ystride);
+        p6 = p6 + *xs.offset(6 * xstride) * *ys.offset(6 * ystride);
+        p7 = p7 + *xs.offset(7 * xstride) * *ys.offset(7 * ystride);
+
+        // may step one past the last element; only dereferenced if in bounds
+        xs = xs.wrapping_offset(8 * xstride);
+        ys = ys.wrapping_offset(8 * ystride);
+        n -= 8;
+    }
+    sum = sum + (p0 + p4);
+    sum = sum + (p1 + p5);
+    sum = sum + (p2 + p6);
+    sum = sum + (p3 + p7);
+
+    while n > 0 {
+        sum = sum + *xs * *ys;
+        xs = xs.wrapping_offset(xstride);
+        ys = ys.wrapping_offset(ystride);
+        n -= 1;
+    }
+    sum
+}
+
 /// Compute pairwise equality
 ///
 /// `xs` and `ys` must be the same length
@@ -71,6 +71,12 @@ where P: NdProducer
         self.inner.layout()
     }
 
+    #[inline(always)]
+    fn is_writable(&self) -> bool
+    {
+        self.inner.is_writable()
+    }
+
     #[inline(always)]
     unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item
     {
@@ -12,6 +12,7 @@ use crate::{ArrayViewMut, DimAdd, Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6,
 use alloc::vec::Vec;
 use std::convert::TryFrom;
 use std::fmt;
+use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
@@ -34,7 +35,9 @@ use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, Rang
 ///
 /// `Slice::new(a, None, -1)` is every element, from `a` until the end, in
 /// reverse order. It can also be created with `Slice::from(a..).step_by(-1)`.
-/// The Python equivalent is `[a::-1]`.
+/// The Python equivalent is `[a:][::-1]`.
+///
+/// See [*Negative step*](s!#negative-step) for how a negative step is applied.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Slice
 {
@@ -107,7 +110,7 @@ pub struct NewAxis;
 /// `SliceInfoElem::Slice { start: a, end: None, step: -1 }` is every element,
 /// from `a` until the end, in reverse order. It can also be created with
 /// `SliceInfoElem::from(Slice::from(a..).step_by(-1))`. The Python equivalent
-/// is `[a::-1]`. The macro equivalent is `s![a..;-1]`.
+/// is `[a:][::-1]`. The macro equivalent is `s![a..;-1]`.
 ///
 /// `SliceInfoElem::NewAxis` is a new axis of length 1. It can also be created
 /// with `SliceInfoElem::from(NewAxis)`. The Python equivalent is
@@ -316,6 +319,22 @@ impl From<NewAxis> for SliceInfoElem
 /// that `D`, `Self::OutDim`, `self.in_dim()`, and `self.out_ndim()` are
 /// consistent with the `&[SliceInfoElem]` returned by `self.as_ref()` and that
 /// `self.as_ref()` always returns the same value when called multiple times.
+///
+/// Arrays of dimension `IxDyn` can also be sliced with a plain list of
+/// [`SliceInfoElem`], as a `[SliceInfoElem; N]`, a `Vec<SliceInfoElem>`, a
+/// `&[SliceInfoElem]` or a reference to one of these. The number of axes is
+/// then only checked when slicing, which panics if it does not match.
+///
+/// ```
+/// use ndarray::{s, ArrayD, IxDyn, SliceInfoElem};
+///
+/// let a = ArrayD::<i32>::zeros(IxDyn(&[4, 5, 6]));
+/// assert_eq!(a.slice(s![1..3, .., 0..;2]).shape(), &[2, 5, 3]);
+///
+/// let info: Vec<SliceInfoElem> = vec![(1..3).into(), 2.into(), (..).into()];
+/// assert_eq!(a.slice(&info).shape(), &[2, 6]);
+/// assert_eq!(a.slice(info).shape(), &[2, 6]);
+/// ```
 #[allow(clippy::missing_safety_doc)] // not implementable downstream
 pub unsafe trait SliceArg<D: Dimension>: AsRef<[SliceInfoElem]>
 {
@@ -418,6 +437,40 @@ unsafe impl SliceArg<IxDyn> for [SliceInfoElem]
     private_impl! {}
 }
 
+unsafe impl<const N: usize> SliceArg<IxDyn> for [SliceInfoElem; N]
+{
+    type OutDim = IxDyn;
+
+    fn in_ndim(&self) -> usize
+    {
+        self[..].in_ndim()
+    }
+
+    fn out_ndim(&self) -> usize
+    {
+        self[..].out_ndim()
+    }
+
+    private_impl! {}
+}
+
+unsafe impl SliceArg<IxDyn> for Vec<SliceInfoElem>
+{
+    type OutDim = IxDyn;
+
+    fn in_ndim(&self) -> usize
+    {
+        self[..].in_ndim()
+    }
+
+    fn out_ndim(&self) -> usize
+    {
+        self[..].out_ndim()
+    }
+
+    private_impl! {}
+}
+
 /// Represents all of the necessary information to perform a slice.
 ///
 /// The type `T` is typically `[SliceInfoElem; n]`, `&[SliceInfoElem]`, or
@@ -667,6 +720,140 @@ where
     }
 }
 
+/// A builder for a [`SliceInfo`] whose elements are only known at runtime,
+/// for example per-axis bounds read from a file, or a number of axes that
+/// depends on the input.
+///
+/// Add the elements in order, one per axis of the input array (except for
+/// new axes), then call [`.build()`](Self::build) with the dimension types
+/// of the input and the output. The result can be passed to
+/// [`.slice()`](crate::ArrayBase::slice) and the other slicing methods like
+/// the output of [`s![]`](s!).
+///
+/// ```
+/// use ndarray::{s, Array3, Ix3, IxDyn, SliceInfoBuilder};
+///
+/// let a = Array3::from_shape_fn((4, 5, 6), |(i, j, k)| i * 100 + j * 10 + k);
+///
+/// // bounds and step per axis, for example from a configuration file
+/// let bounds = [(1, Some(3), 1), (0, None, 2), (-1, None, -1)];
+/// let info = bounds
+///     .iter()
+///     .fold(SliceInfoBuilder::new(), |b, &(start, end, step)| b.slice(start, end, step))
+///     .build::<Ix3, Ix3>()
+///     .unwrap();
+/// assert_eq!(a.slice(&info), a.slice(s![1..3, ..;2, -1..;-1]));
+///
+/// // with an index, the output dimension can be left dynamic
+/// let info = SliceInfoBuilder::new().index(2).full().slice(0, Some(3), 1).build::<Ix3, IxDyn>().unwrap();
+/// assert_eq!(a.slice(&info), a.slice(s![2, .., 0..3]).into_dyn());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SliceInfoBuilder
+{
+    indices: Vec<SliceInfoElem>,
+}
+
+impl SliceInfoBuilder
+{
+    /// Create a builder with no elements.
+    pub fn new() -> Self
+    {
+        SliceInfoBuilder { indices: Vec::new() }
+    }
+
+    /// Add an element, which can be a [`Slice`], an index, a range or
+    /// [`NewAxis`].
+    pub fn push<E>(mut self, elem: E) -> Self
+    where E: Into<SliceInfoElem>
+    {
+        self.indices.push(elem.into());
+        self
+    }
+
+    /// Add a slice of an axis with the given extents, like
+    /// [`Slice::new(start, end, step)`](Slice::new).
+    ///
+    /// `step` must be nonzero.
+    /// (This method checks with a debug assertion that `step` is not zero.)
+    pub fn slice(self, start: isize, end: Option<isize>, step: isize) -> Self
+    {
+        self.push(Slice::new(start, end, step))
+    }
+
+    /// Add the full range of an axis, like `..` in [`s![]`](s!).
+    pub fn full(self) -> Self
+    {
+        self.push(..)
+    }
+
+    /// Add an index of an axis, which removes the axis from the output.
+    /// Negative indices are counted from the back of the axis.
+    pub fn index(self, index: isize) -> Self
+    {
+        self.push(index)
+    }
+
+    /// Add a new axis of length one to the output.
+    pub fn new_axis(self) -> Self
+    {
+        self.push(NewAxis)
+    }
+
+    /// Return the number of elements added so far.
+    pub fn len(&self) -> usize
+    {
+        self.indices.len()
+    }
+
+    /// Return `true` if no elements have been added.
+    pub fn is_empty(&self) -> bool
+    {
+        self.indices.is_empty()
+    }
+
+    /// Create the `SliceInfo`, for an input array of dimension type `Din`
+    /// and an output array of dimension type `Dout`.
+    ///
+    /// Use `IxDyn` for `Din` to slice arrays with dynamic dimension, and for
+    /// `Dout` if the number of output axes is not known at compile time.
+    ///
+    /// **Errors** if `Din` or `Dout` is a fixed dimension type that does not
+    /// match the number of input axes (elements other than new axes) or
+    /// output axes (elements other than indices). For `IxDyn`, the number of
+    /// axes is checked when slicing, which panics if it does not match the
+    /// array.
+    pub fn build<Din, Dout>(self) -> Result<SliceInfo<Vec<SliceInfoElem>, Din, Dout>, ShapeError>
+    where
+        Din: Dimension,
+        Dout: Dimension,
+    {
+        SliceInfo::try_from(self.indices)
+    }
+}
+
+impl<E> FromIterator<E> for SliceInfoBuilder
+where E: Into<SliceInfoElem>
+{
+    fn from_iter<I>(iter: I) -> Self
+    where I: IntoIterator<Item = E>
+    {
+        SliceInfoBuilder {
+            indices: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<E> Extend<E> for SliceInfoBuilder
+where E: Into<SliceInfoElem>
+{
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = E>
+    {
+        self.indices.extend(iter.into_iter().map(Into::into));
+    }
+}
+
 /// Trait for determining dimensionality of input and output for [`s!`] macro.
 #[doc(hidden)]
 pub trait SliceNextDim
@@ -789,6 +976,19 @@ impl_slicenextdim!((), NewAxis, Ix0, Ix1);
 /// the slice. Start at the front of the (possibly reversed) slice, and add
 /// *step.abs()* until reaching the back of the slice (inclusive)."
 ///
+/// In NumPy, the start of the range is always the first index, also for a
+/// negative step, so the results differ: `s![a..b;-c]` is `[a:b][::-c]` in
+/// Python, not `[a:b:-c]`.
+///
+/// In detail, for an axis of length *len*, the range `start..end` is resolved
+/// as follows: a negative `start` or `end` is counted from the back, by
+/// adding *len*, and a missing `end` is *len*. The resolved indices must be
+/// in `0..=len`, otherwise slicing panics. Then `end` is raised to `start`
+/// if it is smaller, which gives an empty slice. The slice has
+/// *ceil((end - start) / step.abs())* elements; a step larger than the
+/// length of the range only keeps its first element (or last, if *step* is
+/// negative).
+///
 /// For example,
 ///
 /// ```
@@ -801,8 +1001,36 @@ impl_slicenextdim!((), NewAxis, Ix0, Ix1);
 /// assert_eq!(arr.slice(s![0..4;-2]), array![3, 1]);
 /// assert_eq!(arr.slice(s![0..;-2]), array![3, 1]);
 /// assert_eq!(arr.slice(s![..;-2]), array![3, 1]);
+/// assert_eq!(arr.slice(s![-1..;-2]), array![3]);
+/// assert_eq!(arr.slice(s![..-1;5]), array![0]);
+/// assert_eq!(arr.slice(s![..-1;-5]), array![2]);
+/// assert_eq!(arr.slice(s![3..1;-1]), array![]);
 /// # }
 /// ```
+///
+/// # Ellipsis
+///
+/// There is no ellipsis token like NumPy's `a[..., 0]`, because the number
+/// of axes it expands to is not known to the macro. For arrays with a static
+/// number of axes, write out the `..` for each axis. For arrays with dynamic
+/// dimension, build the slice arguments at runtime, or use the methods that
+/// operate on a single axis, like
+/// [`.index_axis()`](crate::ArrayBase::index_axis) and
+/// [`.slice_axis()`](crate::ArrayBase::slice_axis):
+///
+/// ```
+/// use ndarray::{s, ArrayD, Axis, IxDyn, SliceInfo, SliceInfoElem};
+/// use std::convert::TryFrom;
+///
+/// let a = ArrayD::from_shape_fn(IxDyn(&[2, 3, 4]), |ix| ix[0] * 100 + ix[1] * 10 + ix[2]);
+///
+/// // a[..., 0]
+/// let mut elems = vec![SliceInfoElem::from(..); a.ndim() - 1];
+/// elems.push(SliceInfoElem::Index(0));
+/// let info = SliceInfo::<_, IxDyn, IxDyn>::try_from(elems).unwrap();
+/// assert_eq!(a.slice(&info), a.slice(s![.., .., 0]).into_dyn());
+/// assert_eq!(a.slice(&info), a.index_axis(Axis(a.ndim() - 1), 0));
+/// ```
 #[macro_export]
 macro_rules! s(
     // convert a..b;c into @convert(a..b, c), final item
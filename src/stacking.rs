@@ -10,7 +10,7 @@
 use alloc::vec::Vec;
 
 use crate::dimension;
-use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::error::{self, from_kind, ErrorKind, ShapeError};
 use crate::imp_prelude::*;
 
 /// Stack arrays along the new axis.
@@ -89,7 +89,7 @@ where
 
     let stacked_dim = arrays.iter().fold(0, |acc, a| acc + a.len_of(axis));
     res_dim.set_axis(axis, stacked_dim);
-    let new_len = dimension::size_of_shape_checked(&res_dim)?;
+    let new_len = dimension::size_of_array_checked::<A, _>(&res_dim)?;
 
     // start with empty array with precomputed capacity
     // append's handling of empty arrays makes sure `axis` is ok for appending
@@ -106,6 +106,94 @@ where
     Ok(res)
 }
 
+impl<A> Array<A, Ix2>
+{
+    /// Assemble a matrix from a grid of blocks.
+    ///
+    /// `blocks` is a list of block rows, each a list of matrices. All blocks
+    /// in a block row must have the same number of rows, and all blocks in a
+    /// block column the same number of columns. Blocks with zero rows or zero
+    /// columns are allowed. The result is allocated once and each block is
+    /// copied into its position.
+    ///
+    /// ***Errors*** with `ErrorKind::IncompatibleShape` if the block rows do
+    /// not all have the same number of blocks, or if a block's height does not
+    /// match the other blocks in its block row, or its width the other blocks
+    /// in its block column. ***Errors*** with `ErrorKind::Overflow` if the
+    /// result is larger than is possible to represent.
+    ///
+    /// See also the [`block!`](crate::block) macro.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = array![[1, 2],
+    ///                [3, 4]];
+    /// let b = array![[5],
+    ///                [6]];
+    /// let c = array![[7, 8]];
+    /// let d = array![[9]];
+    /// let m = Array2::from_blocks(&[[a.view(), b.view()], [c.view(), d.view()]]).unwrap();
+    /// assert_eq!(m, array![[1, 2, 5],
+    ///                      [3, 4, 6],
+    ///                      [7, 8, 9]]);
+    /// ```
+    pub fn from_blocks<'a, R>(blocks: &[R]) -> Result<Self, ShapeError>
+    where
+        A: Clone + 'a,
+        R: AsRef<[ArrayView2<'a, A>]>,
+    {
+        let n_block_cols = blocks.first().map_or(0, |row| row.as_ref().len());
+        if let Some((i, row)) = blocks
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.as_ref().len() != n_block_cols)
+        {
+            return Err(error::block_row_mismatch(i, row.as_ref().len(), n_block_cols).in_operation("from_blocks"));
+        }
+        let heights: Vec<usize> = blocks
+            .iter()
+            .map(|row| row.as_ref().first().map_or(0, |block| block.nrows()))
+            .collect();
+        let widths: Vec<usize> = match blocks.first() {
+            Some(row) => row.as_ref().iter().map(|block| block.ncols()).collect(),
+            None => Vec::new(),
+        };
+        for (i, (row, &height)) in blocks.iter().zip(&heights).enumerate() {
+            for (j, (block, &width)) in row.as_ref().iter().zip(&widths).enumerate() {
+                let error = if block.nrows() != height {
+                    error::block_mismatch(i, j, 0, block.nrows(), height)
+                } else if block.ncols() != width {
+                    error::block_mismatch(i, j, 1, block.ncols(), width)
+                } else {
+                    continue;
+                };
+                return Err(error.in_operation("from_blocks"));
+            }
+        }
+
+        let checked_sum = |lens: &[usize]| {
+            lens.iter()
+                .try_fold(0usize, |acc, &len| acc.checked_add(len))
+                .ok_or_else(|| from_kind(ErrorKind::Overflow))
+        };
+        let dim = Ix2(checked_sum(&heights)?, checked_sum(&widths)?);
+        dimension::size_of_array_checked::<A, _>(&dim)?;
+        let mut res = Array::uninit(dim);
+        let mut row_start = 0;
+        for (row, &height) in blocks.iter().zip(&heights) {
+            let mut col_start = 0;
+            for (block, &width) in row.as_ref().iter().zip(&widths) {
+                block.assign_to(res.slice_mut(s![row_start..row_start + height, col_start..col_start + width]));
+                col_start += width;
+            }
+            row_start += height;
+        }
+        // Safety: the blocks cover every element of the result
+        unsafe { Ok(res.assume_init()) }
+    }
+}
+
 #[deprecated(note = "Use under the name stack instead.", since = "0.15.0")]
 /// Stack arrays along the new axis.
 ///
@@ -153,7 +241,7 @@ where
 
     res_dim.set_axis(axis, arrays.len());
 
-    let new_len = dimension::size_of_shape_checked(&res_dim)?;
+    let new_len = dimension::size_of_array_checked::<A, _>(&res_dim)?;
 
     // start with empty array with precomputed capacity
     // append's handling of empty arrays makes sure `axis` is ok for appending
@@ -260,6 +348,35 @@ macro_rules! concatenate {
     };
 }
 
+/// Assemble a matrix from a grid of blocks.
+///
+/// Uses the [`Array2::from_blocks()`](crate::Array2::from_blocks) function,
+/// calling `ArrayView2::from(&a)` on each block `a`. Each block row is written
+/// in brackets.
+///
+/// ***Panics*** if the `from_blocks` function would return an error.
+///
+/// ```
+/// use ndarray::{array, block, Array2};
+///
+/// let a = Array2::<i32>::eye(2);
+/// let b = array![[5], [6]];
+/// let spacer = Array2::<i32>::zeros((0, 1));
+/// assert_eq!(
+///     block![[a, b], [a, b], [Array2::zeros((0, 2)), spacer]],
+///     array![[1, 0, 5],
+///            [0, 1, 6],
+///            [1, 0, 5],
+///            [0, 1, 6]],
+/// );
+/// ```
+#[macro_export]
+macro_rules! block {
+    ($( [ $( $block:expr ),+ $(,)? ] ),+ $(,)?) => {
+        $crate::Array2::from_blocks(&[ $( &[ $( $crate::ArrayView2::from(&$block) ),+ ][..] ),+ ]).unwrap()
+    };
+}
+
 /// Stack arrays along the new axis.
 ///
 /// Uses the [`stack_new_axis()`] function, calling `ArrayView::from(&a)` on each
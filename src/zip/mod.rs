@@ -23,7 +23,37 @@ use crate::dimension;
 use crate::indexes::{indices, Indices};
 use crate::split_at::{SplitAt, SplitPreference};
 
-pub use self::ndproducer::{IntoNdProducer, NdProducer, Offset};
+pub use self::ndproducer::{IndexedProducer, IntoNdProducer, NdProducer, Offset, ScalarProducer};
+
+/// Create a producer of shape `shape` that yields `x` for every index, to
+/// zip a scalar with arrays.
+///
+/// The producer does not allocate, and it does not affect the memory order
+/// the `Zip` traverses the other producers in. Use it where an array or a
+/// scalar operand is accepted, for example in a generic function taking an
+/// [`IntoNdProducer`], or in [`azip!`](crate::azip).
+///
+/// **Panics** if the number of elements overflows `isize`.
+///
+/// ```
+/// use ndarray::{azip, broadcast_scalar, Array2};
+///
+/// let x = Array2::from_shape_fn((3, 4), |(i, j)| (i + j) as f64);
+/// let mut y = Array2::<f64>::ones((3, 4));
+/// let alpha = 2.;
+/// azip!((y in &mut y, &x in &x, &a in broadcast_scalar(&alpha, x.raw_dim())) *y += a * x);
+/// assert_eq!(y[[2, 3]], 11.);
+/// ```
+#[track_caller]
+pub fn broadcast_scalar<A, Sh>(x: &A, shape: Sh) -> ScalarProducer<'_, A, Sh::Dim>
+where Sh: IntoDimension
+{
+    let dim = shape.into_dimension();
+    if dimension::size_of_shape_checked(&dim).is_err() {
+        panic!("ndarray: Shape too large, number of elements overflows isize");
+    }
+    ScalarProducer::new(x, dim)
+}
 
 /// Return if the expression is a break value.
 macro_rules! fold_while {
@@ -55,8 +85,8 @@ fn array_layout<D: Dimension>(dim: &D, strides: &D) -> Layout
 {
     let n = dim.ndim();
     if dimension::is_layout_c(dim, strides) {
-        // effectively one-dimensional => C and F layout compatible
-        if n <= 1 || dim.slice().iter().filter(|&&len| len > 1).count() <= 1 {
+        // effectively one-dimensional or empty => C and F layout compatible
+        if n <= 1 || dim.slice().iter().filter(|&&len| len > 1).count() <= 1 || dim.size() == 0 {
             Layout::one_dimensional()
         } else {
             Layout::c()
@@ -93,10 +123,11 @@ where
     D: Dimension,
 {
     type Output = ArrayView<'a, A, E::Dim>;
+    #[track_caller]
     fn broadcast_unwrap(self, shape: E) -> Self::Output
     {
         #[allow(clippy::needless_borrow)]
-        let res: ArrayView<'_, A, E::Dim> = (&self).broadcast_unwrap(shape.into_dimension());
+        let res: ArrayView<'_, A, E::Dim> = (&self).broadcast_unwrap(shape.into_dimension(), "Zip::and_broadcast");
         unsafe { ArrayView::new(res.ptr, res.dim, res.strides) }
     }
     private_impl! {}
@@ -134,6 +165,12 @@ trait ZippableTuple: Sized
 /// The order elements are visited is not specified. The producers don’t have to
 /// have the same item type.
 ///
+/// When the inputs disagree on layout, the zip traverses in the memory order
+/// (c or f) preferred by the majority of the producers; a tie goes to the order
+/// of the mutable producers, so that writes to an output stay sequential.
+/// Index producers (as in [`Zip::indexed`]) follow the same traversal and
+/// always report the logical index of the elements.
+///
 /// The `Zip` has two methods for function application: `for_each` and
 /// `fold_while`. The zip object can be split, which allows parallelization.
 /// A read-only zip object (no mutable producers) can be cloned.
@@ -189,6 +226,21 @@ trait ZippableTuple: Sized
 ///
 /// // Check the result against the previous example.
 /// assert_eq!(totals, totals2);
+///
+///
+/// // Example 4: Write the results into an existing, uninitialized array
+/// //  with map_assign_into. Each element of the output is written exactly
+/// //  once and never read, so no time is spent initializing it first.
+///
+/// let mut sums = Array2::<f64>::uninit(a.dim());
+///
+/// Zip::from(&b)
+///     .and(&c)
+///     .map_assign_into(&mut sums, |&x, &y| x + y);
+///
+/// // Safety: map_assign_into has written every element
+/// let sums = unsafe { sums.assume_init() };
+/// assert_eq!(sums, M::from_elem(a.dim(), 3.));
 /// ```
 #[derive(Debug, Clone)]
 #[must_use = "zipping producers is lazy and does nothing unless consumed"]
@@ -200,6 +252,9 @@ pub struct Zip<Parts, D>
     /// The sum of the layout tendencies of the parts;
     /// positive for c- and negative for f-layout preference.
     layout_tendency: i32,
+    /// The sum of the layout tendencies of the writable parts only;
+    /// used to break ties in `layout_tendency`.
+    output_tendency: i32,
 }
 
 impl<P, D> Zip<(P,), D>
@@ -220,6 +275,7 @@ where
         Zip {
             dimension: dim,
             layout,
+            output_tendency: if array.is_writable() { layout.tendency() } else { 0 },
             parts: (array,),
             layout_tendency: layout.tendency(),
         }
@@ -235,7 +291,25 @@ where
     /// The Zip will take the exact dimension of `p` and all inputs
     /// must have the same dimensions (or be broadcast to them).
     ///
+    /// The index is passed to the closure first, in its pattern form (for
+    /// example `(i, j)` for two dimensions). It is always the logical index of
+    /// the elements: the indices are a producer like the others, so they stay
+    /// in lock step with the elements when the traversal follows the memory
+    /// order of the inputs (for example of F order arrays) or when the `Zip`
+    /// is split.
+    ///
     /// *Note:* Indexed zip has overhead.
+    ///
+    /// ```
+    /// use ndarray::{Array2, ShapeBuilder, Zip};
+    ///
+    /// let a = Array2::from_shape_fn((3, 4).f(), |(i, j)| (i * 4 + j) as f64);
+    /// let mut out = Array2::zeros((3, 4));
+    /// Zip::indexed(&mut out)
+    ///     .and(&a)
+    ///     .for_each(|(i, j), o, &a| *o = a * (i + j) as f64);
+    /// assert_eq!(out[[2, 3]], 11. * 5.);
+    /// ```
     pub fn indexed<IP>(p: IP) -> Self
     where IP: IntoNdProducer<Dim = D, Output = P, Item = P::Item>
     {
@@ -279,7 +353,14 @@ where D: Dimension
 
     fn prefer_f(&self) -> bool
     {
-        !self.layout.is(Layout::CORDER) && (self.layout.is(Layout::FORDER) || self.layout_tendency < 0)
+        !self.layout.is(Layout::CORDER) && (self.layout.is(Layout::FORDER) || self.tends_to_f())
+    }
+
+    /// Return true if the parts, by majority, prefer f order; a tie goes
+    /// to the preference of the writable parts, and then to c order.
+    fn tends_to_f(&self) -> bool
+    {
+        self.layout_tendency < 0 || (self.layout_tendency == 0 && self.output_tendency < 0)
     }
 
     /// Return an *approximation* to the max stride axis; if
@@ -367,7 +448,7 @@ where D: Dimension
         if n == 0 {
             panic!("Unreachable: ndim == 0 is contiguous")
         }
-        if n == 1 || self.layout_tendency >= 0 {
+        if n == 1 || !self.tends_to_f() {
             self.for_each_core_strided_c(acc, function)
         } else {
             self.for_each_core_strided_f(acc, function)
@@ -444,7 +525,7 @@ where
     #[inline]
     pub(crate) fn debug_assert_c_order(self) -> Self
     {
-        debug_assert!(self.layout.is(Layout::CORDER) || self.layout_tendency >= 0 ||
+        debug_assert!(self.layout.is(Layout::CORDER) || !self.tends_to_f() ||
                       self.dimension.slice().iter().filter(|&&d| d > 1).count() <= 1,
                       "Assertion failed: traversal is not c-order or 1D for \
                       layout {:?}, tendency {}, dimension {:?}",
@@ -623,6 +704,23 @@ macro_rules! map_impl {
             ///
             /// assert_eq!(sum_prod_nonzip, sum_prod_zip);
             /// ```
+            ///
+            /// In the same way, the mean squared error between two arrays can be
+            /// computed without the temporary array `&a - &b`. The elements are
+            /// visited in the same order as in `.for_each()`, which follows the
+            /// memory layout of the inputs. If the inputs are empty, the result
+            /// is the initial value of the accumulator.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let a = array![[1., 2.], [3., 4.]];
+            /// let b = array![[1., 0.], [4., 4.]];
+            ///
+            /// let sq_dist = Zip::from(&a).and(&b).fold(0., |acc, &a, &b| acc + (a - b) * (a - b));
+            /// let mse = sq_dist / a.len() as f64;
+            /// assert_eq!(mse, 5. / 4.);
+            /// ```
             pub fn fold<F, Acc>(mut self, acc: Acc, mut function: F) -> Acc
             where
                 F: FnMut(Acc, $($p::Item),*) -> Acc,
@@ -636,6 +734,24 @@ macro_rules! map_impl {
             /// Apply a fold function to the input arrays while the return
             /// value is `FoldWhile::Continue`, visiting elements in lock step.
             ///
+            /// The fold stops at the first `FoldWhile::Done`, which is returned
+            /// with the accumulator at that point. If the inputs are empty, the
+            /// result is `FoldWhile::Continue` with the initial value.
+            ///
+            /// ```
+            /// use ndarray::{array, FoldWhile, Zip};
+            ///
+            /// let a = array![1., 2., 3., 4.];
+            /// let b = array![1., 0., 3., 9.];
+            ///
+            /// // sum of squared differences, stopping once it exceeds 3
+            /// let sum = Zip::from(&a).and(&b).fold_while(0., |acc, &a, &b| {
+            ///     let acc = acc + (a - b) * (a - b);
+            ///     if acc > 3. { FoldWhile::Done(acc) } else { FoldWhile::Continue(acc) }
+            /// });
+            /// assert!(sum.is_done());
+            /// assert_eq!(sum.into_inner(), 4.);
+            /// ```
             pub fn fold_while<F, Acc>(mut self, acc: Acc, mut function: F)
                 -> FoldWhile<Acc>
                 where F: FnMut(Acc, $($p::Item),*) -> FoldWhile<Acc>
@@ -733,6 +849,26 @@ macro_rules! map_impl {
                 }
             }
 
+            /// Include the scalar `x` in the Zip, as a producer that yields `x`
+            /// for every element.
+            ///
+            /// See [`broadcast_scalar`] for the producer.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let x = array![[1., 2.], [3., 4.]];
+            /// let mut y = array![[1., 1.], [1., 1.]];
+            /// Zip::from(&mut y).and(&x).and_scalar(&0.5).for_each(|y, &x, &a| *y += a * x);
+            /// assert_eq!(y, array![[1.5, 2.], [2.5, 3.]]);
+            /// ```
+            pub fn and_scalar<'a, Elem>(self, x: &'a Elem)
+                -> Zip<($($p,)* ScalarProducer<'a, Elem, D>, ), D>
+            {
+                let part = ScalarProducer::new(x, self.dimension.clone());
+                self.build_and(part)
+            }
+
             /// Include the producer `p` in the Zip, broadcasting if needed.
             ///
             /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
@@ -752,12 +888,18 @@ macro_rules! map_impl {
                 where P: NdProducer<Dim=D>,
             {
                 let part_layout = part.layout();
+                let output_tendency = if part.is_writable() {
+                    self.output_tendency + part_layout.tendency()
+                } else {
+                    self.output_tendency
+                };
                 let ($($p,)*) = self.parts;
                 Zip {
                     parts: ($($p,)* part, ),
                     layout: self.layout.intersect(part_layout),
                     dimension: self.dimension,
                     layout_tendency: self.layout_tendency + part_layout.tendency(),
+                    output_tendency,
                 }
             }
 
@@ -805,6 +947,14 @@ macro_rules! map_impl {
             ///
             /// The producer should have assignable items as dictated by the `AssignElem` trait,
             /// for example `&mut R`.
+            ///
+            /// Each element of `into` is assigned exactly once, and its previous value is never
+            /// read. The output can therefore be an uninitialized array or view (with elements of
+            /// type `MaybeUninit<R>`), for example from [`Array::uninit`](ArrayBase::uninit),
+            /// which is fully initialized when this method returns. If `f` panics, the values
+            /// already written to an uninitialized output are not dropped.
+            ///
+            /// **Panics** if the shape of `into` is not the same as the shape of the `Zip`.
             pub fn map_assign_into<R, Q>(self, into: Q, mut f: impl FnMut($($p::Item,)* ) -> R)
                 where Q: IntoNdProducer<Dim=D>,
                       Q::Item: AssignElem<R>
@@ -876,8 +1026,8 @@ macro_rules! map_impl {
                         let out_layout = output.layout();
                         assert!(out_layout.is(Layout::CORDER | Layout::FORDER));
                         assert!(
-                            (self.layout_tendency <= 0 && out_layout.tendency() <= 0) ||
-                            (self.layout_tendency >= 0 && out_layout.tendency() >= 0),
+                            out_layout.tendency() == 0 ||
+                            (out_layout.tendency() < 0) == self.tends_to_f(),
                             "layout tendency violation for self layout {:?}, output layout {:?},\
                             output shape {:?}",
                             self.layout, out_layout, output.raw_dim());
@@ -926,12 +1076,14 @@ macro_rules! map_impl {
                     layout: self.layout,
                     parts: p1,
                     layout_tendency: self.layout_tendency,
+                    output_tendency: self.output_tendency,
                 },
                 Zip {
                     dimension: d2,
                     layout: self.layout,
                     parts: p2,
                     layout_tendency: self.layout_tendency,
+                    output_tendency: self.output_tendency,
                 })
             }
 
@@ -1,4 +1,6 @@
 use crate::imp_prelude::*;
+use crate::indexes::IndexPtr;
+use crate::split_at::SplitAt;
 use crate::Layout;
 use crate::NdIndex;
 #[cfg(not(feature = "std"))]
@@ -52,6 +54,13 @@ where P: NdProducer
 /// *producing* multidimensional items).
 ///
 /// See also [`IntoNdProducer`]
+///
+/// # Custom producers
+///
+/// `NdProducer` can not be implemented outside of ndarray, because its
+/// methods are tied to the internals of `Zip`. To use a custom source of items
+/// with `Zip`, implement [`IndexedProducer`] instead; every `IndexedProducer`
+/// is an `NdProducer`.
 pub trait NdProducer
 {
     /// The element produced per iteration.
@@ -73,6 +82,13 @@ pub trait NdProducer
 
     #[doc(hidden)]
     fn layout(&self) -> Layout;
+    #[doc(hidden)]
+    /// Return true if the producer yields mutable access to its elements;
+    /// `Zip` breaks ties in its traversal order toward such producers.
+    fn is_writable(&self) -> bool
+    {
+        false
+    }
     /// Return the shape of the producer.
     fn raw_dim(&self) -> Self::Dim;
     #[doc(hidden)]
@@ -313,6 +329,11 @@ impl<'a, A, D: Dimension> NdProducer for ArrayViewMut<'a, A, D>
         self.layout_impl()
     }
 
+    fn is_writable(&self) -> bool
+    {
+        true
+    }
+
     unsafe fn as_ref(&self, ptr: *mut A) -> Self::Item
     {
         &mut *ptr
@@ -425,6 +446,11 @@ impl<A, D: Dimension> NdProducer for RawArrayViewMut<A, D>
         self.layout_impl()
     }
 
+    fn is_writable(&self) -> bool
+    {
+        true
+    }
+
     unsafe fn as_ref(&self, ptr: *mut A) -> *mut A
     {
         ptr
@@ -451,3 +477,259 @@ impl<A, D: Dimension> NdProducer for RawArrayViewMut<A, D>
         self.split_at(axis, index)
     }
 }
+
+/// A producer that yields a reference to the same value for every index of
+/// its shape.
+///
+/// Create it with [`broadcast_scalar`](crate::broadcast_scalar) or
+/// [`Zip::and_scalar`](crate::Zip). Like a zero-stride view of the value
+/// (see [`ArrayView::from_scalar`]) it does not allocate, but it also
+/// matches any memory layout, so it keeps a `Zip` of contiguous arrays on
+/// its contiguous fast path.
+#[derive(Debug)]
+pub struct ScalarProducer<'a, A, D>
+{
+    elem: &'a A,
+    dim: D,
+}
+
+impl<'a, A, D: Clone> Clone for ScalarProducer<'a, A, D>
+{
+    fn clone(&self) -> Self
+    {
+        ScalarProducer {
+            elem: self.elem,
+            dim: self.dim.clone(),
+        }
+    }
+}
+
+impl<'a, A, D: Dimension> ScalarProducer<'a, A, D>
+{
+    pub(crate) fn new(elem: &'a A, dim: D) -> Self
+    {
+        ScalarProducer { elem, dim }
+    }
+
+    /// Return a zero-stride array view of the value with the shape of the
+    /// producer.
+    pub fn into_view(self) -> ArrayView<'a, A, D>
+    {
+        ArrayView::from_scalar(self.elem, self.dim)
+    }
+}
+
+impl<'a, A, D: Dimension> NdProducer for ScalarProducer<'a, A, D>
+{
+    type Item = &'a A;
+    type Dim = D;
+    type Ptr = *mut A;
+    type Stride = isize;
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim
+    {
+        self.dim.clone()
+    }
+
+    fn as_ptr(&self) -> *mut A
+    {
+        self.elem as *const A as *mut A
+    }
+
+    fn layout(&self) -> Layout
+    {
+        Layout::one_dimensional()
+    }
+
+    unsafe fn as_ref(&self, ptr: *mut A) -> Self::Item
+    {
+        &*ptr
+    }
+
+    unsafe fn uget_ptr(&self, _i: &Self::Dim) -> *mut A
+    {
+        self.as_ptr()
+    }
+
+    fn stride_of(&self, _axis: Axis) -> isize
+    {
+        0
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride
+    {
+        0
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    {
+        let (d1, d2) = self.dim.split_at(axis, index);
+        (ScalarProducer::new(self.elem, d1), ScalarProducer::new(self.elem, d2))
+    }
+}
+
+/// A custom n-dimensional source of items that can be used with
+/// [`Zip`](crate::Zip), where each item is looked up by its index.
+///
+/// Every `IndexedProducer` is an [`NdProducer`], so it can be zipped with
+/// arrays, views and the other producers. The `Zip` calls
+/// [`get`](IndexedProducer::get) once for each index in
+/// [`raw_dim`](IndexedProducer::raw_dim), in an unspecified order, and may
+/// first split the producer with [`split_at`](IndexedProducer::split_at),
+/// for example to process the parts in parallel.
+///
+/// The dimension type must be `Copy`, so it can not be `IxDyn`.
+///
+/// # Contract
+///
+/// `get` is unsafe to call, and `Zip` promises the following to the
+/// implementation:
+///
+/// - `get` is only called with indices that are in bounds for `raw_dim()`.
+/// - `get` is called at most once for each index of a given producer value.
+///   After `split_at`, each part is used in place of the range of indices it
+///   covers, and the original value is consumed.
+///
+/// An implementation may rely on these promises in `get`, for example to
+/// return mutable references to distinct elements. In return, `split_at`
+/// must divide the indices exactly as described on that method.
+///
+/// # Example
+///
+/// A producer of the coordinates of a regular grid, zipped with an array:
+///
+/// ```
+/// use ndarray::{Array2, Axis, IndexedProducer, Ix2, Zip};
+///
+/// struct Grid {
+///     origin: (f64, f64),
+///     step: f64,
+///     dim: Ix2,
+/// }
+///
+/// impl IndexedProducer for Grid {
+///     type Item = (f64, f64);
+///     type Dim = Ix2;
+///
+///     fn raw_dim(&self) -> Ix2 {
+///         self.dim
+///     }
+///
+///     unsafe fn get(&self, index: Ix2) -> (f64, f64) {
+///         (self.origin.0 + index[0] as f64 * self.step,
+///          self.origin.1 + index[1] as f64 * self.step)
+///     }
+///
+///     fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+///         let mut second_origin = self.origin;
+///         match axis.index() {
+///             0 => second_origin.0 += index as f64 * self.step,
+///             _ => second_origin.1 += index as f64 * self.step,
+///         }
+///         let mut first_dim = self.dim;
+///         first_dim[axis.index()] = index;
+///         let mut second_dim = self.dim;
+///         second_dim[axis.index()] -= index;
+///         (Grid { dim: first_dim, ..self },
+///          Grid { origin: second_origin, dim: second_dim, ..self })
+///     }
+/// }
+///
+/// let grid = Grid { origin: (0., 1.), step: 0.5, dim: Ix2(3, 4) };
+/// let mut distance = Array2::zeros((3, 4));
+/// Zip::from(&mut distance)
+///     .and(grid)
+///     .for_each(|d, (x, y)| *d = f64::hypot(x, y));
+/// assert_eq!(distance[[2, 0]], f64::hypot(1., 1.));
+/// ```
+pub trait IndexedProducer
+{
+    /// The element produced for each index.
+    type Item;
+    /// Dimension type
+    type Dim: Dimension + Copy;
+
+    /// Return the shape of the producer.
+    fn raw_dim(&self) -> Self::Dim;
+
+    /// Return the item at `index`.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds for `self.raw_dim()`, and `get` must not be
+    /// called more than once with the same index for this producer value.
+    unsafe fn get(&self, index: Self::Dim) -> Self::Item;
+
+    /// Split the producer in two along `axis`, before `index`.
+    ///
+    /// `index` is at most the length of `axis`. The first part must have the
+    /// shape of `self` with the length of `axis` set to `index`, and produce
+    /// the items of `self` for those indices. The second part must have the
+    /// remaining length along `axis`, and its item at index `i` must be the
+    /// item of `self` at `i` with `index` added along `axis`.
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    where Self: Sized;
+}
+
+// The NdProducer for an IndexedProducer works like the one for Indices: the
+// "pointer" is the current index, and the stride is the axis to increment.
+impl<P> NdProducer for P
+where P: IndexedProducer
+{
+    type Item = P::Item;
+    type Dim = P::Dim;
+    type Ptr = IndexPtr<P::Dim>;
+    type Stride = usize;
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim
+    {
+        IndexedProducer::raw_dim(self)
+    }
+
+    fn as_ptr(&self) -> Self::Ptr
+    {
+        IndexPtr {
+            index: P::Dim::zeros(IndexedProducer::raw_dim(self).ndim()),
+        }
+    }
+
+    fn layout(&self) -> Layout
+    {
+        if IndexedProducer::raw_dim(self).ndim() <= 1 {
+            Layout::one_dimensional()
+        } else {
+            Layout::none()
+        }
+    }
+
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item
+    {
+        self.get(ptr.index)
+    }
+
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr
+    {
+        IndexPtr { index: *i }
+    }
+
+    fn stride_of(&self, axis: Axis) -> Self::Stride
+    {
+        axis.index()
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride
+    {
+        0
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    {
+        IndexedProducer::split_at(self, axis, index)
+    }
+}
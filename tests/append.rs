@@ -392,6 +392,76 @@ fn test_append_zero_size()
     }
 }
 
+#[test]
+fn append_amortized_reallocations()
+{
+    const N: usize = 100_000;
+    let mut a = Array::zeros((0, 4));
+    let mut ptr = a.as_ptr();
+    let mut reallocations = 0;
+    for i in 0..N {
+        let x = i as u32;
+        a.push(Axis(0), aview1(&[x, x + 1, x + 2, x + 3])).unwrap();
+        if a.as_ptr() != ptr {
+            ptr = a.as_ptr();
+            reallocations += 1;
+        }
+        assert!(a.capacity_axis(Axis(0)) > i);
+    }
+    assert!(reallocations <= 2 * 17, "{} reallocations", reallocations);
+    assert_eq!(a, Array::from_shape_fn((N, 4), |(i, j)| (i + j) as u32));
+
+    // appending along axis 1 grows the f-order array in place as well
+    let mut b = Array::zeros((3, 0));
+    let mut ptr = b.as_ptr();
+    let mut reallocations = 0;
+    for i in 0..N / 10 {
+        b.push(Axis(1), aview1(&[i, 2 * i, 3 * i])).unwrap();
+        if b.as_ptr() != ptr {
+            ptr = b.as_ptr();
+            reallocations += 1;
+        }
+    }
+    assert!(reallocations <= 2 * 14, "{} reallocations", reallocations);
+    assert_eq!(b, Array::from_shape_fn((3, N / 10), |(i, j)| (i + 1) * j));
+}
+
+#[test]
+fn with_capacity_axis()
+{
+    let mut a = Array3::<String>::with_capacity_axis((2, 0, 3), Axis(1), 10);
+    assert_eq!(a.shape(), &[2, 0, 3]);
+    assert_eq!(a.capacity_axis(Axis(1)), 10);
+    let ptr = a.as_ptr();
+
+    let block = Array::from_shape_fn((2, 1, 3), |(i, _, k)| format!("{}{}", i, k));
+    for n in 1..=10 {
+        a.append(Axis(1), block.view()).unwrap();
+        assert_eq!(a.as_ptr(), ptr);
+        assert_eq!(a.len_of(Axis(1)), n);
+        assert_eq!(a.capacity_axis(Axis(1)), 10);
+    }
+
+    // growing past the capacity reallocates once, and keeps spare capacity
+    a.append(Axis(1), block.view()).unwrap();
+    assert_ne!(a.as_ptr(), ptr);
+    assert!(a.capacity_axis(Axis(1)) >= 20);
+    assert_eq!(a, block.broadcast((2, 11, 3)).unwrap());
+
+    // a different axis needs a new memory layout
+    assert_eq!(a.capacity_axis(Axis(0)), 2);
+
+    let empty = Array2::<u8>::with_capacity_axis((0, 0), Axis(0), 10);
+    assert_eq!(empty.capacity_axis(Axis(0)), usize::MAX);
+}
+
+#[test]
+#[should_panic]
+fn with_capacity_axis_nonzero_length()
+{
+    let _ = Array2::<u8>::with_capacity_axis((1, 4), Axis(0), 10);
+}
+
 #[test]
 fn push_row_neg_stride_3()
 {
@@ -451,3 +521,248 @@ fn zero_dimensional_ok()
     a.push(Axis(0), one).unwrap();
     assert_eq!(a, array![2, 1, 1]);
 }
+
+#[test]
+fn collect_array1()
+{
+    let a: Array1<usize> = (0..10_000).map(|i| i * 2).collect();
+    assert_eq!(a.len(), 10_000);
+    assert!(a.iter().enumerate().all(|(i, &x)| x == i * 2));
+}
+
+#[test]
+fn extend_array1()
+{
+    let mut a = Array1::from(vec![0, 1]);
+    a.extend(2..1000);
+    assert_eq!(a, Array::from_iter(0..1000));
+
+    // iterator without an exact size hint
+    a.extend((1000..2000).filter(|x| x % 2 == 0));
+    assert_eq!(a.len(), 1500);
+    assert_eq!(a[1499], 1998);
+}
+
+#[test]
+fn push_element_interleaved()
+{
+    let mut a = Array1::<String>::from(Vec::new());
+    for i in 0..100 {
+        a.push_element(i.to_string());
+        assert_eq!(a.len(), i + 1);
+        assert_eq!(a[i], i.to_string());
+        assert_eq!(a[0], "0");
+        assert_eq!(a.as_slice().unwrap().len(), i + 1);
+    }
+    a.reserve(Axis(0), 50).unwrap();
+    a.push_element("x".to_string());
+    assert_eq!(a[100], "x");
+}
+
+#[test]
+fn push_element_after_slicing()
+{
+    let mut a = Array::from_iter(0..10);
+    a.slice_collapse(s![1..;2]);
+    a.push_element(100);
+    assert_eq!(a, array![1, 3, 5, 7, 9, 100]);
+
+    let mut b = Array::from_iter(0..4);
+    b.invert_axis(Axis(0));
+    b.extend(vec![-1, -2]);
+    assert_eq!(b, array![3, 2, 1, 0, -1, -2]);
+
+    let mut c = Array::from_iter(0..4);
+    c.slice_collapse(s![2..2]);
+    c.push_element(7);
+    assert_eq!(c, array![7]);
+
+    let mut d = Array::from_elem(3, ());
+    d.extend(vec![(); 5]);
+    assert_eq!(d.len(), 8);
+}
+
+#[test]
+fn retain_array1()
+{
+    let mut a = Array::from_iter(0..20);
+    a.retain(|&x| x % 3 == 0);
+    assert_eq!(a, array![0, 3, 6, 9, 12, 15, 18]);
+
+    let mut b = Array::from_iter(0..10);
+    b.slice_collapse(s![..;-1]);
+    b.retain(|&x| x > 6);
+    assert_eq!(b, array![9, 8, 7]);
+    b.retain(|_| false);
+    assert_eq!(b.len(), 0);
+
+    assert_eq!(array![1, 2, 3, 4].slice(s![..;2]).filtered(|&x| x > 1), array![3]);
+}
+
+#[test]
+fn retain_axis()
+{
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| i * 3 + j);
+    a.retain_axis(Axis(0), |row| row[0] % 2 == 0);
+    assert_eq!(a, array![[0, 1, 2], [6, 7, 8]]);
+    a.retain_axis(Axis(1), |col| col[1] != 7);
+    assert_eq!(a, array![[0, 2], [6, 8]]);
+
+    let mut f = Array::from_shape_fn((2, 3, 2).f(), |(i, j, k)| (i, j, k));
+    f.retain_axis(Axis(1), |sub| sub[[0, 0]].1 != 1);
+    assert_eq!(f.shape(), &[2, 2, 2]);
+    assert_eq!(f[[1, 1, 0]], (1, 2, 0));
+    assert!(f.is_standard_layout());
+}
+
+#[test]
+fn retain_drops_each_removed_element_once()
+{
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let drops = Rc::new(Cell::new(0));
+    struct Counted(usize, Rc<Cell<usize>>);
+    impl Drop for Counted
+    {
+        fn drop(&mut self)
+        {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let mut a = Array::from_iter((0..10).map(|i| Counted(i, drops.clone())));
+    a.retain(|x| x.0 % 2 == 0);
+    assert_eq!(drops.get(), 5);
+    assert_eq!(a.iter().map(|x| x.0).collect::<Vec<_>>(), vec![0, 2, 4, 6, 8]);
+
+    let mut b = Array::from_shape_vec((3, 2), (0..6).map(|i| Counted(i, drops.clone())).collect()).unwrap();
+    b.retain_axis(Axis(0), |row| row[0].0 != 2);
+    assert_eq!(drops.get(), 7);
+    assert_eq!(b.iter().map(|x| x.0).collect::<Vec<_>>(), vec![0, 1, 4, 5]);
+
+    drop(a);
+    drop(b);
+    assert_eq!(drops.get(), 16);
+}
+
+#[test]
+fn resize_axis_grow_in_place()
+{
+    let mut a = Array::from_shape_fn((2, 3), |(i, j)| i * 3 + j);
+    a.reserve(Axis(0), 2).unwrap();
+    let ptr = a.as_ptr();
+    a.resize_axis(Axis(0), 4, 9).unwrap();
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a, array![[0, 1, 2], [3, 4, 5], [9, 9, 9], [9, 9, 9]]);
+    assert!(a.is_standard_layout());
+
+    // truncate and grow again in the same allocation
+    a.truncate_axis(Axis(0), 1);
+    assert_eq!(a, array![[0, 1, 2]]);
+    a.resize_axis(Axis(0), 3, 7).unwrap();
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a, array![[0, 1, 2], [7, 7, 7], [7, 7, 7]]);
+    assert!(a.into_raw_vec_and_offset().0.capacity() >= 12);
+}
+
+#[test]
+fn resize_axis_grow_with_relayout()
+{
+    let mut a = Array::from_shape_fn((2, 3), |(i, j)| i * 3 + j);
+    a.resize_axis(Axis(1), 5, 0).unwrap();
+    assert_eq!(a, array![[0, 1, 2, 0, 0], [3, 4, 5, 0, 0]]);
+
+    let mut f = Array::from_shape_fn((2, 3).f(), |(i, j)| i * 3 + j);
+    f.resize_axis(Axis(1), 4, 0).unwrap();
+    assert_eq!(f, array![[0, 1, 2, 0], [3, 4, 5, 0]]);
+    f.resize_axis(Axis(0), 3, 1).unwrap();
+    assert_eq!(f, array![[0, 1, 2, 0], [3, 4, 5, 0], [1, 1, 1, 1]]);
+
+    // the same length or less does not append
+    f.resize_axis(Axis(0), 3, 1).unwrap();
+    f.resize_axis(Axis(1), 2, 1).unwrap();
+    assert_eq!(f, array![[0, 1], [3, 4], [1, 1]]);
+}
+
+#[test]
+fn truncate_axis_to_zero()
+{
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j).to_string());
+    a.truncate_axis(Axis(1), 0);
+    assert_eq!(a.shape(), &[3, 0]);
+    a.resize_axis(Axis(1), 1, String::from("x")).unwrap();
+    assert_eq!(a, array![["x"], ["x"], ["x"]].map(|s| s.to_string()));
+
+    let mut b = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    b.slice_collapse(s![..;-1, ..]);
+    b.truncate_axis(Axis(0), 0);
+    assert_eq!(b.shape(), &[0, 4]);
+    b.resize_axis(Axis(0), 1, 5).unwrap();
+    assert_eq!(b, array![[5, 5, 5, 5]]);
+}
+
+#[test]
+fn truncate_axis_drops_each_removed_element_once()
+{
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let drops = Rc::new(Cell::new(0));
+    struct Counted(usize, Rc<Cell<usize>>);
+    impl Drop for Counted
+    {
+        fn drop(&mut self)
+        {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    // the removed rows are dropped right away
+    let mut a = Array::from_shape_vec((3, 2), (0..6).map(|i| Counted(i, drops.clone())).collect()).unwrap();
+    a.truncate_axis(Axis(0), 1);
+    assert_eq!(drops.get(), 4);
+    assert_eq!(a.iter().map(|x| x.0).collect::<Vec<_>>(), vec![0, 1]);
+
+    // the removed column is dropped with the array
+    let mut b = Array::from_shape_vec((3, 2), (0..6).map(|i| Counted(i, drops.clone())).collect()).unwrap();
+    b.truncate_axis(Axis(1), 1);
+    assert_eq!(drops.get(), 4);
+    assert_eq!(b.iter().map(|x| x.0).collect::<Vec<_>>(), vec![0, 2, 4]);
+
+    drop(a);
+    drop(b);
+    assert_eq!(drops.get(), 12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn truncate_axis_panic_in_drop()
+{
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let drops = Rc::new(Cell::new(0));
+    struct PanicOnDrop(usize, Rc<Cell<usize>>);
+    impl Drop for PanicOnDrop
+    {
+        fn drop(&mut self)
+        {
+            self.1.set(self.1.get() + 1);
+            if self.0 == 4 {
+                panic!("drop of element 4");
+            }
+        }
+    }
+
+    let mut a = Array::from_shape_vec((3, 2), (0..6).map(|i| PanicOnDrop(i, drops.clone())).collect()).unwrap();
+    let result = catch_unwind(AssertUnwindSafe(|| a.truncate_axis(Axis(0), 1)));
+    assert!(result.is_err());
+    // the array is already truncated, and the remaining tail is dropped
+    assert_eq!(a.shape(), &[1, 2]);
+    assert_eq!(a.iter().map(|x| x.0).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(drops.get(), 4);
+    drop(a);
+    assert_eq!(drops.get(), 6);
+}
@@ -5,7 +5,7 @@
 use defmac::defmac;
 use ndarray::arr3;
 use ndarray::prelude::*;
-use ndarray::Zip;
+use ndarray::{ErrorKind, Zip};
 
 #[test]
 fn test_from_shape_fn()
@@ -24,6 +24,103 @@ fn test_dimension_zero()
     assert_eq!((vec![0.; 0], None), a.into_raw_vec_and_offset());
 }
 
+#[test]
+fn test_zeros_ones_generic()
+{
+    use num_traits::Zero;
+    use std::num::Wrapping;
+    use std::ops::Add;
+
+    let a = Array2::<Wrapping<u32>>::ones((2, 3));
+    assert_eq!(a.sum(), Wrapping(6));
+    let b = Array2::<Wrapping<u32>>::zeros((2, 3)) - &a;
+    assert_eq!(b[[1, 2]], Wrapping(u32::MAX));
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Meters(f64);
+
+    impl Add for Meters
+    {
+        type Output = Meters;
+        fn add(self, rhs: Meters) -> Meters
+        {
+            Meters(self.0 + rhs.0)
+        }
+    }
+
+    impl Zero for Meters
+    {
+        fn zero() -> Meters
+        {
+            Meters(0.)
+        }
+
+        fn is_zero(&self) -> bool
+        {
+            self.0 == 0.
+        }
+    }
+
+    let m = Array::<Meters, _>::zeros((3, 1).f());
+    assert_eq!(m.shape(), &[3, 1]);
+    assert!(m.iter().all(Zero::is_zero));
+}
+
+#[test]
+fn test_construct_from_borrowed_shape()
+{
+    // a shape received at runtime, e.g. from a file header
+    let header: Vec<usize> = vec![2, 3, 4];
+    let shape: &[usize] = &header;
+
+    let a = ArrayD::<f64>::zeros(shape);
+    assert_eq!(a.shape(), shape);
+    assert_eq!(a.strides(), &[12, 4, 1]);
+    let a = ArrayD::<f64>::zeros(shape.f());
+    assert_eq!(a.shape(), shape);
+    assert_eq!(a.strides(), &[1, 2, 6]);
+    let a = ArrayD::from_elem(shape.f(), 7);
+    assert_eq!(a.strides(), &[1, 2, 6]);
+    assert!(a.iter().all(|&x| x == 7));
+    let a = ArrayD::from_shape_fn(shape, |ix| ix[0] * 100 + ix[1] * 10 + ix[2]);
+    assert_eq!(a[[1, 2, 3]], 123);
+    let a = ArrayD::from_shape_vec(shape.f(), (0..24).collect()).unwrap();
+    assert_eq!(a[[1, 0, 0]], 1);
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order(shape)
+        .unwrap();
+    assert_eq!(a.shape(), shape);
+    assert_eq!(IxDyn::from_slice(shape), IxDyn(&[2, 3, 4]));
+    assert_eq!(IxDyn::zeros(3), IxDyn(&[0, 0, 0]));
+
+    // empty and zero-dimensional shapes
+    let a = ArrayD::<u8>::zeros(&[2, 0][..]);
+    assert_eq!(a.shape(), &[2, 0]);
+    assert_eq!(a.strides(), &[0, 0]);
+    let a = ArrayD::<u8>::ones(&[][..]);
+    assert_eq!(a.ndim(), 0);
+    assert_eq!(a.len(), 1);
+
+    // borrowed fixed-size shapes give a fixed dimension
+    let shape = [2, 3];
+    let a: Array2<i32> = Array::zeros(&shape);
+    assert_eq!(a.raw_dim(), Dim(shape));
+    let a: Array2<i32> = Array::zeros((&shape).f());
+    assert_eq!(a.strides(), &[1, 2]);
+}
+
+#[test]
+fn test_falses_trues()
+{
+    let f = Array::falses((2, 3, 4));
+    assert_eq!(f, Array::from_elem((2, 3, 4), false));
+    let t = Array2::trues((2, 3).f());
+    assert!(t.iter().all(|&x| x));
+    assert_eq!(t.strides(), &[1, 2]);
+    let mask = ArcArray::falses(IxDyn(&[5])) | &Array::trues(5).into_dyn();
+    assert!(mask.iter().all(|&x| x));
+}
+
 #[test]
 #[cfg(feature = "approx")]
 fn test_arc_into_owned()
@@ -50,6 +147,35 @@ fn test_arcarray_thread_safe()
     is_sync(&a);
 }
 
+#[test]
+fn test_views_thread_safe()
+{
+    use std::cell::Cell;
+
+    fn is_send<T: Send>(_t: &T) {}
+    fn is_sync<T: Sync>(_t: &T) {}
+    let mut a = Array2::from_elem((4, 4), 1.);
+    is_send(&a);
+    is_sync(&a);
+    is_send(&a.view());
+    is_sync(&a.view());
+    {
+        // disjoint mutable views can be sent to other threads
+        let (left, right) = a.view_mut().split_at(Axis(1), 2);
+        is_send(&left);
+        is_send(&right);
+        is_sync(&left);
+    }
+    let a_cow = CowArray::from(a.view());
+    is_send(&a_cow);
+    is_sync(&a_cow);
+
+    // `Cell` is `Send` but not `Sync`; compare `Vec<Cell<_>>` and `&mut Cell<_>`
+    let mut c = Array1::from_elem(3, Cell::new(0));
+    is_send(&c);
+    is_send(&c.view_mut());
+}
+
 #[test]
 #[cfg(feature = "std")]
 #[allow(deprecated)] // uninitialized
@@ -216,6 +342,66 @@ fn test_from_shape_2_2_2_with_neg_stride()
     assert_eq!(a.as_ptr(), v_ptr.wrapping_add(4));
 }
 
+#[test]
+fn test_default_non_clone()
+{
+    #[derive(Debug, Default, PartialEq)]
+    struct NoClone(Vec<u8>);
+
+    let a = Array::<NoClone, _>::default((2, 3));
+    assert_eq!(a.len(), 6);
+    assert!(a.iter().all(|x| *x == NoClone::default()));
+
+    let b = Array3::<Option<String>>::default((2, 0, 3));
+    assert_eq!(b.shape(), &[2, 0, 3]);
+}
+
+#[test]
+fn test_from_elem_f_layout_and_fill()
+{
+    let mut a = Array::from_elem((3, 4).f(), String::from("x"));
+    assert_eq!(a.strides(), &[1, 3]);
+    assert!(a.t().is_standard_layout());
+    assert!(a.iter().all(|s| s == "x"));
+
+    a.fill(String::from("y"));
+    assert!(a.iter().all(|s| s == "y"));
+    a.slice_mut(s![1.., ..;2]).fill(String::new());
+    assert_eq!(a.iter().filter(|s| s.is_empty()).count(), 4);
+}
+
+#[test]
+fn test_one_hot()
+{
+    let labels = array![0, 3, 1, 3];
+    let a = Array2::<i32>::one_hot(&labels, 4).unwrap();
+    assert_eq!(a, array![[1, 0, 0, 0], [0, 0, 0, 1], [0, 1, 0, 0], [0, 0, 0, 1]]);
+    let mask = Array2::one_hot_mask(&labels.slice(s![1..]), 4).unwrap();
+    assert_eq!(mask, a.slice(s![1.., ..]).mapv(|x| x == 1));
+
+    let empty = Array2::<f64>::one_hot(&Array1::<usize>::zeros(0), 3).unwrap();
+    assert_eq!(empty.shape(), &[0, 3]);
+    let no_classes = Array2::<f64>::one_hot(&Array1::<usize>::zeros(0), 0).unwrap();
+    assert_eq!(no_classes.shape(), &[0, 0]);
+}
+
+#[test]
+fn test_one_hot_out_of_bounds()
+{
+    use ndarray::ErrorKind;
+
+    let labels = array![0, 4];
+    let err = Array2::<u8>::one_hot(&labels, 4).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let message = err.to_string();
+    assert!(message.contains("one_hot"), "{}", message);
+    assert!(message.contains("the key 4 at index 1 is not less than the number of groups 4"), "{}", message);
+    let message = Array2::one_hot_mask(&labels, 0).unwrap_err().to_string();
+    assert!(message.contains("one_hot_mask"), "{}", message);
+    assert!(message.contains("the key 0 at index 0"), "{}", message);
+    assert!(Array2::<u8>::one_hot(&labels, 5).is_ok());
+}
+
 #[should_panic]
 #[test]
 fn deny_wraparound_zeros()
@@ -266,6 +452,52 @@ fn deny_wraparound_uninit()
     let _five_large = Array::<f32, _>::uninit((3, 7, 29, 36760123, 823996703));
 }
 
+#[should_panic(expected = "ndarray: Shape too large")]
+#[test]
+fn deny_overflow_zeros_usize_max()
+{
+    let _large = Array::<u8, _>::zeros((usize::MAX, 2));
+}
+
+#[should_panic(expected = "ndarray: Shape too large")]
+#[test]
+fn deny_overflow_bytes_from_elem()
+{
+    // the number of elements fits in isize, but the number of bytes does not
+    let _large = Array::<u64, _>::from_elem((isize::MAX as usize / 4, 1), 0);
+}
+
+#[should_panic(expected = "ndarray: Shape too large")]
+#[test]
+fn deny_overflow_bytes_uninit()
+{
+    let _large = Array::<[u8; 3], _>::uninit(isize::MAX as usize / 2);
+}
+
+#[test]
+fn overflow_zero_size_elements()
+{
+    // elements of size zero do not use memory, only the element count is limited
+    let a = Array::<(), _>::from_elem((isize::MAX as usize / 2, 2), ());
+    assert_eq!(a.len(), isize::MAX as usize - 1);
+    assert_eq!(Array::<u64, _>::zeros((isize::MAX as usize, 0)).len(), 0);
+}
+
+#[test]
+fn overflow_fallible_constructors()
+{
+    // a broadcast view with a large length
+    let len = isize::MAX as usize / 8;
+    let one = array![1u64];
+    let view = one.broadcast(len).unwrap();
+    let err = ndarray::concatenate(Axis(0), &[view, view]).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::Overflow);
+    let err = ndarray::stack(Axis(0), &[view, view, view]).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::Overflow);
+    let err = Array2::<f64>::one_hot(&array![0], len * 2).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::Overflow);
+}
+
 #[should_panic]
 #[test]
 fn deny_slice_with_too_many_rows_to_arrayview2()
@@ -333,3 +565,151 @@ fn maybe_uninit_1()
         Zip::from(u).for_each(|ptr| assert_eq!(*ptr, 1.));
     }
 }
+
+#[test]
+fn test_nested_vec_round_trip()
+{
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let a = Array2::from_shape_vec((2, 3), rows.concat()).unwrap();
+    assert_eq!(a.clone().into_nested_vec(), rows);
+    assert_eq!(a.clone().reversed_axes().into_nested_vec(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+    let mut b = a.clone();
+    b.slice_collapse(s![1.., ..;-1]);
+    assert_eq!(b.into_nested_vec(), vec![vec![6, 5, 4]]);
+    assert_eq!(Array2::<i32>::zeros((0, 3)).into_nested_vec(), Vec::<Vec<i32>>::new());
+    assert_eq!(Array2::<i32>::zeros((2, 0)).into_nested_vec(), vec![vec![]; 2]);
+
+    // non-Clone elements are moved
+    let c = Array2::from_shape_fn((2, 2), |(i, j)| Box::new(i * 2 + j));
+    assert_eq!(c.into_nested_vec(), vec![vec![Box::new(0), Box::new(1)], vec![Box::new(2), Box::new(3)]]);
+}
+
+#[test]
+fn test_fixed_rows_round_trip_without_copy()
+{
+    let rows: Vec<[f64; 3]> = (0..10)
+        .map(|i| [i as f64, i as f64 + 0.5, -(i as f64)])
+        .collect();
+    let input = rows.clone();
+    let ptr = input.as_ptr() as *const f64;
+    let a = Array2::from(input);
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a.shape(), &[10, 3]);
+    assert_eq!(a.row(4), aview1(&[4., 4.5, -4.]));
+
+    let back = a.try_into_fixed_rows::<3>().unwrap();
+    assert_eq!(back.as_ptr() as *const f64, ptr);
+    assert_eq!(back, rows);
+
+    // a standard layout array that does not use all of its allocation
+    let mut b = Array2::from(rows.clone());
+    b.slice_collapse(s![2..5, ..]);
+    assert_eq!(b.try_into_fixed_rows::<3>().unwrap(), &rows[2..5]);
+
+    // zero-sized rows and elements
+    let e = Array2::<u8>::zeros((4, 0));
+    assert_eq!(e.try_into_fixed_rows::<0>().unwrap().len(), 4);
+    let z = Array2::from(vec![[(); 2]; 5]);
+    assert_eq!(z.try_into_fixed_rows::<2>().unwrap(), vec![[(); 2]; 5]);
+}
+
+#[test]
+fn test_fixed_rows_errors()
+{
+    use ndarray::ErrorKind;
+
+    let a = Array2::<i32>::zeros((3, 2));
+    assert_eq!(a.clone().try_into_fixed_rows::<3>().unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(a.reversed_axes().try_into_fixed_rows::<3>().unwrap_err().kind(), ErrorKind::IncompatibleLayout);
+}
+
+#[test]
+fn test_vec_and_array1_conversions()
+{
+    let v = vec![1., 2., 3., 4.];
+    let ptr = v.as_ptr();
+    let a = Array1::from(v);
+    assert_eq!(a.as_ptr(), ptr);
+    let w = Vec::from(a);
+    assert_eq!(w.as_ptr(), ptr);
+    assert_eq!(w, [1., 2., 3., 4.]);
+
+    let b: Box<[f64]> = w.into_boxed_slice();
+    let ptr = b.as_ptr();
+    let a: ArcArray<f64, Ix1> = b.into();
+    assert_eq!(a.as_ptr(), ptr);
+
+    assert_eq!(Array1::from([1, 2, 3]), arr1(&[1, 2, 3]));
+    assert_eq!(ArrayView1::from(&[1, 2, 3]), arr1(&[1, 2, 3]));
+    let mut xs = [1, 2, 3];
+    ArrayViewMut1::from(&mut xs).fill(0);
+    assert_eq!(xs, [0; 3]);
+
+    // sliced: the allocation is kept, and the elements are in logical order
+    let v: Vec<_> = (0..10).map(|i| i.to_string()).collect();
+    let ptr = v.as_ptr();
+    let mut a = Array1::from(v);
+    a.slice_collapse(s![3..6]);
+    let w = Vec::from(a);
+    assert_eq!(w, ["3", "4", "5"]);
+    assert_eq!(w.as_ptr(), ptr);
+
+    let mut a = Array1::from((0..10).collect::<Vec<i32>>());
+    a.slice_collapse(s![..;-3]);
+    assert_eq!(Vec::from(a), [9, 6, 3, 0]);
+    assert_eq!(Vec::from(Array1::<i32>::zeros(0)), Vec::<i32>::new());
+}
+
+#[test]
+fn test_from_shape_exact_iter()
+{
+    use std::cell::Cell;
+
+    let a = Array::from_shape_exact_iter((2, 3), (0..6).map(|i| i.to_string())).unwrap();
+    assert_eq!(a, arr2(&[[0, 1, 2], [3, 4, 5]]).map(|i| i.to_string()));
+    assert_eq!(a.into_raw_vec_and_offset().0.capacity(), 6);
+
+    let f = Array::from_shape_exact_iter((2, 3).f(), 0..6).unwrap();
+    assert_eq!(f, arr2(&[[0, 2, 4], [1, 3, 5]]));
+    assert!(f.t().is_standard_layout());
+
+    let d = Array::from_shape_exact_iter(IxDyn(&[2, 0, 3]), 0..0).unwrap();
+    assert_eq!(d.shape(), &[2, 0, 3]);
+
+    // a wrong length is detected before consuming any element
+    let consumed = Cell::new(0);
+    let res = Array::from_shape_exact_iter((2, 3), (0..5).inspect(|_| consumed.set(consumed.get() + 1)));
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(consumed.get(), 0);
+}
+
+/// An iterator with a `len()` that does not match the number of elements.
+struct Lying<I>(I, usize);
+
+impl<I: Iterator> Iterator for Lying<I>
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item>
+    {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.1, Some(self.1))
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for Lying<I> {}
+
+#[test]
+fn test_from_shape_exact_iter_lying_len()
+{
+    let short = Array::from_shape_exact_iter((2, 3), Lying((0..4).map(|i| i.to_string()), 6));
+    assert_eq!(short.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    let long = Array::from_shape_exact_iter((2, 3).f(), Lying(0..7, 6));
+    assert_eq!(long.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    let exact = Array::from_shape_exact_iter((3, 2), Lying(0..6, 6)).unwrap();
+    assert_eq!(exact, arr2(&[[0, 1], [2, 3], [4, 5]]));
+}
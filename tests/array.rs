@@ -11,7 +11,7 @@ use itertools::{zip, Itertools};
 use ndarray::indices;
 use ndarray::prelude::*;
 use ndarray::{arr3, rcarr2};
-use ndarray::{Slice, SliceInfo, SliceInfoElem};
+use ndarray::{Slice, SliceInfo, SliceInfoBuilder, SliceInfoElem};
 use num_complex::Complex;
 use std::convert::TryFrom;
 
@@ -125,6 +125,45 @@ fn test_slice_inclusive_range()
     assert_eq!(arr.slice(s![0..=-1, 0..=2;2]), array![[1, 3], [4, 6]]);
 }
 
+#[test]
+fn test_slice_range_forms()
+{
+    use ndarray::{SliceInfo, SliceInfoElem};
+
+    let a = Array::from_iter(0..10);
+    assert_eq!(a.slice(s![1..4]), aview1(&[1, 2, 3]));
+    assert_eq!(a.slice(s![1..7;2]), aview1(&[1, 3, 5]));
+    assert_eq!(a.slice(s![7..]), aview1(&[7, 8, 9]));
+    assert_eq!(a.slice(s![7..;-1]), aview1(&[9, 8, 7]));
+    assert_eq!(a.slice(s![..3]), aview1(&[0, 1, 2]));
+    assert_eq!(a.slice(s![..=2;2]), aview1(&[0, 2]));
+    assert_eq!(a.slice(s![..;4]), aview1(&[0, 4, 8]));
+    // negative bounds count from the end
+    assert_eq!(a.slice(s![-3..]), aview1(&[7, 8, 9]));
+    assert_eq!(a.slice(s![-5..-2;2]), aview1(&[5, 7]));
+    assert_eq!(a.slice(s![..-8]), aview1(&[0, 1]));
+    // a single index removes the axis
+    assert_eq!(a.slice(s![-1]), aview0(&9));
+
+    let b = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    assert_eq!(b.slice(s![1, 1..3;1, -3..;2,]), b.slice(s![1, 1..3, -3..;2]));
+    // the macro produces a fixed size array of elements, one per argument
+    let info: SliceInfo<[SliceInfoElem; 3], Ix3, Ix2> = s![1..4;2, .., -3];
+    assert_eq!(info.as_ref().len(), 3);
+    assert_eq!(info.in_ndim(), 3);
+    assert_eq!(info.out_ndim(), 2);
+
+    // conversions from the standard range types
+    assert_eq!(Slice::from(1..4), Slice::new(1, Some(4), 1));
+    assert_eq!(Slice::from(-3..), Slice::new(-3, None, 1));
+    assert_eq!(Slice::from(..4), Slice::new(0, Some(4), 1));
+    assert_eq!(Slice::from(..), Slice::new(0, None, 1));
+    assert_eq!(SliceInfoElem::from(1..4), SliceInfoElem::Slice { start: 1, end: Some(4), step: 1 });
+    assert_eq!(SliceInfoElem::from(-2), SliceInfoElem::Index(-2));
+}
+
 /// Test that the compiler can infer a type for a sliced array from the
 /// arguments to `s![]`.
 ///
@@ -206,6 +245,69 @@ fn test_slice_args_eval_step_once()
     assert_eq!(eval_count, 1);
 }
 
+#[test]
+fn test_slice_info_builder()
+{
+    // per-axis (start, end, step) as read from a configuration at runtime
+    let config: Vec<(isize, Option<isize>, isize)> = vec![(1, Some(4), 1), (0, None, 2), (-1, None, -1)];
+    let mut arr = Array3::from_shape_fn((5, 6, 7), |(i, j, k)| i * 100 + j * 10 + k);
+
+    let info = config
+        .iter()
+        .fold(SliceInfoBuilder::new(), |b, &(start, end, step)| b.slice(start, end, step))
+        .build::<Ix3, Ix3>()
+        .unwrap();
+    let expected = arr.slice(s![1..4, ..;2, -1..;-1]).to_owned();
+    assert_eq!(arr.slice(&info), expected);
+    assert_eq!(arr.view().slice_move(&info), expected);
+    arr.slice_mut(&info).fill(999);
+    assert!(arr.slice(s![1..4, ..;2, -1..]).iter().all(|&x| x == 999));
+    assert_eq!(arr.iter().filter(|&&x| x == 999).count(), 3 * 3);
+
+    // indices and new axes, with a dynamic output dimension
+    let arr = Array3::from_shape_fn((5, 6, 7), |(i, j, k)| i * 100 + j * 10 + k);
+    let info = SliceInfoBuilder::new()
+        .index(-2)
+        .new_axis()
+        .full()
+        .push(2..5)
+        .build::<Ix3, IxDyn>()
+        .unwrap();
+    assert_eq!(arr.slice(&info), arr.slice(s![-2, NewAxis, .., 2..5]).into_dyn());
+
+    // the number of elements is checked against fixed dimensions
+    let b = SliceInfoBuilder::new().full().index(1);
+    assert_eq!(b.len(), 2);
+    assert!(b.clone().build::<Ix3, IxDyn>().is_err());
+    assert!(b.clone().build::<Ix2, Ix2>().is_err());
+    assert!(b.build::<Ix2, Ix1>().is_ok());
+
+    // arrays with dynamic dimension and a number of axes known at runtime
+    let dyn_arr = arr.clone().into_dyn();
+    let info: SliceInfoBuilder = (0..dyn_arr.ndim())
+        .map(|i| Slice::new(i as isize, None, 1))
+        .collect();
+    let info = info.build::<IxDyn, IxDyn>().unwrap();
+    assert_eq!(dyn_arr.slice(&info), arr.slice(s![0.., 1.., 2..]).into_dyn());
+    let mut b = SliceInfoBuilder::new();
+    b.extend(vec![SliceInfoElem::from(1), SliceInfoElem::from(..), SliceInfoElem::from(2..)]);
+    let info = b.build::<IxDyn, IxDyn>().unwrap();
+    assert_eq!(dyn_arr.slice(&info), arr.slice(s![1, .., 2..]).into_dyn());
+}
+
+#[test]
+#[should_panic]
+fn test_slice_info_builder_wrong_ndim()
+{
+    let arr = ArrayD::<f64>::zeros(IxDyn(&[2, 3, 4]));
+    let info = SliceInfoBuilder::new()
+        .full()
+        .full()
+        .build::<IxDyn, IxDyn>()
+        .unwrap();
+    arr.slice(&info);
+}
+
 #[test]
 fn test_slice_array_fixed()
 {
@@ -322,6 +424,121 @@ fn test_slice_dyninput_vec_dyn()
     arr.view().slice_collapse(info2);
 }
 
+/// The indices selected by `slice` from an axis of length `len`, by the
+/// documented rules for slicing, or `None` if it is out of bounds.
+fn reference_slice_indices(len: usize, slice: Slice) -> Option<Vec<usize>>
+{
+    let resolve = |i: isize| if i < 0 { i + len as isize } else { i };
+    let start = resolve(slice.start);
+    let end = resolve(slice.end.unwrap_or(len as isize));
+    if !(0..=len as isize).contains(&start) || !(0..=len as isize).contains(&end) {
+        return None;
+    }
+    let end = end.max(start);
+    let mut indices: Vec<usize> = (start as usize..end as usize).collect();
+    if slice.step < 0 {
+        indices.reverse();
+    }
+    Some(
+        indices
+            .into_iter()
+            .step_by(slice.step.unsigned_abs())
+            .collect(),
+    )
+}
+
+#[test]
+fn test_slice_exhaustive()
+{
+    let len = 7;
+    let a = Array::from_iter(0..len);
+    let bound = len as isize + 2;
+    let mut slices = Vec::new();
+    for start in -bound..=bound {
+        for end in (-bound..=bound).map(Some).chain(Some(None)) {
+            for step in (-bound..=bound).filter(|&step| step != 0) {
+                slices.push(Slice::new(start, end, step));
+            }
+        }
+    }
+    for &slice in &slices {
+        match reference_slice_indices(len, slice) {
+            Some(indices) => {
+                let v = a.slice_axis(Axis(0), slice);
+                assert_eq!(v.to_vec(), indices, "{:?}", slice);
+                let expected_stride = if indices.len() > 1 { slice.step } else { 0 };
+                assert_eq!(v.strides(), &[expected_stride], "{:?}", slice);
+
+                // slicing a reversed view again
+                let r = a.slice(s![..;-1]);
+                let reversed: Vec<usize> = indices.iter().map(|&i| len - 1 - i).collect();
+                assert_eq!(r.slice_axis(Axis(0), slice).to_vec(), reversed, "{:?}", slice);
+
+                // and the result of another slice
+                for &second in &[Slice::new(-1, None, -2), Slice::new(1, Some(-1), 3)] {
+                    if let Some(second_indices) = reference_slice_indices(indices.len(), second) {
+                        let twice: Vec<usize> = second_indices.iter().map(|&i| indices[i]).collect();
+                        assert_eq!(v.slice_axis(Axis(0), second).to_vec(), twice, "{:?} {:?}", slice, second);
+                    }
+                }
+            }
+            None => {
+                let result = std::panic::catch_unwind(|| a.slice_axis(Axis(0), slice).len());
+                assert!(result.is_err(), "{:?} should be out of bounds", slice);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_slice_dyn_elems()
+{
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| 100 * i + 10 * j + k).into_dyn();
+    let expected = a.slice(s![1..3, 2, ..;2]).into_dyn();
+
+    let array = [SliceInfoElem::from(1..3), SliceInfoElem::from(2), SliceInfoElem::from(Slice::from(..).step_by(2))];
+    assert_eq!(a.slice(array), expected);
+    assert_eq!(a.slice(&array), expected);
+    assert_eq!(a.slice(&array[..]), expected);
+
+    let vec = array.to_vec();
+    assert_eq!(a.slice(&vec), expected);
+    assert_eq!(a.view().slice_move(vec.clone()), expected);
+
+    let mut b = a.clone();
+    b.slice_mut(vec.clone()).fill(0);
+    assert_eq!(b.slice(&vec).sum(), 0);
+    assert_eq!(b.sum() + expected.sum(), a.sum());
+
+    let mut c = a.view();
+    c.slice_collapse(vec![SliceInfoElem::from(1..3), SliceInfoElem::from(2), SliceInfoElem::from(..)]);
+    assert_eq!(c.shape(), &[2, 1, 6]);
+}
+
+#[test]
+#[should_panic(expected = "`info` has 2 axes, but the array has shape [4, 5, 6]")]
+fn test_slice_dyn_elems_wrong_ndim()
+{
+    let a = ArrayD::<i32>::zeros(IxDyn(&[4, 5, 6]));
+    let _ = a.slice(vec![SliceInfoElem::from(..), SliceInfoElem::from(0)]);
+}
+
+#[test]
+fn test_slice_dyn_elems_generic()
+{
+    fn first_rows<D: Dimension>(a: &Array<usize, D>, n: usize) -> ArrayD<usize>
+    {
+        let mut info = vec![SliceInfoElem::from(..); a.ndim()];
+        info[0] = SliceInfoElem::from(..n);
+        a.view().into_dyn().slice_move(info).to_owned()
+    }
+
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| 100 * i + 10 * j + k);
+    assert_eq!(first_rows(&a, 2), a.slice(s![..2, .., ..]).into_dyn());
+    let b = a.index_axis(Axis(2), 0).to_owned();
+    assert_eq!(first_rows(&b, 3), b.slice(s![..3, ..]).into_dyn());
+}
+
 #[test]
 fn test_slice_with_subview_and_new_axis()
 {
@@ -407,6 +624,42 @@ fn test_slice_collapse_with_newaxis()
     arr.slice_collapse(s![0, 0, NewAxis]);
 }
 
+#[test]
+fn test_slice_token_combinations()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+
+    // one axis collapsed and one inserted keeps the number of axes
+    let v: ArrayView2<'_, _> = a.slice(s![.., NewAxis, 0]);
+    assert_eq!(v, array![[0], [10], [20]]);
+    let v: ArrayView3<'_, _> = a.slice(s![NewAxis, .., 1..3]);
+    assert_eq!(v, array![[[1, 2], [11, 12], [21, 22]]]);
+    let v: ArrayView0<'_, _> = a.slice(s![1, -1]);
+    assert_eq!(v, aview0(&13));
+    let v: ArrayView3<'_, _> = a.slice(s![NewAxis, NewAxis, 2, ..;-2]);
+    assert_eq!(v, array![[[23, 21]]]);
+    let v: ArrayView4<'_, _> = a.slice(s![1..;2, NewAxis, ..;3, NewAxis]);
+    assert_eq!(v.shape(), &[1, 1, 2, 1]);
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), [10, 13]);
+    let v: ArrayView1<'_, _> = a.slice(s![0, NewAxis, 2]);
+    assert_eq!(v, array![2]);
+
+    // with dynamic input dimension, the output dimension type is still
+    // determined by the slice argument
+    let d = a.view().into_dyn();
+    let v: ArrayView2<'_, _> = d.slice(s![.., NewAxis, 0]);
+    assert_eq!(v, array![[0], [10], [20]]);
+    let v: ArrayView1<'_, _> = d.slice(s![NewAxis, 1, 2]);
+    assert_eq!(v, array![12]);
+
+    // the same combinations for mutable and consuming slicing
+    let mut b = a.clone();
+    b.slice_mut(s![NewAxis, 1, ..;2]).fill(0);
+    assert_eq!(b.row(1), array![0, 11, 0, 13]);
+    let moved: Array3<_> = b.slice_move(s![.., NewAxis, 1..2]);
+    assert_eq!(moved.shape(), &[3, 1, 1]);
+}
+
 #[test]
 fn test_multislice()
 {
@@ -690,6 +943,121 @@ fn test_cow_shrink()
     assert_eq!(before[1], 5);
 }
 
+#[test]
+fn test_cow_preserves_layout()
+{
+    use std::rc::Rc;
+
+    // whole array shared: the buffer is cloned with its strides
+    let mut f = ArcArray::from_shape_fn((3, 4).f(), |(i, j)| i * 10 + j);
+    let before = f.clone();
+    f[[0, 0]] = 100;
+    assert_eq!(f.strides(), &[1, 3]);
+    assert_eq!(before[[0, 0]], 0);
+
+    // small non-contiguous part shared: only the visible elements are
+    // copied, in F order
+    let g = ArcArray::from_shape_fn((6, 8).f(), |(i, j)| i * 10 + j);
+    let mut part = g.clone();
+    part.slice_collapse(s![..3;1, ..;4]);
+    assert!(!part.is_standard_layout() && !part.is_fortran_layout());
+    part[[1, 1]] = 100;
+    assert!(part.is_fortran_layout());
+    assert_eq!(part, array![[0, 4], [10, 100], [20, 24]]);
+    assert_eq!(g[[1, 4]], 14);
+
+    // small contiguous part of a transposed array: keeps its strides
+    let t = ArcArray::from_shape_fn((8, 6), |(i, j)| i * 10 + j).reversed_axes();
+    let mut part = t.clone();
+    part.slice_collapse(s![.., ..2]);
+    part[[0, 0]] = 100;
+    assert_eq!(part.strides(), &[1, 6]);
+    assert_eq!(t[[0, 0]], 0);
+
+    // large part shared: still only the visible elements are copied
+    let marker = Rc::new(());
+    let h = ArcArray::from_elem((5, 4), marker.clone());
+    let mut part = h.clone();
+    part.slice_collapse(s![..3, ..]);
+    part[[0, 0]] = Rc::new(());
+    assert_eq!(Rc::strong_count(&marker), 1 + 20 + 11);
+}
+
+#[test]
+fn test_cow_unshared_is_not_copied()
+{
+    let mut a = ArcArray::from_shape_fn((4, 5).f(), |(i, j)| i * 10 + j);
+    a.slice_collapse(s![1.., ..;2]);
+    let ptr = a.as_ptr();
+    let strides = a.strides().to_vec();
+    a[[0, 0]] = 100;
+    a.map_inplace(|x| *x += 1);
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a.strides(), &strides[..]);
+
+    // a dropped clone does not cause a copy either
+    drop(a.clone());
+    a[[1, 1]] = 0;
+    assert_eq!(a.as_ptr(), ptr);
+}
+
+#[test]
+fn test_arcarray_shared_slices()
+{
+    let a = ArcArray::from_shape_fn((4, 6), |(i, j)| i * 10 + j);
+    let range = a.as_slice().unwrap().as_ptr_range();
+
+    let mut big = a.slice_shared(s![..3, ..;-1]);
+    let mut row = a.index_axis_shared(Axis(0), 2);
+    let col = a.clone().slice_move(s![.., 1]);
+    assert_eq!(a.strong_count(), 4);
+    assert_eq!(big, a.slice(s![..3, ..;-1]));
+    assert_eq!(row, array![20, 21, 22, 23, 24, 25]);
+    assert_eq!(col, array![1, 11, 21, 31]);
+    for p in [big.as_ptr(), row.as_ptr(), col.as_ptr()] {
+        assert!(range.contains(&p));
+    }
+
+    // mutating a shared slice copies only its elements, even if it covers
+    // most of the buffer, and leaves the other handles as they were
+    big[[0, 0]] = 100;
+    assert_eq!(big.len(), 18);
+    assert!(!range.contains(&big.as_ptr()));
+    assert_eq!(a.strong_count(), 3);
+    assert_eq!(a[[0, 5]], 5);
+    assert_eq!(big[[0, 0]], 100);
+    assert_eq!(big.slice(s![.., 1..]), a.slice(s![..3, ..5;-1]));
+
+    row[0] = 200;
+    assert_eq!(a.strong_count(), 2);
+    assert_eq!(a[[2, 0]], 20);
+    assert_eq!(row, array![200, 21, 22, 23, 24, 25]);
+    assert_eq!(col[2], 21);
+
+    // a slice that covers the whole buffer keeps its strides
+    let mut whole = a.slice_shared(s![.., ..]);
+    whole[[0, 0]] = 300;
+    assert_eq!(whole.strides(), a.strides());
+    assert_eq!(a[[0, 0]], 0);
+}
+
+#[test]
+fn test_arcarray_shared_slice_keeps_buffer_alive()
+{
+    use std::rc::Rc;
+
+    let marker = Rc::new(());
+    let a = ArcArray::from_elem((3, 4), marker.clone());
+    assert_eq!(Rc::strong_count(&marker), 13);
+    let corner = a.slice_shared(s![..1, ..1]);
+    drop(a);
+    // the whole buffer is alive while the slice is
+    assert_eq!(Rc::strong_count(&marker), 13);
+    assert_eq!(corner.strong_count(), 1);
+    drop(corner);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn test_sub()
@@ -722,6 +1090,46 @@ fn test_sub_oob_1()
     mat.index_axis(Axis(0), 2);
 }
 
+#[test]
+fn index_axis_matches_slice()
+{
+    let a = Array::from_iter(0..60)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    for j in 0..4 {
+        let sliced = a
+            .slice(s![.., j..j + 1, ..])
+            .to_shape((3, 5))
+            .unwrap()
+            .into_owned();
+        assert_eq!(a.index_axis(Axis(1), j), sliced);
+        assert_eq!(a.index_axis(Axis(1), j), a.slice(s![.., j, ..]));
+        assert_eq!(a.clone().index_axis_move(Axis(1), j), sliced);
+
+        let mut b = a.clone();
+        b.index_axis_mut(Axis(1), j).fill(-1);
+        assert!(b.slice(s![.., j, ..]).iter().all(|&x| x == -1));
+        assert_eq!(b.iter().filter(|&&x| x == -1).count(), 15);
+
+        let mut c = a.view();
+        c.collapse_axis(Axis(1), j);
+        assert_eq!(c.shape(), &[3, 1, 5]);
+        assert_eq!(c, a.slice(s![.., j..j + 1, ..]));
+    }
+    let mut d = a.view().into_dyn();
+    d.collapse_axis(Axis(2), 4);
+    assert_eq!(d.ndim(), 3);
+    assert_eq!(d, a.slice(s![.., .., 4..]).into_dyn());
+}
+
+#[test]
+#[should_panic(expected = "Index 4 must be less than axis length 4 along Axis(1)")]
+fn collapse_axis_oob()
+{
+    let mut a = Array3::<u8>::zeros((3, 4, 5));
+    a.collapse_axis(Axis(1), 4);
+}
+
 #[test]
 #[cfg(feature = "approx")]
 fn test_select()
@@ -761,6 +1169,207 @@ fn test_select_1d()
     assert_eq!(r3, arr1(&[]));
 }
 
+#[test]
+fn test_filter_axis()
+{
+    let a = Array::from_shape_fn((4, 3), |(i, j)| (i * 3 + j) as i32);
+    // all match, none match, interleaved
+    assert_eq!(a.filter_axis(Axis(0), |_| true), a);
+    let none = a.filter_axis(Axis(0), |_| false);
+    assert_eq!(none.shape(), &[0, 3]);
+    assert_eq!(a.filter_axis(Axis(0), |row| row[0] % 2 == 0), arr2(&[[0, 1, 2], [6, 7, 8]]));
+    assert_eq!(a.filter_axis(Axis(1), |col| col.sum() != 22), arr2(&[[0, 2], [3, 5], [6, 8], [9, 11]]));
+    assert_eq!(a.filter_axis(Axis(1), |_| false).shape(), &[4, 0]);
+
+    let b = Array::from_shape_fn((3, 4, 2), |(i, j, k)| i * 100 + j * 10 + k);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        assert_eq!(b.filter_axis(axis, |_| true), b);
+        let mut expected_len = b.shape().to_vec();
+        expected_len[axis.index()] = 0;
+        assert_eq!(b.filter_axis(axis, |_| false).shape(), &expected_len[..]);
+        let mut i = 0;
+        let odd = b.filter_axis(axis, |_| {
+            i += 1;
+            i % 2 == 0
+        });
+        let indices: Vec<_> = (1..b.len_of(axis)).step_by(2).collect();
+        assert_eq!(odd, b.select(axis, &indices));
+    }
+    // from a view with negative strides
+    let rev = b.slice(s![..;-1, .., ..;-1]);
+    assert_eq!(rev.filter_axis(Axis(0), |m| m[[0, 0]] != 101), arr3(&[[[201, 200], [211, 210], [221, 220], [231, 230]], [[1, 0], [11, 10], [21, 20], [31, 30]]]));
+
+    let c = arr1(&[3, 1, 4, 1, 5]);
+    assert_eq!(c.filter_axis(Axis(0), |x| x[()] > 2), arr1(&[3, 4, 5]));
+    assert_eq!(c.filter_axis(Axis(0), |_| false), arr1(&[]));
+}
+
+#[test]
+fn test_partition_axis()
+{
+    let a = Array::from_shape_fn((5, 2), |(i, j)| (i * 2 + j) as i32);
+    let (even, odd) = a.partition_axis(Axis(0), |row| row[0] % 4 == 0);
+    assert_eq!(even, arr2(&[[0, 1], [4, 5], [8, 9]]));
+    assert_eq!(odd, arr2(&[[2, 3], [6, 7]]));
+
+    let (all, none) = a.partition_axis(Axis(1), |_| true);
+    assert_eq!(all, a);
+    assert_eq!(none.shape(), &[5, 0]);
+    let (none, all) = a.partition_axis(Axis(1), |_| false);
+    assert_eq!(none.shape(), &[5, 0]);
+    assert_eq!(all, a);
+
+    let b = Array::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+    let (small, large) = b.partition_axis(Axis(2), |m| m.sum() < 372);
+    assert_eq!(small, b.select(Axis(2), &[0, 1]));
+    assert_eq!(large, b.select(Axis(2), &[2, 3]));
+}
+
+#[test]
+fn test_unique()
+{
+    let a = arr1(&[5, 3, 9, 3, 5, 5, 1]);
+    assert_eq!(a.unique(), arr1(&[1, 3, 5, 9]));
+    let (values, counts) = a.slice(s![..;-1]).unique_counts();
+    assert_eq!(values, arr1(&[1, 3, 5, 9]));
+    assert_eq!(counts, arr1(&[1, 2, 3, 1]));
+
+    let empty = Array1::<i32>::zeros(0);
+    assert_eq!(empty.unique(), empty);
+    assert_eq!(empty.unique_counts().1.len(), 0);
+}
+
+#[test]
+fn test_unique_axis()
+{
+    // duplicate rows are not adjacent
+    let a = arr2(&[[3, 1, 2], [0, 4, 4], [3, 1, 2], [3, 0, 9], [0, 4, 4], [3, 1, 2]]);
+    let (unique, inverse) = a.unique_axis_inverse(Axis(0));
+    assert_eq!(unique, arr2(&[[0, 4, 4], [3, 0, 9], [3, 1, 2]]));
+    assert_eq!(inverse, arr1(&[2, 0, 2, 1, 0, 2]));
+    assert_eq!(unique.select(Axis(0), inverse.as_slice().unwrap()), a);
+    assert_eq!(a.unique_axis(Axis(0)), unique);
+
+    // columns of the transposed array are the same lanes
+    assert_eq!(a.t().unique_axis(Axis(1)), unique.t());
+    assert_eq!(a.unique_axis(Axis(1)), a.select(Axis(1), &[1, 2, 0]));
+
+    // 3-D, deduplicating along the middle axis
+    let b = Array::from_shape_fn((2, 5, 3), |(i, j, k)| (i * 10 + (j % 2) * 3 + k) as f64);
+    let (unique, inverse) = b.unique_axis_inverse(Axis(1));
+    assert_eq!(unique, b.select(Axis(1), &[0, 1]));
+    assert_eq!(inverse, arr1(&[0, 1, 0, 1, 0]));
+
+    // NaN is equal to itself and sorts after all other elements
+    let nan = f64::NAN;
+    let c = arr2(&[[1., nan], [1., 2.], [nan, 0.], [1., nan], [1., 2.]]);
+    let (unique, inverse) = c.unique_axis_inverse(Axis(0));
+    assert_eq!(unique.shape(), &[3, 2]);
+    assert_eq!(unique.row(0), arr1(&[1., 2.]));
+    assert!(unique[[1, 1]].is_nan() && unique[[2, 0]].is_nan());
+    assert_eq!(inverse, arr1(&[1, 0, 2, 1, 0]));
+    assert_eq!(arr1(&[2., nan, 1., nan]).unique_axis(Axis(0)).len(), 3);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    let (unique, inverse) = empty.unique_axis_inverse(Axis(0));
+    assert_eq!(unique.shape(), &[0, 3]);
+    assert_eq!(inverse.len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "unique_axis requires elements that are comparable with each other, except NaN")]
+fn test_unique_axis_incomparable()
+{
+    // ordered by inclusion: neither of 0b01 and 0b10 is a subset of the other
+    #[derive(Clone, Debug, PartialEq)]
+    struct Set(u8);
+
+    impl PartialOrd for Set
+    {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
+        {
+            match (self.0 & other.0 == self.0, self.0 & other.0 == other.0) {
+                (true, true) => Some(std::cmp::Ordering::Equal),
+                (true, false) => Some(std::cmp::Ordering::Less),
+                (false, true) => Some(std::cmp::Ordering::Greater),
+                (false, false) => None,
+            }
+        }
+    }
+
+    let a = Array::from(vec![Set(0b01), Set(0b10)]);
+    a.unique_axis(Axis(0));
+}
+
+#[test]
+#[should_panic]
+fn test_filter_axis_out_of_bounds()
+{
+    let a = Array::<i32, _>::zeros((2, 2));
+    let _ = a.filter_axis(Axis(2), |_| true);
+}
+
+#[test]
+fn test_index_fill_and_assign()
+{
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    a.index_fill(Axis(1), &[3, 1, 3], -1);
+    assert_eq!(a, arr2(&[[0, -1, 2, -1], [4, -1, 6, -1], [8, -1, 10, -1]]));
+
+    let src = arr2(&[[10, 11, 12, 13], [20, 21, 22, 23], [30, 31, 32, 33]]);
+    a.index_assign(Axis(0), &[2, 0, 2], &src);
+    // the last write to a repeated index wins
+    assert_eq!(a, arr2(&[[20, 21, 22, 23], [4, -1, 6, -1], [30, 31, 32, 33]]));
+
+    // inverse of select
+    let x = Array::from_shape_fn((5, 2, 3), |(i, j, k)| i * 100 + j * 10 + k);
+    let indices = [4, 0, 2];
+    let mut y = Array::zeros(x.raw_dim());
+    y.index_assign(Axis(0), &indices, &x.select(Axis(0), &indices));
+    for &i in &indices {
+        assert_eq!(y.index_axis(Axis(0), i), x.index_axis(Axis(0), i));
+    }
+}
+
+#[test]
+fn test_scatter_add_repeated_indices()
+{
+    let indices = [0, 3, 3, 1, 0, 3];
+    let src = Array::from_shape_fn((6, 2), |(i, j)| (i * 2 + j) as f64);
+    let mut a = Array::<f64, _>::ones((4, 2));
+    a.scatter_add(Axis(0), &indices, &src);
+
+    let mut expected = Array::<f64, _>::ones((4, 2));
+    for (k, &i) in indices.iter().enumerate() {
+        for j in 0..2 {
+            expected[[i, j]] += src[[k, j]];
+        }
+    }
+    assert_eq!(a, expected);
+
+    // along the last axis of a transposed array
+    let mut b = Array::<i64, _>::zeros((3, 2)).reversed_axes();
+    b.scatter_add(Axis(1), &[2, 2, 2], &Array::from_elem((2, 3), 1));
+    assert_eq!(b, arr2(&[[0, 0, 3], [0, 0, 3]]));
+}
+
+#[test]
+#[should_panic(expected = "index 5 is out of bounds")]
+fn test_scatter_add_index_out_of_bounds()
+{
+    let mut a = Array::<i32, _>::zeros((3, 2));
+    a.scatter_add(Axis(0), &[0, 5], &Array::ones((2, 2)));
+}
+
+#[test]
+#[should_panic(expected = "does not match 2 indices")]
+fn test_index_assign_wrong_source_shape()
+{
+    let mut a = Array::<i32, _>::zeros((3, 2));
+    a.index_assign(Axis(0), &[0, 1], &Array::ones((3, 2)));
+}
+
 #[test]
 fn diag()
 {
@@ -775,6 +1384,33 @@ fn diag()
     assert_eq!(d.dim(), 1);
 }
 
+#[test]
+fn trace_and_symmetry()
+{
+    let a = arr2(&[[1., 2., 3.], [2., 5., -1.], [3., -1., 9.]]);
+    assert_eq!(a.trace(), 15.);
+    assert!(a.is_symmetric(0.));
+    assert!(a.t().is_symmetric(0.));
+
+    let mut b = a.clone();
+    b[[2, 1]] += 1e-6;
+    assert!(b.is_symmetric(1e-5));
+    assert!(!b.is_symmetric(1e-7));
+    assert!(!b.slice(s![..;-1, ..]).is_symmetric(1e-5));
+
+    // rectangular: the diagonal has min(m, n) elements
+    let r = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(r.trace(), 6);
+    assert_eq!(r.t().trace(), 6);
+    assert!(!r.is_symmetric(100));
+    assert!(!arr2(&[[0; 3]; 1]).is_symmetric(0));
+
+    let empty = Array2::<i32>::zeros((0, 0));
+    assert_eq!(empty.trace(), 0);
+    assert!(empty.is_symmetric(0));
+    assert_eq!(Array2::<i32>::zeros((0, 4)).trace(), 0);
+}
+
 /// Check that the merged shape is correct.
 ///
 /// Note that this does not check the strides in the "merged" case!
@@ -968,6 +1604,246 @@ fn standard_layout()
     assert!(x4.is_standard_layout());
 }
 
+#[test]
+fn layout_flags()
+{
+    // (shape, strides, C, F)
+    let cases: &[(&[usize], &[isize], bool, bool)] = &[
+        (&[], &[], true, true),
+        (&[3], &[1], true, true),
+        (&[3], &[2], false, false),
+        (&[3], &[-1], false, false),
+        (&[1], &[7], true, true),
+        (&[0], &[5], true, true),
+        (&[2, 3], &[3, 1], true, false),
+        (&[2, 3], &[1, 2], false, true),
+        (&[2, 3], &[6, 2], false, false),
+        (&[2, 3], &[-3, 1], false, false),
+        (&[1, 3], &[9, 1], true, true),
+        (&[3, 1], &[1, 9], true, true),
+        (&[1, 1], &[4, 4], true, true),
+        (&[2, 0], &[7, 3], true, true),
+        (&[2, 3, 4], &[12, 4, 1], true, false),
+        (&[2, 3, 4], &[1, 2, 6], false, true),
+        (&[2, 1, 4], &[4, 5, 1], true, false),
+        (&[2, 1, 4], &[1, 5, 2], false, true),
+        (&[2, 3, 4], &[12, 1, 3], false, false),
+    ];
+    let data = [0; 64];
+    for &(shape, strides, c, f) in cases {
+        // negative strides are made by inverting an axis
+        let abs_strides = strides.iter().map(|s| s.unsigned_abs()).collect::<Vec<_>>();
+        let mut v = ArrayView::from_shape(IxDyn(shape).strides(IxDyn(&abs_strides)), &data[..]).unwrap();
+        for (i, _) in strides.iter().enumerate().filter(|(_, &s)| s < 0) {
+            v.invert_axis(Axis(i));
+        }
+        let layout = v.layout();
+        assert_eq!(layout.is_c(), c, "C for {:?} {:?}", shape, v.strides());
+        assert_eq!(layout.is_f(), f, "F for {:?} {:?}", shape, v.strides());
+        assert_eq!(layout.is_contiguous(), c || f);
+        assert_eq!(v.is_standard_layout(), c);
+        assert_eq!(v.is_fortran_layout(), f);
+    }
+
+    let a = Array::from_shape_fn((3, 4).f(), |(i, j)| i * 4 + j);
+    assert!(a.layout().is_f() && !a.layout().is_c());
+    assert!(a.t().layout().is_c());
+    assert!(!a.slice(s![..;-1, ..]).layout().is_contiguous());
+    assert!(a.column(0).layout().is_c());
+}
+
+#[test]
+fn strides_predict_addresses()
+{
+    fn check<D: Dimension>(v: ArrayView<'_, i32, D>)
+    {
+        assert_eq!(v.strides().len(), v.shape().len());
+        for (i, stride) in v.strides().iter().enumerate() {
+            assert_eq!(v.stride_of(Axis(i)), *stride);
+        }
+        // the pattern of a dynamic dimensional index is the index itself
+        for (index, elt) in v.view().into_dyn().indexed_iter() {
+            let offset: isize = index
+                .slice()
+                .iter()
+                .zip(v.strides())
+                .map(|(&i, &s)| i as isize * s)
+                .sum();
+            assert_eq!(unsafe { v.as_ptr().offset(offset) }, elt as *const i32);
+        }
+    }
+
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| (i * 100 + j * 10 + k) as i32);
+    check(a.view());
+    check(a.slice(s![1.., ..;2, 3]));
+    check(a.slice(s![..;-1, 1..4, ..;-3]));
+    check(a.t());
+    check(a.view().into_dyn().slice_move(s![.., -1, ..;-2]));
+
+    let f = Array::from_shape_fn((4, 5).f(), |(i, j)| (i * 10 + j) as i32);
+    assert_eq!(f.strides(), &[1, 4]);
+    check(f.view());
+    check(f.slice(s![..;-2, 1..]));
+    assert_eq!(f.slice(s![..;-2, 1..]).strides(), &[-2, 4]);
+}
+
+#[test]
+fn transpose_inplace()
+{
+    for n in [0, 1, 2, 31, 32, 33, 70] {
+        let a = Array::from_shape_fn((n, n), |(i, j)| i * 100 + j);
+        let mut b = a.clone();
+        b.transpose_inplace();
+        assert_eq!(b, a.t());
+        assert!(b.is_standard_layout());
+
+        let mut f = a.t().to_owned();
+        f.transpose_inplace();
+        assert_eq!(f, a);
+        assert!(f.t().is_standard_layout());
+
+        let mut shared = a.to_shared();
+        let other = shared.clone();
+        shared.transpose_inplace();
+        assert_eq!(shared, a.t());
+        assert_eq!(other, a);
+    }
+    let mut a = Array::from_shape_fn((9, 8), |(i, j)| i * 10 + j);
+    let expected = a.slice(s![..;-2, 1..6]).t().to_owned();
+    a.slice_mut(s![..;-2, 1..6]).transpose_inplace();
+    assert_eq!(a.slice(s![..;-2, 1..6]), expected);
+}
+
+#[test]
+#[should_panic]
+fn transpose_inplace_not_square()
+{
+    Array2::<i32>::zeros((2, 3)).transpose_inplace();
+}
+
+#[test]
+fn blocked_standard_layout_copy()
+{
+    for &(m, n) in &[(1, 5), (5, 1), (32, 32), (33, 70), (70, 33), (100, 3)] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| format!("{},{}", i, j));
+        let t = a.t();
+        let std = t.as_standard_layout();
+        assert!(std.is_standard_layout());
+        assert_eq!(std, t);
+
+        // non-contiguous transposed views take the same path in to_owned
+        let sliced = t.slice(s![..;2, ..]);
+        let owned = sliced.to_owned();
+        assert!(owned.is_standard_layout());
+        assert_eq!(owned, sliced);
+
+        let rev = a.slice(s![..;-1, ..;-3]).reversed_axes();
+        assert_eq!(rev.as_standard_layout(), rev);
+        assert_eq!(rev.to_owned(), rev);
+    }
+}
+
+#[test]
+fn blocked_standard_layout_copy_nd()
+{
+    let a = Array::from_shape_fn((4, 5, 6, 3), |(i, j, k, l)| format!("{},{},{},{}", i, j, k, l));
+    let perms = [[3, 2, 1, 0], [0, 3, 1, 2], [2, 0, 3, 1], [1, 0, 2, 3]];
+    for perm in &perms {
+        let p = a.view().permuted_axes(*perm);
+        let std = p.as_standard_layout();
+        assert!(std.is_owned());
+        assert!(std.is_standard_layout());
+        assert_eq!(std, p);
+
+        let sliced = p.slice(s![..;-1, .., 1.., ..;2]);
+        assert_eq!(sliced.as_standard_layout(), sliced);
+        assert_eq!(sliced.to_owned(), sliced);
+        assert_eq!(sliced.into_dyn().as_standard_layout(), sliced.into_dyn());
+    }
+
+    // 3-D transposed
+    let b = Array::from_shape_fn((7, 9, 10), |(i, j, k)| (i * 100 + j * 10 + k) as f32);
+    let t = b.t();
+    let std = t.as_standard_layout();
+    assert!(std.is_standard_layout());
+    assert_eq!(std, t);
+    for ((k, j, i), &x) in std.indexed_iter() {
+        assert_eq!(x, b[[i, j, k]]);
+    }
+}
+
+#[test]
+fn into_standard_layout()
+{
+    // no-op when already in standard layout
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| i * 100 + j * 10 + k);
+    let ptr = a.as_ptr();
+    let a = a.into_standard_layout();
+    assert_eq!(a.as_ptr(), ptr);
+    let sliced = a.clone().slice_move(s![1.., .., ..]);
+    let sliced_ptr = sliced.as_ptr();
+    assert_eq!(sliced.into_standard_layout().as_ptr(), sliced_ptr);
+    assert_eq!(a.as_standard_layout().as_ptr(), ptr);
+
+    let t = a.clone().permuted_axes([2, 0, 1]);
+    let expected = t.as_standard_layout().into_owned();
+    let t = t.into_standard_layout();
+    assert!(t.is_standard_layout());
+    assert_eq!(t, expected);
+
+    // elements that need to be dropped are moved, not cloned
+    let s = Array::from_shape_fn((3, 5), |(i, j)| vec![i, j]);
+    let s = s
+        .reversed_axes()
+        .slice_move(s![..;-2, ..])
+        .into_standard_layout();
+    assert!(s.is_standard_layout());
+    assert_eq!(s, Array::from_shape_fn((3, 3), |(j, i)| vec![i, 4 - 2 * j]));
+}
+
+#[test]
+fn flip_and_rot90()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.fliplr(), arr2(&[[3, 2, 1], [6, 5, 4]]));
+    assert_eq!(a.flipud(), arr2(&[[4, 5, 6], [1, 2, 3]]));
+
+    let rot = [
+        arr2(&[[1, 2, 3], [4, 5, 6]]),
+        arr2(&[[3, 6], [2, 5], [1, 4]]),
+        arr2(&[[6, 5, 4], [3, 2, 1]]),
+        arr2(&[[4, 1], [5, 2], [6, 3]]),
+    ];
+    for k in -8i32..8 {
+        let expected = &rot[k.rem_euclid(4) as usize];
+        let view = a.rot90(k);
+        assert_eq!(view, expected);
+        let owned = a.rot90_owned(k);
+        assert_eq!(owned, expected);
+        assert!(owned.is_standard_layout());
+    }
+    assert_eq!(a.rot90(1).rot90(1), a.rot90(2));
+    assert_eq!(a.rot90(1).rot90(-1), a);
+}
+
+#[test]
+fn flip_and_rot90_are_views()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    let range = a.as_slice().unwrap().as_ptr_range();
+    let views = [a.fliplr(), a.flipud(), a.rot90(0), a.rot90(1), a.rot90(2), a.rot90(3)];
+    for v in &views {
+        let elements = v.iter().map(|x| x as *const usize).collect::<Vec<_>>();
+        assert!(elements.iter().all(|p| range.contains(p)));
+    }
+    // the corner elements are shared with the original array
+    assert_eq!(&a.fliplr()[[0, 3]] as *const _, &a[[0, 0]] as *const _);
+    assert_eq!(&a.flipud()[[2, 0]] as *const _, &a[[0, 0]] as *const _);
+    assert_eq!(&a.rot90(1)[[3, 0]] as *const _, &a[[0, 0]] as *const _);
+    assert_eq!(&a.rot90(2)[[2, 3]] as *const _, &a[[0, 0]] as *const _);
+    assert_eq!(&a.rot90(3)[[0, 2]] as *const _, &a[[0, 0]] as *const _);
+}
+
 #[test]
 fn iter_size_hint()
 {
@@ -1064,6 +1940,98 @@ fn mapv_into_any_diff_types()
     assert_eq!(a.mapv_into_any(|a| a.round() as i32 % 2 == 0), a_even);
 }
 
+#[test]
+fn mapv_into_any_reuses_allocation()
+{
+    let a = Array::from_shape_fn((3, 4).f(), |(i, j)| (i * 4 + j) as f32);
+    let expected = a.mapv(f32::to_bits);
+    let ptr = a.as_ptr() as usize;
+    let b = a.mapv_into_any(f32::to_bits);
+    assert_eq!(b, expected);
+    assert_eq!(b.strides(), expected.strides());
+    assert_eq!(b.as_ptr() as usize, ptr);
+
+    // a sliced array with the whole allocation still in use, inverted
+    let mut a = Array::from_iter(0..12i32)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    a.invert_axis(Axis(0));
+    let ptr = a.as_ptr() as usize;
+    let b = a.mapv_into_any(|x| x as u32 * 2);
+    assert_eq!(b.row(0), aview1(&[16, 18, 20, 22]));
+    assert_eq!(b.as_ptr() as usize, ptr);
+
+    // different size: new allocation
+    let a = Array::from_elem((2, 3), 7u8);
+    let ptr = a.as_ptr() as usize;
+    let b = a.mapv_into_any(u64::from);
+    assert_eq!(b, Array::from_elem((2, 3), 7u64));
+    assert_ne!(b.as_ptr() as usize, ptr);
+
+    // unused elements in the allocation: new allocation
+    let mut a = Array::from_iter(0..12i32);
+    a.slice_collapse(s![..6]);
+    let b = a.mapv_into_any(|x| x as u32);
+    assert_eq!(b, Array::from_iter(0..6u32));
+
+    // shared data is not reused
+    let a = Array::from_elem(4, 1.5f64).into_shared();
+    let c = a.clone();
+    let b = a.mapv_into_any(|x| x as i64);
+    assert_eq!(b, Array::from_elem(4, 1i64));
+    assert_eq!(c, Array::from_elem(4, 1.5));
+}
+
+#[test]
+fn mapv_into_any_panic_drops_each_element_once()
+{
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPPED_A: AtomicUsize = AtomicUsize::new(0);
+    static DROPPED_B: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Clone)]
+    struct A(u64);
+    impl Drop for A
+    {
+        fn drop(&mut self)
+        {
+            DROPPED_A.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    struct B(#[allow(dead_code)] u64);
+    impl Drop for B
+    {
+        fn drop(&mut self)
+        {
+            DROPPED_B.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let a = Array::from_shape_fn((2, 5), |(i, j)| A((i * 5 + j) as u64));
+    let mut mapped = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        a.mapv_into_any(|x| {
+            if x.0 == 6 {
+                panic!("stop");
+            }
+            mapped += 1;
+            B(x.0)
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(mapped, 6);
+    assert_eq!(DROPPED_A.load(Ordering::SeqCst), 10);
+    assert_eq!(DROPPED_B.load(Ordering::SeqCst), 6);
+
+    let a = Array::from_shape_fn(3, |i| A(i as u64));
+    let b = a.mapv_into_any(|x| B(x.0));
+    assert_eq!(DROPPED_A.load(Ordering::SeqCst), 13);
+    drop(b);
+    assert_eq!(DROPPED_B.load(Ordering::SeqCst), 9);
+}
+
 #[test]
 fn as_slice_memory_order_mut_arcarray()
 {
@@ -1215,6 +2183,68 @@ fn array1_into_raw_vec()
     assert_eq!(offset, Some(0));
 }
 
+#[test]
+fn into_raw_parts_round_trip()
+{
+    // sliced, inverted and Fortran arrays are rebuilt in place
+    let mut sliced = Array::from_iter(0..24)
+        .into_shape_with_order((4, 6))
+        .unwrap();
+    sliced.slice_collapse(s![1..3, 2..;2]);
+    let mut inverted = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    inverted.invert_axis(Axis(1));
+    let fortran = Array::from_shape_fn((3, 4).f(), |(i, j)| i * 4 + j);
+    for a in [sliced, inverted, fortran] {
+        let expected = a.clone();
+        let ptr = a.as_ptr();
+        let (v, offset, dim, strides) = a.into_raw_parts();
+        let b = Array::from_raw_parts(v, offset, dim, strides).unwrap();
+        assert_eq!(b, expected);
+        assert_eq!(b.strides(), expected.strides());
+        assert_eq!(b.as_ptr(), ptr);
+    }
+
+    let (v, offset, dim, strides) = Array2::<f32>::zeros((0, 3)).into_raw_parts();
+    assert_eq!(offset, None);
+    assert_eq!(Array::from_raw_parts(v, offset, dim, strides).unwrap().shape(), &[0, 3]);
+}
+
+#[test]
+fn from_raw_parts_invalid()
+{
+    use ndarray::ErrorKind;
+
+    let v = || (0..6).collect::<Vec<i32>>();
+    let ok = Array::from_raw_parts(v(), Some(1), Ix2(2, 2), Ix2(3, 1)).unwrap();
+    assert_eq!(ok, array![[1, 2], [4, 5]]);
+    let ok = Array::from_raw_parts(v(), None, Ix2(2, 3), Ix2(1, 2)).unwrap();
+    assert_eq!(ok, array![[0, 2, 4], [1, 3, 5]]);
+
+    // past the end of the vector
+    let err = Array::from_raw_parts(v(), Some(2), Ix2(2, 2), Ix2(3, 1)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let err = Array::from_raw_parts(v(), Some(7), Ix1(0), Ix1(1)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    // an inverted axis needs room before the offset
+    let err = Array::from_raw_parts(v(), Some(1), Ix1(3), Ix1(-1isize as usize)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    // elements referenced twice
+    let err = Array::from_raw_parts(v(), None, Ix2(2, 3), Ix2(1, 1)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    // shape and strides of different dimensionality
+    let err = Array::from_raw_parts(v(), None, IxDyn(&[2, 3]), IxDyn(&[3])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleLayout);
+    // strides too large to compute the offset of the first element
+    for offset in [None, Some(0)] {
+        let err = Array::from_raw_parts(v(), offset, Ix1(3), Ix1(isize::MIN as usize)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overflow);
+        let err = Array::from_raw_parts(v(), offset, Ix2(2, 2), Ix2(isize::MAX as usize, 1)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overflow);
+    }
+}
+
 #[test]
 fn owned_array1()
 {
@@ -1900,6 +2930,65 @@ fn deny_split_at_index_out_of_bounds()
     a.view().split_at(Axis(1), 3);
 }
 
+#[test]
+fn split_evenly()
+{
+    for len in 0..10 {
+        for n in 1..12 {
+            let mut a = Array::zeros((3, len));
+            let parts = a.view_mut().split_evenly(Axis(1), n);
+            assert_eq!(parts.len(), n);
+            let lengths: Vec<_> = parts.iter().map(|p| p.len_of(Axis(1))).collect();
+            assert_eq!(lengths.iter().sum::<usize>(), len);
+            assert!(lengths.iter().max().unwrap() - lengths.iter().min().unwrap() <= 1);
+            for (k, mut part) in parts.into_iter().enumerate() {
+                part += k + 1;
+            }
+            // each element was written exactly once, in consecutive runs
+            let row: Vec<_> = a.row(0).to_vec();
+            let mut expected = Vec::new();
+            for (k, &l) in lengths.iter().enumerate() {
+                expected.extend(std::iter::repeat(k + 1).take(l));
+            }
+            assert_eq!(row, expected);
+            assert_eq!(a.row(1), a.row(0));
+            assert_eq!(a.row(2), a.row(0));
+
+            let b = Array::from_shape_fn((3, len), |(i, j)| i * 100 + j);
+            let views = b.view().split_evenly(Axis(1), n);
+            let joined = ndarray::concatenate(Axis(1), &views).unwrap();
+            assert_eq!(joined, b);
+        }
+    }
+}
+
+#[test]
+fn split_evenly_threads()
+{
+    let mut a = Array::zeros((10, 4));
+    crossbeam_utils::thread::scope(|scope| {
+        for (k, mut part) in a
+            .view_mut()
+            .split_evenly(Axis(0), 4)
+            .into_iter()
+            .enumerate()
+        {
+            scope.spawn(move |_| part.fill(k));
+        }
+    })
+    .unwrap();
+    assert_eq!(a.column(0), aview1(&[0, 0, 0, 1, 1, 1, 2, 2, 3, 3]));
+    assert!(a.columns().into_iter().all(|c| c == a.column(0)));
+}
+
+#[test]
+#[should_panic]
+fn deny_split_evenly_zero_parts()
+{
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    a.view().split_evenly(Axis(0), 0);
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn test_range()
@@ -2008,6 +3097,71 @@ fn map_memory_order()
     assert_eq!(amap.strides(), v.strides());
 }
 
+#[test]
+fn map_indexed_any_layout()
+{
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| 100 * i + 10 * j + k);
+    let sources = [
+        a.view(),
+        a.view().reversed_axes(),
+        a.view().permuted_axes([1, 0, 2]),
+        a.slice(s![1..;2, ..;-1, 1..5]),
+        a.slice(s![..;-1, 2..3, ..;3]).reversed_axes(),
+    ];
+    for v in &sources {
+        let m = v.map_indexed(|(i, j, k), &x| (x, [i, j, k]));
+        assert_eq!(m.shape(), v.shape());
+        for ((i, j, k), &x) in v.indexed_iter() {
+            assert_eq!(m[[i, j, k]], (x, [i, j, k]));
+        }
+        // `map` keeps the shape too, and agrees element by element
+        let m2 = v.map(|&x| x + 1);
+        for (index, &x) in v.indexed_iter() {
+            assert_eq!(m2[index], x + 1);
+        }
+    }
+
+    let d = a.slice(s![.., ..;2, 1]).into_dyn();
+    let md = d.map_indexed(|index, &x| index[0] * 1000 + x);
+    for (index, &x) in d.indexed_iter() {
+        assert_eq!(md[index.clone()], index[0] * 1000 + x);
+    }
+}
+
+#[test]
+fn into_shrunk()
+{
+    use std::rc::Rc;
+
+    let a = Array::from_shape_fn((40, 50), |(i, j)| 100 * i + j);
+    let slices = [s![10..12, ..], s![..;-1, ..], s![20..22;-1, ..], s![.., 3..5], s![1..30;3, 2..10;-2], s![5..5, ..]];
+    for &info in &slices {
+        for source in [a.clone(), a.t().as_standard_layout().reversed_axes().into_owned()] {
+            let expected = source.slice(info).to_owned();
+            let shrunk = source.slice_move(info).into_shrunk();
+            assert_eq!(shrunk, expected);
+            let (v, _) = shrunk.into_raw_vec_and_offset();
+            assert_eq!(v.len(), expected.len());
+            assert!(v.capacity() <= 2 * expected.len(), "{:?}", info);
+        }
+    }
+
+    let b = Array::from_shape_fn((3, 4, 5), |(i, j, k)| i + j + k);
+    let b0 = b.index_axis_move(Axis(0), 2).index_axis_move(Axis(1), 4);
+    let b0 = b0.index_axis_move(Axis(0), 1).into_shrunk();
+    assert_eq!(b0, arr0(7));
+
+    // every element is dropped exactly once
+    let elt = Rc::new(());
+    let c = Array::from_shape_simple_fn((10, 10), || elt.clone());
+    let c = c.slice_move(s![2..4, 1..;3]).into_shrunk();
+    assert_eq!(Rc::strong_count(&elt), 1 + c.len());
+    let c = c.slice_move(s![.., 1]).into_shrunk();
+    assert_eq!(Rc::strong_count(&elt), 1 + 2);
+    drop(c);
+    assert_eq!(Rc::strong_count(&elt), 1);
+}
+
 #[test]
 fn map_mut_with_unsharing()
 {
@@ -2060,6 +3214,52 @@ fn test_view_from_shape()
     assert_eq!(a, answer);
 }
 
+#[test]
+fn test_view_from_shape_overlapping()
+{
+    let signal: Vec<i32> = (0..10).map(|x| x * x).collect();
+    for w in 1..=signal.len() {
+        let n = signal.len() - w + 1;
+        let windows = ArrayView::from_shape_overlapping((n, w).strides((1, 1)), &signal).unwrap();
+        assert_eq!(windows.shape(), &[n, w]);
+        for (i, row) in windows.outer_iter().enumerate() {
+            assert_eq!(row, aview1(&signal[i..i + w]));
+        }
+        assert_eq!(windows, signal_windows(&signal, w));
+    }
+
+    // overlapping strides are rejected by from_shape
+    assert_eq!(
+        ArrayView::from_shape((8, 3).strides((1, 1)), &signal).unwrap_err().kind(),
+        ndarray::ErrorKind::Unsupported
+    );
+
+    // zero and negative strides
+    let rep = ArrayView::from_shape_overlapping((3, 4).strides((0, 1)), &signal[..4]).unwrap();
+    assert_eq!(rep, array![[0, 1, 4, 9], [0, 1, 4, 9], [0, 1, 4, 9]]);
+    let rev = ArrayView::from_shape_overlapping((2, 3).strides((1, -1isize as usize)), &signal[..4]).unwrap();
+    assert_eq!(rev, array![[4, 1, 0], [9, 4, 1]]);
+
+    // the largest offset is checked, also with repeated strides
+    assert_eq!(
+        ArrayView::from_shape_overlapping((9, 3).strides((1, 1)), &signal).unwrap_err().kind(),
+        ndarray::ErrorKind::OutOfBounds
+    );
+    assert_eq!(
+        ArrayView::from_shape_overlapping((4, 4, 4).strides((1, 1, 2)), &signal).unwrap_err().kind(),
+        ndarray::ErrorKind::OutOfBounds
+    );
+    assert!(ArrayView::from_shape_overlapping((4, 4, 4).strides((1, 1, 1)), &signal[..]).is_ok());
+    assert!(ArrayView::from_shape_overlapping((4, 3, 2).strides((1, 2, 2)), &signal[..]).is_ok());
+    assert!(ArrayView::from_shape_overlapping((0, 3).strides((1, 4)), &signal[..8]).is_ok());
+    assert!(ArrayView::from_shape_overlapping(11, &signal).is_err());
+}
+
+fn signal_windows(signal: &[i32], w: usize) -> Array2<i32>
+{
+    Array::from_shape_fn((signal.len() - w + 1, w), |(i, j)| signal[i + j])
+}
+
 #[test]
 fn test_contiguous()
 {
@@ -2284,6 +3484,44 @@ fn test_default_ixdyn()
     assert_eq!(a, b);
 }
 
+#[test]
+fn test_default_in_struct_and_empty()
+{
+    #[derive(Default)]
+    struct Model
+    {
+        weights: Array2<f64>,
+        bias: Array1<f64>,
+        scale: Array0<f64>,
+    }
+
+    let m = Model::default();
+    assert_eq!(m.weights.shape(), &[0, 0]);
+    assert_eq!(m.bias, Array1::<f64>::empty());
+    assert_eq!(m.scale, arr0(0.));
+
+    let a = Array2::<f64>::empty();
+    assert!(a.is_empty());
+    assert_eq!(a.iter().count(), 0);
+    assert_eq!(a.sum(), 0.);
+    assert_eq!(a.rows().into_iter().count(), 0);
+    let b = ndarray::concatenate![Axis(0), a.view().into_shape_with_order((0, 2)).unwrap(), array![[1., 2.]]];
+    assert_eq!(b, array![[1., 2.]]);
+
+    let mut c = Array1::<f64>::empty();
+    c.append(Axis(0), aview1(&[1., 2.])).unwrap();
+    assert_eq!(c, array![1., 2.]);
+
+    assert_eq!(ArrayD::<f32>::empty(), <ArrayD<f32> as Default>::default());
+}
+
+#[test]
+#[should_panic(expected = "zero-dimensional array cannot be empty")]
+fn test_empty_zero_dimensional()
+{
+    let _ = Array0::<f32>::empty();
+}
+
 #[test]
 fn test_map_axis()
 {
@@ -2318,6 +3556,113 @@ fn test_map_axis()
     itertools::assert_equal(result.iter().cloned().sorted(), 1..=3 * 4);
 }
 
+#[test]
+fn test_apply_along_axis()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+
+    let b = a.apply_along_axis(Axis(0), 2, |lane| array![lane[0] + lane[1], lane[2] + lane[3]]);
+    assert_eq!(b, arr2(&[[5, 7, 9], [17, 19, 21]]));
+    let c = a.apply_along_axis(Axis(1), 4, |lane| {
+        lane.iter()
+            .chain(lane.iter())
+            .cloned()
+            .skip(1)
+            .take(4)
+            .collect()
+    });
+    assert_eq!(c, arr2(&[[2, 3, 1, 2], [5, 6, 4, 5], [8, 9, 7, 8], [11, 12, 10, 11]]));
+
+    // lanes of a non-contiguous array, and lanes that are not cloneable
+    let a = Array::from_shape_fn((3, 5, 2).f(), |(i, j, k)| i * 100 + j * 10 + k);
+    let d = a
+        .slice(s![.., ..;2, ..])
+        .apply_along_axis(Axis(1), 1, |lane| {
+            let total = lane.sum();
+            Array::from_elem(1, Box::new(total))
+        });
+    assert_eq!(d.shape(), &[3, 1, 2]);
+    assert_eq!(*d[[2, 0, 1]], 201 + 221 + 241);
+
+    // zero-length lanes in and out
+    let arr = Array3::<f32>::zeros((3, 0, 4));
+    let mut counter = 0;
+    let result = arr.apply_along_axis(Axis(1), 2, |x| {
+        assert_eq!(x.shape(), &[0]);
+        counter += 1;
+        array![counter, counter]
+    });
+    assert_eq!(result.shape(), &[3, 2, 4]);
+    assert_eq!(counter, 12);
+    let result = arr.apply_along_axis(Axis(0), 0, |x| {
+        assert_eq!(x.shape(), &[3]);
+        Array1::<f32>::zeros(0)
+    });
+    assert_eq!(result.shape(), &[0, 0, 4]);
+}
+
+#[test]
+#[should_panic(expected = "expected a lane of length 2, got 3")]
+fn test_apply_along_axis_wrong_length()
+{
+    let a = Array2::<i32>::zeros((2, 3));
+    a.apply_along_axis(Axis(1), 2, |lane| lane.to_owned());
+}
+
+#[test]
+fn test_topk_axis()
+{
+    let a = Array::from_shape_fn((4, 9), |(i, j)| ((i * 7 + j * 5) % 6) as i32);
+    for axis in 0..2 {
+        for count in 0..12 {
+            let values = a.topk_axis(Axis(axis), count);
+            let indices = a.topk_argmax_axis(Axis(axis), count);
+            let k = count.min(a.len_of(Axis(axis)));
+            let mut shape = a.raw_dim();
+            shape[axis] = k;
+            assert_eq!(values.raw_dim(), shape);
+            assert_eq!(indices.raw_dim(), shape);
+            for ((lane, vals), idxs) in a
+                .lanes(Axis(axis))
+                .into_iter()
+                .zip(values.lanes(Axis(axis)))
+                .zip(indices.lanes(Axis(axis)))
+            {
+                // reference: stable sort by descending value keeps ties in index order
+                let mut expected = (0..lane.len()).collect::<Vec<_>>();
+                expected.sort_by(|&i, &j| lane[j].cmp(&lane[i]));
+                expected.truncate(k);
+                assert_eq!(idxs.to_vec(), expected);
+                assert_eq!(vals.to_vec(), expected.iter().map(|&i| lane[i]).collect::<Vec<_>>());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_topk_axis_nan_last()
+{
+    let nan = f64::NAN;
+    let a = arr2(&[[nan, 1., nan, 3., 1.], [2., nan, 5., 4., 0.]]);
+    assert_eq!(a.topk_argmax_axis(Axis(1), 5), arr2(&[[3, 1, 4, 0, 2], [2, 3, 0, 4, 1]]));
+    assert_eq!(a.topk_axis(Axis(1), 2), arr2(&[[3., 1.], [5., 4.]]));
+    let all = a.topk_axis(Axis(1), 10);
+    assert_eq!(all.shape(), &[2, 5]);
+    assert!(all[[0, 3]].is_nan() && all[[0, 4]].is_nan() && all[[1, 4]].is_nan());
+}
+
+#[test]
+fn test_topk_axis_dyn()
+{
+    let a = ArrayD::from_shape_fn(IxDyn(&[2, 3, 4]), |ix| (ix[0] * 12 + ix[1] * 4 + ix[2]) % 5);
+    let top = a.topk_axis(Axis(2), 2);
+    assert_eq!(top.shape(), &[2, 3, 2]);
+    assert_eq!((top[[0, 0, 0]], top[[0, 0, 1]]), (3, 2));
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.topk_axis(Axis(0), 2).shape(), &[0, 3]);
+    assert_eq!(empty.topk_axis(Axis(1), 2).shape(), &[0, 2]);
+}
+
 #[test]
 fn test_accumulate_axis_inplace_noop()
 {
@@ -2361,6 +3706,46 @@ fn test_accumulate_axis_inplace_nonstandard_layout() {
                             [12, 23, 33]]));
 }
 
+#[test]
+fn test_scan_axis()
+{
+    let a = array![[1, 5, 2], [4, 3, 6]];
+    assert_eq!(a.scan_axis(Axis(1), None, |&x, &y| x.max(y)), array![[1, 5, 5], [4, 4, 6]]);
+    assert_eq!(a.scan_axis(Axis(0), None, |&x, &y| x + y), array![[1, 5, 2], [5, 8, 8]]);
+    assert_eq!(a.scan_axis(Axis(1), Some(0), |&x, &y| x + y), array![[0, 1, 6], [0, 4, 7]]);
+    assert_eq!(a.t().scan_axis(Axis(0), None, |&x, &y| x + y), array![[1, 4], [6, 7], [8, 13]]);
+
+    let flags = array![false, true, false];
+    assert_eq!(flags.scan_axis(Axis(0), None, |&x, &y| x || y), array![false, true, true]);
+
+    // zero and one length axes
+    let empty = Array2::<i32>::zeros((2, 0));
+    assert_eq!(empty.scan_axis(Axis(1), None, |_, _| unreachable!()).shape(), &[2, 0]);
+    assert_eq!(empty.scan_axis(Axis(0), Some(1), |_, _| unreachable!()).shape(), &[2, 0]);
+    let one = array![[7], [8]];
+    assert_eq!(one.scan_axis(Axis(1), None, |_, _| unreachable!()), one);
+    assert_eq!(one.scan_axis(Axis(1), Some(0), |_, _| unreachable!()), array![[0], [0]]);
+}
+
+#[test]
+fn test_scan_axis_association()
+{
+    // concatenation is not commutative, so this shows the order of the
+    // arguments
+    #[derive(Clone, Debug, PartialEq)]
+    struct Word(String);
+    let concat = |a: &Word, b: &Word| Word(format!("({}{})", a.0, b.0));
+
+    let a = Array::from_iter("abcd".chars().map(|c| Word(c.to_string())));
+    let inclusive = a.scan_axis(Axis(0), None, concat);
+    let inclusive: Vec<_> = inclusive.iter().map(|w| &w.0[..]).collect();
+    assert_eq!(inclusive, ["a", "(ab)", "((ab)c)", "(((ab)c)d)"]);
+
+    let exclusive = a.scan_axis(Axis(0), Some(Word("_".into())), concat);
+    let exclusive: Vec<_> = exclusive.iter().map(|w| &w.0[..]).collect();
+    assert_eq!(exclusive, ["_", "(_a)", "((_a)b)", "(((_a)b)c)"]);
+}
+
 #[test]
 fn test_to_vec()
 {
@@ -2708,7 +4093,7 @@ fn test_remove_index_oob2()
     a.remove_index(Axis(1), 0); // oob
 }
 
-#[should_panic(expected = "index out of bounds")]
+#[should_panic(expected = "Axis(2) is out of bounds for an array with 2 axes")]
 #[test]
 fn test_remove_index_oob3()
 {
@@ -2779,3 +4164,140 @@ fn test_split_complex_invert_axis()
     assert_eq!(cmplx.re, a.mapv(|z| z.re));
     assert_eq!(cmplx.im, a.mapv(|z| z.im));
 }
+
+#[test]
+fn error_kinds()
+{
+    use ndarray::{ErrorKind, Order};
+
+    let mut a = Array2::<i32>::zeros((3, 4));
+    let kind = a.try_assign(&array![1, 2]).unwrap_err().kind();
+    assert_eq!(kind, ErrorKind::IncompatibleShape);
+    let kind = a
+        .try_zip_mut_with(&array![[1], [2]], |_, _| ())
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::IncompatibleShape);
+
+    let kind = a
+        .sum_by_key_axis(Axis(1), &array![0, 1], 2)
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::IncompatibleShape);
+    let kind = a
+        .sum_by_key_axis(Axis(1), &array![0, 1, 2, 3], 2)
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::OutOfBounds);
+    let kind = a
+        .sum_by_key_axis(Axis(1), &array![0, 1, 1, 0], usize::MAX)
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::Overflow);
+
+    let kind = Array2::from_shape_vec_with_order((2, 3), Order::ColumnMajor, vec![0; 7])
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::IncompatibleShape);
+    let kind = Array2::from_shape_exact_iter((2, 3), 0..7)
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::IncompatibleShape);
+    let kind = Array2::from_shape_exact_iter((usize::MAX, 2), 0..0)
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::Overflow);
+
+    let (v, offset, _, _) = a.clone().into_dyn().into_raw_parts();
+    let kind = ArrayD::from_raw_parts(v, offset, IxDyn(&[3, 4]), IxDyn(&[4]))
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::IncompatibleLayout);
+    let (v, offset, dim, strides) = a.clone().into_raw_parts();
+    let kind = Array2::from_raw_parts(v, offset.map(|o| o + 1), dim, strides)
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::OutOfBounds);
+
+    let kind = a.clone().try_into_fixed_rows::<3>().unwrap_err().kind();
+    assert_eq!(kind, ErrorKind::IncompatibleShape);
+    let kind = a
+        .clone()
+        .reversed_axes()
+        .try_into_fixed_rows::<3>()
+        .unwrap_err()
+        .kind();
+    assert_eq!(kind, ErrorKind::IncompatibleLayout);
+
+    let kind = Array2::<f32>::one_hot(&array![0, 5], 3).unwrap_err().kind();
+    assert_eq!(kind, ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn shape_error_messages()
+{
+    use ndarray::linalg::try_general_mat_vec_mul;
+    use ndarray::{ErrorKind, ShapeError};
+
+    let err = Array2::from_shape_vec((4, 2), vec![0; 10]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: [10] and [4, 2] (in from_shape_vec)"
+    );
+
+    let err = Array2::from_shape_vec((4, 2).strides((1, 4)), vec![0; 6]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/OutOfBounds: out of bounds indexing: shape [4, 2] with strides [1, 4] \
+         needs 8 elements, but the data has length 6 (in from_shape_vec)"
+    );
+
+    let data = [0; 5];
+    let err = ArrayView2::from_shape((2, 3), &data).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/OutOfBounds: out of bounds indexing: shape [2, 3] needs 6 elements, but the data has length 5"
+    );
+    let err = ArrayView2::from_shape((2, 3).strides((-3isize as usize, 1)), &data).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/OutOfBounds: out of bounds indexing: shape [2, 3] with strides [-3, 1] \
+         needs 6 elements, but the data has length 5"
+    );
+
+    let a = Array::from_elem((2, 3), 0);
+    let err = a.view().into_shape_with_order((4, 2)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: [2, 3] and [4, 2] (in into_shape_with_order)"
+    );
+    let err = a.to_shape((5, 1)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: [2, 3] and [5, 1] (in to_shape)"
+    );
+    let err = a.clone().into_shape_clone(7).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: [2, 3] and [7] (in into_shape_clone)"
+    );
+
+    let mut y = Array::zeros(2);
+    let err = try_general_mat_vec_mul(1, &a, &Array::zeros(2), 0, &mut y).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: [2, 3] and [2] (in general_mat_vec_mul)"
+    );
+
+    // the kind is still what errors compare by
+    assert_eq!(err, ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    assert!(std::mem::size_of::<ShapeError>() <= 2 * std::mem::size_of::<usize>());
+}
+
+#[test]
+#[should_panic(expected = "ndarray: could not broadcast [2, 3] and [4] together in '+'")]
+fn shape_error_message_broadcast()
+{
+    let _ = Array2::<f32>::zeros((2, 3)) + Array1::<f32>::zeros(4);
+}
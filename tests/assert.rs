@@ -0,0 +1,71 @@
+use ndarray::assert_arrays_eq;
+use ndarray::prelude::*;
+
+#[test]
+fn assert_arrays_eq_mixed()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let b = Array::from_shape_vec((3, 2), vec![1, 4, 2, 5, 3, 6]).unwrap();
+    assert_arrays_eq!(a, b.t());
+    assert_arrays_eq!(a.view(), a.clone().into_dyn());
+    assert_arrays_eq!(a.row(1), array![4, 5, 6],);
+    assert_arrays_eq!(arr0(1.), arr0(1.));
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `a == b`: arrays differ in 2 of 6 elements\n  \
+                           shape: [2, 3]\n  \
+                           at [0, 2]: left = 3, right = 0\n  \
+                           at [1, 0]: left = 4, right = 0")]
+fn assert_arrays_eq_reports_mismatches()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let mut b = a.clone();
+    b[[0, 2]] = 0;
+    b[[1, 0]] = 0;
+    assert_arrays_eq!(a, b);
+}
+
+#[test]
+#[should_panic(expected = "at [0, 4]: left = 0, right = 1\n  ... and 15 more")]
+fn assert_arrays_eq_reports_first_mismatches()
+{
+    let a = Array2::<i32>::zeros((4, 5));
+    let b = Array2::<i32>::ones((4, 5));
+    assert_arrays_eq!(a, b);
+}
+
+#[test]
+#[should_panic(expected = "arrays have different shapes\n  left shape:  [2, 3]\n  right shape: [3, 2]")]
+fn assert_arrays_eq_shape_mismatch()
+{
+    let a = Array2::<i32>::zeros((2, 3));
+    assert_arrays_eq!(a, a.t());
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn assert_arrays_abs_diff_eq_within_epsilon()
+{
+    use ndarray::assert_arrays_abs_diff_eq;
+
+    let a = array![[0.1 + 0.2, 1.], [2., 3.]];
+    let b = array![[0.3, 1.], [2., 3.]];
+    assert_arrays_abs_diff_eq!(a, b.view(), 1e-12);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[should_panic(
+    expected = "assertion failed: `abs_diff_eq(a, b, epsilon = 0.01)`: arrays differ in 1 of 3 elements\n  \
+                           shape: [3]\n  \
+                           at [1]: left = 1.0, right = 1.5"
+)]
+fn assert_arrays_abs_diff_eq_reports_mismatches()
+{
+    use ndarray::assert_arrays_abs_diff_eq;
+
+    let a = array![0., 1., 2.];
+    let b = array![0., 1.5, 2.005];
+    assert_arrays_abs_diff_eq!(a, b, 0.01);
+}
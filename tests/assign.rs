@@ -28,6 +28,49 @@ fn assign()
     assert_eq!(a, arr2(&[[0, 0], [3, 4]]));
 }
 
+#[test]
+fn assign_broadcast()
+{
+    let mut a = Array2::zeros((3, 4));
+    a.assign(&array![1, 2, 3, 4]);
+    assert_eq!(a, array![[1, 2, 3, 4], [1, 2, 3, 4], [1, 2, 3, 4]]);
+
+    // a column: its broadcast rows have stride 0
+    a.assign(&array![[5], [6], [7]]);
+    assert_eq!(a, array![[5, 5, 5, 5], [6, 6, 6, 6], [7, 7, 7, 7]]);
+
+    // a column into a transposed matrix
+    let mut b = Array2::zeros((4, 3).f());
+    b.assign(&array![[1], [2], [3], [4]]);
+    assert_eq!(b, array![[1, 2, 3, 4]].t().broadcast((4, 3)).unwrap());
+
+    let mut c = Array3::zeros((2, 3, 4));
+    c.try_assign(&arr0(9)).unwrap();
+    assert_eq!(c, Array3::from_elem((2, 3, 4), 9));
+    c.try_assign(&array![[1], [2], [3]]).unwrap();
+    assert_eq!(c.slice(s![1, .., 2]), array![1, 2, 3]);
+}
+
+#[test]
+fn try_assign_incompatible()
+{
+    let mut a = Array2::zeros((3, 4));
+    let err = a.try_assign(&array![1, 2, 3]).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::IncompatibleShape);
+    assert_eq!(a, Array2::zeros((3, 4)));
+
+    let mut calls = 0;
+    let err = a
+        .try_zip_mut_with(&Array3::<i32>::zeros((2, 3, 4)), |_, _| calls += 1)
+        .unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::IncompatibleShape);
+    assert_eq!(calls, 0);
+
+    a.try_zip_mut_with(&array![[1], [2], [3]], |x, &y| *x += y * 10)
+        .unwrap();
+    assert_eq!(a.column(3), array![10, 20, 30]);
+}
+
 #[test]
 fn assign_to()
 {
@@ -280,3 +323,99 @@ impl<'a> Drop for Element<'a>
         self.0.fetch_add(1, Ordering::Relaxed);
     }
 }
+
+#[test]
+fn swap_contents_owned()
+{
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    let mut b = Array::from_shape_fn((3, 4).f(), |(i, j)| 100 + i * 10 + j);
+    let (a_ptr, b_ptr) = (a.as_ptr(), b.as_ptr());
+    let (a_copy, b_copy) = (a.clone(), b.clone());
+    ndarray::swap_contents(&mut a, &mut b);
+    assert_eq!(a, b_copy);
+    assert_eq!(b, a_copy);
+    // the buffers are exchanged, not copied
+    assert_eq!(a.as_ptr(), b_ptr);
+    assert_eq!(b.as_ptr(), a_ptr);
+    assert!(a.is_fortran_layout());
+
+    let mut c = a.to_shared();
+    let mut d = b.to_shared();
+    let shared = c.clone();
+    let c_ptr = c.as_ptr();
+    ndarray::swap_contents(&mut c, &mut d);
+    assert_eq!(d.as_ptr(), c_ptr);
+    assert_eq!(c, a_copy);
+    assert_eq!(shared, b_copy);
+}
+
+#[test]
+fn swap_with_views()
+{
+    let mut a = Array::from_shape_fn((4, 6), |(i, j)| i * 10 + j);
+    let expected = {
+        let mut e = a.clone();
+        let left = a.slice(s![.., ..3;2]).to_owned();
+        let right = a.slice(s![..;-1, 3..]).slice(s![.., ..;2]).to_owned();
+        e.slice_mut(s![.., ..3;2]).assign(&right);
+        e.slice_mut(s![..;-1, 3..;2]).assign(&left);
+        e
+    };
+    let (left, right) = a.view_mut().split_at(Axis(1), 3);
+    let mut left = left.slice_move(s![.., ..;2]);
+    let mut right = right.slice_move(s![..;-1, ..;2]);
+    let ptr = left.as_ptr();
+    left.swap_with(&mut right);
+    assert_eq!(left.as_ptr(), ptr);
+    assert_eq!(a, expected);
+
+    let (mut top, mut bottom) = a.view_mut().split_at(Axis(0), 2);
+    top.swap_with(&mut bottom);
+    top.swap_with(&mut bottom);
+    assert_eq!(a, expected);
+}
+
+#[test]
+fn swap_contents_views()
+{
+    let mut a = Array::from_shape_fn((4, 6), |(i, j)| i * 10 + j);
+    let mut expected = a.clone();
+    for i in 0..4 {
+        for j in 0..3 {
+            expected.swap((i, j), (3 - i, 3 + j));
+        }
+    }
+    let (mut left, right) = a.view_mut().split_at(Axis(1), 3);
+    let mut right = right.slice_move(s![..;-1, ..]);
+    let ptr = left.as_ptr();
+    ndarray::swap_contents(&mut left, &mut right);
+    assert_eq!(left.as_ptr(), ptr);
+    assert_eq!(a, expected);
+}
+
+#[test]
+#[should_panic(expected = "swap_contents requires arrays of the same shape, got [2, 2] and [1, 2]")]
+fn swap_contents_views_shape_mismatch()
+{
+    let mut a = Array2::<i32>::zeros((3, 2));
+    let (mut top, mut bottom) = a.view_mut().split_at(Axis(0), 2);
+    ndarray::swap_contents(&mut top, &mut bottom);
+}
+
+#[test]
+#[should_panic(expected = "swap_contents requires arrays of the same shape, got [2, 3] and [3, 2]")]
+fn swap_contents_shape_mismatch()
+{
+    let mut a = Array2::<i32>::zeros((2, 3));
+    let mut b = Array2::<i32>::zeros((3, 2));
+    ndarray::swap_contents(&mut a, &mut b);
+}
+
+#[test]
+#[should_panic(expected = "swap_with requires arrays of the same shape, got [2, 2] and [1, 2]")]
+fn swap_with_shape_mismatch()
+{
+    let mut a = Array2::<i32>::zeros((3, 2));
+    let (mut top, mut bottom) = a.view_mut().split_at(Axis(0), 2);
+    top.swap_with(&mut bottom);
+}
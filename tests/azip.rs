@@ -4,7 +4,7 @@
 )]
 
 use ndarray::prelude::*;
-use ndarray::Zip;
+use ndarray::{broadcast_scalar, IntoNdProducer, Zip};
 
 use itertools::{assert_equal, cloned};
 
@@ -110,6 +110,144 @@ fn test_zip_assign_into_cell()
     assert_abs_diff_eq!(a2, &b + &c, epsilon = 1e-6);
 }
 
+#[test]
+fn test_zip_assign_into_uninit()
+{
+    let a = Array::from_shape_fn((4, 6).f(), |(i, j)| i * 10 + j);
+    let b = Array::from_shape_fn((4, 6), |(i, j)| i * j);
+
+    let mut out = Array2::uninit((4, 6));
+    Zip::from(&a)
+        .and(&b)
+        .map_assign_into(&mut out, |&x, &y| x + y);
+    let out = unsafe { out.assume_init() };
+    assert_eq!(out, &a + &b);
+
+    // assign into an uninitialized view, part of a larger output
+    let mut big = Array2::<usize>::uninit((4, 12));
+    Zip::from(&a).map_assign_into(big.slice_mut(s![.., ..;2]), |&x| x);
+    Zip::from(&b).map_assign_into(big.slice_mut(s![.., 1..;2]), |&y| y);
+    let big = unsafe { big.assume_init() };
+    assert_eq!(big.slice(s![.., ..;2]), a);
+    assert_eq!(big.slice(s![.., 1..;2]), b);
+}
+
+#[test]
+#[should_panic]
+fn test_zip_assign_into_shape_mismatch()
+{
+    let a = Array2::<f32>::zeros((3, 3));
+    let mut out = Array2::uninit((3, 4));
+    Zip::from(&a).map_assign_into(&mut out, |&x| x);
+}
+
+#[test]
+fn test_zip_assign_into_uninit_panic()
+{
+    use std::cell::Cell;
+    use std::panic;
+
+    struct CountDrop<'a>(&'a Cell<usize>);
+
+    impl<'a> Drop for CountDrop<'a>
+    {
+        fn drop(&mut self)
+        {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let calls = Cell::new(0);
+    let a = Array::from_shape_fn((5, 10), |(i, j)| i * 10 + j);
+    let mut out = Array2::uninit((5, 10));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        Zip::from(&a).map_assign_into(&mut out, |&x| {
+            if x == 23 {
+                panic!("stop");
+            }
+            calls.set(calls.get() + 1);
+            CountDrop(&drops)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(calls.get(), 23);
+    // the written elements are owned by the still uninitialized output and are not dropped
+    assert_eq!(drops.get(), 0);
+}
+
+/// A user defined producer: the sum of the row and column of each element,
+/// starting from `offset`.
+struct IndexSum
+{
+    offset: Ix2,
+    dim: Ix2,
+}
+
+impl ndarray::IndexedProducer for IndexSum
+{
+    type Item = usize;
+    type Dim = Ix2;
+
+    fn raw_dim(&self) -> Ix2
+    {
+        self.dim
+    }
+
+    unsafe fn get(&self, index: Ix2) -> usize
+    {
+        let (i, j) = index.into_pattern();
+        assert!(i < self.dim[0] && j < self.dim[1]);
+        self.offset[0] + i + self.offset[1] + j
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    {
+        let mut first_dim = self.dim;
+        first_dim[axis.index()] = index;
+        let mut second_dim = self.dim;
+        second_dim[axis.index()] -= index;
+        let mut second_offset = self.offset;
+        second_offset[axis.index()] += index;
+        (
+            IndexSum {
+                offset: self.offset,
+                dim: first_dim,
+            },
+            IndexSum {
+                offset: second_offset,
+                dim: second_dim,
+            },
+        )
+    }
+}
+
+#[test]
+fn test_zip_indexed_producer()
+{
+    let a = Array::from_shape_fn((5, 7), |(i, j)| (i * 10 + j) as f32);
+    let mut b = Array::zeros((5, 7));
+    Zip::from(&mut b)
+        .and(&a)
+        .and(IndexSum {
+            offset: Ix2(0, 0),
+            dim: Ix2(5, 7),
+        })
+        .for_each(|b, &a, s| *b = a + s as f32);
+    assert_eq!(b, Array::from_shape_fn((5, 7), |(i, j)| (i * 11 + 2 * j) as f32));
+
+    // splitting the zip splits the user producer in the same way
+    let mut c = Array::zeros((5, 7).f());
+    let zip = Zip::from(&mut c).and(&a).and(IndexSum {
+        offset: Ix2(0, 0),
+        dim: Ix2(5, 7),
+    });
+    let (first, second) = zip.split();
+    first.for_each(|c, &a, s| *c = a + s as f32);
+    second.for_each(|c, &a, s| *c = a + s as f32);
+    assert_eq!(c, b);
+}
+
 #[test]
 fn test_zip_collect_drop()
 {
@@ -463,6 +601,123 @@ fn test_indices_split_1()
     }
 }
 
+#[test]
+fn test_indices_memory_order()
+{
+    // operands where the memory order differs from the logical order
+    let f = Array::from_shape_fn((5, 7).f(), |(i, j)| (i, j));
+    let base = Array::from_shape_fn((10, 14), |(i, j)| (i, j));
+    let sliced = base.slice(s![..;2, ..;-2]);
+    let t = Array::from_shape_fn((7, 5), |(j, i)| (i, j));
+
+    let mut count = 0;
+    Zip::indexed(&f).for_each(|i, &elt| {
+        count += 1;
+        assert_eq!(elt, i);
+    });
+    assert_eq!(count, f.len());
+
+    Zip::indexed(&sliced)
+        .and(&f)
+        .and(t.t())
+        .for_each(|(i, j), &s, &f, &t| {
+            assert_eq!(s, (2 * i, 13 - 2 * j));
+            assert_eq!(f, (i, j));
+            assert_eq!(t, (i, j));
+        });
+
+    let mut out = Array::zeros((5, 7).f());
+    Zip::indexed(&mut out)
+        .and(&sliced)
+        .for_each(|(i, j), o, &(si, sj)| *o = (i + j) * 100 + si + sj);
+    assert_eq!(out, Array::from_shape_fn((5, 7), |(i, j)| (i + j) * 100 + 2 * i + 13 - 2 * j));
+
+    let (a, b) = Zip::indexed(&f).and(&sliced).split();
+    for part in [a, b] {
+        part.for_each(|i, &f, &(si, sj)| {
+            assert_eq!(f, i);
+            assert_eq!((si, sj), (2 * i.0, 13 - 2 * i.1));
+        });
+    }
+}
+
+#[test]
+fn test_zip_layout_negotiation()
+{
+    let dim = (6, 9, 4);
+    let c = Array::from_shape_fn(dim, |(i, j, k)| (i * 100 + j * 10 + k) as f32);
+    let f = Array::from_shape_fn(dim.f(), |(i, j, k)| (i * 100 + j * 10 + k) as f32);
+    let expected = &c * 3.;
+
+    for &out_f in &[false, true] {
+        for &(a, b, d) in &[(&c, &c, &c), (&c, &f, &f), (&f, &c, &c), (&f, &f, &c), (&c, &f, &c)] {
+            let mut out = Array::zeros(dim.set_f(out_f));
+            Zip::from(&mut out)
+                .and(a)
+                .and(b)
+                .and(d)
+                .for_each(|o, &x, &y, &z| *o = x + y + z);
+            assert_eq!(out, expected);
+
+            let collected = Zip::from(a)
+                .and(b)
+                .and(d)
+                .map_collect(|&x, &y, &z| x + y + z);
+            assert_eq!(collected, expected);
+
+            let mut out = Array::zeros(dim.set_f(out_f));
+            Zip::indexed(&mut out)
+                .and(a)
+                .and(b)
+                .for_each(|(i, j, k), o, &x, &y| {
+                    assert_eq!(x, y);
+                    assert_eq!(x, c[[i, j, k]]);
+                    *o = x;
+                });
+            assert_eq!(out, c);
+        }
+    }
+
+    // A tie in layout is broken toward the output, which is then written in
+    // its memory order.
+    for &(out_f, input) in &[(false, &f), (true, &c)] {
+        let mut out = Array::zeros(dim.set_f(out_f));
+        let mut count = 0;
+        Zip::from(input).and(&mut out).for_each(|_, o| {
+            *o = count;
+            count += 1;
+        });
+        assert_equal(out.as_slice_memory_order().unwrap().iter().cloned(), 0..out.len());
+    }
+}
+
+#[test]
+fn test_zip_fold_sq_dist()
+{
+    let a = Array::from_shape_fn((6, 7), |(i, j)| (i * 7 + j) as f64);
+    let b = Array::from_shape_fn((6, 7).f(), |(i, j)| (i + j) as f64 / 2.);
+    let b_sliced = Array::from_shape_fn((12, 21), |(i, j)| (i + j) as f64);
+    let b_sliced = b_sliced.slice(s![..;2, ..;-3]);
+    for b in [b.view(), b_sliced] {
+        let diff = &a - &b;
+        let expected = (&diff * &diff).sum();
+        let sq_dist = Zip::from(&a)
+            .and(&b)
+            .fold(0., |acc, &a, &b| acc + (a - b) * (a - b));
+        assert_eq!(sq_dist, expected);
+        let sq_dist = Zip::from(a.t())
+            .and(b.t())
+            .fold(0., |acc, &a, &b| acc + (a - b) * (a - b));
+        assert_eq!(sq_dist, expected);
+    }
+
+    let empty = Array2::<f64>::zeros((3, 0));
+    assert_eq!(Zip::from(&empty).and(&empty).fold(7., |_, _, _| unreachable!()), 7.);
+    let done = Zip::from(&empty).fold_while(7., |_, _| unreachable!());
+    assert!(!done.is_done());
+    assert_eq!(done.into_inner(), 7.);
+}
+
 #[test]
 fn test_zip_all()
 {
@@ -483,3 +738,78 @@ fn test_zip_all_empty_array()
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| true));
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| false));
 }
+
+#[test]
+fn test_zip_broadcast_scalar()
+{
+    let a = Array::from_shape_fn((5, 7), |(i, j)| (i * 7 + j) as f32);
+    let b = a.t().to_owned().reversed_axes();
+    let alpha = 1.5;
+
+    let mut expected = Array::zeros(a.raw_dim());
+    Zip::from(&mut expected)
+        .and(&a)
+        .and(&b)
+        .for_each(|y, &a, &b| *y = alpha * a + b);
+
+    let mut y = Array::zeros(a.raw_dim());
+    Zip::from(&mut y)
+        .and(&a)
+        .and(&b)
+        .and(broadcast_scalar(&alpha, a.raw_dim()))
+        .for_each(|y, &a, &b, &alpha| *y = alpha * a + b);
+    assert_eq!(y, expected);
+
+    let mut y = Array::zeros(a.raw_dim());
+    Zip::from(&mut y)
+        .and(&a)
+        .and(&b)
+        .and_scalar(&alpha)
+        .for_each(|y, &a, &b, &alpha| *y = alpha * a + b);
+    assert_eq!(y, expected);
+
+    let mut y = Array::zeros(a.raw_dim());
+    azip!((y in &mut y, &a in &a, &b in &b, &alpha in broadcast_scalar(&alpha, a.raw_dim())) *y = alpha * a + b);
+    assert_eq!(y, expected);
+
+    // the scalar does not keep an f-order zip off its contiguous path
+    let mut y = Array::zeros(a.raw_dim().f());
+    Zip::from(&mut y)
+        .and(&b)
+        .and_scalar(&alpha)
+        .for_each(|y, &b, &alpha| *y = alpha * b);
+    assert_eq!(y, &b * alpha);
+
+    let v = ArrayView::from_scalar(&alpha, (5, 7));
+    assert_eq!(v.strides(), &[0, 0]);
+    assert!(v.iter().all(|&x| x == alpha));
+    assert_eq!(broadcast_scalar(&alpha, (5, 7)).into_view(), v);
+}
+
+#[test]
+fn test_zip_broadcast_scalar_generic()
+{
+    fn axpy<'a, P>(y: &mut Array2<f64>, alpha: P, x: &Array2<f64>)
+    where P: IntoNdProducer<Item = &'a f64, Dim = Ix2>
+    {
+        Zip::from(y)
+            .and(alpha)
+            .and(x)
+            .for_each(|y, &alpha, &x| *y += alpha * x);
+    }
+
+    let x = Array::from_shape_fn((3, 4), |(i, j)| (i + j) as f64);
+    let alphas = Array::from_shape_fn((3, 4), |(i, _)| i as f64);
+
+    let mut y = Array2::zeros((3, 4));
+    axpy(&mut y, &alphas, &x);
+    assert_eq!(y, &alphas * &x);
+
+    let mut y = Array2::zeros((3, 4));
+    axpy(&mut y, broadcast_scalar(&2., (3, 4)), &x);
+    assert_eq!(y, &x * 2.);
+
+    let mut y = Array2::zeros((3, 4));
+    axpy(&mut y, ArrayView::from_scalar(&2., (3, 4)), &x);
+    assert_eq!(y, &x * 2.);
+}
@@ -98,3 +98,186 @@ fn test_broadcast_1d()
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn test_zip_map()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64 / 2.);
+    let b = Array::from_shape_fn((3, 4).f(), |(i, j)| (i + j) as i32);
+    let gt = a.zip_map(&b, |&x, &y| x > y as f64);
+    assert_eq!(gt, Array::from_shape_fn((3, 4), |(i, j)| a[[i, j]] > b[[i, j]] as f64));
+
+    // rhs is broadcast to the shape of self
+    let row = array![1, 2, 3, 4];
+    let sums = a
+        .t()
+        .zip_map(&array![[10], [20], [30], [40]], |&x, &y| x + y as f64);
+    assert_eq!(sums, &a.t() + &array![[10.], [20.], [30.], [40.]]);
+    let products = b.zip_map(&row, |&x, &y| x * y);
+    assert_eq!(products, &b * &row);
+    assert_eq!(b.zip_map(&arr0(5), |&x, &y| x - y), &b - 5);
+
+    let strings = Array::from_elem((2, 3), String::from("x"));
+    let repeated = strings.zip_map(&array![1, 2, 3], |s, &n| s.repeat(n));
+    assert_eq!(repeated[[1, 2]], "xxx");
+}
+
+#[test]
+fn test_try_zip_map()
+{
+    let digits = array![["1", "22", "ff"], ["7", "x", "10"]];
+    let radix = array![10, 10, 16];
+
+    let mut calls = 0;
+    let parsed: Result<Array2<u32>, _> = digits.try_zip_map(&radix, |s, &r| {
+        calls += 1;
+        u32::from_str_radix(s, r)
+    });
+    assert!(parsed.is_err());
+    // elements are visited in logical order, stopping at the first error
+    assert_eq!(calls, 5);
+
+    let parsed = digits
+        .slice(s![..1, ..])
+        .try_zip_map(&radix, |s, &r| u32::from_str_radix(s, r));
+    assert_eq!(parsed, Ok(array![[1, 22, 255]]));
+
+    let f = Array::from_shape_fn((3, 4).f(), |(i, j)| i * 4 + j);
+    let ok: Result<_, ()> = f.try_zip_map(&arr0(1), |&x, &y| Ok(x + y));
+    let ok = ok.unwrap();
+    assert_eq!(ok, &f + 1);
+    assert!(ok.is_standard_layout());
+}
+
+#[test]
+fn shape_mismatch_panic_messages()
+{
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    fn panic_message<F: FnOnce()>(f: F) -> String
+    {
+        let payload = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+        match payload.downcast::<String>() {
+            Ok(msg) => *msg,
+            Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+        }
+    }
+
+    let a = Array2::<f64>::zeros((3, 4));
+    let b = Array2::<f64>::zeros((4, 4));
+    let c = Array1::<f64>::zeros(3);
+
+    assert_eq!(
+        panic_message(|| {
+            let _ = &a + &b;
+        }),
+        "ndarray: could not broadcast [3, 4] and [4, 4] together in '+': \
+         axis 0 of lhs has length 3 but axis 0 of rhs has length 4"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = a.clone() * &c;
+        }),
+        "ndarray: could not broadcast [3, 4] and [3] together in '*': \
+         axis 1 of lhs has length 4 but axis 0 of rhs has length 3"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = &c - b.clone();
+        }),
+        "ndarray: could not broadcast [3] and [4, 4] together in '-': \
+         axis 0 of lhs has length 3 but axis 1 of rhs has length 4"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let mut b = b.clone();
+            b += &a;
+        }),
+        "ndarray: could not broadcast [3, 4] to [4, 4] in '+=': \
+         axis 0 of rhs has length 3 but axis 0 of lhs has length 4"
+    );
+    assert_eq!(
+        panic_message(|| b.clone().assign(&c)),
+        "ndarray: could not broadcast [3] to [4, 4] in 'assign': \
+         axis 0 of rhs has length 3 but axis 1 of lhs has length 4"
+    );
+    assert_eq!(
+        panic_message(|| b.clone().zip_mut_with(&a, |x, y| *x = *y)),
+        "ndarray: could not broadcast [3, 4] to [4, 4] in 'zip_mut_with': \
+         axis 0 of rhs has length 3 but axis 0 of lhs has length 4"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = b.zip_map(&a, |x, y| x + y);
+        }),
+        "ndarray: could not broadcast [3, 4] to [4, 4] in 'zip_map': \
+         axis 0 of rhs has length 3 but axis 0 of lhs has length 4"
+    );
+    assert_eq!(
+        panic_message(|| Array1::<f64>::zeros(4).assign(&a)),
+        "ndarray: could not broadcast [3, 4] to [4] in 'assign': rhs has 2 axes but lhs has only 1"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = &Array3::<f64>::zeros((2, 1, 5)) + &Array2::<f64>::zeros((3, 4));
+        }),
+        "ndarray: could not broadcast [2, 1, 5] and [3, 4] together in '+': \
+         axis 2 of lhs has length 5 but axis 1 of rhs has length 4"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = a.dot(&a);
+        }),
+        "ndarray: shapes [3, 4] and [3, 4] are not compatible in 'dot'"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = a.dot(&c);
+        }),
+        "ndarray: shapes [3, 4] and [3] are not compatible in 'dot'"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = c.dot(&b);
+        }),
+        "ndarray: shapes [3] and [4, 4] are not compatible in 'dot'"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = c.dot(&Array1::zeros(4));
+        }),
+        "ndarray: shapes [3] and [4] are not compatible in 'dot'"
+    );
+}
+
+#[test]
+fn broadcast_with_report()
+{
+    use ndarray::{BroadcastAxis::*, ErrorKind};
+
+    let a = Array::from_shape_fn((3, 1), |(i, _)| i);
+    let (view, report) = a.broadcast_with_report((2, 3, 4)).unwrap();
+    assert_eq!(report, [Prepended, Matched, Stretched]);
+    assert_eq!(view, a.broadcast((2, 3, 4)).unwrap());
+
+    let (_, report) = a.broadcast_with_report((3, 1)).unwrap();
+    assert_eq!(report, [Matched, Matched]);
+    let (_, report) = a.broadcast_with_report((5, 1, 3, 7)).unwrap();
+    assert_eq!(report, [Prepended, Prepended, Matched, Stretched]);
+    let (_, report) = arr0(1.).broadcast_with_report((2, 2)).unwrap();
+    assert_eq!(report, [Prepended, Prepended]);
+
+    let err = a.broadcast_with_report((2, 4)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: can not broadcast [3, 1] to [2, 4], \
+         because axis 0 of the array has length 3 but axis 0 of the shape has length 2 (in broadcast_with_report)"
+    );
+    let err = a.broadcast_with_report(3).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("because the array has 2 axes but the shape has only 1 (in broadcast_with_report)"));
+    let err = a.broadcast_with_report((usize::MAX, 3, 2)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overflow);
+}
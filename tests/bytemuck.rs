@@ -0,0 +1,132 @@
+#![cfg(feature = "bytemuck")]
+
+use ndarray::prelude::*;
+use ndarray::ErrorKind;
+
+#[test]
+fn bytes_round_trip_f32()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| i as f32 * 0.5 - j as f32);
+    let bytes = a.as_bytes().unwrap();
+    assert_eq!(bytes.len(), 12 * 4);
+    let b = ArrayView::<f32, _>::from_bytes((3, 4), bytes).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(b.as_ptr(), a.as_ptr());
+
+    // F-order arrays are contiguous in memory order
+    let f = a.t().to_owned();
+    assert_eq!(f.as_bytes().unwrap(), bytes);
+    let g = ArrayView::<f32, _>::from_bytes((4, 3).f(), f.as_bytes().unwrap()).unwrap();
+    assert_eq!(g, a.t());
+
+    assert!(a.slice(s![.., ..2]).as_bytes().is_none());
+}
+
+#[test]
+fn bytes_round_trip_u16_mut()
+{
+    let mut a = Array::from_iter(0..10u16);
+    let mut buf = a.as_bytes().unwrap().to_vec();
+    {
+        let mut v = ArrayViewMut::<u16, _>::from_bytes_mut(10, &mut buf[..]).unwrap();
+        assert_eq!(v, a);
+        v[3] = 300;
+    }
+    a.as_bytes_mut().unwrap().copy_from_slice(&buf);
+    assert_eq!(a[3], 300);
+    assert_eq!(a[4], 4);
+}
+
+#[test]
+fn from_bytes_errors()
+{
+    let storage = Array::from_elem(9, 0u32);
+    let bytes = storage.as_bytes().unwrap();
+
+    // misaligned: an offset of one byte into a buffer aligned for u32
+    let err = ArrayView::<u32, _>::from_bytes(4, &bytes[1..17]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleLayout);
+
+    // length not a multiple of the element size
+    let err = ArrayView::<u32, _>::from_bytes(2, &bytes[..10]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+
+    // shape does not match the number of elements
+    let err = ArrayView::<u32, _>::from_bytes((2, 3), &bytes[..16]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    assert!(ArrayView::<u32, _>::from_bytes((2, 2), &bytes[..16]).is_ok());
+}
+
+#[test]
+fn raw_io_round_trip()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 100 + j * 10 + k) as f64 - 0.5);
+    let mut expected = Vec::new();
+    for &x in a.iter() {
+        expected.extend_from_slice(&x.to_ne_bytes());
+    }
+
+    let f = Array::from_shape_fn((3, 4, 5).f(), |(i, j, k)| (i * 100 + j * 10 + k) as f64 - 0.5);
+    assert!(!f.is_standard_layout());
+    let sliced = Array::from_shape_fn((6, 4, 10), |(i, j, k)| (i / 2 * 100 + j * 10 + k / 2) as f64 - 0.5);
+    let sliced = sliced.slice(s![..;2, .., ..;2]);
+
+    for source in [a.view(), f.view(), sliced] {
+        assert_eq!(source, a);
+        let mut buf = Vec::new();
+        source.write_raw(&mut buf).unwrap();
+        assert_eq!(buf, expected);
+        let b = Array3::<f64>::read_raw(a.raw_dim(), &mut &buf[..]).unwrap();
+        assert_eq!(b, a);
+        assert!(b.is_standard_layout());
+    }
+
+    // the reader is left at the end of the elements
+    let mut buf = Vec::new();
+    a.write_raw(&mut buf).unwrap();
+    buf.push(7);
+    let mut reader = &buf[..];
+    let b = Array1::<f64>::read_raw(a.len(), &mut reader).unwrap();
+    assert_eq!(b, Array::from_iter(a.iter().cloned()));
+    assert_eq!(reader, &[7]);
+}
+
+#[test]
+fn raw_io_truncated()
+{
+    use std::io::ErrorKind;
+
+    let a = Array::from_iter(0..12u32)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    let mut buf = Vec::new();
+    a.write_raw(&mut buf).unwrap();
+
+    let err = Array2::<u32>::read_raw((3, 4), &mut &buf[..buf.len() - 4]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    // a partial element at the end
+    let err = Array2::<u32>::read_raw((3, 4), &mut &buf[..buf.len() - 1]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    let err = Array2::<u32>::read_raw((usize::MAX / 2, 4), &mut &buf[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    let b = Array2::<u32>::read_raw((0, 4), &mut &buf[..0]).unwrap();
+    assert_eq!(b.shape(), &[0, 4]);
+}
+
+#[test]
+fn raw_io_zero_sized()
+{
+    let a = Array2::from_elem((2, 3), ());
+    let mut buf = Vec::new();
+    a.write_raw(&mut buf).unwrap();
+    a.t().write_raw(&mut buf).unwrap();
+    assert!(buf.is_empty());
+
+    let mut reader: &[u8] = &[7];
+    let b = Array1::<()>::read_raw(3, &mut reader).unwrap();
+    assert_eq!(b.len(), 3);
+    assert_eq!(reader, &[7]);
+}
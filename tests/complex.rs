@@ -1,5 +1,6 @@
+use ndarray::linalg::general_mat_mul;
 use ndarray::Array;
-use ndarray::{arr1, arr2, Axis};
+use ndarray::{arr1, arr2, s, Axis};
 use num_complex::Complex;
 use num_traits::Num;
 
@@ -23,3 +24,70 @@ fn complex_mat_mul()
         arr1(&[c(1.5, 1.), c(2.5, 0.)])
     );
 }
+
+#[test]
+fn complex_sum_and_dot()
+{
+    let a = arr1(&[c(1., 2.), c(0., -1.), c(3., 0.)]);
+    let b = arr1(&[c(3., 0.), c(1., 1.), c(-1., 2.)]);
+    assert_eq!(a.sum(), c(4., 1.));
+    // (1+2i)*3 + (-i)(1+i) + 3(-1+2i) = (3+6i) + (1-i) + (-3+6i)
+    assert_eq!(a.dot(&b), c(1., 11.));
+    assert_eq!(a.slice(s![..;-1]).dot(&b.slice(s![..;-1])), c(1., 11.));
+
+    let m = arr2(&[[c(1., 1.), c(0., 2.)], [c(2., 0.), c(1., -1.)]]);
+    let v = arr1(&[c(1., 0.), c(0., 1.)]);
+    assert_eq!(m.dot(&v), arr1(&[c(-1., 1.), c(3., 1.)]));
+    assert_eq!(m.dot(&m), arr2(&[[c(0., 6.), c(0., 4.)], [c(4., 0.), c(0., 2.)]]));
+
+    let m32 = m.mapv(|z| c(z.re as f32, z.im as f32));
+    let mut out = Array::from_elem((2, 2), c(1f32, 0.));
+    // i M Mᵀ + 2
+    general_mat_mul(c(0., 1.), &m32, &m32.t(), c(2., 0.), &mut out);
+    assert_eq!(out, arr2(&[[c(0., -4.), c(-2., 4.)], [c(-2., 4.), c(4., 4.)]]));
+}
+
+#[test]
+fn complex_conj_and_vdot()
+{
+    let a = arr2(&[[c(1., 2.), c(0., -1.)], [c(-3., 0.5), c(2., 2.)]]);
+    assert_eq!(a.conj(), arr2(&[[c(1., -2.), c(0., 1.)], [c(-3., -0.5), c(2., -2.)]]));
+    assert_eq!(a.conj().conj(), a);
+    assert_eq!((&a * &a.conj()).mapv(|z| z.im), Array::zeros((2, 2)));
+
+    let x = arr1(&[c(1., 2.), c(0., -1.), c(3., 0.)]);
+    let y = arr1(&[c(3., 0.), c(1., 1.), c(-1., 2.)]);
+    assert_eq!(x.vdot(&y), c(-1., 1.));
+    assert_eq!(x.vdot(&y), y.vdot(&x).conj());
+    assert_eq!(x.vdot(&y), x.conj().dot(&y));
+    assert_eq!(x.vdot(&x), c(15., 0.));
+    assert_eq!(a.row(1).vdot(&a.column(0)), c(-3., -0.5) * c(1., 2.) + c(2., -2.) * c(-3., 0.5));
+}
+
+#[test]
+fn complex_is_hermitian()
+{
+    let a = arr2(&[[c(2., 0.), c(1., -1.), c(0., 3.)], [c(1., 1.), c(3., 0.), c(4., 0.)], [
+        c(0., -3.),
+        c(4., 0.),
+        c(-1., 0.),
+    ]]);
+    assert!(a.is_hermitian(0.));
+    assert!(a.conj().is_hermitian(0.));
+    assert!(!a.mapv(|z| z * c(0., 1.)).is_hermitian(0.1));
+
+    // imaginary part on the diagonal
+    let mut b = a.clone();
+    b[[1, 1]].im = 1e-6;
+    assert!(b.is_hermitian(1e-5));
+    assert!(!b.is_hermitian(1e-7));
+
+    assert!(!a.slice(s![..2, ..]).is_hermitian(1.));
+}
+
+#[test]
+#[should_panic]
+fn complex_vdot_length_mismatch()
+{
+    arr1(&[c(1., 0.)]).vdot(&arr1(&[c(1., 0.), c(2., 0.)]));
+}
@@ -2,7 +2,24 @@
 
 use defmac::defmac;
 
-use ndarray::{arr2, ArcArray, Array, Axis, Dim, Dimension, IxDyn, RemoveAxis};
+use ndarray::{
+    arr0,
+    arr2,
+    s,
+    ArcArray,
+    Array,
+    ArrayD,
+    Axis,
+    Dim,
+    Dimension,
+    Ix0,
+    Ix1,
+    Ix2,
+    IxDyn,
+    Order,
+    RemoveAxis,
+    ShapeBuilder,
+};
 
 use std::hash::{Hash, Hasher};
 
@@ -63,6 +80,62 @@ fn remove_axis()
         .unwrap();
 }
 
+#[test]
+fn axis_from_signed()
+{
+    use ndarray::ErrorKind;
+
+    for (axis, expected) in [(0, 0), (2, 2), (-1, 2), (-3, 0)] {
+        assert_eq!(Axis::from_signed(axis, 3), Ok(Axis(expected)));
+    }
+    for axis in [3, -4, isize::MAX, isize::MIN] {
+        assert_eq!(Axis::from_signed(axis, 3).unwrap_err().kind(), ErrorKind::OutOfBounds);
+    }
+    assert!(Axis::from_signed(0, 0).is_err());
+    assert!(Axis::from_signed(-1, 0).is_err());
+    let err = Axis::from_signed(-4, 3).unwrap_err().to_string();
+    assert!(err.ends_with("Axis(-4) is out of bounds for an array with 3 axes"), "{}", err);
+
+    // with axis taking methods
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+    let ax = |axis| Axis::from_signed(axis, a.ndim()).unwrap();
+    assert_eq!(a.sum_axis(ax(-1)), a.sum_axis(Axis(2)));
+    assert_eq!(a.len_of(ax(-2)), 3);
+    assert_eq!(a.index_axis(ax(-3), 1), a.index_axis(Axis(0), 1));
+    assert_eq!(a.lanes(ax(-1)).into_iter().next().unwrap(), a.slice(ndarray::s![0, 0, ..]));
+
+    assert_eq!(Axis(3).to_string(), "Axis(3)");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn axis_out_of_bounds_panics()
+{
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    fn message<T>(f: impl FnOnce() -> T) -> String
+    {
+        let payload = catch_unwind(AssertUnwindSafe(f)).map(drop).unwrap_err();
+        payload.downcast_ref::<String>().cloned().unwrap()
+    }
+
+    let a = Array::from_shape_fn((2, 3), |(i, j)| i * 10 + j);
+    let d = a.clone().into_dyn();
+    let expected = "ndarray: Axis(2) is out of bounds for an array with 2 axes";
+    assert_eq!(message(|| a.len_of(Axis(2))), expected);
+    assert_eq!(message(|| a.stride_of(Axis(2))), expected);
+    assert_eq!(message(|| a.index_axis(Axis(2), 0)), expected);
+    assert_eq!(message(|| d.index_axis(Axis(2), 0)), expected);
+    assert_eq!(message(|| a.slice_axis(Axis(2), ndarray::Slice::from(..))), expected);
+    assert_eq!(message(|| a.sum_axis(Axis(2))), expected);
+    assert_eq!(message(|| d.sum_axis(Axis(2))), expected);
+    assert_eq!(message(|| a.lanes(Axis(2))), expected);
+    assert_eq!(message(|| a.axis_iter(Axis(2))), expected);
+    assert_eq!(message(|| a.view().invert_axis(Axis(2))), expected);
+    assert_eq!(message(|| a.view().remove_axis(Axis(2))), expected);
+    assert_eq!(message(|| a.view().split_at(Axis(2), 0)), expected);
+}
+
 #[test]
 #[allow(clippy::eq_op)]
 fn dyn_dimension()
@@ -81,6 +154,49 @@ fn dyn_dimension()
     assert_eq!(z.shape(), &dim[..]);
 }
 
+#[test]
+fn dyn_index_with_tuples()
+{
+    let a = ArrayD::from_shape_fn(IxDyn(&[2, 3, 4]), |ix| ix[0] * 100 + ix[1] * 10 + ix[2]);
+    assert_eq!(a[(1, 2, 3)], 123);
+    assert_eq!(a[(1, 2, 3)], a[[1, 2, 3]]);
+    assert_eq!(a.get((0, 1, 2)), Some(&12));
+    assert_eq!(a.get((0, 3, 0)), None);
+
+    // an index with the wrong number of axes is never valid
+    assert_eq!(a.get((1, 2)), None);
+    assert_eq!(a.get((1, 2, 3, 0)), None);
+    assert_eq!(a.get([1, 2]), None);
+    assert_eq!(a.get(1), None);
+
+    let mut b = ArrayD::<i32>::zeros(IxDyn(&[4]));
+    b[2] = 7;
+    b[(3,)] = 8;
+    assert_eq!(b.get(2), Some(&7));
+    assert_eq!(b.view()[(3,)], 8);
+    assert_eq!(b.get_mut((1, 0)), None);
+
+    let c = ArrayD::from_elem(IxDyn(&[1, 1, 1, 1, 1, 2]), 5);
+    assert_eq!(c[(0, 0, 0, 0, 0, 1)], 5);
+    assert_eq!(c.get((0, 0, 0, 0, 1)), None);
+}
+
+#[test]
+#[should_panic(expected = "has 2 axes, but the array of shape [2, 3, 4] has 3 axes")]
+fn dyn_index_wrong_ndim()
+{
+    let a = ArrayD::<f32>::zeros(IxDyn(&[2, 3, 4]));
+    let _ = a[(1, 2)];
+}
+
+#[test]
+#[should_panic(expected = "index [1, 2, 4] is out of bounds for array of shape [2, 3, 4]")]
+fn dyn_index_out_of_bounds()
+{
+    let a = ArrayD::<f32>::zeros(IxDyn(&[2, 3, 4]));
+    let _ = a[[1, 2, 4]];
+}
+
 #[test]
 fn dyn_insert()
 {
@@ -360,3 +476,151 @@ fn test_all_ndindex()
     ndindex!(10, 4, 3, 2, 2);
     ndindex!(10, 4, 3, 2, 2, 2);
 }
+
+#[test]
+fn prev_for_reverses_next_for()
+{
+    fn check<D: Dimension>(dim: D)
+    {
+        let mut forward = Vec::new();
+        let mut index = dim.first_index();
+        while let Some(ix) = index {
+            forward.push(ix.clone());
+            index = dim.next_for(ix);
+        }
+        assert_eq!(forward.len(), dim.size());
+
+        let mut backward = Vec::new();
+        let mut index = dim.last_index();
+        while let Some(ix) = index {
+            backward.push(ix.clone());
+            index = dim.prev_for(ix);
+        }
+        backward.reverse();
+        assert_eq!(forward, backward, "shape {:?}", dim);
+
+        for (ix, next) in forward.iter().zip(&forward[1..]) {
+            assert_eq!(dim.prev_for(next.clone()).as_ref(), Some(ix));
+        }
+    }
+
+    check(Dim([5]));
+    check(Dim([3, 4]));
+    check(Dim([4, 1]));
+    check(Dim([2, 3, 4]));
+    check(Dim([3, 1, 2]));
+    check(Dim([2, 1, 3, 2]));
+    check(Dim([2, 2, 1, 2, 3]));
+    check(IxDyn(&[3, 2, 2]));
+    check(IxDyn(&[7]));
+}
+
+#[test]
+fn last_index_empty_and_single()
+{
+    assert_eq!(Dim([0]).last_index(), None);
+    assert_eq!(Dim([3, 0]).last_index(), None);
+    assert_eq!(Dim([0, 3]).last_index(), None);
+    assert_eq!(Dim([2, 0, 2]).last_index(), None);
+    assert_eq!(Dim([2, 2, 2, 0]).last_index(), None);
+    assert_eq!(IxDyn(&[1, 0]).last_index(), None);
+
+    fn check_single<D: Dimension>(dim: D)
+    {
+        let last = dim.last_index().unwrap();
+        assert_eq!(Some(&last), dim.first_index().as_ref());
+        assert_eq!(dim.prev_for(last.clone()), None);
+        assert_eq!(dim.next_for(last), None);
+    }
+    check_single(Dim([]));
+    check_single(Dim([1]));
+    check_single(Dim([1, 1]));
+    check_single(Dim([1, 1, 1]));
+    check_single(Dim([1, 1, 1, 1]));
+    check_single(IxDyn(&[1, 1]));
+    check_single(IxDyn(&[]));
+}
+
+#[test]
+fn strides_for_order()
+{
+    fn check<D: Dimension>(dim: D, c: &[usize], f: &[usize])
+    {
+        assert_eq!(dim.strides_for(Order::RowMajor).slice(), c, "row major strides of {:?}", dim);
+        assert_eq!(dim.strides_for(Order::ColumnMajor).slice(), f, "column major strides of {:?}", dim);
+        assert_eq!(dim.default_strides(), dim.strides_for(Order::C));
+        assert_eq!(dim.fortran_strides(), dim.strides_for(Order::F));
+
+        // the same strides as the array constructors
+        let a = Array::<u8, _>::zeros(dim.clone());
+        assert_eq!(a.strides(), c.iter().map(|&s| s as isize).collect::<Vec<_>>());
+        let a = Array::<u8, _>::zeros(dim.f());
+        assert_eq!(a.strides(), f.iter().map(|&s| s as isize).collect::<Vec<_>>());
+    }
+    check(Dim([]), &[], &[]);
+    check(Dim([5]), &[1], &[1]);
+    check(Dim([3, 4]), &[4, 1], &[1, 3]);
+    check(Dim([2, 3, 4]), &[12, 4, 1], &[1, 2, 6]);
+    check(IxDyn(&[2, 3, 4, 5]), &[60, 20, 5, 1], &[1, 2, 6, 24]);
+
+    // axes of length 1 get ordinary strides
+    check(Dim([1]), &[1], &[1]);
+    check(Dim([1, 4]), &[4, 1], &[1, 1]);
+    check(Dim([3, 1]), &[1, 1], &[1, 3]);
+    check(Dim([2, 1, 4]), &[4, 4, 1], &[1, 2, 2]);
+
+    // a zero-length axis makes every stride zero
+    check(Dim([0]), &[0], &[0]);
+    check(Dim([0, 4]), &[0, 0], &[0, 0]);
+    check(Dim([3, 0]), &[0, 0], &[0, 0]);
+    check(Dim([2, 0, 4]), &[0, 0, 0], &[0, 0, 0]);
+    check(IxDyn(&[2, 3, 0, 5]), &[0, 0, 0, 0], &[0, 0, 0, 0]);
+}
+
+#[test]
+fn size_and_size_checked()
+{
+    assert_eq!(Dim([]).size(), 1);
+    assert_eq!(Dim([]).size_checked(), Some(1));
+    assert_eq!(Dim([2, 3, 4]).size(), 24);
+    assert_eq!(Dim([2, 3, 4]).size_checked(), Some(24));
+    assert_eq!(Dim([2, 0, 4]).size(), 0);
+    assert_eq!(IxDyn(&[2, 0, 4]).size_checked(), Some(0));
+    assert_eq!(Dim([usize::MAX, 2]).size_checked(), None);
+    assert_eq!(IxDyn(&[1 << 40, 1 << 40]).size_checked(), None);
+}
+
+#[test]
+fn dim_pattern_per_rank()
+{
+    let a0 = arr0(1);
+    let () = a0.dim();
+    assert_eq!(a0.raw_dim(), Ix0());
+
+    let a1 = Array::<u8, _>::zeros(5);
+    let n: usize = a1.dim();
+    assert_eq!(n, 5);
+    assert_eq!(a1.view().dim(), 5);
+    assert_eq!(a1.raw_dim(), Ix1(5));
+
+    let mut a2 = Array::<u8, _>::zeros((3, 4));
+    let (m, n) = a2.dim();
+    assert_eq!((m, n), (3, 4));
+    assert_eq!(a2.t().dim(), (4, 3));
+    assert_eq!(a2.view_mut().dim(), (3, 4));
+    assert_eq!(a2.raw_dim(), Ix2(3, 4));
+
+    let a3 = Array::<u8, _>::zeros((2, 3, 4));
+    let (p, m, n) = a3.slice(s![.., 1.., ..;2]).dim();
+    assert_eq!((p, m, n), (2, 2, 2));
+    assert_eq!(a3.index_axis(Axis(0), 1).dim(), (3, 4));
+
+    let a6 = Array::<u8, _>::zeros((1, 2, 3, 4, 5, 6));
+    assert_eq!(a6.dim(), (1, 2, 3, 4, 5, 6));
+    assert_eq!(a6.raw_dim(), Dim([1, 2, 3, 4, 5, 6]));
+
+    let dynamic = a3.clone().into_dyn();
+    assert_eq!(dynamic.dim(), IxDyn(&[2, 3, 4]));
+    assert_eq!(dynamic.raw_dim(), dynamic.dim());
+    assert_eq!(dynamic.into_shared().dim(), IxDyn(&[2, 3, 4]));
+}
@@ -0,0 +1,147 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, ExternalArray, ExternalStorage, ExternalStorageMut, ShapeBuilder, Zip};
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::mem;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use memmap2::Mmap;
+
+/// Storage in a boxed slice, which keeps its address when moved.
+#[derive(Clone)]
+struct Boxed<A>(Box<[A]>);
+
+unsafe impl<A> ExternalStorage for Boxed<A>
+{
+    type Elem = A;
+
+    fn as_ptr(&self) -> NonNull<A>
+    {
+        NonNull::new(self.0.as_ptr() as *mut A).unwrap()
+    }
+
+    fn len(&self) -> usize
+    {
+        self.0.len()
+    }
+}
+
+// Cloning the box copies the elements, so the storage owns them exclusively.
+unsafe impl<A> ExternalStorageMut for Boxed<A> {}
+
+fn boxed(n: usize) -> Boxed<f64>
+{
+    Boxed((0..n).map(|i| i as f64).collect())
+}
+
+/// Read-only storage of the `f64` elements of a memory-mapped file.
+#[derive(Clone)]
+struct MmapF64(Arc<Mmap>);
+
+unsafe impl ExternalStorage for MmapF64
+{
+    type Elem = f64;
+
+    fn as_ptr(&self) -> NonNull<f64>
+    {
+        NonNull::new(self.0.as_ptr() as *mut f64).unwrap()
+    }
+
+    fn len(&self) -> usize
+    {
+        self.0.len() / mem::size_of::<f64>()
+    }
+}
+
+#[test]
+fn from_storage()
+{
+    let a = ExternalArray::from_storage((3, 4), boxed(12)).unwrap();
+    assert_eq!(a, Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64));
+
+    let f = ExternalArray::from_storage((3, 4).f(), boxed(12)).unwrap();
+    assert_eq!(f, Array::from_shape_fn((3, 4), |(i, j)| (i + j * 3) as f64));
+
+    // negative strides start at the end of the storage
+    let r = ExternalArray::from_storage((3, 4).strides((-4isize as usize, 1)), boxed(12)).unwrap();
+    assert_eq!(r, a.slice(s![..;-1, ..]));
+
+    let err = ExternalArray::from_storage((2, 5), boxed(12)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = ExternalArray::from_storage((3, 4).strides((5, 1)), boxed(12)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn read_only_api()
+{
+    let a = ExternalArray::from_storage((6, 5), boxed(30)).unwrap();
+    let owned = a.to_owned();
+
+    assert_eq!(a.slice(s![1..;2, ..;-1]), owned.slice(s![1..;2, ..;-1]));
+    assert_eq!(a.column(3), owned.column(3));
+    assert!(a.iter().eq(owned.iter()));
+    assert_eq!(a.outer_iter().count(), 6);
+    assert_eq!(a.sum(), owned.sum());
+    assert_eq!(a.sum_axis(Axis(0)), owned.sum_axis(Axis(0)));
+    assert_eq!(a.fold(f64::MIN, |m, &x| m.max(x)), 29.);
+    assert_eq!(a.dot(&a.t()), owned.dot(&owned.t()));
+    assert_eq!(a.map(|x| x * 2.), &owned * 2.);
+    assert_eq!(&a + &owned, &owned * 2.);
+
+    let mut zipped = 0.;
+    Zip::from(&a).and(&owned).for_each(|&x, &y| zipped += x * y);
+    assert_eq!(zipped, owned.iter().map(|x| x * x).sum::<f64>());
+}
+
+#[test]
+fn clone_and_mutate()
+{
+    let mut a = ExternalArray::from_storage((4, 4), boxed(16)).unwrap();
+    a.slice_collapse(s![1.., 1..]);
+    let b = a.clone();
+    assert_eq!(a, b);
+    assert_ne!(a.as_ptr(), b.as_ptr());
+
+    a.fill(0.);
+    a[[0, 0]] = 1.;
+    a += &b;
+    assert_eq!(a[[0, 0]], 6.);
+    assert_eq!(a[[2, 2]], 15.);
+    assert_eq!(b[[0, 0]], 5.);
+
+    let storage = a.into_storage();
+    assert_eq!(storage.0[5], 6.);
+    assert_eq!(storage.0[0], 0.);
+}
+
+#[test]
+fn memory_mapped()
+{
+    let path = std::env::temp_dir().join(format!("ndarray-external-storage-{}.bin", std::process::id()));
+    {
+        let mut file = File::create(&path).unwrap();
+        for i in 0..24 {
+            file.write_all(&(i as f64).to_ne_bytes()).unwrap();
+        }
+    }
+    let map = unsafe { Mmap::map(&File::open(&path).unwrap()).unwrap() };
+    let a = ExternalArray::from_storage((2, 3, 4), MmapF64(Arc::new(map))).unwrap();
+    let expected = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f64);
+
+    assert_eq!(a, expected);
+    assert_eq!(a.sum_axis(Axis(2)), expected.sum_axis(Axis(2)));
+    let b = a.clone().index_axis_move(Axis(0), 1);
+    assert_eq!(b.dot(&Array1::ones(4)), expected.slice(s![1, .., ..]).sum_axis(Axis(1)));
+
+    let c = a.clone();
+    assert_eq!(c.as_ptr(), a.as_ptr());
+    assert_eq!(c.into_owned(), expected);
+
+    drop(a);
+    fs::remove_file(&path).unwrap();
+}
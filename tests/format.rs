@@ -1,5 +1,6 @@
 use ndarray::prelude::*;
 use ndarray::rcarr1;
+use ndarray::FormatOptions;
 
 #[test]
 fn formatting()
@@ -74,3 +75,204 @@ fn debug_format()
  [0, 0, 0, 0]], shape=[3, 4], strides=[4, 1], layout=Cc (0x5), dynamic ndim=2"
     );
 }
+
+fn write_formatted<A, D>(a: &Array<A, D>, opts: &FormatOptions) -> String
+where
+    A: std::fmt::Display,
+    D: Dimension,
+{
+    let mut s = String::new();
+    a.write_formatted(&mut s, opts).unwrap();
+    s
+}
+
+#[test]
+fn write_formatted_default_matches_display()
+{
+    let arrays = [
+        Array::from_iter(0..7).into_dyn(),
+        Array::from_iter(0..600)
+            .into_shape_with_order((20, 30))
+            .unwrap()
+            .into_dyn(),
+        Array::from_iter(0..1000)
+            .into_shape_with_order((10, 10, 10))
+            .unwrap()
+            .into_dyn(),
+        Array::<i32, _>::zeros((2, 0, 3)).into_dyn(),
+        arr0(5).into_dyn(),
+    ];
+    for a in &arrays {
+        assert_eq!(write_formatted(a, &FormatOptions::new()), format!("{}", a));
+        assert_eq!(write_formatted(a, &FormatOptions::new().no_elision()), format!("{:#}", a));
+    }
+}
+
+#[test]
+fn write_formatted_float()
+{
+    let a = array![0.5, -1.25, 3.];
+    let opts = FormatOptions::new().precision(3);
+    assert_eq!(write_formatted(&a, &opts), "[0.500, -1.250, 3.000]");
+
+    let a = array![[0.5, -1.25], [3., 100.]];
+    let opts = FormatOptions::new().precision(1).width(6).separator(" ");
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[[   0.5   -1.2]
+ [   3.0  100.0]]"
+    );
+
+    let a = Array::from_iter((0..8).map(|x| x as f64 / 4.))
+        .into_shape_with_order((2, 2, 2))
+        .unwrap();
+    let opts = FormatOptions::new().precision(2).separator("; ");
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[[[0.00; 0.25];
+  [0.50; 0.75]];
+
+ [[1.00; 1.25];
+  [1.50; 1.75]]]"
+    );
+}
+
+#[test]
+fn write_formatted_integer()
+{
+    let a = array![1, -20, 300];
+    let opts = FormatOptions::new().width(4).separator("|");
+    assert_eq!(write_formatted(&a, &opts), "[   1| -20| 300]");
+
+    let a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    let opts = FormatOptions::new().width(2);
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[[ 0,  1,  2],
+ [ 3,  4,  5]]"
+    );
+
+    let a = Array::from_iter(0..8)
+        .into_shape_with_order((2, 2, 2))
+        .unwrap();
+    let opts = FormatOptions::new().separator(" ");
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[[[0 1]
+  [2 3]]
+
+ [[4 5]
+  [6 7]]]"
+    );
+}
+
+#[test]
+fn write_formatted_elision()
+{
+    let a = Array::from_iter(0..10);
+    let opts = FormatOptions::new().max_axis_len(4);
+    assert_eq!(write_formatted(&a, &opts), "[0, 1, ..., 8, 9]");
+    let opts = opts.ellipsis("…");
+    assert_eq!(write_formatted(&a, &opts), "[0, 1, …, 8, 9]");
+    let opts = opts.no_elision();
+    assert_eq!(write_formatted(&a, &opts), "[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]");
+
+    let a = Array::from_iter(0..25)
+        .into_shape_with_order((5, 5))
+        .unwrap();
+    let opts = FormatOptions::new().max_axis_len(2).width(2);
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[[ 0, ...,  4],
+ ...,
+ [20, ..., 24]]"
+    );
+
+    let a = Array::from_iter(0..27)
+        .into_shape_with_order((3, 3, 3))
+        .unwrap();
+    let opts = FormatOptions::new().max_axis_len(2);
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[[[0, ..., 2],
+  ...,
+  [6, ..., 8]],
+
+ ...,
+
+ [[18, ..., 20],
+  ...,
+  [24, ..., 26]]]"
+    );
+}
+
+#[test]
+fn write_formatted_line_width()
+{
+    let a = Array::from_iter(0..12);
+    let opts = FormatOptions::new().width(2).line_width(20);
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[ 0,  1,  2,  3,  4,
+  5,  6,  7,  8,  9,
+ 10, 11]"
+    );
+
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((2, 6))
+        .unwrap();
+    let opts = FormatOptions::new().width(2).line_width(16).separator(" ");
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[[ 0  1  2  3  4
+   5]
+ [ 6  7  8  9 10
+  11]]"
+    );
+
+    // an element wider than the line is written on a line of its own
+    let a = array![1, 123456, 2];
+    let opts = FormatOptions::new().line_width(4);
+    assert_eq!(
+        write_formatted(&a, &opts),
+        "\
+[1,
+ 123456,
+ 2]"
+    );
+}
+
+#[test]
+fn write_formatted_with_closure()
+{
+    let a = array![[1200., 0.5], [-3., 0.]];
+    let mut s = String::new();
+    a.write_formatted_with(&mut s, &FormatOptions::new().separator(" "), |x, w| write!(w, "{:>8.2e}", x))
+        .unwrap();
+    assert_eq!(
+        s,
+        "\
+[[  1.20e3  5.00e-1]
+ [ -3.00e0   0.00e0]]"
+    );
+}
+
+#[test]
+fn write_formatted_io()
+{
+    let a = array![[1, 2], [3, 4]];
+    let mut out = Vec::new();
+    a.write_formatted_io(&mut out, &FormatOptions::new())
+        .unwrap();
+    assert_eq!(out, b"[[1, 2],\n [3, 4]]");
+}
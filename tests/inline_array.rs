@@ -0,0 +1,152 @@
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, InlineArray};
+
+use std::rc::Rc;
+
+type Matrix3 = InlineArray<f64, Ix2, 9>;
+
+#[test]
+fn construct_and_convert()
+{
+    let a = Array::from_shape_fn((3, 3), |(i, j)| (i * 10 + j) as f64);
+    let m = Matrix3::from_shape_fn((3, 3), |(i, j)| (i * 10 + j) as f64).unwrap();
+    assert_eq!(m.view(), a);
+    assert_eq!(m.dim(), (3, 3));
+    assert_eq!(m.len(), 9);
+    assert_eq!(m.capacity(), 9);
+    assert_eq!(m[[1, 2]], 12.);
+    assert_eq!(m.as_slice(), a.as_slice().unwrap());
+
+    // from views in any layout
+    let t = Matrix3::from_array(&a.t()).unwrap();
+    assert_eq!(t.view(), a.t());
+    assert_eq!(t.as_slice(), &[0., 10., 20., 1., 11., 21., 2., 12., 22.]);
+    let corner = Matrix3::from_array(&a.slice(s![1.., ..;-1])).unwrap();
+    assert_eq!(corner.dim(), (2, 3));
+    assert_eq!(corner.view(), a.slice(s![1.., ..;-1]));
+
+    assert_eq!(m.to_owned(), a);
+    assert_eq!(m.clone().into_array(), a);
+    assert!(m.clone().into_array().is_standard_layout());
+    assert_eq!(m, m.clone());
+    assert_ne!(m, t);
+
+    // shapes smaller than the capacity
+    let v = InlineArray::<i32, Ix1, 16>::from_elem(4, 7).unwrap();
+    assert_eq!(v.view(), array![7, 7, 7, 7]);
+    assert_eq!(v.capacity(), 16);
+    let e = InlineArray::<i32, Ix2, 4>::zeros((0, 5)).unwrap();
+    assert!(e.is_empty());
+    assert_eq!(e.shape(), &[0, 5]);
+}
+
+#[test]
+fn capacity_exceeded()
+{
+    let err = Matrix3::zeros((4, 4)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let err = Matrix3::from_array(&Array::zeros((2, 5))).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let err = Matrix3::from_elem((usize::MAX, 2), 0.).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overflow);
+    // empty, but the other axis lengths do not fit in an array
+    let err = InlineArray::<f64, Ix3, 6>::zeros((usize::MAX, 2, 0)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overflow);
+    let err = InlineArray::<f64, IxDyn, 4>::from_shape_fn(vec![2, 3], |_| 0.).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+
+    // exactly at capacity
+    assert!(InlineArray::<f64, IxDyn, 6>::from_shape_fn(vec![2, 3], |_| 0.).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "does not fit in an inline array with capacity 4")]
+fn dot_capacity_exceeded()
+{
+    let column = InlineArray::<f64, Ix2, 4>::zeros((3, 1)).unwrap();
+    let row = InlineArray::<f64, Ix2, 4>::zeros((1, 3)).unwrap();
+    let _ = column.dot(&row);
+}
+
+#[test]
+#[should_panic(expected = "does not fit in an inline array with capacity 9")]
+fn dot_capacity_overflow()
+{
+    // both operands are empty, but the product would have too many elements
+    // to count
+    let big = isize::MAX as usize;
+    let column = InlineArray::<f64, Ix2, 9>::zeros((big, 0)).unwrap();
+    let row = InlineArray::<f64, Ix2, 9>::zeros((0, big)).unwrap();
+    let _ = column.dot(&row);
+}
+
+#[test]
+fn write_through_views()
+{
+    let mut m = Matrix3::zeros((3, 3)).unwrap();
+    m.view_mut().diag_mut().fill(1.);
+    m[[0, 2]] = 5.;
+    m.view_mut().row_mut(1).map_inplace(|x| *x += 2.);
+    m.as_slice_mut()[7] = -1.;
+    assert_eq!(m.view(), array![[1., 0., 5.], [2., 3., 2.], [0., -1., 1.]]);
+}
+
+#[test]
+fn arithmetic()
+{
+    let a = Array::from_shape_fn((3, 3), |(i, j)| (i * 3 + j) as f64);
+    let b = Array::from_shape_fn((3, 3), |(i, j)| (i as f64 - j as f64) / 2.);
+    let ma = Matrix3::from_array(&a).unwrap();
+    let mb = Matrix3::from_array(&b).unwrap();
+
+    assert_eq!((&ma + &mb).view(), &a + &b);
+    assert_eq!((&ma - &mb).view(), &a - &b);
+    assert_eq!((&ma * &mb).view(), &a * &b);
+    assert_eq!((&ma / &(&mb + 10.)).view(), &a / &(&b + 10.));
+    assert_eq!((&ma * 2.).view(), &a * 2.);
+    assert_eq!(ma.dot(&mb).view(), a.dot(&b));
+    assert_eq!(ma.mapv(f64::sqrt).view(), a.mapv(f64::sqrt));
+    assert_eq!(ma.map(|&x| x as i32).view(), a.map(|&x| x as i32));
+
+    // the right hand side is broadcast
+    let row = InlineArray::<f64, Ix1, 3>::from_array(&array![1., 2., 3.]).unwrap();
+    assert_eq!((&ma + &row).view(), &a + &array![1., 2., 3.]);
+
+    // non-square products
+    let r = InlineArray::<i32, Ix2, 6>::from_shape_fn((2, 3), |(i, j)| (i + j) as i32).unwrap();
+    let c = InlineArray::<i32, Ix2, 3>::from_shape_fn((3, 1), |(i, _)| i as i32).unwrap();
+    assert_eq!(r.dot(&c).view(), r.view().dot(&c.view()));
+    assert_eq!(r.dot(&c).capacity(), 6);
+}
+
+#[test]
+#[should_panic(expected = "could not broadcast [2] to [3, 3] in '+'")]
+fn arithmetic_shape_mismatch()
+{
+    let m = Matrix3::zeros((3, 3)).unwrap();
+    let v = InlineArray::<f64, Ix1, 3>::zeros(2).unwrap();
+    let _ = &m + &v;
+}
+
+#[test]
+fn drop_elements()
+{
+    let rc = Rc::new(());
+    let a = InlineArray::<_, Ix2, 8>::from_elem((2, 3), rc.clone()).unwrap();
+    assert_eq!(Rc::strong_count(&rc), 7);
+    let b = a.clone();
+    assert_eq!(Rc::strong_count(&rc), 13);
+    drop(a);
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    let heap = b.into_array();
+    assert_eq!(Rc::strong_count(&rc), 7);
+    drop(heap);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    // a dynamic shape with more axes than fit inline is moved, not cloned
+    let d = InlineArray::<_, IxDyn, 8>::from_elem(vec![1, 2, 1, 2, 1], rc.clone()).unwrap();
+    let heap = d.into_array();
+    assert_eq!(heap.shape(), &[1, 2, 1, 2, 1]);
+    assert_eq!(Rc::strong_count(&rc), 5);
+}
@@ -19,3 +19,52 @@ fn test_arr2_into_arrd_nonstandard_strides()
 
     assert!(arr == brr);
 }
+
+#[test]
+fn test_into_dyn_round_trip_all_ranks()
+{
+    fn round_trip<D: Dimension>(shape: D)
+    {
+        let mut a = Array::from_shape_fn(shape.f(), |_| 0usize);
+        for (i, elt) in a.iter_mut().enumerate() {
+            *elt = i;
+        }
+        let ptr = a.as_ptr();
+        let strides = a.strides().to_vec();
+        let dyn_dim = a.raw_dim().into_dyn();
+
+        let owned = a.clone();
+        let owned_ptr = owned.as_ptr();
+        let b = owned.into_dyn();
+        assert_eq!(b.raw_dim(), dyn_dim);
+        assert_eq!(b.strides(), &strides[..]);
+        let c = b.into_dimensionality::<D>().unwrap();
+        assert_eq!(c.as_ptr(), owned_ptr);
+        assert_eq!(c.strides(), &strides[..]);
+        assert_eq!(c, a);
+
+        let v = a.view().into_dyn();
+        assert_eq!(v.as_ptr(), ptr);
+        assert_eq!(v.into_dimensionality::<D>().unwrap(), a);
+    }
+    round_trip(Ix0());
+    round_trip(Ix1(3));
+    round_trip(Ix2(3, 2));
+    round_trip(Ix3(3, 2, 4));
+    round_trip(Ix4(2, 1, 3, 2));
+    round_trip(Ix5(2, 3, 1, 2, 2));
+    round_trip(Ix6(1, 2, 3, 2, 1, 2));
+}
+
+#[test]
+fn test_into_dimensionality_wrong_rank()
+{
+    let a = ArrayD::<f32>::zeros(IxDyn(&[2, 3, 4]));
+    let err = a.view().into_dimensionality::<Ix2>().unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::IncompatibleShape);
+    assert!(a.view().into_dimensionality::<Ix4>().is_err());
+    assert!(a.into_dimensionality::<Ix3>().is_ok());
+
+    assert_eq!(Ix2(3, 4).into_dyn(), IxDyn(&[3, 4]));
+    assert!(Array2::<u8>::zeros((2, 2)).into_dimensionality::<Ix1>().is_err());
+}
@@ -3,7 +3,7 @@
 )]
 
 use ndarray::prelude::*;
-use ndarray::{arr3, indices, s, Slice, Zip};
+use ndarray::{arr3, indices, s, ErrorKind, RemoveAxis, Slice, Zip};
 
 use itertools::assert_equal;
 use itertools::enumerate;
@@ -222,6 +222,101 @@ fn inner_iter_size_hint()
     }
 }
 
+#[test]
+fn lanes_len_and_get()
+{
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    let rows = a.rows();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows.get(2), Some(aview1(&[20, 21, 22, 23])));
+    assert_eq!(rows.get(3), None);
+    let columns = a.columns();
+    assert_eq!(columns.len(), 4);
+    assert_eq!(columns.get(1), Some(aview1(&[1, 11, 21])));
+    assert_eq!(columns.get(4), None);
+    assert_eq!(a.slice(s![.., ..;-1]).rows().get(0), Some(aview1(&[3, 2, 1, 0])));
+
+    let mut rows = a.rows_mut();
+    assert_eq!(rows.len(), 3);
+    rows.get_mut(1).unwrap().fill(0);
+    assert!(rows.get_mut(3).is_none());
+    assert_eq!(a.row(1), aview1(&[0, 0, 0, 0]));
+
+    let e = Array2::<i32>::zeros((0, 3));
+    assert!(e.rows().is_empty());
+    assert_eq!(e.rows().get(0), None);
+    assert_eq!(e.columns().len(), 3);
+    assert_eq!(e.columns().get(2), Some(aview1(&[])));
+}
+
+quickcheck::quickcheck! {
+    fn lanes_get_matches_iteration(shape: (u8, u8, u8), axis: u8, picks: Vec<u8>, transpose: bool) -> bool {
+        let shape = (shape.0 as usize % 4, shape.1 as usize % 4, shape.2 as usize % 4);
+        let mut a = Array::from_shape_fn(shape, |(i, j, k)| i * 100 + j * 10 + k);
+        if transpose {
+            a.invert_axis(Axis(1));
+            a.swap_axes(0, 2);
+        }
+        let axis = Axis(axis as usize % 3);
+        let lanes = a.lanes(axis);
+        let lane_dim = a.raw_dim().remove_axis(axis);
+        let lane_indices: Vec<_> = indices(lane_dim).into_iter().collect();
+        lanes.len() == lane_indices.len()
+            && lanes.len() == a.lanes(axis).into_iter().count()
+            && picks.iter().all(|&p| {
+                let i = p as usize % (lanes.len() + 2);
+                let nth = a.lanes(axis).into_iter().nth(i);
+                match lane_indices.get(i) {
+                    Some(&index) => nth.is_some() && lanes.get(index) == nth,
+                    None => nth.is_none() && lanes.get((lane_dim[0] + i, 0)).is_none(),
+                }
+            })
+    }
+}
+
+#[test]
+fn lanes_split_at()
+{
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| i * 100 + j * 10 + k);
+    let sorted = |lanes: Vec<ArrayView1<'_, usize>>| {
+        let mut v: Vec<Vec<usize>> = lanes.into_iter().map(|l| l.to_vec()).collect();
+        v.sort();
+        v
+    };
+    for axis in 0..3 {
+        let all: Vec<_> = a.lanes(Axis(axis)).into_iter().collect();
+        let dim = a.raw_dim().remove_axis(Axis(axis));
+        for split_axis in 0..2 {
+            for index in 0..=dim[split_axis] {
+                let (first, second) = a.lanes(Axis(axis)).split_at(Axis(split_axis), index);
+                assert_eq!(first.len() + second.len(), all.len());
+                assert_eq!(first.len(), index * dim[1 - split_axis]);
+                let mut both: Vec<_> = first.into_iter().collect();
+                both.extend(second);
+                assert_eq!(sorted(both), sorted(all.clone()));
+            }
+        }
+    }
+
+    // splitting the rows of a 2D array keeps their order
+    let b = Array::from_shape_fn((5, 2), |(i, j)| i * 10 + j);
+    let (top, bottom) = b.rows().split_at(Axis(0), 2);
+    assert_equal(top.into_iter().chain(bottom), b.rows());
+    let mut c = b.clone();
+    let (mut top, bottom) = c.rows_mut().split_at(Axis(0), 1);
+    top.get_mut(0).unwrap().fill(7);
+    assert_eq!(bottom.len(), 4);
+    assert_eq!(c.row(0), aview1(&[7, 7]));
+}
+
+#[test]
+#[should_panic]
+fn lanes_split_at_out_of_bounds()
+{
+    let a = Array2::<f32>::zeros((3, 4));
+    let _ = a.rows().split_at(Axis(0), 4);
+}
+
 #[allow(deprecated)] // into_outer_iter
 #[test]
 fn outer_iter()
@@ -420,6 +515,53 @@ fn axis_iter_mut()
     assert_eq!(a, b);
 }
 
+#[test]
+fn axis_iter_rev()
+{
+    let a = Array::from_iter(0..60)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    let b = a.slice(s![..;-1, ..;2, 1..]);
+    for v in [a.view(), b] {
+        for ax in 0..v.ndim() {
+            let forward: Vec<_> = v.axis_iter(Axis(ax)).collect();
+            let mut backward: Vec<_> = v.axis_iter(Axis(ax)).rev().collect();
+            backward.reverse();
+            assert_eq!(forward, backward);
+
+            let mut iter = v.axis_iter(Axis(ax));
+            assert_eq!(iter.len(), v.len_of(Axis(ax)));
+            iter.next_back();
+            assert_eq!(iter.len(), v.len_of(Axis(ax)) - 1);
+            for (i, sub) in v.axis_iter(Axis(ax)).enumerate().rev() {
+                assert_eq!(sub, v.index_axis(Axis(ax), i));
+            }
+        }
+    }
+    let outer: Vec<_> = a.outer_iter().rev().collect();
+    let axis0: Vec<_> = a.axis_iter(Axis(0)).rev().collect();
+    assert_eq!(outer, axis0);
+}
+
+#[test]
+fn axis_iter_mut_write_index()
+{
+    let mut a = Array::<usize, _>::zeros((3, 4, 5));
+    for ax in 0..a.ndim() {
+        // all the mutable subviews are alive at the same time
+        let subviews: Vec<_> = a.axis_iter_mut(Axis(ax)).rev().collect();
+        let n = subviews.len();
+        for (i, mut sub) in subviews.into_iter().enumerate() {
+            sub.fill(n - 1 - i);
+        }
+        assert!(a.indexed_iter().all(|(ix, &x)| x == [ix.0, ix.1, ix.2][ax]));
+    }
+    for (i, mut sub) in a.outer_iter_mut().enumerate() {
+        sub.fill(i * 10);
+    }
+    assert_eq!(a.index_axis(Axis(0), 2), Array::from_elem((4, 5), 20));
+}
+
 #[test]
 fn axis_chunks_iter()
 {
@@ -1055,3 +1197,194 @@ impl Drop for DropCount<'_>
         self.drops.set(self.drops.get() + 1);
     }
 }
+
+#[test]
+fn inner_slices()
+{
+    // the runs concatenated are the elements in memory order
+    fn check<D: Dimension>(a: ArrayView<'_, i32, D>, run_len: usize)
+    {
+        let runs = a.inner_slices();
+        assert_eq!(runs.run_len(), run_len);
+        assert_eq!(runs.len() * run_len, a.len());
+        let mut ptrs: Vec<*const i32> = Vec::new();
+        let mut elements = Vec::new();
+        for run in runs {
+            assert_eq!(run.len(), run_len);
+            ptrs.extend(run.iter().map(|x| x as *const i32));
+            elements.extend_from_slice(run);
+        }
+        assert!(ptrs.windows(2).all(|w| w[0] < w[1]));
+        let mut expected = a.iter().collect::<Vec<_>>();
+        expected.sort_by_key(|&x| x as *const i32);
+        assert_eq!(elements.iter().collect::<Vec<_>>(), expected);
+    }
+
+    let a = Array::from_iter(0..60)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    check(a.view(), 60);
+    check(a.t(), 60);
+    check(a.slice(s![.., 1..3, ..]), 10);
+    check(a.slice(s![.., .., 1..3]), 2);
+    check(a.slice(s![..;-1, .., ..]), 60);
+    check(a.slice(s![1.., ..;-1, ..]), 40);
+    check(a.slice(s![.., 1..;-1, ..;-1]), 15);
+    check(a.slice(s![.., .., ..;2]), 1);
+    check(a.slice(s![1..2, .., 2]), 1);
+    check(a.slice(s![..1, ..1, ..]), 5);
+    check(a.slice(s![1, 2, 3]), 1);
+    check(a.slice(s![.., 0..0, ..]), 0);
+    assert_eq!(a.slice(s![.., 0..0, ..]).inner_slices().count(), 0);
+
+    let f = Array::from_shape_fn((4, 5).f(), |(i, j)| (i * 10 + j) as i32);
+    check(f.view(), 20);
+    check(f.slice(s![1..3, ..]), 2);
+    check(f.slice(s![.., 1..3]), 8);
+    assert_eq!(f.inner_slices().next().unwrap(), f.as_slice_memory_order().unwrap());
+}
+
+#[test]
+fn inner_slices_mut()
+{
+    let mut a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let mut view = a.slice_mut(s![.., ..;-1, 1..3]);
+    let mut runs = view.inner_slices_mut();
+    assert_eq!(runs.run_len(), 2);
+    assert_eq!(runs.len(), 6);
+    for run in &mut runs {
+        run.swap(0, 1);
+        run[0] += 100;
+    }
+    assert_eq!(a.slice(s![0, 0, ..]), aview1(&[0, 102, 1, 3]));
+    assert_eq!(a.slice(s![1, 2, ..]), aview1(&[20, 122, 21, 23]));
+
+    let mut b = Array2::<i32>::zeros((3, 4).f());
+    let mut runs = b.inner_slices_mut();
+    assert_eq!(runs.run_len(), 12);
+    runs.next()
+        .unwrap()
+        .copy_from_slice(&(0..12).collect::<Vec<_>>());
+    assert!(runs.next().is_none());
+    assert_eq!(b.column(1), aview1(&[3, 4, 5]));
+}
+
+#[test]
+fn multi_axis_iter()
+{
+    let a = Array::from_shape_fn((2, 3, 4, 5), |(n, h, w, c)| n * 1000 + h * 100 + w * 10 + c);
+    let views = [a.view(), a.slice(s![.., ..;-1, 1.., ..;2])];
+    for a in &views {
+        // (N, C) iterated in row major order, yielding the (H, W) images
+        let mut expected = Vec::new();
+        for image_n in a.axis_iter(Axis(0)) {
+            for image in image_n.axis_iter(Axis(2)) {
+                expected.push(image.to_owned().into_dyn());
+            }
+        }
+        let iter = a.multi_axis_iter(&[Axis(3), Axis(0)]).unwrap();
+        assert_eq!(iter.len(), a.len_of(Axis(0)) * a.len_of(Axis(3)));
+        assert_equal(iter, expected);
+
+        // (H, W) iterated, yielding (N, C) subviews
+        let mut expected = Vec::new();
+        for sub_h in a.axis_iter(Axis(1)) {
+            for sub in sub_h.axis_iter(Axis(1)) {
+                expected.push(sub.to_owned().into_dyn());
+            }
+        }
+        assert_equal(a.multi_axis_iter(&[Axis(1), Axis(2)]).unwrap(), expected);
+
+        assert_equal(a.multi_axis_iter(&[Axis(2)]).unwrap(), a.axis_iter(Axis(2)).map(|v| v.into_dyn()));
+
+        // all axes: each element, in logical order
+        let all = a.multi_axis_iter(&[Axis(0), Axis(1), Axis(2), Axis(3)]).unwrap();
+        assert_eq!(all.len(), a.len());
+        assert_equal(all.map(|v| v[[]]), a.iter().cloned());
+
+        // no axis: the whole array once
+        let mut none = a.multi_axis_iter(&[]).unwrap();
+        assert_eq!(none.len(), 1);
+        assert_eq!(none.next().unwrap(), a.view().into_dyn());
+        assert!(none.next().is_none());
+    }
+
+    let empty = Array3::<u8>::zeros((2, 0, 3));
+    assert_eq!(empty.multi_axis_iter(&[Axis(1)]).unwrap().len(), 0);
+    let subviews = empty.multi_axis_iter(&[Axis(0), Axis(2)]).unwrap();
+    assert_eq!(subviews.len(), 6);
+    assert!(subviews.into_iter().all(|v| v.shape() == [0]));
+
+    let err = a.multi_axis_iter(&[Axis(1), Axis(3), Axis(1)]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    let message = err.to_string();
+    assert!(message.contains("Axis(1) is listed more than once"), "{}", message);
+    let err = a.multi_axis_iter(&[Axis(4)]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let message = err.to_string();
+    assert!(message.contains("Axis(4) is out of bounds for an array with 4 axes"), "{}", message);
+    assert!(a.clone().multi_axis_iter_mut(&[Axis(0), Axis(0)]).is_err());
+}
+
+#[test]
+fn multi_axis_iter_mut()
+{
+    let mut a = Array4::<usize>::zeros((2, 3, 4, 5));
+    let mut iter = a.multi_axis_iter_mut(&[Axis(0), Axis(3)]).unwrap();
+    assert_eq!(iter.len(), 10);
+    let first = iter.next().unwrap();
+    let subviews: Vec<_> = iter.collect();
+    // all subviews can be held at once
+    drop(first);
+    for (i, mut image) in subviews.into_iter().enumerate() {
+        assert_eq!(image.shape(), &[3, 4]);
+        image.fill(i + 1);
+    }
+    assert_eq!(a.slice(s![0, .., .., 0]), Array2::zeros((3, 4)));
+    assert_eq!(a.slice(s![0, .., .., 1]), Array2::from_elem((3, 4), 1));
+    assert_eq!(a.slice(s![1, .., .., 4]), Array2::from_elem((3, 4), 9));
+
+    for (i, mut elt) in a
+        .multi_axis_iter_mut(&[Axis(0), Axis(1), Axis(2), Axis(3)]).unwrap()
+        .enumerate()
+    {
+        elt[[]] = i;
+    }
+    assert_eq!(a, Array::from_iter(0..a.len()).into_shape_with_order(a.raw_dim()).unwrap());
+}
+
+#[test]
+fn iter_logical_and_memory_order()
+{
+    let a = Array::from_shape_vec((2, 3, 2).f(), (0..12).collect()).unwrap();
+    let logical: Vec<i32> = (0..2)
+        .flat_map(|i| (0..3).flat_map(move |j| (0..2).map(move |k| i + 2 * j + 6 * k)))
+        .collect();
+    assert_equal(a.iter().copied(), logical.iter().copied());
+    assert_equal(a.iter_memory_order().copied(), 0..12);
+
+    // negative strides are traversed backwards in memory order
+    let mut b = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    b.invert_axis(Axis(0));
+    assert_equal(b.iter().copied(), vec![8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3]);
+    assert_equal(b.iter_memory_order().copied(), 0..12);
+
+    // not contiguous: rows of a F order matrix with a column skipped
+    let c = Array::from_shape_vec((3, 4).f(), (0..12).collect()).unwrap();
+    let c = c.slice(s![.., 1..]);
+    assert_equal(c.iter().copied(), vec![3, 6, 9, 4, 7, 10, 5, 8, 11]);
+    assert_equal(c.iter_memory_order().copied(), 3..12);
+    let mut visited = Vec::new();
+    c.for_each(|&x| visited.push(x));
+    assert_eq!(visited, (3..12).collect::<Vec<_>>());
+
+    let mut d = Array::from_shape_vec((2, 2).f(), vec![0; 4]).unwrap();
+    for (i, x) in d.iter_memory_order_mut().enumerate() {
+        *x = i;
+    }
+    assert_eq!(d, array![[0, 2], [1, 3]]);
+}
@@ -4,6 +4,8 @@
 )]
 
 use ndarray::Array;
+use ndarray::ArrayD;
+use ndarray::Axis;
 use ndarray::IntoDimension;
 use ndarray::Ix3;
 use ndarray::Order;
@@ -182,3 +184,66 @@ fn test_into_dimension()
     let c = a2.clone().into_dimensionality::<IxDyn>().unwrap();
     assert_eq!(a2, c);
 }
+
+#[test]
+fn test_ixdyn_inplace_axes()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let mut b = a.clone().into_dyn();
+
+    b.insert_axis_inplace(Axis(0));
+    b.insert_axis_inplace(Axis(4));
+    b.insert_axis_inplace(Axis(2));
+    assert_eq!(b.shape(), &[1, 2, 1, 3, 4, 1]);
+    let expected = a
+        .clone()
+        .insert_axis(Axis(0))
+        .insert_axis(Axis(4))
+        .insert_axis(Axis(2));
+    assert_eq!(b, expected.into_dyn());
+
+    b.index_axis_inplace(Axis(3), 1);
+    b.index_axis_inplace(Axis(0), 0);
+    assert_eq!(b.shape(), &[2, 1, 4, 1]);
+    let expected = a
+        .index_axis(Axis(1), 1)
+        .insert_axis(Axis(1))
+        .insert_axis(Axis(3));
+    assert_eq!(b, expected.into_dyn());
+
+    b.index_axis_inplace(Axis(2), 3);
+    b.index_axis_inplace(Axis(0), 1);
+    b.index_axis_inplace(Axis(0), 0);
+    b.index_axis_inplace(Axis(0), 0);
+    assert_eq!(b.ndim(), 0);
+    assert_eq!(b[[]], 113);
+    b.insert_axis_inplace(Axis(0));
+    assert_eq!(b, ArrayD::from_elem(vec![1], 113));
+
+    // views and raw views
+    let a = a.into_dyn();
+    let mut v = a.view();
+    v.index_axis_inplace(Axis(2), 2);
+    v.insert_axis_inplace(Axis(1));
+    assert_eq!(v.shape(), &[2, 1, 3]);
+    assert_eq!(v[[1, 0, 2]], 122);
+    let mut r = a.raw_view();
+    r.index_axis_inplace(Axis(0), 1);
+    assert_eq!(unsafe { r.deref_into_view() }, a.index_axis(Axis(0), 1));
+}
+
+#[test]
+#[should_panic(expected = "cannot insert axis 4 in an array with 3 axes")]
+fn test_ixdyn_insert_axis_inplace_out_of_bounds()
+{
+    let mut a = ArrayD::<f32>::zeros(vec![2, 3, 4]);
+    a.insert_axis_inplace(Axis(4));
+}
+
+#[test]
+#[should_panic]
+fn test_ixdyn_index_axis_inplace_out_of_bounds()
+{
+    let mut a = ArrayD::<f32>::zeros(vec![2, 3, 4]);
+    a.index_axis_inplace(Axis(1), 3);
+}
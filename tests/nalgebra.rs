@@ -0,0 +1,89 @@
+#![cfg(feature = "nalgebra")]
+
+use nalgebra::{DMatrix, DVector};
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, ShapeBuilder};
+
+use std::convert::TryFrom;
+
+#[test]
+fn matrix_round_trip()
+{
+    let c = Array::from_shape_fn((3, 4), |(i, j)| (i * 10 + j) as f64);
+    let f = Array::from_shape_fn((3, 4).f(), |(i, j)| (i * 10 + j) as f64);
+    for a in [c, f] {
+        let m = DMatrix::from(a.clone());
+        assert_eq!(m.shape(), (3, 4));
+        for ((i, j), x) in a.indexed_iter() {
+            assert_eq!(m[(i, j)], *x);
+        }
+        let b = Array2::from(m);
+        assert_eq!(b, a);
+        assert!(b.t().is_standard_layout());
+    }
+
+    // sliced and transposed sources are copied
+    let a = Array::from_shape_fn((5, 6), |(i, j)| (i * 10 + j) as f64);
+    let s = a.slice(s![1..;2, ..;-1]).to_owned();
+    assert_eq!(Array2::from(DMatrix::from(a.slice(s![1..;2, ..;-1]))), s);
+    assert_eq!(Array2::from(DMatrix::from(a.clone().reversed_axes())), a.t());
+    let mut sliced = a.clone().reversed_axes();
+    sliced.slice_collapse(s![1..3, ..]);
+    assert_eq!(Array2::from(DMatrix::from(sliced)), a.t().slice(s![1..3, ..]));
+}
+
+#[test]
+fn matrix_zero_copy()
+{
+    let a = Array::from_shape_fn((3, 4).f(), |(i, j)| (i * 10 + j) as f64);
+    let ptr = a.as_ptr();
+    let m = DMatrix::from(a);
+    assert_eq!(m.as_ptr(), ptr);
+    let view = ArrayView2::from(&m);
+    assert_eq!(view.as_ptr(), ptr);
+    assert_eq!(view[[2, 3]], 23.);
+    let b = Array2::from(m);
+    assert_eq!(b.as_ptr(), ptr);
+
+    // C layout is copied
+    let c = Array::from_shape_fn((3, 4), |(i, j)| (i * 10 + j) as f64);
+    let ptr = c.as_ptr();
+    assert_ne!(DMatrix::from(c).as_ptr(), ptr);
+}
+
+#[test]
+fn vector_round_trip()
+{
+    let a = Array::range(0., 6., 1.);
+    let a2 = a.clone();
+    let ptr = a2.as_ptr();
+    let v = DVector::from(a2);
+    assert_eq!(v.as_ptr(), ptr);
+    assert_eq!(v.as_slice(), a.as_slice().unwrap());
+    assert_eq!(ArrayView1::from(v.as_slice()), a);
+    let b = Array1::from(v);
+    assert_eq!(b.as_ptr(), ptr);
+
+    let strided = a.slice(s![..;-2]);
+    assert_eq!(Array1::from(DVector::from(strided)), strided);
+    let mut sliced = a.clone();
+    sliced.slice_collapse(s![2..]);
+    assert_eq!(DVector::from(sliced).as_slice(), &[2., 3., 4., 5.]);
+}
+
+#[test]
+fn conversion_errors()
+{
+    let a = ArrayD::<f64>::zeros(vec![2, 3]);
+    assert_eq!(DMatrix::try_from(a.clone()).unwrap().shape(), (2, 3));
+    assert_eq!(DVector::try_from(a).unwrap_err().kind(), ErrorKind::IncompatibleShape);
+
+    let a = ArrayD::<f64>::zeros(vec![4]);
+    assert_eq!(DVector::try_from(a.clone()).unwrap().len(), 4);
+    assert_eq!(DMatrix::try_from(a).unwrap_err().kind(), ErrorKind::IncompatibleShape);
+
+    let column = DMatrix::from_fn(3, 1, |i, _| i as f64);
+    assert_eq!(Array1::try_from(column).unwrap(), array![0., 1., 2.]);
+    let m = DMatrix::<f64>::zeros(3, 2);
+    assert_eq!(Array1::try_from(m).unwrap_err().kind(), ErrorKind::IncompatibleShape);
+}
@@ -0,0 +1,366 @@
+//! Property tests for views with negative, stepped and permuted strides.
+//!
+//! Each test builds a random view through a chain of slicing, axis inversion
+//! and transposition, and keeps a reference of the same chain computed with
+//! plain index arithmetic: for every element of the view, in logical order,
+//! the row major index of the element in the base array. Reductions, copies,
+//! layout queries and formatting of the view are checked against a dense
+//! array made from the reference.
+
+use ndarray::prelude::*;
+use ndarray::{RawData, ShapeBuilder, Slice, Zip};
+use quickcheck::quickcheck;
+
+#[derive(Copy, Clone, Debug)]
+enum Op
+{
+    Invert(usize),
+    Swap(usize, usize),
+    Slice(usize, usize, usize, isize),
+    Collapse(usize, usize),
+    Transpose,
+}
+
+impl Op
+{
+    /// Choose an operation for an array of `shape` from the arbitrary numbers
+    /// `(kind, a, b, c)`.
+    fn new(shape: &[usize], (kind, a, b, c): (u8, u8, u8, u8)) -> Option<Op>
+    {
+        if shape.is_empty() {
+            return None;
+        }
+        let axis = a as usize % shape.len();
+        let len = shape[axis];
+        Some(match kind % 5 {
+            0 => Op::Invert(axis),
+            1 => Op::Swap(axis, b as usize % shape.len()),
+            2 => {
+                // keep at least about half the axis, so that views rarely end up empty
+                let start = b as usize % (len / 2 + 1);
+                let end = len - c as usize % ((len - start) / 2 + 1);
+                let step = [1, 2, 3, -1, -2, -3][(kind / 5) as usize % 6];
+                Op::Slice(axis, start, end, step)
+            }
+            3 if len > 0 => Op::Collapse(axis, b as usize % len),
+            _ => Op::Transpose,
+        })
+    }
+
+    fn apply<S>(self, a: &mut ArrayBase<S, IxDyn>)
+    where S: RawData
+    {
+        match self {
+            Op::Invert(axis) => a.invert_axis(Axis(axis)),
+            Op::Swap(i, j) => a.swap_axes(i, j),
+            Op::Slice(axis, start, end, step) =>
+                a.slice_axis_inplace(Axis(axis), Slice::new(start as isize, Some(end as isize), step)),
+            Op::Collapse(axis, index) => a.collapse_axis(Axis(axis), index),
+            Op::Transpose => {
+                let n = a.ndim();
+                for i in 0..n / 2 {
+                    a.swap_axes(i, n - 1 - i);
+                }
+            }
+        }
+    }
+}
+
+/// The shape of a view and the row major index in the base array of each of
+/// its elements, in logical order.
+#[derive(Clone, Debug)]
+struct Reference
+{
+    shape: Vec<usize>,
+    offsets: Vec<usize>,
+}
+
+impl Reference
+{
+    fn new(shape: &[usize]) -> Self
+    {
+        let size = shape.iter().product();
+        Reference {
+            shape: shape.to_vec(),
+            offsets: (0..size).collect(),
+        }
+    }
+
+    fn flat(&self, index: &[usize]) -> usize
+    {
+        index
+            .iter()
+            .zip(&self.shape)
+            .fold(0, |acc, (&i, &n)| acc * n + i)
+    }
+
+    /// Return the reference with `shape`, where the element at each index is
+    /// the one at `source(index)` in `self`.
+    fn remap(&self, shape: Vec<usize>, source: impl Fn(&[usize]) -> Vec<usize>) -> Self
+    {
+        let mut offsets = Vec::new();
+        for_each_index(&shape, |index| offsets.push(self.offsets[self.flat(&source(index))]));
+        Reference { shape, offsets }
+    }
+
+    fn apply(&self, op: Op) -> Self
+    {
+        let mut shape = self.shape.clone();
+        match op {
+            Op::Invert(axis) => self.remap(shape, |index| {
+                let mut index = index.to_vec();
+                index[axis] = self.shape[axis] - 1 - index[axis];
+                index
+            }),
+            Op::Swap(i, j) => {
+                shape.swap(i, j);
+                self.remap(shape, |index| {
+                    let mut index = index.to_vec();
+                    index.swap(i, j);
+                    index
+                })
+            }
+            Op::Slice(axis, start, end, step) => {
+                let positions: Vec<usize> = if step > 0 {
+                    (start..end).step_by(step as usize).collect()
+                } else {
+                    (start..end).rev().step_by(-step as usize).collect()
+                };
+                shape[axis] = positions.len();
+                self.remap(shape, |index| {
+                    let mut index = index.to_vec();
+                    index[axis] = positions[index[axis]];
+                    index
+                })
+            }
+            Op::Collapse(axis, i) => {
+                shape[axis] = 1;
+                self.remap(shape, |index| {
+                    let mut index = index.to_vec();
+                    index[axis] = i;
+                    index
+                })
+            }
+            Op::Transpose => {
+                shape.reverse();
+                self.remap(shape, |index| index.iter().rev().cloned().collect())
+            }
+        }
+    }
+}
+
+/// Call `f` with each index of `shape` in row major order.
+fn for_each_index(shape: &[usize], mut f: impl FnMut(&[usize]))
+{
+    if shape.contains(&0) {
+        return;
+    }
+    let mut index = vec![0; shape.len()];
+    loop {
+        f(&index);
+        let mut axis = shape.len();
+        loop {
+            if axis == 0 {
+                return;
+            }
+            axis -= 1;
+            index[axis] += 1;
+            if index[axis] < shape[axis] {
+                break;
+            }
+            index[axis] = 0;
+        }
+    }
+}
+
+/// Build the base array, the ops and the reference from arbitrary input.
+fn setup(shape: &[u8], f_order: bool, ops: &[(u8, u8, u8, u8)]) -> (ArrayD<i64>, Vec<Op>, Reference)
+{
+    // up to four axes of length up to four, rarely empty
+    let shape: Vec<usize> = shape
+        .iter()
+        .take(shape.len() % 5)
+        .map(|&n| if n % 8 == 0 { 0 } else { 1 + n as usize % 4 })
+        .collect();
+    let base = ArrayD::from_shape_fn(shape.clone().set_f(f_order), |index| {
+        index
+            .slice()
+            .iter()
+            .zip(&shape)
+            .fold(0, |acc, (&i, &n)| acc * n + i) as i64
+            - 20
+    });
+    let mut reference = Reference::new(&shape);
+    let mut chain = Vec::new();
+    for &arg in ops.iter().take(6) {
+        if let Some(op) = Op::new(&reference.shape, arg) {
+            reference = reference.apply(op);
+            chain.push(op);
+        }
+    }
+    (base, chain, reference)
+}
+
+/// The values of the base array in row major order.
+fn base_values(base: &ArrayD<i64>) -> Vec<i64>
+{
+    base.iter().cloned().collect()
+}
+
+fn naive_sum_axis(dense: &ArrayD<i64>, axis: usize) -> ArrayD<i64>
+{
+    let mut shape = dense.shape().to_vec();
+    shape.remove(axis);
+    let mut sum = ArrayD::zeros(shape);
+    for_each_index(dense.shape(), |index| {
+        let mut smaller = index.to_vec();
+        smaller.remove(axis);
+        sum[&smaller[..]] += dense[index];
+    });
+    sum
+}
+
+fn check_view(base: &ArrayD<i64>, view: ArrayViewD<'_, i64>, reference: &Reference)
+{
+    let values = base_values(base);
+    let expected: Vec<i64> = reference.offsets.iter().map(|&o| values[o]).collect();
+    let dense = ArrayD::from_shape_vec(reference.shape.clone(), expected.clone()).unwrap();
+
+    // elements and iteration
+    assert_eq!(view.shape(), &reference.shape[..]);
+    assert_eq!(view.iter().cloned().collect::<Vec<_>>(), expected);
+    assert_eq!(view, dense);
+    for (index, x) in view.indexed_iter() {
+        assert_eq!(*x, dense[index]);
+    }
+    Zip::from(&view)
+        .and(&dense)
+        .for_each(|x, y| assert_eq!(x, y));
+
+    // reductions
+    assert_eq!(view.sum(), expected.iter().sum::<i64>());
+    assert_eq!(view.iter().min(), expected.iter().min());
+    assert_eq!(view.iter().max(), expected.iter().max());
+    assert_eq!(
+        view.fold(1i64, |acc, &x| acc.wrapping_mul(x | 1)),
+        expected.iter().fold(1i64, |acc, &x| acc.wrapping_mul(x | 1))
+    );
+    for axis in 0..view.ndim() {
+        assert_eq!(view.sum_axis(Axis(axis)), naive_sum_axis(&dense, axis));
+    }
+
+    // copies
+    let owned = view.to_owned();
+    assert_eq!(owned, dense);
+    assert_eq!(view.as_standard_layout(), dense);
+    assert!(view.as_standard_layout().is_standard_layout());
+    assert_eq!(view.map(|&x| x * 2), dense.map(|&x| x * 2));
+    assert_eq!(view.mapv(|x| x - 1), dense.mapv(|x| x - 1));
+    let mut assigned = ArrayD::zeros(view.raw_dim());
+    assigned.assign(&view);
+    assert_eq!(assigned, dense);
+
+    // layout, from the addresses of the elements
+    let origin = base.as_ptr() as isize;
+    let addresses: Vec<isize> = view
+        .iter()
+        .map(|x| (x as *const i64 as isize - origin) / std::mem::size_of::<i64>() as isize)
+        .collect();
+    let forward = addresses.windows(2).all(|w| w[1] == w[0] + 1);
+    assert_eq!(view.is_standard_layout(), forward, "{:?}", view);
+    assert_eq!(view.as_slice().is_some(), forward);
+    if let (Some(&low), Some(&high)) = (addresses.iter().min(), addresses.iter().max()) {
+        let dense_block = (high - low + 1) as usize == addresses.len();
+        match view.as_slice_memory_order() {
+            Some(slc) => {
+                assert!(dense_block, "{:?}", view);
+                assert_eq!((slc.as_ptr() as isize - origin) / std::mem::size_of::<i64>() as isize, low);
+                let mut sorted = slc.to_vec();
+                sorted.sort_unstable();
+                let mut expected_sorted = expected.clone();
+                expected_sorted.sort_unstable();
+                assert_eq!(sorted, expected_sorted);
+                // a contiguous view is copied with its strides
+                assert_eq!(owned.strides(), view.strides());
+            }
+            None => assert!(!dense_block, "{:?}", view),
+        }
+    }
+
+    // formatting
+    assert_eq!(format!("{}", view), format!("{}", dense));
+    assert_eq!(format!("{:?}", view.iter().collect::<Vec<_>>()), format!("{:?}", dense.iter().collect::<Vec<_>>()));
+
+    // products
+    if let Ok(m) = view.view().into_dimensionality::<Ix2>() {
+        let d = dense.view().into_dimensionality::<Ix2>().unwrap();
+        let (rows, cols) = d.dim();
+        let gram = Array2::from_shape_fn((rows, rows), |(i, j)| (0..cols).map(|k| d[[i, k]] * d[[j, k]]).sum::<i64>());
+        assert_eq!(m.dot(&m.t()), gram);
+        let col_sums = Array1::from_shape_fn(cols, |j| (0..rows).map(|i| d[[i, j]]).sum::<i64>());
+        assert_eq!(m.t().dot(&Array1::ones(rows)), col_sums);
+    }
+    if let Ok(v) = view.view().into_dimensionality::<Ix1>() {
+        assert_eq!(v.dot(&v), expected.iter().map(|x| x * x).sum::<i64>());
+    }
+}
+
+quickcheck! {
+    fn strided_views_match_reference(shape: Vec<u8>, f_order: bool, ops: Vec<(u8, u8, u8, u8)>) -> bool {
+        let (base, chain, reference) = setup(&shape, f_order, &ops);
+        let mut view = base.view();
+        for &op in &chain {
+            op.apply(&mut view);
+        }
+        check_view(&base, view, &reference);
+        true
+    }
+
+    fn assign_through_strided_views(shape: Vec<u8>, f_order: bool, ops: Vec<(u8, u8, u8, u8)>) -> bool {
+        let (base, chain, reference) = setup(&shape, f_order, &ops);
+        let mut target = base.clone();
+        let mut view = target.view_mut();
+        for &op in &chain {
+            op.apply(&mut view);
+        }
+        let source = ArrayD::from_shape_fn(view.raw_dim(), |index| index.slice().iter().sum::<usize>() as i64 + 1000);
+        view.assign(&source);
+
+        let mut expected = base_values(&base);
+        for (&offset, &x) in reference.offsets.iter().zip(source.iter()) {
+            expected[offset] = x;
+        }
+        assert_eq!(base_values(&target), expected);
+
+        // writing back through the view restores the base array
+        let mut view = target.view_mut();
+        for &op in &chain {
+            op.apply(&mut view);
+        }
+        let original: Vec<i64> = reference.offsets.iter().map(|&o| base_values(&base)[o]).collect();
+        view.assign(&ArrayD::from_shape_vec(reference.shape.clone(), original).unwrap());
+        target == base
+    }
+}
+
+#[test]
+fn reversed_dense_views()
+{
+    // reversed but dense layouts are contiguous in memory, not in standard
+    // layout
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i64);
+    for view in [a.slice(s![..;-1, ..]), a.slice(s![.., ..;-1]), a.slice(s![..;-1, ..;-1])] {
+        assert!(!view.is_standard_layout());
+        assert!(view.as_slice().is_none());
+        let slc = view.as_slice_memory_order().unwrap();
+        assert_eq!(slc, a.as_slice().unwrap());
+        let owned = view.to_owned();
+        assert_eq!(owned, view);
+        assert_eq!(owned.strides(), view.strides());
+    }
+    // stepped views are not
+    assert!(a.slice(s![.., ..;-2]).as_slice_memory_order().is_none());
+    let f = Array::from_shape_fn((3, 4).f(), |(i, j)| (i * 4 + j) as i64);
+    assert!(f.slice(s![..;-1, ..]).as_slice_memory_order().is_some());
+    assert!(f.slice(s![1.., ..]).as_slice_memory_order().is_none());
+}
@@ -4,7 +4,7 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{arr0, arr1, arr2, array, aview1, s, Array, Array1, Array2, Array3, Axis, ShapeBuilder};
 use std::f64;
 
 #[test]
@@ -75,6 +75,111 @@ fn sum_mean_prod_empty()
     assert_eq!(a, None);
 }
 
+#[test]
+fn reductions_empty_matrix()
+{
+    use ndarray::{IxDyn, RemoveAxis};
+
+    for shape in [&[0][..], &[0, 3], &[3, 0]] {
+        let a = Array::<f64, _>::ones(IxDyn(shape));
+        assert_eq!(a.sum(), 0.);
+        assert_eq!(a.product(), 1.);
+        assert_eq!(a.mean(), None);
+        #[cfg(feature = "std")]
+        assert!(a.var(0.).is_nan());
+
+        for (i, &len) in shape.iter().enumerate() {
+            let axis = Axis(i);
+            let rest = IxDyn(shape).remove_axis(axis);
+            assert_eq!(a.sum_axis(axis), Array::zeros(rest.clone()));
+            assert_eq!(a.product_axis(axis), Array::ones(rest.clone()));
+            assert_eq!(a.fold_axis(axis, 7., |&acc, &x| acc + x), Array::from_elem(rest.clone(), 7.));
+            assert_eq!(a.map_axis(axis, |lane| lane.len()), Array::from_elem(rest.clone(), len));
+            let mean = a.mean_axis(axis);
+            if len == 0 {
+                assert_eq!(mean, None);
+            } else {
+                assert_eq!(mean, Some(Array::ones(rest.clone())));
+            }
+            #[cfg(feature = "std")]
+            {
+                let var = a.var_axis(axis, 0.);
+                assert_eq!(var.raw_dim(), rest);
+                if len == 0 {
+                    var.for_each(|x| assert!(x.is_nan()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn reductions_over_axes()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 100 + j * 10 + k) as i64);
+    let mut inverted = a.slice(s![..;-1, .., ..;2]).to_owned();
+    inverted.invert_axis(Axis(1));
+    let arrays = [a.clone(), a.t().as_standard_layout().into_owned().reversed_axes(), inverted];
+    for a in &arrays {
+        // compare with chained single axis reductions, removing the later
+        // axes first so that the axis numbers do not shift
+        assert_eq!(a.sum_axes(&[Axis(0), Axis(2)]).unwrap(), a.sum_axis(Axis(2)).sum_axis(Axis(0)).into_dyn());
+        assert_eq!(a.sum_axes(&[Axis(2), Axis(0)]).unwrap(), a.sum_axis(Axis(2)).sum_axis(Axis(0)).into_dyn());
+        assert_eq!(a.sum_axes(&[Axis(1)]).unwrap(), a.sum_axis(Axis(1)).into_dyn());
+        assert_eq!(a.sum_axes(&[Axis(0), Axis(1), Axis(2)]).unwrap(), arr0(a.sum()).into_dyn());
+        assert_eq!(a.sum_axes(&[]).unwrap(), a.clone().into_dyn());
+        assert_eq!(
+            a.fold_axes(&[Axis(1), Axis(2)], i64::MIN, |&acc, &x| acc.max(x))
+                .unwrap(),
+            a.fold_axis(Axis(2), i64::MIN, |&acc, &x| acc.max(x))
+                .fold_axis(Axis(1), i64::MIN, |&acc, &x| acc.max(x))
+                .into_dyn()
+        );
+
+        let af = a.mapv(|x| x as f64);
+        assert_eq!(
+            af.mean_axes(&[Axis(0), Axis(1)]).unwrap().unwrap(),
+            af.mean_axis(Axis(1))
+                .unwrap()
+                .mean_axis(Axis(0))
+                .unwrap()
+                .into_dyn()
+        );
+
+        // the kept axes broadcast back against the array
+        let sum = a.sum_axes_keepdims(&[Axis(0), Axis(2)]).unwrap();
+        assert_eq!(sum.shape(), &[1, a.len_of(Axis(1)), 1]);
+        assert_eq!(sum.clone().into_shape_with_order(a.len_of(Axis(1))).unwrap(), a.sum_axis(Axis(2)).sum_axis(Axis(0)));
+        let mean = af.mean_axes_keepdims(&[Axis(0), Axis(2)]).unwrap().unwrap();
+        let centered = &af - &mean;
+        assert!(centered.sum_axes(&[Axis(0), Axis(2)]).unwrap().iter().all(|x| x.abs() < 1e-9));
+    }
+
+    let empty = Array3::<f64>::zeros((2, 0, 3));
+    assert_eq!(empty.sum_axes(&[Axis(1), Axis(2)]).unwrap(), Array::zeros(2).into_dyn());
+    assert_eq!(empty.mean_axes(&[Axis(1)]).unwrap(), None);
+    assert_eq!(empty.mean_axes_keepdims(&[Axis(0), Axis(2)]).unwrap(), Some(Array3::zeros((1, 0, 1))));
+}
+
+#[test]
+fn reductions_over_axes_errors()
+{
+    use ndarray::ErrorKind;
+
+    let a = Array3::<f64>::zeros((2, 3, 4));
+    let err = a.sum_axes(&[Axis(2), Axis(0), Axis(2)]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    let message = err.to_string();
+    assert!(message.contains("Axis(2) is listed more than once"), "{}", message);
+    assert_eq!(a.mean_axes_keepdims(&[Axis(1), Axis(1)]).unwrap_err().kind(), ErrorKind::Unsupported);
+
+    let err = a.sum_axes_keepdims(&[Axis(3)]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let message = err.to_string();
+    assert!(message.contains("Axis(3) is out of bounds for an array with 3 axes"), "{}", message);
+    assert_eq!(a.fold_axes(&[Axis(0), Axis(5)], 0., |&acc, _| acc).unwrap_err().kind(), ErrorKind::OutOfBounds);
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn var()
@@ -336,3 +441,584 @@ fn std_axis_empty_axis()
     assert_eq!(v.shape(), &[2]);
     v.mapv(|x| assert!(x.is_nan()));
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn nan_reductions()
+{
+    use ndarray::s;
+
+    let nan = f64::NAN;
+    let a = array![[1., nan, 3.], [nan, nan, -2.]];
+    assert_eq!(a.nansum(), 2.);
+    assert_eq!(a.nanmean(), 2. / 3.);
+    assert_eq!(a.nanmin(), -2.);
+    assert_eq!(a.nanmax(), 3.);
+    assert_eq!(a.t().nansum(), 2.);
+    assert_eq!(a.slice(s![.., ..;2]).nanmax(), 3.);
+
+    let all_nan = Array2::from_elem((2, 2), nan);
+    assert_eq!(all_nan.nansum(), 0.);
+    assert!(all_nan.nanmean().is_nan());
+    assert!(all_nan.nanmin().is_nan());
+    assert!(all_nan.nanmax().is_nan());
+
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(empty.nansum(), 0.);
+    assert!(empty.nanmean().is_nan());
+    assert!(empty.nanmin().is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nansum_contiguous_matches_loop()
+{
+    let a = Array::from_shape_fn(37, |i| if i % 5 == 0 { f64::NAN } else { i as f64 });
+    let expected: f64 = a.iter().filter(|x| !x.is_nan()).sum();
+    assert_eq!(a.nansum(), expected);
+
+    // inf + -inf in different unrolled lanes must still produce NaN
+    let b = array![f64::INFINITY, f64::NEG_INFINITY, 0., 0., 0., 0., 0., 0.];
+    assert!(b.nansum().is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nan_reductions_axis()
+{
+    use ndarray::ShapeBuilder;
+
+    let nan = f64::NAN;
+    let a = array![[1., nan, 3.], [4., nan, nan]];
+    let mut f = Array2::zeros(a.dim().f());
+    f.assign(&a);
+    for a in [a.view(), f.view()] {
+        assert_eq!(a.nansum_axis(Axis(0)), array![5., 0., 3.]);
+        assert_eq!(a.nansum_axis(Axis(1)), array![4., 4.]);
+
+        let mean = a.nanmean_axis(Axis(0));
+        assert_eq!(mean[0], 2.5);
+        assert!(mean[1].is_nan());
+        assert_eq!(mean[2], 3.);
+        assert_eq!(a.nanmean_axis(Axis(1)), array![2., 4.]);
+
+        let min = a.nanmin_axis(Axis(0));
+        assert_eq!(min[0], 1.);
+        assert!(min[1].is_nan());
+        assert_eq!(min[2], 3.);
+        assert_eq!(a.nanmin_axis(Axis(1)), array![1., 4.]);
+
+        let max = a.nanmax_axis(Axis(0));
+        assert_eq!(max[0], 4.);
+        assert!(max[1].is_nan());
+        assert_eq!(max[2], 3.);
+        assert_eq!(a.nanmax_axis(Axis(1)), array![3., 4.]);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nan_mask_and_replace()
+{
+    let a = array![1., f64::NAN, 3.];
+    assert_eq!(a.is_nan(), array![false, true, false]);
+    assert_eq!(a.nan_to_num(-1.), array![1., -1., 3.]);
+}
+
+#[test]
+#[cfg(all(feature = "approx", feature = "std"))]
+fn running_stats_matches_batch()
+{
+    use ndarray::{stack, RunningStats};
+
+    let samples: Vec<Array2<f64>> = (0..7)
+        .map(|k| Array2::from_shape_fn((2, 3), |(i, j)| ((k * 7 + i * 3 + j) % 5) as f64 * 1.5 - k as f64))
+        .collect();
+    let views: Vec<_> = samples.iter().map(|a| a.view()).collect();
+    let batch = stack(Axis(0), &views).unwrap();
+
+    let mut stats = RunningStats::new((2, 3));
+    assert_eq!(stats.mean(), None);
+    for (k, sample) in samples.iter().enumerate() {
+        // mix layouts: every other sample is pushed as a transposed-back view
+        if k % 2 == 0 {
+            stats.push(sample).unwrap();
+        } else {
+            let f = sample.t().to_owned();
+            stats.push(&f.t()).unwrap();
+        }
+    }
+    assert_eq!(stats.count(), 7);
+    assert_abs_diff_eq!(stats.mean().unwrap(), batch.mean_axis(Axis(0)).unwrap(), epsilon = 1e-12);
+    for &ddof in &[0., 1.] {
+        assert_abs_diff_eq!(stats.var(ddof), batch.var_axis(Axis(0), ddof), epsilon = 1e-12);
+        assert_abs_diff_eq!(stats.std(ddof), batch.std_axis(Axis(0), ddof), epsilon = 1e-12);
+    }
+
+    // split the samples between two workers and merge
+    let mut left = RunningStats::new((2, 3));
+    let mut right = RunningStats::new((2, 3));
+    for sample in &samples[..3] {
+        left.push(sample).unwrap();
+    }
+    for sample in &samples[3..] {
+        right.push(sample).unwrap();
+    }
+    left.merge(&right).unwrap();
+    assert_eq!(left.count(), 7);
+    assert_abs_diff_eq!(left.mean().unwrap(), batch.mean_axis(Axis(0)).unwrap(), epsilon = 1e-12);
+    assert_abs_diff_eq!(left.var(1.), batch.var_axis(Axis(0), 1.), epsilon = 1e-12);
+
+    let mut empty = RunningStats::new((2, 3));
+    empty.merge(&left).unwrap();
+    assert_abs_diff_eq!(empty.var(0.), left.var(0.), epsilon = 1e-12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn running_stats_shape_mismatch()
+{
+    use ndarray::{ErrorKind, RunningStats};
+
+    let mut stats = RunningStats::new((2, 3));
+    let err = stats.push(&Array2::<f64>::zeros((3, 2))).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(stats.count(), 0);
+    let other = RunningStats::new((2, 2));
+    assert_eq!(stats.merge(&other).unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    assert!(stats.var(0.).iter().all(|x| x.is_nan()));
+    assert!(stats.var(1.).iter().all(|x| x.is_nan()));
+    assert!(stats.std(1.).iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn weighted_reductions()
+{
+    use ndarray::s;
+
+    let a = Array2::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as f64 - 7.);
+    let backing = Array1::from_shape_fn(10, |i| i as f64 - 2.5);
+    // strided weights, including negative entries
+    let w0 = backing.slice(s![..8;2]);
+    let w1 = backing.slice(s![..;2]);
+    // column-major data takes the other code path in each direction
+    let f = a.t().to_owned();
+    for a in [a.view(), f.t()] {
+        let mut expected0 = Array1::zeros(5);
+        for i in 0..4 {
+            for j in 0..5 {
+                expected0[j] += a[[i, j]] * w0[i];
+            }
+        }
+        assert_eq!(a.weighted_sum_axis(Axis(0), &w0), expected0);
+        assert_eq!(a.weighted_mean_axis(Axis(0), &w0), Some(&expected0 / w0.sum()));
+
+        let mut expected1 = Array1::zeros(4);
+        for i in 0..4 {
+            for j in 0..5 {
+                expected1[i] += a[[i, j]] * w1[j];
+            }
+        }
+        assert_eq!(a.weighted_sum_axis(Axis(1), &w1), expected1);
+        assert_eq!(a.weighted_mean_axis(Axis(1), &w1), Some(&expected1 / w1.sum()));
+    }
+
+    let weights = Array2::from_shape_fn((4, 5), |(i, j)| (i + 2 * j) as f64 - 3.);
+    let mut sum = 0.;
+    let mut weight_sum = 0.;
+    for (x, w) in a.iter().zip(&weights) {
+        sum += x * w;
+        weight_sum += w;
+    }
+    assert_eq!(a.weighted_mean(&weights), Some(sum / weight_sum));
+    assert_eq!(a.weighted_mean(&weights.t().to_owned().t()), Some(sum / weight_sum));
+}
+
+#[test]
+fn weighted_reductions_zero_weight_sum()
+{
+    let a = array![[1., 2.], [3., 4.]];
+    assert_eq!(a.weighted_mean(&array![[1., -1.], [2., -2.]]), None);
+    assert_eq!(a.weighted_mean_axis(Axis(0), &array![0., 0.]), None);
+    assert_eq!(a.weighted_sum_axis(Axis(0), &array![0., 0.]), array![0., 0.]);
+
+    let empty = Array2::<f64>::zeros((0, 3));
+    assert_eq!(empty.weighted_mean(&empty), None);
+    assert_eq!(empty.weighted_sum_axis(Axis(0), &array![]), array![0., 0., 0.]);
+    assert_eq!(empty.weighted_mean_axis(Axis(0), &array![]), None);
+}
+
+#[test]
+#[should_panic]
+fn weighted_sum_axis_wrong_length()
+{
+    let a = array![[1., 2.], [3., 4.]];
+    a.weighted_sum_axis(Axis(1), &array![1., 2., 3.]);
+}
+
+#[test]
+fn cast_checked()
+{
+    let a = array![[1.5, 2.], [255., 256.]];
+    let err = a.cast::<u8>().unwrap_err();
+    assert_eq!(err.index(), &[1, 1]);
+    assert_eq!(a.column(0).cast::<u8>().unwrap(), array![1, 255]);
+
+    let b = array![0., f64::NAN, -5.];
+    assert_eq!(b.cast::<u8>().unwrap_err().index(), &[1]);
+    assert_eq!(b.cast::<i32>().unwrap_err().index(), &[1]);
+    assert_eq!(array![0., -5.].cast::<i32>().unwrap(), array![0, -5]);
+    assert_eq!(array![-1i64, 2].cast::<u32>().unwrap_err().index(), &[0]);
+
+    // the error index is the first failure in logical order, also for
+    // arrays in another memory order
+    let c = array![[0., 1000.], [1000., 0.]].reversed_axes();
+    assert_eq!(c.cast::<u8>().unwrap_err().index(), &[0, 1]);
+    let d = Array::from_shape_fn((4, 6), |(i, j)| (i * 6 + j) as f64 * 20.);
+    let strided = d.slice(s![..;-1, ..;2]);
+    assert_eq!(strided.cast::<u8>().unwrap_err().index(), &[0, 0]);
+    assert_eq!(
+        d.slice(s![..;2, ..;3]).cast::<u16>().unwrap(),
+        array![[0, 60], [240, 300]]
+    );
+}
+
+#[test]
+fn cast_checked_drops_converted_elements()
+{
+    use num_traits::{NumCast, ToPrimitive};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+    // a number type that owns an allocation, and counts its live values
+    #[derive(Debug)]
+    struct Counted(Box<u8>);
+
+    impl NumCast for Counted
+    {
+        fn from<T: ToPrimitive>(n: T) -> Option<Self>
+        {
+            let value = n.to_u8()?;
+            LIVE.fetch_add(1, Ordering::SeqCst);
+            Some(Counted(Box::new(value)))
+        }
+    }
+
+    impl ToPrimitive for Counted
+    {
+        fn to_i64(&self) -> Option<i64>
+        {
+            Some(*self.0 as i64)
+        }
+
+        fn to_u64(&self) -> Option<u64>
+        {
+            Some(*self.0 as u64)
+        }
+    }
+
+    impl Drop for Counted
+    {
+        fn drop(&mut self)
+        {
+            LIVE.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    // the elements converted before the failure are dropped
+    let a = array![[1., 2.], [3., 300.]];
+    assert_eq!(a.cast::<Counted>().unwrap_err().index(), &[1, 1]);
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+    assert_eq!(a.t().cast::<Counted>().unwrap_err().index(), &[1, 1]);
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+
+    let b = a.slice(s![.., 0]).cast::<Counted>().unwrap();
+    assert_eq!(LIVE.load(Ordering::SeqCst), 2);
+    assert_eq!(b.map(|x| *x.0), array![1, 3]);
+    drop(b);
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn cast_identity()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f32 - 5.5);
+    assert_eq!(a.cast::<f32>().unwrap(), a);
+    assert_eq!(a.t().cast::<f32>().unwrap(), a.t());
+
+    // an identity cast of an owned array reuses its allocation
+    let ptr = a.as_ptr();
+    let b = a.cast_into::<f32>().unwrap();
+    assert_eq!(b.as_ptr(), ptr);
+    assert_eq!(b.cast_into::<f64>().unwrap()[[0, 0]], -5.5);
+}
+
+#[test]
+fn cast_saturating_and_lossy()
+{
+    let a = array![-3.5, 1.5, 300., f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+    assert_eq!(a.cast_saturating::<u8>(), array![0, 1, 255, 0, 255, 0]);
+    assert_eq!(a.cast_saturating::<i8>(), array![-3, 1, 127, 0, 127, -128]);
+    assert_eq!(a.cast_lossy::<u8>(), array![0, 1, 255, 0, 255, 0]);
+    assert_eq!(array![-1i32, 256, 7].cast_lossy::<u8>(), array![255, 0, 7]);
+    assert_eq!(array![1e300].cast_lossy::<f32>(), array![f32::INFINITY]);
+    assert_eq!(array![-1i64, 5].cast_saturating::<u32>(), array![0, 5]);
+}
+
+#[test]
+fn integer_saturating_and_wrapping()
+{
+    let a = array![[250u8, 128], [5, 0]];
+    let b = array![10u8, 200];
+    assert_eq!(a.saturating_add(&b), array![[255, 255], [15, 200]]);
+    assert_eq!(a.saturating_sub(&b), array![[240, 0], [0, 0]]);
+    assert_eq!(a.saturating_mul(&b), array![[255, 255], [50, 0]]);
+    assert_eq!(a.wrapping_add(&b), array![[4, 72], [15, 200]]);
+    assert_eq!(a.wrapping_sub(&b), array![[240, 184], [251, 56]]);
+    assert_eq!(a.wrapping_mul(&b), array![[196, 0], [50, 0]]);
+
+    assert_eq!(a.saturating_add_scalar(6), array![[255, 134], [11, 6]]);
+    assert_eq!(a.saturating_sub_scalar(6), array![[244, 122], [0, 0]]);
+    assert_eq!(a.saturating_mul_scalar(2), array![[255, 255], [10, 0]]);
+    assert_eq!(a.wrapping_add_scalar(6), array![[0, 134], [11, 6]]);
+    assert_eq!(a.wrapping_sub_scalar(6), array![[244, 122], [255, 250]]);
+    assert_eq!(a.wrapping_mul_scalar(2), array![[244, 0], [10, 0]]);
+
+    let c = array![i32::MAX - 1, i32::MIN + 1, 0];
+    assert_eq!(c.saturating_add_scalar(5), array![i32::MAX, i32::MIN + 6, 5]);
+    assert_eq!(c.saturating_sub_scalar(5), array![i32::MAX - 6, i32::MIN, -5]);
+    assert_eq!(c.saturating_mul(&array![2, 2, 2]), array![i32::MAX, i32::MIN, 0]);
+    assert_eq!(c.wrapping_add_scalar(2), array![i32::MIN, i32::MIN + 3, 2]);
+    assert_eq!(c.wrapping_sub(&array![-2]), array![i32::MIN, i32::MIN + 3, 2]);
+
+    // both sides broadcast, and any memory layout works
+    let col = array![[1u8], [255]];
+    assert_eq!(col.saturating_add(&array![0u8, 1, 2]), array![[1, 2, 3], [255, 255, 255]]);
+    let t = a.t();
+    assert_eq!(t.saturating_add(&a), array![[255, 133], [133, 0]]);
+    assert_eq!(t.wrapping_add(&a), array![[244, 133], [133, 0]]);
+}
+
+#[test]
+fn integer_checked()
+{
+    let a = array![[250u8, 100], [5, 0]];
+    assert_eq!(a.checked_add_scalar(5).unwrap(), array![[255, 105], [10, 5]]);
+    assert_eq!(a.checked_add_scalar(6).unwrap_err().index(), &[0, 0]);
+    assert_eq!(a.checked_sub_scalar(1).unwrap_err().index(), &[1, 1]);
+    assert_eq!(a.checked_mul_scalar(2).unwrap_err().index(), &[0, 0]);
+    assert_eq!(a.checked_mul_scalar(1).unwrap(), a);
+    assert_eq!(a.checked_sub(&array![5u8, 0]).unwrap(), array![[245, 100], [0, 0]]);
+    assert_eq!(a.checked_sub(&array![[0u8], [6]]).unwrap_err().index(), &[1, 0]);
+    assert_eq!(a.checked_add(&array![0u8, 156]).unwrap_err().index(), &[0, 1]);
+
+    // the index of the first overflow is in logical order, for any layout
+    let t = a.t();
+    assert_eq!(t.checked_sub(&array![0u8, 1]).unwrap_err().index(), &[1, 1]);
+    let err = t.checked_add(&array![[6u8], [0]]).unwrap_err();
+    assert_eq!(err.index(), &[0, 0]);
+    assert_eq!(
+        err.to_string(),
+        "OverflowError: the result at index [0, 0] overflows the element type"
+    );
+
+    let c = array![i32::MAX - 1, i32::MIN + 1, -7];
+    assert_eq!(c.checked_add_scalar(1).unwrap(), array![i32::MAX, i32::MIN + 2, -6]);
+    assert_eq!(c.checked_add_scalar(2).unwrap_err().index(), &[0]);
+    assert_eq!(c.checked_sub_scalar(2).unwrap_err().index(), &[1]);
+    assert_eq!(c.checked_mul(&array![1, 1, i32::MAX]).unwrap_err().index(), &[2]);
+    assert_eq!(c.checked_mul(&array![-1]).unwrap(), array![-i32::MAX + 1, i32::MAX, 7]);
+}
+
+#[test]
+#[should_panic(expected = "could not broadcast [2, 2] and [3] together in 'saturating_add'")]
+fn integer_shape_mismatch()
+{
+    let _ = Array2::<u8>::zeros((2, 2)).saturating_add(&Array1::zeros(3));
+}
+
+#[cfg(feature = "std")]
+fn check_inplace(a: &Array2<f64>, f: impl Fn(&mut ndarray::ArrayViewMut2<'_, f64>), g: impl Fn(f64) -> f64)
+{
+    // strided and inverted view
+    let mut c = a.clone();
+    f(&mut c.slice_mut(s![..;-2, 1..;2]));
+    assert_eq!(c.slice(s![..;-2, 1..;2]), a.slice(s![..;-2, 1..;2]).mapv(&g));
+    assert_eq!(c.column(0), a.column(0));
+    // transposed array
+    let mut t = a.clone().reversed_axes();
+    f(&mut t.view_mut());
+    assert_eq!(t, a.t().mapv(&g));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn float_maths_match_std()
+{
+    let a = Array::from_shape_fn((5, 6), |(i, j)| 0.25 + i as f64 * 1.7 + j as f64 * 0.3);
+    let b = &a - 4.;
+    for x in [&a, &b] {
+        for v in [x.view(), x.slice(s![..;-2, 1..;2]), x.t()] {
+            assert_eq!(v.exp(), v.mapv(f64::exp));
+            assert_eq!(v.abs(), v.mapv(f64::abs));
+            assert_eq!(v.floor(), v.mapv(f64::floor));
+            assert_eq!(v.signum(), v.mapv(f64::signum));
+            assert_eq!(v.recip(), v.mapv(f64::recip));
+            assert_eq!(v.powi(3), v.mapv(|x| x.powi(3)));
+        }
+    }
+    assert_eq!(a.t().sqrt(), a.t().mapv(f64::sqrt));
+    assert_eq!(a.t().powf(0.5), a.t().mapv(|x| x.powf(0.5)));
+
+    check_inplace(&a, |w| w.exp_inplace(), f64::exp);
+    check_inplace(&a, |w| w.ln_inplace(), f64::ln);
+    check_inplace(&a, |w| w.log2_inplace(), f64::log2);
+    check_inplace(&a, |w| w.log10_inplace(), f64::log10);
+    check_inplace(&a, |w| w.sqrt_inplace(), f64::sqrt);
+    check_inplace(&a, |w| w.powf_inplace(1.5), |x| x.powf(1.5));
+    check_inplace(&b, |w| w.abs_inplace(), f64::abs);
+    check_inplace(&b, |w| w.recip_inplace(), f64::recip);
+    check_inplace(&b, |w| w.floor_inplace(), f64::floor);
+    check_inplace(&b, |w| w.ceil_inplace(), f64::ceil);
+    check_inplace(&b, |w| w.round_inplace(), f64::round);
+    check_inplace(&b, |w| w.signum_inplace(), f64::signum);
+    check_inplace(&b, |w| w.powi_inplace(3), |x| x.powi(3));
+}
+
+#[test]
+fn min_max_axis()
+{
+    let a = Array::from_shape_fn((4, 5), |(i, j)| ((i * 7 + j * 3) % 11) as i32);
+    let naive_min = |axis: Axis| a.map_axis(axis, |lane| *lane.iter().min().unwrap());
+    let naive_max = |axis: Axis| a.map_axis(axis, |lane| *lane.iter().max().unwrap());
+    for &axis in &[Axis(0), Axis(1)] {
+        assert_eq!(a.min_axis(axis).unwrap(), naive_min(axis));
+        assert_eq!(a.max_axis(axis).unwrap(), naive_max(axis));
+        // strided and column major inputs
+        let mut f = Array::zeros(a.raw_dim().f());
+        f.assign(&a);
+        assert_eq!(f.min_axis(axis).unwrap(), naive_min(axis));
+        assert_eq!(f.max_axis(axis).unwrap(), naive_max(axis));
+    }
+    let s = a.slice(s![..;-2, 1..;2]);
+    assert_eq!(s.max_axis(Axis(0)).unwrap(), array![a[[3, 1]].max(a[[1, 1]]), a[[3, 3]].max(a[[1, 3]])]);
+
+    // empty axes
+    let e = Array2::<f64>::zeros((0, 3));
+    assert_eq!(e.min_axis(Axis(0)), None);
+    assert_eq!(e.max_axis(Axis(1)), Some(Array1::zeros(0)));
+}
+
+#[test]
+fn min_max_axis_nan()
+{
+    let a = array![[1., f64::NAN, 3.], [4., 2., f64::NAN], [0., 5., 6.]];
+    let mut f = Array::zeros(a.raw_dim().f());
+    f.assign(&a);
+    for a in [a, f] {
+        let min = a.min_axis(Axis(0)).unwrap();
+        assert_eq!(min[0], 0.);
+        assert!(min[1].is_nan() && min[2].is_nan());
+        let max = a.max_axis(Axis(1)).unwrap();
+        assert!(max[0].is_nan() && max[1].is_nan());
+        assert_eq!(max[2], 6.);
+    }
+}
+
+#[test]
+fn maximum_minimum()
+{
+    let a = array![[-1., 2., 5.], [3., -4., f64::NAN]];
+    let b = array![[0., 3., 1.], [f64::NAN, -5., 0.]];
+    let max = a.maximum(&b);
+    assert_eq!(max.slice(s![0, ..]), array![0., 3., 5.]);
+    assert!(max[[1, 0]].is_nan() && max[[1, 2]].is_nan());
+    assert_eq!(max[[1, 1]], -4.);
+    let min = a.minimum(&b);
+    assert_eq!(min.slice(s![0, ..]), array![-1., 2., 1.]);
+    assert_eq!(min[[1, 1]], -5.);
+    assert!(min[[1, 0]].is_nan() && min[[1, 2]].is_nan());
+
+    // broadcast a row and a scalar against the matrix
+    let m = Array::from_shape_fn((3, 4), |(i, j)| i as i32 * 4 + j as i32 - 6);
+    let row = array![0, 1, -10, 10];
+    assert_eq!(m.maximum(&row), Array::from_shape_fn((3, 4), |(i, j)| m[[i, j]].max(row[j])));
+    assert_eq!(m.minimum(&arr0(0)), m.mapv(|x| x.min(0)));
+
+    // strided inputs
+    let s = m.slice(s![..;2, ..;-1]);
+    let t = m.slice(s![1.., ..;-1]);
+    assert_eq!(s.maximum(&t), Array::from_shape_fn((2, 4), |(i, j)| s[[i, j]].max(t[[i, j]])));
+}
+
+#[test]
+#[should_panic(expected = "could not broadcast [3] to [2, 4] in 'maximum'")]
+fn maximum_broadcast_mismatch()
+{
+    let _ = Array2::<f64>::zeros((2, 4)).maximum(&Array1::zeros(3));
+}
+
+#[test]
+fn sum_by_key_axis_matches_map()
+{
+    use std::collections::HashMap;
+
+    let mut state = 12345u64;
+    let mut next = |n: u64| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % n) as usize
+    };
+    let a = Array::from_shape_simple_fn((40, 3, 5), || next(100) as i64 - 50);
+    // group 2 receives no subviews
+    let n_groups = 6;
+    for &axis in &[Axis(0), Axis(1), Axis(2)] {
+        let keys: Array1<usize> = (0..a.len_of(axis))
+            .map(|_| [0, 1, 3, 4, 5][next(5)])
+            .collect();
+
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (i, &key) in keys.iter().enumerate() {
+            groups.entry(key).or_default().push(i);
+        }
+        let sums = a.sum_by_key_axis(axis, &keys, n_groups).unwrap();
+        let mut expected_shape = a.shape().to_vec();
+        expected_shape[axis.index()] = n_groups;
+        assert_eq!(sums.shape(), &expected_shape[..]);
+        for g in 0..n_groups {
+            let expected = match groups.get(&g) {
+                Some(indices) => a.select(axis, indices).sum_axis(axis),
+                None => a.index_axis(axis, 0).mapv(|_| 0),
+            };
+            assert_eq!(sums.index_axis(axis, g), expected);
+        }
+
+        let counts = a
+            .fold_by_key_axis(axis, &keys, n_groups, 0, |&n, _| n + 1)
+            .unwrap();
+        for g in 0..n_groups {
+            let count = groups.get(&g).map_or(0, |indices| indices.len());
+            assert!(counts.index_axis(axis, g).iter().all(|&n| n == count));
+        }
+    }
+}
+
+#[test]
+fn fold_by_key_axis_errors()
+{
+    let a = Array2::<f64>::ones((3, 2));
+    let err = a.sum_by_key_axis(Axis(0), &array![0, 1], 2).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::IncompatibleShape);
+    let err = a.sum_by_key_axis(Axis(0), &array![0, 2, 3], 2).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::OutOfBounds);
+    let message = err.to_string();
+    assert!(message.contains("the key 2 at index 1 is not less than the number of groups 2"), "{}", message);
+    let err = a
+        .sum_by_key_axis(Axis(1), &array![0, 1], usize::MAX)
+        .unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::Overflow);
+    let empty = a.sum_by_key_axis(Axis(1), &array![1, 1], 0);
+    assert!(empty.is_err());
+}
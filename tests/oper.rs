@@ -2,11 +2,12 @@
     clippy::many_single_char_names, clippy::deref_addrof, clippy::unreadable_literal, clippy::many_single_char_names
 )]
 #![cfg(feature = "std")]
+use ndarray::linalg::batch_mat_mul;
 use ndarray::linalg::general_mat_mul;
 use ndarray::linalg::kron;
 use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
-use ndarray::{Data, LinalgScalar};
+use ndarray::{Data, ErrorKind, LinalgScalar};
 use ndarray::{Ix, Ixs};
 use num_traits::Zero;
 
@@ -206,6 +207,47 @@ fn dot_product_neg_stride()
     }
 }
 
+#[test]
+fn dot_product_strided()
+{
+    // integer elements, so that the unrolled kernel must match the naive loop exactly
+    let data = Array::from_iter((0..40 * 17).map(|i| (i * 37 % 101) as i64 - 50));
+    let naive = |a: ArrayView1<i64>, b: ArrayView1<i64>| a.iter().zip(&b).map(|(x, y)| x * y).sum::<i64>();
+    let mut state = 7usize;
+    let mut next = |n: usize| {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345) % (1 << 31);
+        state % n
+    };
+    for len in 0..40 {
+        for &(s1, s2) in &[(1, 17), (17, 1), (17, 17), (-1, 17), (3, -5)] {
+            let a = data.slice(s![..(len * 17); s1]);
+            let b = data.slice(s![..(len * 17); s2]);
+            let (a, b) = (a.slice(s![..len]), b.slice(s![..len]));
+            assert_eq!(a.dot(&b), naive(a, b), "len {}, strides {}, {}", len, s1, s2);
+        }
+        let (s1, s2) = (next(17) as isize + 1, next(17) as isize + 1);
+        let a = data.slice(s![next(17)..; s1]);
+        let b = data.slice(s![..; -s2]);
+        let n = len.min(a.len()).min(b.len());
+        let (a, b) = (a.slice(s![..n]), b.slice(s![..n]));
+        assert_eq!(a.dot(&b), naive(a, b), "len {}, strides {}, {}", n, s1, -s2);
+    }
+
+    // matrix-vector products with strided rows
+    let m = data
+        .slice(s![..17 * 36])
+        .into_shape_with_order((17, 36))
+        .unwrap();
+    let x = Array::from_iter((0..17).map(|i| i as i64 - 8));
+    let expected = Array::from_iter(m.columns().into_iter().map(|col| naive(col, x.view())));
+    assert_eq!(m.t().dot(&x), expected);
+    assert_eq!(x.dot(&m), expected);
+    let ms = m.slice(s![..;2, ..;-3]);
+    let xs = x.slice(s![..;2]);
+    let expected = Array::from_iter(ms.columns().into_iter().map(|col| naive(col, xs)));
+    assert_eq!(ms.t().dot(&xs), expected);
+}
+
 #[test]
 fn fold_and_sum()
 {
@@ -686,6 +728,174 @@ fn gen_mat_mul_i32()
     }
 }
 
+// small integers in f64, so that the products are exact
+fn pseudo_random_mat3(shape: (Ix, Ix, Ix), seed: u64) -> Array3<f64>
+{
+    let mut state = seed;
+    Array::from_shape_simple_fn(shape, || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 59) as f64) - 16.
+    })
+}
+
+#[test]
+fn batch_mat_mul_matches_loop()
+{
+    let shapes = [
+        (3, 3, 4, 5, 6),
+        (1, 4, 3, 2, 5),
+        (4, 1, 3, 2, 5),
+        (1, 1, 7, 8, 9),
+        (5, 5, 17, 15, 16),
+        (0, 0, 2, 3, 4),
+        (0, 1, 2, 3, 4),
+        (2, 2, 0, 3, 4),
+        (2, 2, 3, 0, 4),
+    ];
+    for (seed, &(batch_a, batch_b, m, k, n)) in shapes.iter().enumerate() {
+        let a = pseudo_random_mat3((batch_a, m, k), seed as u64);
+        let b = pseudo_random_mat3((batch_b, k, n), !(seed as u64));
+        let batch = if batch_a == 1 { batch_b } else { batch_a };
+
+        let c = batch_mat_mul(&a, &b);
+        assert_eq!(c.dim(), (batch, m, n));
+        for i in 0..batch {
+            let ai = a.index_axis(Axis(0), if batch_a == 1 { 0 } else { i });
+            let bi = b.index_axis(Axis(0), if batch_b == 1 { 0 } else { i });
+            assert_eq!(c.index_axis(Axis(0), i), reference_mat_mul(&ai, &bi));
+        }
+
+        // operands with transposed matrices
+        let at = a.view().permuted_axes([0, 2, 1]).to_owned();
+        let at = at.view().permuted_axes([0, 2, 1]);
+        let bt = b.view().permuted_axes([0, 2, 1]).to_owned();
+        let bt = bt.view().permuted_axes([0, 2, 1]);
+        assert_eq!(batch_mat_mul(&at, &bt), c);
+    }
+}
+
+#[test]
+#[should_panic(expected = "inputs of shape [3, 2, 2] and [2, 2, 2] are not compatible")]
+fn batch_mat_mul_batch_mismatch()
+{
+    let a = Array3::<f64>::zeros((3, 2, 2));
+    let b = Array3::<f64>::zeros((2, 2, 2));
+    let _ = batch_mat_mul(&a, &b);
+}
+
+#[test]
+#[should_panic(expected = "inputs of shape [2, 2, 3] and [2, 2, 2] are not compatible")]
+fn batch_mat_mul_inner_mismatch()
+{
+    let a = Array3::<f64>::zeros((2, 2, 3));
+    let b = Array3::<f64>::zeros((2, 2, 2));
+    let _ = batch_mat_mul(&a, &b);
+}
+
+#[test]
+fn dot_into_reuses_buffer()
+{
+    let (m, k, n) = (5, 7, 4);
+    // output buffers: standard layout, column major, and strided with a
+    // negative stride
+    let mut c = Array2::<f64>::zeros((m, n));
+    let mut c_f = Array2::<f64>::zeros((m, n).f());
+    let mut big = Array2::<f64>::zeros((3 * k, 3 * n));
+    let mut y = Array1::<f64>::zeros(m);
+    let mut big_y = Array1::<f64>::zeros(3 * n);
+    for seed in 0..20 {
+        let a = pseudo_random_mat3((1, m, k), seed).index_axis_move(Axis(0), 0);
+        let b = pseudo_random_mat3((1, k, n), !seed).index_axis_move(Axis(0), 0);
+        let x = b.column(0).to_owned();
+        let v = a.row(0).to_owned();
+
+        a.dot_into(&b, &mut c);
+        assert_eq!(c, a.dot(&b));
+        a.dot_into(&b, &mut c_f);
+        assert_eq!(c_f, a.dot(&b));
+        a.t()
+            .dot_into(&a, &mut big.slice_mut(s![..2 * k;2, ..k]).reversed_axes());
+        assert_eq!(big.slice(s![..2 * k;2, ..k]).t(), a.t().dot(&a));
+        let mut c_strided = big.slice_mut(s![..2 * m;2, ..;-3]);
+        a.dot_into(&b, &mut c_strided);
+        assert_eq!(c_strided, a.dot(&b));
+
+        a.dot_into(&x, &mut y);
+        assert_eq!(y, a.dot(&x));
+        let mut y_strided = big_y.slice_mut(s![..;-3]);
+        v.dot_into(&b, &mut y_strided);
+        assert_eq!(y_strided, v.dot(&b));
+    }
+
+    // integers use the generic kernels
+    let a = range_i32(4, 3);
+    let b = range_i32(3, 5);
+    let mut c = Array2::from_elem((4, 5), -1);
+    for _ in 0..3 {
+        a.dot_into(&b, &mut c.view_mut().reversed_axes().reversed_axes());
+        assert_eq!(c, a.dot(&b));
+    }
+}
+
+#[test]
+fn try_dot_into_shape_errors()
+{
+    let a = range_mat64(2, 3);
+    let mut c = Array2::from_elem((2, 2), 7.);
+    let err = a.try_dot_into(&range_mat64(2, 2), &mut c).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = a.try_dot_into(&range_mat64(3, 3), &mut c).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(c, Array2::from_elem((2, 2), 7.));
+
+    let mut y = Array1::zeros(3);
+    let err = a
+        .try_dot_into(&Array::linspace(0., 2., 3), &mut y)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = Array::linspace(0., 2., 3)
+        .try_dot_into(&a, &mut y)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+}
+
+#[test]
+fn try_dot_into_rejects_aliasing()
+{
+    let mut data = vec![1., 2., 3., 4., 5., 6.];
+    // views of the same memory can not be made in safe code; the overlap is
+    // rejected before any element is read or written
+    let ptr = data.as_mut_ptr();
+    let a = unsafe { ArrayView2::from_shape_ptr((2, 2), ptr) };
+    let mut out = unsafe { ArrayViewMut2::from_shape_ptr((2, 2), ptr.add(2)) };
+    let b = arr2(&[[1., 0.], [0., 1.]]);
+    let err = a.try_dot_into(&b, &mut out).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    let err = b.try_dot_into(&a, &mut out).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+    let x = unsafe { ArrayView1::from_shape_ptr(2, ptr.add(3)) };
+    let mut y = unsafe { ArrayViewMut1::from_shape_ptr(2, ptr.add(4)) };
+    let err = b.try_dot_into(&x, &mut y).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+    // adjacent, but not overlapping
+    let mut y = unsafe { ArrayViewMut1::from_shape_ptr(2, ptr) };
+    b.dot_into(&x, &mut y);
+    assert_eq!(data, [4., 5., 3., 4., 5., 6.]);
+}
+
+#[test]
+#[should_panic(expected = "IncompatibleShape")]
+fn dot_into_shape_mismatch()
+{
+    let a = range_mat64(2, 3);
+    let mut c = Array2::zeros((2, 3));
+    a.dot_into(&range_mat64(3, 2), &mut c);
+}
+
 #[cfg(feature = "approx")]
 #[test]
 fn gen_mat_vec_mul()
@@ -755,6 +965,48 @@ fn gen_mat_vec_mul()
     }
 }
 
+#[cfg(feature = "approx")]
+#[test]
+fn gen_mat_vec_mul_layouts_beta_zero()
+{
+    use ndarray::linalg::general_mat_vec_mul;
+
+    let c = range_mat64(7, 5);
+    let mut f = Array2::zeros((7, 5).f());
+    f.assign(&c);
+    assert!(f.t().is_standard_layout());
+    let x = range1_mat64(5);
+    let expected = c.dot(&x);
+    for a in [c.view(), f.view()] {
+        assert_eq!(a.dot(&x), expected);
+        // beta zero must overwrite, not scale, the previous contents of y
+        let mut y = Array1::from_elem(7, f64::NAN);
+        general_mat_vec_mul(1., &a, &x, 0., &mut y);
+        assert_eq!(y, expected);
+        let mut y = Array1::ones(7);
+        general_mat_vec_mul(2., &a, &x, -1., &mut y);
+        assert_eq!(y, &expected * 2. - 1.);
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn try_gen_mat_vec_mul_shape_error()
+{
+    use ndarray::linalg::try_general_mat_vec_mul;
+    use ndarray::ErrorKind;
+
+    let a = range_mat64(3, 4);
+    let mut y = Array1::zeros(3);
+    let err = try_general_mat_vec_mul(1., &a, &range1_mat64(3), 0., &mut y).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let mut y2 = Array1::zeros(4);
+    let err = try_general_mat_vec_mul(1., &a, &range1_mat64(4), 0., &mut y2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    try_general_mat_vec_mul(1., &a, &range1_mat64(4), 0., &mut y).unwrap();
+    assert_eq!(y, a.dot(&range1_mat64(4)));
+}
+
 #[cfg(feature = "approx")]
 #[test]
 fn vec_mat_mul()
@@ -877,3 +1129,179 @@ fn kron_i64()
     let r = arr2(&[[0, 1, 0, 0], [1, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 1], [0, 0, 1, 0]]);
     assert_eq!(kron(&a, &b), r);
 }
+
+#[cfg(feature = "approx")]
+#[test]
+fn solve_triangular_multiplies_back()
+{
+    use ndarray::linalg::{Diag, UpLo};
+
+    let n = 7;
+    // well conditioned: a dominant diagonal, and junk in the other triangle
+    let a = Array::from_shape_fn((n, n), |(i, j)| {
+        if i == j {
+            n as f64 + i as f64
+        } else {
+            ((i * 3 + j * 5) % 7) as f64 - 3.
+        }
+    });
+    let b = Array::from_shape_fn((n, 3).f(), |(i, j)| (i as f64).sin() + j as f64);
+    for &uplo in &[UpLo::Lower, UpLo::Upper] {
+        let mut t = match uplo {
+            UpLo::Lower => Array::from_shape_fn((n, n), |(i, j)| if i >= j { a[[i, j]] } else { 0. }),
+            UpLo::Upper => Array::from_shape_fn((n, n), |(i, j)| if i <= j { a[[i, j]] } else { 0. }),
+        };
+        let x = a.solve_triangular(&b, uplo, Diag::NonUnit).unwrap();
+        assert_abs_diff_eq!(t.dot(&x), b, epsilon = 1e-12);
+        let x1 = a
+            .solve_triangular(&b.column(1), uplo, Diag::NonUnit)
+            .unwrap();
+        assert_eq!(x1, x.column(1));
+        assert_abs_diff_eq!(t.dot(&x1), b.column(1), epsilon = 1e-12);
+
+        t.diag_mut().fill(1.);
+        let x = a.solve_triangular(&b, uplo, Diag::Unit).unwrap();
+        assert_abs_diff_eq!(t.dot(&x), b, epsilon = 1e-12);
+    }
+
+    // a transposed view solves with the other triangle of the array
+    let x = a
+        .t()
+        .solve_triangular(&b, UpLo::Upper, Diag::NonUnit)
+        .unwrap();
+    let t = Array::from_shape_fn((n, n), |(i, j)| if i <= j { a[[j, i]] } else { 0. });
+    assert_abs_diff_eq!(t.dot(&x), b, epsilon = 1e-12);
+
+    let empty = Array2::<f64>::zeros((0, 0));
+    assert_eq!(empty.solve_triangular(&Array1::zeros(0), UpLo::Lower, Diag::NonUnit).unwrap().len(), 0);
+}
+
+#[test]
+fn solve_triangular_singular()
+{
+    use ndarray::linalg::{Diag, UpLo};
+
+    let a = arr2(&[[1., 0., 0.], [2., 0., 0.], [3., 4., 0.]]);
+    let err = a
+        .solve_triangular(&arr1(&[1., 2., 3.]), UpLo::Lower, Diag::NonUnit)
+        .unwrap_err();
+    assert_eq!(err.index(), 1);
+    assert_eq!(err.to_string(), "SingularError: the diagonal element at index 1 is zero");
+    let x = a
+        .solve_triangular(&arr1(&[1., 2., 3.]), UpLo::Lower, Diag::Unit)
+        .unwrap();
+    assert_eq!(x, arr1(&[1., 0., 0.]));
+}
+
+#[test]
+#[should_panic(expected = "needs a square matrix")]
+fn solve_triangular_not_square()
+{
+    use ndarray::linalg::{Diag, UpLo};
+
+    let a = Array2::<f64>::eye(3);
+    let _ = a
+        .slice(s![..2, ..])
+        .solve_triangular(&arr1(&[1., 2.]), UpLo::Lower, Diag::NonUnit);
+}
+
+#[test]
+fn outer_and_rank1_update()
+{
+    use ndarray::linalg::{outer, rank1_update};
+
+    let x = Array::from_iter((0..5).map(|i| i * 3 - 4));
+    let y = Array::from_iter((0..4).map(|j| 7 - j * j));
+    let naive = Array::from_shape_fn((5, 4), |(i, j)| x[i] * y[j]);
+    let p = outer(&x, &y);
+    assert_eq!(p, naive);
+    assert!(p.is_standard_layout());
+    assert_eq!(outer(&x.slice(s![..;-2]), &y), naive.slice(s![..;-2, ..]));
+
+    let start = Array::from_shape_fn((5, 4), |(i, j)| (i * 10 + j) as i32);
+    let expected = &start + &(&naive * 3);
+    let mut c = start.clone();
+    rank1_update(3, &x, &y, &mut c);
+    assert_eq!(c, expected);
+    let mut f = Array::zeros((5, 4).f());
+    f.assign(&start);
+    rank1_update(3, &x, &y, &mut f);
+    assert_eq!(f, expected);
+    assert!(f.t().is_standard_layout());
+    let mut s = start.clone();
+    rank1_update(3, &x, &y, &mut s.slice_mut(s![.., ..]));
+    assert_eq!(s, expected);
+
+    // empty vectors
+    let empty = Array1::<i32>::zeros(0);
+    assert_eq!(outer(&empty, &y).shape(), &[0, 4]);
+    assert_eq!(outer(&x, &empty).shape(), &[5, 0]);
+    let mut e = Array2::<i32>::zeros((0, 4));
+    rank1_update(1, &empty, &y, &mut e);
+    let mut e = Array2::<i32>::zeros((5, 0));
+    rank1_update(1, &x, &empty, &mut e);
+}
+
+#[test]
+#[should_panic(expected = "rank1_update of vectors of lengths 2 and 3 into a matrix of shape 3 × 2")]
+fn rank1_update_shape_mismatch()
+{
+    let mut a = Array2::<f64>::zeros((3, 2));
+    ndarray::linalg::rank1_update(1., &arr1(&[1., 2.]), &arr1(&[1., 2., 3.]), &mut a);
+}
+
+#[test]
+fn cross_products()
+{
+    use ndarray::linalg::{cross, cross_axis};
+
+    let x = array![1, 2, 3];
+    let y = array![4, 5, 6];
+    assert_eq!(cross(&x, &y), array![-3, 6, -3]);
+    assert_eq!(cross(&y, &x), array![3, -6, 3]);
+    assert_eq!(cross(&x, &x), array![0, 0, 0]);
+    assert_eq!(cross(&x.slice(s![..;-1]), &y), array![7, -14, 7]);
+
+    // vectors along axis 1, with anticommutativity and orthogonality
+    let a = Array::from_shape_fn((7, 3), |(i, j)| ((i * 5 + j * 3) % 7) as i64 - 3);
+    let b = Array::from_shape_fn((7, 3), |(i, j)| ((i * 2 + j * 5) % 11) as i64 - 5);
+    let c = cross_axis(&a, &b, Axis(1));
+    assert_eq!(c.shape(), &[7, 3]);
+    assert_eq!(cross_axis(&b, &a, Axis(1)), -&c);
+    for ((u, v), w) in a.rows().into_iter().zip(b.rows()).zip(c.rows()) {
+        assert_eq!(cross(&u, &v), w);
+        assert_eq!(u.dot(&w), 0);
+        assert_eq!(v.dot(&w), 0);
+    }
+
+    // the same vectors along axis 0 of the transposes
+    assert_eq!(cross_axis(&a.t(), &b.t(), Axis(0)), c.t());
+
+    // one operand is broadcast
+    let c = cross_axis(&a, &y, Axis(1));
+    for (u, w) in a.rows().into_iter().zip(c.rows()) {
+        assert_eq!(cross(&u, &y), w);
+    }
+    let y_col = y.view().insert_axis(Axis(1));
+    let c = cross_axis(&y_col, &a.t(), Axis(0));
+    assert_eq!(c.shape(), &[3, 7]);
+    for (u, w) in a.rows().into_iter().zip(c.columns()) {
+        assert_eq!(cross(&y, &u), w);
+    }
+}
+
+#[test]
+#[should_panic(expected = "cross product of vectors of lengths 3 and 4, but both must have length 3")]
+fn cross_wrong_length()
+{
+    ndarray::linalg::cross(&arr1(&[1., 2., 3.]), &arr1(&[1., 2., 3., 4.]));
+}
+
+#[test]
+#[should_panic(expected = "cross_axis needs length 3 along axis 0, but an operand of shape [1, 4] has length 1")]
+fn cross_axis_broadcast_vector_axis()
+{
+    let a = Array2::<f64>::zeros((3, 4));
+    let b = Array2::<f64>::zeros((1, 4));
+    ndarray::linalg::cross_axis(&a, &b, Axis(0));
+}
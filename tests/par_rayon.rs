@@ -92,3 +92,14 @@ fn test_axis_chunks_iter_mut()
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+fn test_par_batch_mat_mul()
+{
+    use ndarray::linalg::{batch_mat_mul, par_batch_mat_mul};
+    let a = Array::from_shape_fn((16, 9, 7), |(b, i, j)| (b * 63 + i * 7 + j) as f64 % 13. - 6.);
+    let b = Array::from_shape_fn((16, 7, 5), |(b, i, j)| (b * 35 + i * 5 + j) as f64 % 11. - 5.);
+    assert_eq!(par_batch_mat_mul(&a, &b), batch_mat_mul(&a, &b));
+    let b1 = b.slice(s![3..4, .., ..]);
+    assert_eq!(par_batch_mat_mul(&a, &b1), batch_mat_mul(&a, &b1));
+}
@@ -150,3 +150,88 @@ fn test_zip_assign_into()
 
     assert_abs_diff_eq!(a, &b + &c, epsilon = 1e-6);
 }
+
+#[test]
+fn test_par_fold_matches_serial()
+{
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i * 31 + j * 7) as i64 % 101 - 50);
+    let b = Array::from_shape_fn((M, N), |(i, j)| (i * 13 + j * 17) as i64 % 89 - 44);
+
+    let serial = Zip::from(&a)
+        .and(&b)
+        .fold(0, |acc, &x, &y| acc + (x - y) * (x - y));
+    let parallel = Zip::from(&a)
+        .and(&b)
+        .par_fold(|| 0, |acc, &x, &y| acc + (x - y) * (x - y), |x, y| x + y);
+    assert_eq!(parallel, serial);
+
+    // the same through transposed and strided views
+    let (at, bt) = (a.t(), b.t());
+    let parallel = Zip::from(&at)
+        .and(&bt)
+        .par_fold(|| 0, |acc, &x, &y| acc + (x - y) * (x - y), |x, y| x + y);
+    assert_eq!(parallel, serial);
+    let (a2, b2) = (a.slice(s![..;2, ..]), b.slice(s![..;2, ..]));
+    assert_eq!(
+        Zip::from(&a2)
+            .and(&b2)
+            .par_fold(|| 0, |acc, &x, &y| acc + x * y, |x, y| x + y),
+        Zip::from(&a2).and(&b2).fold(0, |acc, &x, &y| acc + x * y)
+    );
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn test_par_fold_mse()
+{
+    use approx::assert_relative_eq;
+
+    let a = Array::from_shape_fn((M, N), |(i, j)| 1. / (i + 2 * j + 1) as f64);
+    let b = Array::from_shape_fn((M, N), |(i, j)| f64::ln((1 + i + j) as f64));
+    let serial = Zip::from(&a)
+        .and(&b)
+        .fold(0., |acc, &x, &y| acc + (x - y) * (x - y))
+        / a.len() as f64;
+    let parallel = Zip::from(&a)
+        .and(&b)
+        .par_fold(|| 0., |acc, &x, &y| acc + (x - y) * (x - y), |x, y| x + y)
+        / a.len() as f64;
+    assert_relative_eq!(parallel, serial, max_relative = 1e-12);
+}
+
+#[test]
+fn test_par_zip_one_long_axis()
+{
+    // one long axis and several short ones, in both memory orders
+    for &is_f in &[false, true] {
+        let dim = (2, 1, 3, 100_000).set_f(is_f);
+        let mut a = Array::<usize, _>::zeros(dim);
+        let b = Array::from_shape_fn(dim, |(i, j, k, l)| i + j + k + l);
+
+        Zip::indexed(&mut a)
+            .and(&b)
+            .par_for_each(|(i, _, k, l), x, &y| *x = y * 4 + (i * 3 + k) * 100_000 + l);
+        let expected = Array::from_shape_fn(dim, |(i, _, k, l)| (i + k + l) * 4 + (i * 3 + k) * 100_000 + l);
+        assert_eq!(a, expected);
+
+        let sum = Zip::from(&a).par_fold(|| 0, |acc, &x| acc + x, |x, y| x + y);
+        assert_eq!(sum, expected.sum());
+    }
+}
+
+#[test]
+fn test_par_zip_with_min_len()
+{
+    use ndarray::parallel::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut a = Array2::<usize>::zeros((M, N));
+    let jobs = AtomicUsize::new(0);
+    Zip::from(a.rows_mut())
+        .into_par_iter()
+        .with_min_len(M / 4)
+        .for_each_init(|| jobs.fetch_add(1, Ordering::Relaxed), |_, (mut row,)| row.fill(1));
+    assert_eq!(a.sum(), M * N);
+    // at most four parts can be split off
+    assert!(jobs.load(Ordering::Relaxed) <= 4);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "quickcheck")]
+
+use ndarray::prelude::*;
+use quickcheck::{quickcheck, Arbitrary, Gen};
+
+quickcheck! {
+    fn sum_is_sum_of_elements(a: ArrayD<i32>) -> bool {
+        let expected = a.iter().fold(0i32, |acc, &x| acc.wrapping_add(x));
+        a.fold(0i32, |acc, &x| acc.wrapping_add(x)) == expected
+            && a.as_standard_layout().iter().fold(0i32, |acc, &x| acc.wrapping_add(x)) == expected
+    }
+
+    fn transpose_twice_is_identity(a: Array2<u8>) -> bool {
+        a.t().t() == a
+    }
+}
+
+#[test]
+fn arbitrary_arrays_are_bounded_and_varied()
+{
+    let mut g = Gen::new(20);
+    let mut non_contiguous = 0;
+    let mut ranks = [false; 5];
+    for _ in 0..500 {
+        let a = ArrayD::<u8>::arbitrary(&mut g);
+        assert!(a.len() <= 20);
+        assert!(a.ndim() <= 4);
+        ranks[a.ndim()] = true;
+        if a.as_slice_memory_order().is_none() {
+            non_contiguous += 1;
+        }
+    }
+    assert!(ranks.iter().all(|&r| r));
+    assert!(non_contiguous > 0);
+}
+
+#[test]
+fn shrink_reduces_shape_and_elements()
+{
+    let a = array![[10u32, 20, 30], [40, 50, 60]];
+    let shrunk: Vec<Array2<u32>> = a.shrink().collect();
+    assert!(shrunk.iter().any(|b| b.dim() == (1, 3)));
+    assert!(shrunk.iter().any(|b| b.dim() == (2, 1)));
+    assert!(shrunk
+        .iter()
+        .any(|b| b.dim() == (2, 3) && b[[0, 0]] < 10 && b.slice(s![.., 1..]) == a.slice(s![.., 1..])));
+    assert!(shrunk.iter().all(|b| b.len() < a.len() || b != a));
+}
+
+quickcheck! {
+    fn from_shape_vec_custom_strides(rows: u8, cols: u8, pad: (u8, u8), extra: u8, transpose: bool) -> bool {
+        use ndarray::ShapeBuilder;
+
+        let (rows, cols) = (rows as usize % 5, cols as usize % 5);
+        // the inner axis has a stride of 1 to 3, and the outer axis steps over
+        // whole inner axes plus some padding
+        let inner_len = if transpose { rows } else { cols };
+        let inner = 1 + pad.0 as usize % 3;
+        let outer = inner * inner_len.max(1) + pad.1 as usize % 3;
+        let strides = if transpose { (inner, outer) } else { (outer, inner) };
+        // an empty array may still not point past the end of the data
+        let max_offset = rows.saturating_sub(1) * strides.0 + cols.saturating_sub(1) * strides.1;
+        let needed = if rows == 0 || cols == 0 { max_offset } else { max_offset + 1 };
+        let len = needed + extra as usize % 4;
+
+        if needed > 0 && Array2::from_shape_vec((rows, cols).strides(strides), vec![0u8; needed - 1]).is_ok() {
+            return false;
+        }
+        let v: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        let base = v.as_ptr() as usize;
+        let a = Array2::from_shape_vec((rows, cols).strides(strides), v).unwrap();
+        let expected_offset = if a.is_empty() { None } else { Some(0) };
+        let addresses_match = a
+            .indexed_iter()
+            .all(|((i, j), x)| x as *const u8 as usize == base + i * strides.0 + j * strides.1);
+        let (v, offset) = a.into_raw_vec_and_offset();
+        addresses_match && v.len() == len && v.as_ptr() as usize == base && offset == expected_offset
+    }
+
+    fn from_shape_vec_fortran_order(rows: u8, cols: u8) -> bool {
+        use ndarray::ShapeBuilder;
+
+        let (rows, cols) = (rows as usize % 6, cols as usize % 6);
+        let v: Vec<u8> = (0..rows * cols).map(|i| i as u8).collect();
+        let base = v.as_ptr() as usize;
+        let a = Array2::from_shape_vec((rows, cols).f(), v).unwrap();
+        a.indexed_iter()
+            .all(|((i, j), x)| x as *const u8 as usize == base + i + j * rows)
+            && a.into_raw_vec_and_offset().0.as_ptr() as usize == base
+    }
+}
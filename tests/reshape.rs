@@ -329,3 +329,57 @@ fn into_shape_clone()
         assert_eq!(s, array![[1, 5], [2, 6], [3, 7], [4, 8]]);
     }
 }
+
+#[test]
+fn flatten_with_order()
+{
+    let c = Array::from_shape_fn((2, 3, 2), |(i, j, k)| i * 6 + j * 2 + k);
+    let mut f = Array::zeros((2, 3, 2).f());
+    f.assign(&c);
+    let row_major: Vec<usize> = (0..12).collect();
+    let column_major: Vec<usize> = f.t().iter().cloned().collect();
+    assert_ne!(row_major, column_major);
+    assert_eq!(column_major[..4], [0, 6, 2, 8]);
+
+    for a in [c.view(), f.view()] {
+        assert_eq!(a.flatten().to_vec(), row_major);
+        assert_eq!(a.flatten_with_order(Order::C).to_vec(), row_major);
+        assert_eq!(a.flatten_with_order(Order::F).to_vec(), column_major);
+        assert_eq!(a.to_vec_with_order(Order::RowMajor), row_major);
+        assert_eq!(a.to_vec_with_order(Order::ColumnMajor), column_major);
+    }
+
+    // no copy when the order matches the memory layout
+    let flat = f.flatten_with_order(Order::F);
+    assert!(flat.is_view());
+    assert_eq!(flat.as_ptr(), f.as_ptr());
+    assert!(f.flatten().is_owned());
+    let flat = c.flatten();
+    assert!(flat.is_view());
+    assert_eq!(flat.as_ptr(), c.as_ptr());
+
+    // strided and reversed views
+    let s = c.slice(s![.., ..;-2, ..]);
+    assert_eq!(s.to_vec_with_order(Order::C), vec![4, 5, 0, 1, 10, 11, 6, 7]);
+    assert_eq!(s.to_vec_with_order(Order::F), vec![4, 10, 0, 6, 5, 11, 1, 7]);
+    let r = aview1(&[1, 2, 3]).slice_move(s![..;-1]);
+    assert_eq!(r.to_vec_with_order(Order::F), vec![3, 2, 1]);
+    assert_eq!(r.flatten(), aview1(&[3, 2, 1]));
+}
+
+#[test]
+fn from_shape_vec_with_order()
+{
+    let v: Vec<usize> = (0..12).collect();
+    let ptr = v.as_ptr();
+    let f = Array::from_shape_vec_with_order((3, 4), Order::ColumnMajor, v).unwrap();
+    assert_eq!(f.as_ptr(), ptr);
+    assert_eq!(f, Array::from_shape_fn((3, 4), |(i, j)| i + j * 3));
+    assert_eq!(f.to_vec_with_order(Order::F), (0..12).collect::<Vec<_>>());
+
+    let c = Array::from_shape_vec_with_order((3, 4), Order::RowMajor, (0..12).collect()).unwrap();
+    assert_eq!(c, Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j));
+
+    let err = Array::from_shape_vec_with_order((3, 4), Order::F, vec![0; 11]).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::OutOfBounds);
+}
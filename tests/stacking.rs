@@ -1,4 +1,4 @@
-use ndarray::{arr2, arr3, aview1, aview2, concatenate, stack, Array2, Axis, ErrorKind, Ix1};
+use ndarray::{arr2, arr3, aview1, aview2, block, concatenate, stack, Array2, ArrayView2, Axis, ErrorKind, Ix1};
 
 #[test]
 fn concatenating()
@@ -53,3 +53,60 @@ fn stacking()
     let res: Result<Array2<f64>, _> = ndarray::stack::<_, Ix1>(Axis(0), &[]);
     assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
 }
+
+#[test]
+fn from_blocks()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let b = arr2(&[[7], [8]]);
+    let c = arr2(&[[9, 10, 11]]);
+    let d = arr2(&[[12]]);
+    let expected = arr2(&[[1, 2, 3, 7], [4, 5, 6, 8], [9, 10, 11, 12]]);
+    let m = Array2::from_blocks(&[[a.view(), b.view()], [c.view(), d.view()]]).unwrap();
+    assert_eq!(m, expected);
+    assert_eq!(block![[a, b], [c, d]], expected);
+    assert_eq!(block![[a.t(), c.t()]], arr2(&[[1, 4, 9], [2, 5, 10], [3, 6, 11]]));
+
+    // zero-size blocks
+    let empty_row = Array2::<i32>::zeros((0, 3));
+    let empty_corner = Array2::<i32>::zeros((0, 1));
+    let no_cols = Array2::<i32>::zeros((2, 0));
+    assert_eq!(block![[a, b], [empty_row, empty_corner]], arr2(&[[1, 2, 3, 7], [4, 5, 6, 8]]));
+    assert_eq!(block![[no_cols, a]], a);
+
+    let none: &[[ndarray::ArrayView2<i32>; 0]] = &[];
+    assert_eq!(Array2::from_blocks(none).unwrap().dim(), (0, 0));
+}
+
+#[test]
+fn from_blocks_errors()
+{
+    let a = Array2::<f32>::zeros((2, 2));
+    let b = Array2::<f32>::zeros((3, 1));
+    let c = Array2::<f32>::zeros((1, 1));
+
+    // inconsistent height in a block row
+    let err = Array2::from_blocks(&[[a.view(), b.view()]]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let message = err.to_string();
+    assert!(message.contains("the block at (0, 1) has 3 rows, but the blocks in its block row have 2"), "{}", message);
+    assert!(message.contains("from_blocks"), "{}", message);
+    // inconsistent width in a block column
+    let err = Array2::from_blocks(&[[a.view()], [c.view()]]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let message = err.to_string();
+    assert!(
+        message.contains("the block at (1, 0) has 1 columns, but the blocks in its block column have 2"),
+        "{}",
+        message
+    );
+    // ragged grid
+    let err = Array2::from_blocks(&[&[a.view(), a.view()][..], &[a.view()][..]]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let message = err.to_string();
+    assert!(message.contains("block row 1 has 1 blocks, but block row 0 has 2"), "{}", message);
+    // total height overflows usize
+    let tall = ArrayView2::<f32>::from_shape((isize::MAX as usize, 0), &[]).unwrap();
+    let res = Array2::from_blocks(&[[tall], [tall], [tall]]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Overflow);
+}
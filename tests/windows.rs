@@ -167,6 +167,29 @@ fn windows_iterator_3d_with_stride()
         ]);
 }
 
+#[test]
+fn windows_with_unit_stride_equals_windows()
+{
+    let a = Array::from_iter(0..120)
+        .into_shape_with_order((4, 5, 6))
+        .unwrap();
+    let b = a.slice(s![.., ..;-2, 1..]);
+    for v in [a.view(), b] {
+        for w in [(1, 1, 1), (2, 2, 3), (4, 1, 5), (5, 1, 1)] {
+            itertools::assert_equal(v.windows_with_stride(w, (1, 1, 1)), v.windows(w));
+        }
+    }
+}
+
+#[test]
+fn windows_with_stride_start_indices()
+{
+    let a = Array::from_shape_fn((7, 9), |(i, j)| (i, j));
+    let windows = a.windows_with_stride((3, 2), (2, 3));
+    let starts: Vec<_> = windows.into_iter().map(|w| w[[0, 0]]).collect();
+    assert_eq!(starts, [(0, 0), (0, 3), (0, 6), (2, 0), (2, 3), (2, 6), (4, 0), (4, 3), (4, 6)]);
+}
+
 #[test]
 fn test_window_zip()
 {
@@ -333,3 +356,36 @@ fn test_windows_with_stride_on_inverted_axis()
             arr2(&[[6, 5], [2, 1]]),
         ]);
 }
+
+#[test]
+fn windows_and_chunks_zip_with_output()
+{
+    use ndarray::NdProducer;
+
+    let a = Array::from_shape_fn((7, 5), |(i, j)| i * 5 + j);
+
+    let windows = a.windows((3, 2));
+    assert_eq!(windows.raw_dim(), Dim([5, 4]));
+    let mut out = Array2::zeros((5, 4));
+    Zip::indexed(&mut out)
+        .and(windows)
+        .for_each(|(i, j), o, w| *o = w.sum() - a.slice(s![i..i + 3, j..j + 2]).sum());
+    assert!(out.iter().all(|&x| x == 0));
+
+    // the remainder that does not fit in a whole chunk is skipped
+    let chunks = a.exact_chunks((3, 2));
+    assert_eq!(chunks.raw_dim(), Dim([2, 2]));
+    let mut out = Array2::zeros((2, 2));
+    Zip::from(&mut out)
+        .and(chunks)
+        .for_each(|o, c| *o = c[[0, 0]]);
+    assert_eq!(out, array![[0, 2], [15, 17]]);
+
+    // windows of a 1-D array step through the positions in memory order
+    let b = Array::from_iter(0..10);
+    let mut out = Array1::zeros(8);
+    Zip::from(&mut out)
+        .and(b.windows(3))
+        .for_each(|o, w| *o = w.sum());
+    assert_eq!(out, Array::from_iter((0..8).map(|i| 3 * i + 3)));
+}
@@ -0,0 +1,44 @@
+//! A `#![no_std]` crate using ndarray with default features disabled.
+//!
+//! Building this crate checks that the core functionality of ndarray is
+//! available without `std`; the tests call into it from a regular test
+//! harness.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use ndarray::{s, Array, Array1, Array2, ArrayView2, Axis, Ix2};
+
+/// Create a `rows` × `cols` matrix holding `0, 1, 2, ...` in row major order.
+pub fn counting_matrix(rows: usize, cols: usize) -> Array2<i64>
+{
+    Array::from_iter(0..(rows * cols) as i64)
+        .into_shape_with_order((rows, cols))
+        .unwrap()
+}
+
+/// Sum every other column of `a`, starting from the last one.
+pub fn sum_stepped_columns(a: ArrayView2<'_, i64>) -> i64
+{
+    a.slice(s![.., ..;-2]).sum()
+}
+
+/// Sum the rows of `a` after adding one to every element.
+pub fn row_sums_plus_one(a: &Array2<i64>) -> Array1<i64>
+{
+    (a + 1).sum_axis(Axis(1))
+}
+
+/// Return the elements of the transpose of `a` in logical order.
+pub fn transposed_elements(a: &Array2<i64>) -> Vec<i64>
+{
+    a.t().iter().copied().collect()
+}
+
+/// Return the shape of `a` after converting to a dynamic dimension and back.
+pub fn dyn_round_trip(a: Array2<i64>) -> (usize, usize)
+{
+    a.into_dyn().into_dimensionality::<Ix2>().unwrap().dim()
+}
@@ -0,0 +1,27 @@
+use ndarray::array;
+use nostd_tests::*;
+
+#[test]
+fn construction()
+{
+    let a = counting_matrix(2, 3);
+    assert_eq!(a, array![[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(dyn_round_trip(a), (2, 3));
+}
+
+#[test]
+fn slicing_and_sum()
+{
+    let a = counting_matrix(3, 4);
+    // columns 3 and 1
+    assert_eq!(sum_stepped_columns(a.view()), (3 + 7 + 11) + (1 + 5 + 9));
+    assert_eq!(row_sums_plus_one(&a), array![10, 26, 42]);
+    assert_eq!(a.sum(), 66);
+}
+
+#[test]
+fn iteration()
+{
+    let a = counting_matrix(2, 2);
+    assert_eq!(transposed_elements(&a), [0, 2, 1, 3]);
+}